@@ -0,0 +1,84 @@
+//! Module for the report of `--check`, which validates the input parameters and exits without computing.
+//!
+//! The report lists the quantities derived from the input parameters, e.g. `dx`, `dt` and the CFL number,
+//! and the stability of the time integration or the convergence of the iteration predicted from the known bounds, if any.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// Report of the input parameters printed by `--check`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    quantities: Vec<(String, String)>,
+    predictions: Vec<(&'static str, String)>,
+}
+
+impl Report {
+    /// Create a new empty `Report` instance.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add the derived quantity `name`, where the floating-point numbers should be formatted by the caller, e.g. `{:.10e}`.
+    pub fn quantity(mut self, name: &str, value: impl Display) -> Self {
+        self.quantities.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add the predicted stability of the time integration, e.g. `stable` or `unstable since mu > 1/2`.
+    pub fn stability(mut self, stability: impl Display) -> Self {
+        self.predictions.push(("stability", stability.to_string()));
+        self
+    }
+
+    /// Add the predicted convergence of the iteration, e.g. `convergent`.
+    pub fn convergence(mut self, convergence: impl Display) -> Self {
+        self.predictions
+            .push(("convergence", convergence.to_string()));
+        self
+    }
+
+    /// Write the report.
+    ///
+    /// # Output Format
+    /// The output is formatted as follows:
+    /// ```text
+    /// input parameters are valid
+    ///   name = value
+    ///   ...
+    ///   stability: stability
+    ///   convergence: convergence
+    /// ```
+    /// where the lines of the predictions are written only if they are added.
+    ///
+    /// # Examples
+    /// ```
+    /// use cli::check::Report;
+    ///
+    /// let report = Report::new()
+    ///     .quantity("dx", format!("{:.10e}", 0.02))
+    ///     .quantity("n_x", 100)
+    ///     .stability("stable since mu <= 1/2");
+    /// let mut outputstream: Vec<u8> = Vec::new();
+    /// report.write(&mut outputstream).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(outputstream).unwrap(),
+    ///     "input parameters are valid\n  dx = 2.0000000000e-2\n  n_x = 100\n  stability: stable since mu <= 1/2\n"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if output fails.
+    pub fn write(&self, outputstream: &mut impl Write) -> io::Result<()> {
+        writeln!(outputstream, "input parameters are valid")?;
+        for (name, value) in &self.quantities {
+            writeln!(outputstream, "  {} = {}", name, value)?;
+        }
+        for (name, prediction) in &self.predictions {
+            writeln!(outputstream, "  {}: {}", name, prediction)?;
+        }
+
+        Ok(())
+    }
+}
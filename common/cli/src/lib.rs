@@ -0,0 +1,277 @@
+//! This crate provides the command line interface shared by the examples of the packages.
+//!
+//! Every example reads the input file and writes the output files under the output directory,
+//! both of which default to the paths of the example in this repository,
+//! ```text
+//! inputs/section_*/package_name/example_name/input.yml
+//! outputs/section_*/package_name/example_name/
+//! ```
+//! and can be changed by the command line arguments, e.g.
+//! ```text
+//! cargo run --example example_name -- --input path/to/input.yml --output-dir path/to/outputs
+//! ```
+//!
+//! The secondary input parameters, e.g. `ncycle_out`, can be omitted in the input file,
+//! and the effective values of all the input parameters are printed at startup, see [write_input_params].
+//!
+//! The input parameters can be overridden without editing the input file, see [overrides], e.g.
+//! ```text
+//! cargo run --example example_name -- --set n_cfl=0.9 --set step_max=2000
+//! ```
+//!
+//! With `--check`, the input parameters are validated and the quantities derived from them are printed
+//! without computing, see [check], e.g.
+//! ```text
+//! cargo run --example example_name -- --check
+//! ```
+//!
+//! The input parameters of every package are validated by collecting all the violated constraints,
+//! see [validation].
+
+pub mod check;
+pub mod overrides;
+pub mod validation;
+
+use check::Report;
+use clap::Parser;
+use overrides::Override;
+use serde::Serialize;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Cursor, Write};
+use std::path::PathBuf;
+use std::process;
+
+/// Command line arguments of the examples.
+#[derive(Debug, Parser)]
+struct RawArgs {
+    /// Path of the input file [default: inputs/<example>/input.yml]
+    #[arg(short, long, value_name = "FILE")]
+    input: Option<PathBuf>,
+    /// Directory of the output files [default: outputs/<example>]
+    #[arg(short, long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+    /// Override an input parameter, e.g. `--set n_cfl=0.9`, which may be repeated
+    #[arg(short, long = "set", value_name = "KEY=VALUE", value_parser = Override::parse)]
+    set: Vec<Override>,
+    /// Validate the input parameters, print the derived quantities and exit without computing
+    #[arg(long)]
+    check: bool,
+}
+
+/// Command line arguments of the examples, where the defaults are filled in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecArgs {
+    /// Path of the input file.
+    pub input: PathBuf,
+    /// Directory of the output files.
+    pub output_dir: PathBuf,
+    /// Overrides of the input parameters in the order given.
+    pub overrides: Vec<Override>,
+    /// Validate the input parameters and exit without computing if `true`.
+    pub check: bool,
+}
+
+impl ExecArgs {
+    /// Read the input parameters from the input file with the overrides merged
+    /// by `read_input_params` of the package, which deserializes and validates them,
+    /// and echo their effective values to stdout by [write_input_params].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::error::Error;
+    /// use std::io::Read;
+    ///
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Params {
+    ///     n_cfl: f64,
+    /// }
+    ///
+    /// fn read_input_params(inputstream: &mut impl Read) -> Result<Params, Box<dyn Error>> {
+    ///     let mut contents = String::new();
+    ///     inputstream.read_to_string(&mut contents)?;
+    ///     let params: Params = serde_yaml::from_str(&contents)?;
+    ///     if params.n_cfl <= 0.0 {
+    ///         return Err("n_cfl must be positive".into());
+    ///     }
+    ///     Ok(params)
+    /// }
+    ///
+    /// let input = std::env::temp_dir().join("cli_doc_read_input_params.yml");
+    /// std::fs::write(&input, "n_cfl: 0.5\n").unwrap();
+    /// let input = input.to_str().unwrap();
+    ///
+    /// let exec_args = cli::try_parse_from("example", ["example", "-i", input, "--set", "n_cfl=0.9"]).unwrap();
+    /// assert_eq!(exec_args.read_input_params(read_input_params).unwrap().n_cfl, 0.9);
+    ///
+    /// let exec_args = cli::try_parse_from("example", ["example", "-i", input, "--set", "n_cfl=-1"]).unwrap();
+    /// assert!(exec_args.read_input_params(read_input_params).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the input file cannot be read, the overrides cannot be merged,
+    /// `read_input_params` fails, or any of the overridden keys is not a parameter.
+    pub fn read_input_params<T, F>(&self, read_input_params: F) -> Result<T, Box<dyn Error>>
+    where
+        T: Serialize,
+        F: FnOnce(&mut Cursor<String>) -> Result<T, Box<dyn Error>>,
+    {
+        let contents = fs::read_to_string(&self.input)
+            .map_err(|err| format!("cannot open {}: {}", self.input.display(), err))?;
+        let contents = overrides::merge(&contents, &self.overrides)?;
+
+        let input_params = read_input_params(&mut Cursor::new(contents))?;
+        overrides::check_keys(&input_params, &self.overrides)?;
+        write_input_params(&input_params, &mut io::stdout())?;
+
+        Ok(input_params)
+    }
+
+    /// Print the report created by `report` and exit successfully if `--check` is given, or do nothing otherwise.
+    ///
+    /// This should be called after the input parameters are read and validated,
+    /// and before any output file is created.
+    pub fn exit_if_check(&self, report: impl FnOnce() -> Report) {
+        if !self.check {
+            return;
+        }
+
+        report().write(&mut io::stdout()).unwrap_or_else(|err| {
+            eprintln!("Problem writing report: {}", err);
+            process::exit(1);
+        });
+        process::exit(0);
+    }
+}
+
+/// Write the effective values of the input parameters `input_params` in YAML format,
+/// where the omitted parameters are filled with their defaults.
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Params {
+///     n_cfl: f64,
+///     ncycle_out: usize,
+/// }
+///
+/// let params = Params { n_cfl: 0.5, ncycle_out: 1 };
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// cli::write_input_params(&params, &mut outputstream).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(outputstream).unwrap(),
+///     "effective input parameters:\n  n_cfl: 0.5\n  ncycle_out: 1\n"
+/// );
+/// ```
+///
+/// # Errors
+/// Returns an error if the input parameters cannot be serialized or output fails.
+pub fn write_input_params(
+    input_params: &impl Serialize,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    writeln!(outputstream, "effective input parameters:")?;
+    for line in serde_yaml::to_string(input_params)?.lines() {
+        writeln!(outputstream, "  {}", line)?;
+    }
+
+    Ok(())
+}
+
+/// Parse the command line arguments of the `example`, e.g. `section_2/parabolic/solve_diffusion_eq_by_ftcs_method`.
+///
+/// Prints the help or the error message and exits if requested or the arguments are invalid.
+pub fn parse(example: &str) -> ExecArgs {
+    fill_defaults(example, RawArgs::parse())
+}
+
+/// Parse the command line arguments `args` of the `example`, whose first item is the name of the binary.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+///
+/// let example = "section_1/bad_upwind/solve_transport_eq_by_good_upwind_method";
+/// let exec_args = cli::try_parse_from(example, ["example", "--input", "my_input.yml"]).unwrap();
+///
+/// assert_eq!(exec_args.input, PathBuf::from("my_input.yml"));
+/// assert_eq!(exec_args.output_dir, PathBuf::from(format!("outputs/{}", example)));
+/// ```
+///
+/// # Errors
+/// Returns an error if the arguments are invalid or the help or the version is requested.
+pub fn try_parse_from<I, T>(example: &str, args: I) -> Result<ExecArgs, clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    Ok(fill_defaults(example, RawArgs::try_parse_from(args)?))
+}
+
+fn fill_defaults(example: &str, raw_args: RawArgs) -> ExecArgs {
+    ExecArgs {
+        input: raw_args
+            .input
+            .unwrap_or_else(|| PathBuf::from(format!("inputs/{}/input.yml", example))),
+        output_dir: raw_args
+            .output_dir
+            .unwrap_or_else(|| PathBuf::from(format!("outputs/{}", example))),
+        overrides: raw_args.set,
+        check: raw_args.check,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_try_parse_from_works_with_defaults_and_short_options() {
+        let example = "section_2/parabolic/solve_diffusion_eq_by_ftcs_method";
+
+        // check if the defaults are the paths of the example
+        let exec_args = try_parse_from(example, ["example"]).unwrap();
+        assert_eq!(
+            exec_args,
+            ExecArgs {
+                input: PathBuf::from(
+                    "inputs/section_2/parabolic/solve_diffusion_eq_by_ftcs_method/input.yml"
+                ),
+                output_dir: PathBuf::from(
+                    "outputs/section_2/parabolic/solve_diffusion_eq_by_ftcs_method"
+                ),
+                overrides: vec![],
+                check: false,
+            }
+        );
+
+        // check if the short options override both paths
+        let exec_args = try_parse_from(example, ["example", "-i", "a.yml", "-o", "out"]).unwrap();
+        assert_eq!(exec_args.input, PathBuf::from("a.yml"));
+        assert_eq!(exec_args.output_dir, PathBuf::from("out"));
+
+        // check if the overrides are collected in order
+        let exec_args =
+            try_parse_from(example, ["example", "--set", "mu=0.4", "-s", "step_max=10"]).unwrap();
+        assert_eq!(
+            exec_args.overrides,
+            vec![
+                Override::parse("mu=0.4").unwrap(),
+                Override::parse("step_max=10").unwrap()
+            ]
+        );
+
+        // check if the check mode is enabled
+        assert!(
+            try_parse_from(example, ["example", "--check"])
+                .unwrap()
+                .check
+        );
+
+        // check if the unknown argument and the override without `=` are rejected
+        assert!(try_parse_from(example, ["example", "--set", "mu"]).is_err());
+        assert!(try_parse_from(example, ["example", "--unknown"]).is_err());
+    }
+}
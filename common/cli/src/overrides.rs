@@ -0,0 +1,188 @@
+//! Module to override the input parameters by `--set KEY=VALUE` on the command line.
+//!
+//! The `KEY` is the name of the parameter in the input file, where the nested parameters are joined by `.`,
+//! e.g. `resolution.n_x`, and the `VALUE` is parsed as YAML, e.g. `0.9`, `true`, `beamwarming` or `[1, 2]`.
+//! The overrides are merged into the input file before the input parameters are deserialized and validated,
+//! so that the overridden values are checked in the same way as those in the input file.
+
+use serde_yaml::{Mapping, Value};
+use std::error::Error;
+
+/// Override of an input parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    /// Name of the parameter, whose nested levels are joined by `.`.
+    pub key: String,
+    /// Value of the parameter in YAML.
+    pub value: String,
+}
+
+impl Override {
+    /// Parse the override from `KEY=VALUE`.
+    ///
+    /// # Errors
+    /// Returns an error if `=` is missing or any level of `KEY` is empty.
+    pub fn parse(key_value: &str) -> Result<Self, String> {
+        let (key, value) = key_value
+            .split_once('=')
+            .ok_or_else(|| format!("expected KEY=VALUE, but found `{}`", key_value))?;
+        let key = key.trim();
+        if key.split('.').any(str::is_empty) {
+            return Err(format!("the key of `{}` must not be empty", key_value));
+        }
+
+        Ok(Self {
+            key: key.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// Return the input `contents` in YAML with the `overrides` merged in order,
+/// where the parameters absent from `contents` are added.
+///
+/// # Examples
+/// ```
+/// use cli::overrides::{self, Override};
+///
+/// let contents = "n_cfl: 0.5\nstep_max: 100\nresolution:\n  n_x: 50\n";
+/// let overrides = [
+///     Override::parse("n_cfl=0.9").unwrap(),
+///     Override::parse("resolution.n_x=200").unwrap(),
+/// ];
+/// let merged = overrides::merge(contents, &overrides).unwrap();
+///
+/// let value: serde_yaml::Value = serde_yaml::from_str(&merged).unwrap();
+/// assert_eq!(value["n_cfl"].as_f64(), Some(0.9));
+/// assert_eq!(value["step_max"].as_u64(), Some(100));
+/// assert_eq!(value["resolution"]["n_x"].as_u64(), Some(200));
+/// ```
+///
+/// # Errors
+/// Returns an error if `contents` or any of the values is not valid YAML,
+/// or a parameter to be nested is not a mapping in `contents`.
+pub fn merge(contents: &str, overrides: &[Override]) -> Result<String, Box<dyn Error>> {
+    if overrides.is_empty() {
+        return Ok(contents.to_string());
+    }
+
+    let mut root: Value = serde_yaml::from_str(contents)?;
+    for r#override in overrides {
+        let value: Value = serde_yaml::from_str(&r#override.value)
+            .map_err(|err| format!("invalid value of `{}`: {}", r#override.key, err))?;
+
+        let mut node = &mut root;
+        for level in r#override.key.split('.') {
+            if node.is_null() {
+                *node = Value::Mapping(Mapping::new());
+            }
+            let mapping = node.as_mapping_mut().ok_or_else(|| {
+                format!(
+                    "`{}` cannot be nested in a non-mapping value",
+                    r#override.key
+                )
+            })?;
+            node = mapping
+                .entry(Value::String(level.to_string()))
+                .or_insert(Value::Null);
+        }
+        *node = value;
+    }
+
+    Ok(serde_yaml::to_string(&root)?)
+}
+
+/// Check if every key of the `overrides` is a parameter of `input_params`, which catches the misspelled keys
+/// that would otherwise be ignored silently or fall back to the defaults.
+///
+/// # Errors
+/// Returns an error if any of the keys is not found in the serialized `input_params`.
+pub fn check_keys(
+    input_params: &impl serde::Serialize,
+    overrides: &[Override],
+) -> Result<(), Box<dyn Error>> {
+    let root = serde_yaml::to_value(input_params)?;
+    for r#override in overrides {
+        let found = r#override
+            .key
+            .split('.')
+            .try_fold(&root, |node, level| node.get(level))
+            .is_some();
+        if !found {
+            return Err(format!("unknown input parameter `{}` in --set", r#override.key).into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_override_parse_works() {
+        // check if the value may contain `=` and the spaces are trimmed
+        assert_eq!(
+            Override::parse(" label = a=b ").unwrap(),
+            Override {
+                key: "label".to_string(),
+                value: "a=b".to_string()
+            }
+        );
+
+        // check if the invalid overrides are rejected
+        assert!(Override::parse("n_cfl").is_err());
+        assert!(Override::parse("=0.9").is_err());
+        assert!(Override::parse("resolution..n_x=1").is_err());
+    }
+
+    #[test]
+    fn fn_merge_applies_overrides_in_order_and_adds_absent_keys() {
+        // setup the input with a scalar parameter
+        let contents = "scheme: lax\nn_cfl: 0.5\n";
+        let overrides = [
+            Override::parse("n_cfl=0.8").unwrap(),
+            Override::parse("n_cfl=1.2").unwrap(),
+            Override::parse("boundary=periodic").unwrap(),
+        ];
+
+        // check if the last override wins and the absent key is added
+        let value: Value = serde_yaml::from_str(&merge(contents, &overrides).unwrap()).unwrap();
+        assert_eq!(value["scheme"].as_str(), Some("lax"));
+        assert_eq!(value["n_cfl"].as_f64(), Some(1.2));
+        assert_eq!(value["boundary"].as_str(), Some("periodic"));
+
+        // check if the scalar parameter cannot be nested
+        let overrides = [Override::parse("scheme.name=lax").unwrap()];
+        assert!(merge(contents, &overrides).is_err());
+    }
+
+    #[test]
+    fn fn_check_keys_rejects_misspelled_keys() {
+        // setup the input parameters
+        #[derive(serde::Serialize)]
+        struct Params {
+            n_cfl: f64,
+            step_max: Option<usize>,
+        }
+        let input_params = Params {
+            n_cfl: 0.5,
+            step_max: None,
+        };
+
+        // check if the existing keys including the unset optional one pass and the misspelled one fails
+        let overrides = [
+            Override::parse("n_cfl=0.9").unwrap(),
+            Override::parse("step_max=10").unwrap(),
+        ];
+        assert!(check_keys(&input_params, &overrides).is_ok());
+        let overrides = [Override::parse("n_cf=0.9").unwrap()];
+        assert_eq!(
+            check_keys(&input_params, &overrides)
+                .unwrap_err()
+                .to_string(),
+            "unknown input parameter `n_cf` in --set"
+        );
+    }
+}
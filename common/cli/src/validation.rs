@@ -0,0 +1,180 @@
+//! Module to validate the input parameters.
+//!
+//! The input parameters of every package are validated by [ValidationError],
+//! which collects all the violated constraints instead of failing on the first one,
+//! so that all the invalid parameters are reported at once.
+
+use std::error::Error;
+use std::fmt::{self, Debug};
+
+/// Error of the validation of the input parameters, which collects all the violated constraints
+/// instead of failing on the first one.
+///
+/// # Examples
+/// ```
+/// use cli::validation::ValidationError;
+///
+/// let (n_x, mu) = (0_usize, -0.5);
+/// let mut errors = ValidationError::new();
+/// errors.check(n_x > 0, "n_x", n_x, "must be positive");
+/// errors.check(mu > 0.0, "mu", mu, "must be positive");
+/// let err = errors.into_result().unwrap_err();
+///
+/// assert_eq!(err.violations().len(), 2);
+/// assert_eq!(err.violations()[1].field, "mu");
+/// assert_eq!(
+///     err.to_string(),
+///     "2 input parameters are invalid:\n  n_x = 0 must be positive\n  mu = -0.5 must be positive"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationError {
+    violations: Vec<Violation>,
+}
+
+/// Constraint violated by an input parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Name of the parameter, where the nested ones are joined by `.`, e.g. `resolution.mu`.
+    pub field: String,
+    /// Value of the parameter formatted by `{:?}`.
+    pub value: String,
+    /// Constraint violated by the parameter, e.g. `must be positive`.
+    pub constraint: String,
+}
+
+impl ValidationError {
+    /// Create a new `ValidationError` instance without violations.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Record the violation of the constraint on the parameter `field` if `satisfied` is `false`.
+    pub fn check(&mut self, satisfied: bool, field: &str, value: impl Debug, constraint: &str) {
+        if !satisfied {
+            self.violations.push(Violation {
+                field: field.to_string(),
+                value: format!("{:?}", value),
+                constraint: constraint.to_string(),
+            });
+        }
+    }
+
+    /// Record the violation of the parameter `field` reported by `result` of a validation returning a message,
+    /// where the leading name of the parameter in the message is removed.
+    pub fn check_result(
+        &mut self,
+        result: Result<(), &'static str>,
+        field: &str,
+        value: impl Debug,
+    ) {
+        if let Err(message) = result {
+            let constraint = message
+                .strip_prefix(field)
+                .map_or(message, |constraint| constraint.trim_start());
+            self.check(false, field, value, constraint);
+        }
+    }
+
+    /// Record the violations in `result` of the validation of the parameters flattened into the input,
+    /// e.g. the resolution of the parabolic examples.
+    pub fn merge(&mut self, result: Result<(), ValidationError>) {
+        if let Err(errors) = result {
+            self.violations.extend(errors.violations);
+        }
+    }
+
+    /// Return the violations in the order recorded.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Return `Ok(())` if no violations are recorded, or this error otherwise.
+    pub fn into_result(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.violations.len() {
+            1 => write!(f, "1 input parameter is invalid:")?,
+            n => write!(f, "{} input parameters are invalid:", n)?,
+        }
+        for violation in &self.violations {
+            write!(f, "\n  {}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {} {}", self.field, self.value, self.constraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_validation_error_collects_all_violations() {
+        // check the parameters, of which the first and the last two are invalid
+        let mut errors = ValidationError::new();
+        errors.check(false, "n_x", 0_usize, "must be positive");
+        errors.check(true, "step_max", 10_usize, "must be positive");
+        errors.check_result(Err("omega must be between 1 and 2"), "omega", 2.5);
+        let mut nested_errors = ValidationError::new();
+        nested_errors.check(false, "dx", 0.3, "must divide the length of the domain");
+        errors.merge(nested_errors.into_result());
+        errors.merge(ValidationError::new().into_result());
+
+        // check if all the violations are recorded in order with the leading field name removed from the message
+        let err = errors.into_result().unwrap_err();
+        let violations: Vec<_> = err
+            .violations()
+            .iter()
+            .map(|v| (v.field.as_str(), v.value.as_str(), v.constraint.as_str()))
+            .collect();
+        assert_eq!(
+            violations,
+            [
+                ("n_x", "0", "must be positive"),
+                ("omega", "2.5", "must be between 1 and 2"),
+                ("dx", "0.3", "must divide the length of the domain"),
+            ]
+        );
+        assert!(ValidationError::new().into_result().is_ok());
+    }
+
+    #[test]
+    fn fn_validation_error_is_formatted_with_field_value_and_constraint() {
+        // check if a single violation is formatted in the singular with the value formatted by {:?}
+        let mut errors = ValidationError::new();
+        errors.check(false, "scheme", "ftcs", "must be implicit");
+        assert_eq!(
+            errors.to_string(),
+            "1 input parameter is invalid:\n  scheme = \"ftcs\" must be implicit"
+        );
+
+        // check if the message not starting with the field name is kept as it is
+        errors.check_result(
+            Err("only periodic boundary condition is supported"),
+            "boundary_condition",
+            Some(1),
+        );
+        assert_eq!(
+            errors.to_string(),
+            "2 input parameters are invalid:\n  scheme = \"ftcs\" must be implicit\n  \
+             boundary_condition = Some(1) only periodic boundary condition is supported"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Module for solving the symmetric positive definite banded equations by the Cholesky decomposition.
+
+use ndarray::prelude::*;
+
+/// Solver for the symmetric positive definite banded equations.
+#[derive(Debug)]
+pub struct BandedCholesky {
+    mat_lower: Array2<f64>,
+}
+
+impl BandedCholesky {
+    /// Create a new `BandedCholesky` instance by decomposing the coefficient matrix `A` into `L L^T`.
+    ///
+    /// # Arguments
+    /// * `mat_coef` - lower band of the symmetric coefficient matrix with the bandwidth `b`,
+    ///   whose element `[i, j]` is `A_{i,i-j}` for `0 \le j \le b`.
+    ///   The elements outside the matrix, `j > i`, are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if the coefficient matrix is not positive definite.
+    pub fn new(mut mat_coef: Array2<f64>) -> Result<Self, &'static str> {
+        Self::decompose_mat_coef(&mut mat_coef)?;
+
+        Ok(Self {
+            mat_lower: mat_coef,
+        })
+    }
+
+    /// Solve the banded equation.
+    ///
+    /// # Arguments
+    /// * `vec_rhs` - right-hand side vector of the banded equation, which is overwritten with the solution.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::banded_cholesky::BandedCholesky;
+    /// use numerics::norms;
+    ///
+    /// // A = [[4, 1, 1], [1, 4, 1], [1, 1, 4]] with the bandwidth 2
+    /// let mat_coef = array![
+    ///   [4.0, 0.0, 0.0],
+    ///   [4.0, 1.0, 0.0],
+    ///   [4.0, 1.0, 1.0],
+    /// ];
+    /// let banded_cholesky = BandedCholesky::new(mat_coef).unwrap();
+    /// let mut vec_rhs = array![6.0, 6.0, 6.0];
+    /// banded_cholesky.solve(&mut vec_rhs).unwrap();
+    ///
+    /// let exact_solution = array![1.0, 1.0, 1.0];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the length of `vec_rhs` is not equal to the size of the coefficient matrix.
+    pub fn solve(&self, vec_rhs: &mut Array1<f64>) -> Result<(), &'static str> {
+        let (n, width) = self.mat_lower.dim();
+        if vec_rhs.len() != n {
+            return Err("The length of vec_rhs must be equal to the size of mat_coef");
+        }
+
+        // Forward substitution of L y = b
+        for i in 0..n {
+            for j in 1..width.min(i + 1) {
+                vec_rhs[i] -= self.mat_lower[[i, j]] * vec_rhs[i - j];
+            }
+            vec_rhs[i] /= self.mat_lower[[i, 0]];
+        }
+
+        // Back substitution of L^T x = y
+        for i in (0..n).rev() {
+            for j in 1..width.min(n - i) {
+                vec_rhs[i] -= self.mat_lower[[i + j, j]] * vec_rhs[i + j];
+            }
+            vec_rhs[i] /= self.mat_lower[[i, 0]];
+        }
+
+        Ok(())
+    }
+
+    fn decompose_mat_coef(mat_coef: &mut Array2<f64>) -> Result<(), &'static str> {
+        let (n, width) = mat_coef.dim();
+        for i in 0..n {
+            for j in (0..width.min(i + 1)).rev() {
+                // L_{i,i-j} = (A_{i,i-j} - \sum_k L_{i,k} L_{i-j,k}) / L_{i-j,i-j}
+                let mut sum = mat_coef[[i, j]];
+                for k in j + 1..width.min(i + 1) {
+                    sum -= mat_coef[[i, k]] * mat_coef[[i - j, k - j]];
+                }
+
+                if j == 0 {
+                    if sum <= 0.0 {
+                        return Err("The coefficient matrix must be positive definite");
+                    }
+                    mat_coef[[i, 0]] = sum.sqrt();
+                } else {
+                    mat_coef[[i, j]] = sum / mat_coef[[i - j, 0]];
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+//! Module for solving the block trinomial equations, whose coefficient matrices are block tridiagonal,
+//! which arise from the implicit schemes for the coupled systems of `m` unknowns at each point,
+//! e.g. `m = 2` for the shallow water equations and `m = 3` for the Euler equations in 1D.
+//!
+//! The equations are solved by the block version of the Thomas algorithm,
+//! ```math
+//! L_i = A_i B_{i-1}'^{-1}, B_i' = B_i - L_i C_{i-1},
+//! ```
+//! where `A_i`, `B_i` and `C_i` are the lower, diagonal and upper blocks of the `i`-th row, respectively.
+//! No pivoting is performed across the blocks, so that the diagonal blocks should be dominant,
+//! while the partial pivoting is performed inside each block `B_i'` to be inverted.
+
+use ndarray::prelude::*;
+
+/// Solver for the block trinomial equations.
+#[derive(Debug)]
+pub struct BlockTrinomialEq {
+    mat_coef: Array1<(Array2<f64>, Array2<f64>, Array2<f64>)>,
+}
+
+impl BlockTrinomialEq {
+    /// Create a new `BlockTrinomialEq` instance.
+    ///
+    /// # Arguments
+    /// * `mat_coef` - coefficient matrix of the block trinomial equation.
+    ///   The 1st component of each element is the diagonal block of the coefficient matrix
+    ///   and the 0th and 2nd components are the lower and upper blocks, respectively.
+    ///   All the blocks must be `m \times m` matrices of the same `m`.
+    ///
+    /// # Errors
+    /// Returns an error if `mat_coef` is empty, the blocks are not of the same square shape
+    /// or any of the diagonal blocks becomes singular in the elimination.
+    pub fn new(
+        mut mat_coef: Array1<(Array2<f64>, Array2<f64>, Array2<f64>)>,
+    ) -> Result<Self, &'static str> {
+        if mat_coef.is_empty() {
+            return Err("mat_coef must not be empty");
+        }
+        let dim_block = mat_coef[0].1.dim();
+        if dim_block.0 != dim_block.1 || dim_block.0 == 0 {
+            return Err("the blocks of mat_coef must be square and not empty");
+        }
+        if mat_coef
+            .iter()
+            .any(|(a, b, c)| a.dim() != dim_block || b.dim() != dim_block || c.dim() != dim_block)
+        {
+            return Err("the blocks of mat_coef must have the same shape");
+        }
+
+        Self::decompose_mat_coef(&mut mat_coef)?;
+
+        Ok(Self { mat_coef })
+    }
+
+    /// Solve the block trinomial equation.
+    ///
+    /// # Arguments
+    /// * `vec_rhs` - right-hand side vector of the block trinomial equation,
+    ///   whose `i`-th row is the `m` components at the `i`-th point.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::block_trinomial_eq::BlockTrinomialEq;
+    /// use numerics::norms;
+    ///
+    /// let lower = array![[-1.0, 0.0], [0.0, -1.0]];
+    /// let diag = array![[4.0, 1.0], [1.0, 4.0]];
+    /// let upper = array![[-1.0, 0.5], [0.0, -1.0]];
+    /// let mat_coef = Array::from_elem(3, (lower, diag, upper));
+    /// let block_trinomial_eq = BlockTrinomialEq::new(mat_coef).unwrap();
+    /// let mut vec_rhs = array![[5.5, 6.0], [9.0, 8.0], [14.0, 16.0]];
+    /// block_trinomial_eq.solve(&mut vec_rhs).unwrap();
+    ///
+    /// let exact_solution = array![[1.0, 2.0], [2.0, 3.0], [3.0, 4.0]];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the shape of `vec_rhs` is not `(n, m)` for `n` blocks of `m \times m` in each row.
+    pub fn solve(&self, vec_rhs: &mut Array2<f64>) -> Result<(), &'static str> {
+        let len = self.mat_coef.len();
+        if vec_rhs.dim() != (len, self.mat_coef[0].1.nrows()) {
+            return Err("The shape of vec_rhs must be (n, m) for n blocks of m x m in each row");
+        }
+
+        // Forward elimination
+        for i in 1..len {
+            let correction = self.mat_coef[i].0.dot(&vec_rhs.row(i - 1));
+            let mut row = vec_rhs.row_mut(i);
+            row -= &correction;
+        }
+
+        // Back substitution
+        for i in (0..len).rev() {
+            let mut row = vec_rhs.row(i).to_owned();
+            if i < len - 1 {
+                row -= &self.mat_coef[i].2.dot(&vec_rhs.row(i + 1));
+            }
+            let solution = self.mat_coef[i].1.dot(&row);
+            vec_rhs.row_mut(i).assign(&solution);
+        }
+
+        Ok(())
+    }
+
+    fn decompose_mat_coef(
+        mat_coef: &mut Array1<(Array2<f64>, Array2<f64>, Array2<f64>)>,
+    ) -> Result<(), &'static str> {
+        // Forward elimination, where the diagonal blocks are replaced with their inverses
+        mat_coef[0].1 = invert(&mat_coef[0].1)?;
+        for i in 1..mat_coef.len() {
+            let lower = mat_coef[i].0.dot(&mat_coef[i - 1].1);
+            let diag = &mat_coef[i].1 - &lower.dot(&mat_coef[i - 1].2);
+            mat_coef[i].0 = lower;
+            mat_coef[i].1 = invert(&diag)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Return the inverse of the square matrix `mat` by the Gauss-Jordan elimination with the partial pivoting.
+fn invert(mat: &Array2<f64>) -> Result<Array2<f64>, &'static str> {
+    let n_dim = mat.nrows();
+    let scale = mat.fold(0.0f64, |acc, a| acc.max(a.abs()));
+    let mut mat = mat.clone();
+    let mut mat_inv = Array2::eye(n_dim);
+
+    for j in 0..n_dim {
+        // choose the pivot of the largest magnitude in the j-th column
+        let i_pivot = (j..n_dim)
+            .max_by(|&i_a, &i_b| mat[[i_a, j]].abs().total_cmp(&mat[[i_b, j]].abs()))
+            .unwrap_or(j);
+        if mat[[i_pivot, j]].abs() <= n_dim as f64 * f64::EPSILON * scale {
+            return Err("the diagonal blocks of mat_coef must not be singular in the elimination");
+        }
+        for k in 0..n_dim {
+            mat.swap([j, k], [i_pivot, k]);
+            mat_inv.swap([j, k], [i_pivot, k]);
+        }
+
+        // eliminate the j-th column of the other rows
+        let pivot = mat[[j, j]];
+        mat.row_mut(j).mapv_inplace(|a| a / pivot);
+        mat_inv.row_mut(j).mapv_inplace(|a| a / pivot);
+        for i in (0..n_dim).filter(|&i| i != j) {
+            let factor = mat[[i, j]];
+            if factor != 0.0 {
+                let row = mat.row(j).to_owned();
+                mat.row_mut(i).scaled_add(-factor, &row);
+                let row_inv = mat_inv.row(j).to_owned();
+                mat_inv.row_mut(i).scaled_add(-factor, &row_inv);
+            }
+        }
+    }
+
+    Ok(mat_inv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::norms;
+
+    #[test]
+    fn fn_block_trinomial_eq_solve_works_with_3x3_blocks() {
+        // setup the block tridiagonal matrix of 4 x 4 blocks with the distinct 3 x 3 blocks,
+        // whose diagonal blocks need the pivoting inside the blocks
+        let n_blocks = 4;
+        let mat_coef: Array1<(Array2<f64>, Array2<f64>, Array2<f64>)> = (0..n_blocks)
+            .map(|i| {
+                let shift = i as f64;
+                (
+                    Array::from_shape_fn((3, 3), |(j, k)| 0.1 * (j + 2 * k) as f64 - shift * 0.05),
+                    array![[0.0, 5.0, 1.0], [6.0, 0.5, -1.0], [1.0, -1.0, 7.0 + shift]],
+                    Array::from_shape_fn((3, 3), |(j, k)| -0.2 * (2 * j + k) as f64 + shift * 0.1),
+                )
+            })
+            .collect();
+        let mat_dense = Array::from_shape_fn((3 * n_blocks, 3 * n_blocks), |(r, c)| {
+            let (i, j) = (r / 3, c / 3);
+            let (j_local, k_local) = (r % 3, c % 3);
+            match j as isize - i as isize {
+                -1 => mat_coef[i].0[[j_local, k_local]],
+                0 => mat_coef[i].1[[j_local, k_local]],
+                1 => mat_coef[i].2[[j_local, k_local]],
+                _ => 0.0,
+            }
+        });
+        let x_exact =
+            Array::from_shape_fn((n_blocks, 3), |(i, j)| (i as f64 - 1.5) * (j as f64 + 1.0));
+        let rhs = mat_dense.dot(&Array::from_iter(x_exact.iter().cloned()));
+        let mut vec_rhs = rhs.into_shape((n_blocks, 3)).unwrap();
+
+        // solve the block trinomial equation
+        let block_trinomial_eq = BlockTrinomialEq::new(mat_coef).unwrap();
+        block_trinomial_eq.solve(&mut vec_rhs).unwrap();
+
+        // check if the solution is correct
+        let is_correctly_solved = norms::linf(&(&vec_rhs - x_exact)) < 1e-10;
+        assert!(is_correctly_solved);
+    }
+
+    #[test]
+    fn fn_block_trinomial_eq_new_fails_with_singular_block() {
+        // setup the matrix whose first diagonal block is singular
+        let singular = array![[1.0, 2.0], [2.0, 4.0]];
+        let mat_coef = Array::from_elem(2, (Array2::eye(2), singular, Array2::eye(2)));
+
+        // check if the creation fails
+        assert_eq!(
+            BlockTrinomialEq::new(mat_coef).unwrap_err(),
+            "the diagonal blocks of mat_coef must not be singular in the elimination"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Module for solving the cyclic trinomial equations, which arise from the implicit schemes with the periodic boundary.
+//!
+//! The coefficient matrix of the cyclic trinomial equation is tridiagonal except for the corner components,
+//! ```math
+//! A = \begin{pmatrix}
+//! b_0 & c_0 & & & a_0 \\
+//! a_1 & b_1 & c_1 & & \\
+//! & \ddots & \ddots & \ddots & \\
+//! & & a_{N-2} & b_{N-2} & c_{N-2} \\
+//! c_{N-1} & & & a_{N-1} & b_{N-1}
+//! \end{pmatrix}.
+//! ```
+//! Writing `A = B + u v^T` with
+//! ```math
+//! u = (\gamma, 0, \cdots, 0, c_{N-1})^T, v = (1, 0, \cdots, 0, a_0 / \gamma)^T, \gamma = -b_0,
+//! ```
+//! the tridiagonal `B` differs from `A` only in `b_0` and `b_{N-1}`,
+//! and the Sherman-Morrison formula gives the solution of `A x = r` as
+//! ```math
+//! x = y - \frac{v^T y}{1 + v^T z} z, B y = r, B z = u,
+//! ```
+//! so that the cyclic trinomial equation is reduced to two trinomial equations.
+
+use crate::trinomial_eq::TrinomialEq;
+use ndarray::prelude::*;
+use ndarray::DataMut;
+use num_traits::Float;
+
+/// Solver for the cyclic trinomial equations of the floating-point type `F` by the Sherman-Morrison formula.
+#[derive(Debug)]
+pub struct CyclicTrinomialEq<F = f64> {
+    trinomial_eq: TrinomialEq<F>,
+    vec_z: Array1<F>,
+    coef_corner: F,
+}
+
+impl<F: Float + Send + Sync> CyclicTrinomialEq<F> {
+    /// Create a new `CyclicTrinomialEq` instance.
+    ///
+    /// # Arguments
+    /// * `mat_coef` - coefficient matrix of the cyclic trinomial equation.
+    ///   The 1st component of each element is the diagonal component of the coefficient matrix
+    ///   and the 0th and 2nd components are the lower and upper components, respectively.
+    ///   The 0th component of the first element and the 2nd component of the last element
+    ///   are the upper-right and lower-left corner components, respectively,
+    ///   which are added to the off-diagonal components for 2 elements.
+    ///
+    /// # Errors
+    /// Returns an error if `mat_coef` has less than 2 elements or the diagonal component of the first element is zero.
+    pub fn new(mat_coef: Array1<(F, F, F)>) -> Result<Self, &'static str> {
+        let n_dim = mat_coef.len();
+        if n_dim < 2 {
+            return Err("mat_coef must have at least 2 elements for the cyclic trinomial equation");
+        }
+        if mat_coef[0].1 == F::zero() {
+            return Err("the diagonal component of the first element of mat_coef must not be zero");
+        }
+
+        let corner_upper = mat_coef[0].0;
+        let corner_lower = mat_coef[n_dim - 1].2;
+        let gamma = -mat_coef[0].1;
+
+        // A = B + u v^T, where u = (gamma, 0, ..., 0, corner_lower) and v = (1, 0, ..., 0, corner_upper / gamma)
+        let mut mat_coef_b = mat_coef;
+        mat_coef_b[0].0 = F::zero();
+        mat_coef_b[0].1 = mat_coef_b[0].1 - gamma;
+        mat_coef_b[n_dim - 1].1 = mat_coef_b[n_dim - 1].1 - corner_lower * corner_upper / gamma;
+        mat_coef_b[n_dim - 1].2 = F::zero();
+        let trinomial_eq = TrinomialEq::new(mat_coef_b)?;
+
+        // solve B z = u
+        let mut vec_z = Array::zeros(n_dim);
+        vec_z[0] = gamma;
+        vec_z[n_dim - 1] = corner_lower;
+        trinomial_eq.solve(&mut vec_z)?;
+
+        Ok(Self {
+            trinomial_eq,
+            vec_z,
+            coef_corner: corner_upper / gamma,
+        })
+    }
+
+    /// Solve the cyclic trinomial equation.
+    ///
+    /// # Arguments
+    /// * `vec_rhs` - right-hand side vector of the cyclic trinomial equation, which is overwritten by the solution.
+    ///   A view, e.g. a slice of a longer array, can be given as well as an owned array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::cyclic_trinomial_eq::CyclicTrinomialEq;
+    /// use numerics::norms;
+    ///
+    /// let mat_coef = array![
+    ///   (1.0, 4.0, 1.0),
+    ///   (1.0, 4.0, 1.0),
+    ///   (1.0, 4.0, 1.0),
+    ///   (1.0, 4.0, 1.0),
+    /// ];
+    /// let cyclic_trinomial_eq = CyclicTrinomialEq::new(mat_coef).unwrap();
+    /// let mut vec_rhs = array![8.0, 12.0, 16.0, 12.0];
+    /// cyclic_trinomial_eq.solve(&mut vec_rhs).unwrap();
+    ///
+    /// let exact_solution = array![1.0, 2.0, 3.0, 2.0];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the length of `vec_rhs` is not equal to the length of `mat_coef`.
+    pub fn solve<S>(&self, vec_rhs: &mut ArrayBase<S, Ix1>) -> Result<(), &'static str>
+    where
+        S: DataMut<Elem = F>,
+    {
+        let n_dim = vec_rhs.len();
+
+        // solve B y = rhs and x = y - (v^T y) / (1 + v^T z) z
+        self.trinomial_eq.solve(vec_rhs)?;
+        let v_y = vec_rhs[0] + self.coef_corner * vec_rhs[n_dim - 1];
+        let v_z = self.vec_z[0] + self.coef_corner * self.vec_z[n_dim - 1];
+        let coef_z = -v_y / (F::one() + v_z);
+        azip!((x in vec_rhs, &z in &self.vec_z) *x = *x + coef_z * z);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::norms;
+
+    #[test]
+    fn fn_cyclic_trinomial_eq_solve_works_with_asymmetric_matrix() {
+        // setup the cyclic matrix with the distinct components
+        let mat_coef = array![
+            (0.5, 3.0, -1.0),
+            (2.0, -4.0, 1.5),
+            (-0.5, 5.0, 1.0),
+            (1.0, 2.5, -2.0),
+            (0.3, 6.0, 0.7),
+        ];
+        let n_dim = mat_coef.len();
+        let mat_dense = Array::from_shape_fn((n_dim, n_dim), |(i, j)| {
+            if j == i {
+                mat_coef[i].1
+            } else if j == (i + n_dim - 1) % n_dim {
+                mat_coef[i].0
+            } else if j == (i + 1) % n_dim {
+                mat_coef[i].2
+            } else {
+                0.0
+            }
+        });
+        let x_exact = array![1.0, -2.0, 0.5, 3.0, -1.5];
+        let mut vec_rhs = mat_dense.dot(&x_exact);
+
+        // solve the cyclic trinomial equation
+        let cyclic_trinomial_eq = CyclicTrinomialEq::new(mat_coef).unwrap();
+        cyclic_trinomial_eq.solve(&mut vec_rhs).unwrap();
+
+        // check if the solution is correct
+        let is_correctly_solved = norms::linf(&(&vec_rhs - x_exact)) < 1e-10;
+        assert!(is_correctly_solved);
+    }
+
+    #[test]
+    fn fn_cyclic_trinomial_eq_new_fails_with_too_short_matrix() {
+        // setup the matrix of 1 x 1, whose corner components coincide with the diagonal component
+        let mat_coef = array![(1.0, 4.0, 1.0)];
+
+        // check if the creation fails
+        assert_eq!(
+            CyclicTrinomialEq::new(mat_coef).unwrap_err(),
+            "mat_coef must have at least 2 elements for the cyclic trinomial equation"
+        );
+    }
+}
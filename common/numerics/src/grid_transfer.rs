@@ -0,0 +1,279 @@
+//! Module for the transfer of the fields between the grids of two resolutions,
+//! which are the building blocks of the multigrid methods and are used to compare the solutions on different grids.
+//!
+//! The coarse grid of `n + 1` points and the fine grid of `2 n + 1` points halving the spacing share the edges,
+//! so that the `i`-th coarse point coincides with the `2 i`-th fine point in each direction.
+//!
+//! The restriction is the full weighting, e.g. in 1D
+//! ```math
+//! u^c_i = \frac{1}{4} u^f_{2i-1} + \frac{1}{2} u^f_{2i} + \frac{1}{4} u^f_{2i+1},
+//! ```
+//! and the tensor product of it in 2D, while the values at the edges are injected, `u^c_i = u^f_{2i}`,
+//! so that the fixed boundary values are kept.
+//!
+//! The prolongation is the linear interpolation in 1D and the bilinear interpolation in 2D, e.g. in 1D
+//! ```math
+//! u^f_{2i} = u^c_i, u^f_{2i+1} = \frac{1}{2} (u^c_i + u^c_{i+1}).
+//! ```
+//! In the interior, the full weighting restriction is `1 / 2^d` times the transpose of the prolongation in `d` dimensions.
+//!
+//! For the semi-coarsening, which coarsens only one direction of the 2D grid, e.g. for the anisotropic problems,
+//! the 1D transfers are applied along the direction, while the values at the edges of the other direction are injected.
+
+use ndarray::prelude::*;
+
+/// Full weights of the restriction at the offsets `-1`, `0` and `1`.
+const WEIGHTS: [f64; 3] = [0.25, 0.5, 0.25];
+
+/// Restrict the field `u_fine` of `2 n + 1` points to the coarse grid of `n + 1` points by the full weighting.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use numerics::grid_transfer;
+///
+/// let u_fine = array![0.0, 1.0, 0.0, 1.0, 0.0];
+/// let u_coarse = grid_transfer::restrict_1d(&u_fine).unwrap();
+///
+/// assert_eq!(u_coarse, array![0.0, 0.5, 0.0]);
+/// ```
+///
+/// # Errors
+/// Returns an error if `u_fine` doesn't have `2 n + 1` points for `n \ge 1`.
+pub fn restrict_1d(u_fine: &Array1<f64>) -> Result<Array1<f64>, &'static str> {
+    let n_coarse = coarse_len(u_fine.len())?;
+
+    Ok(Array::from_shape_fn(n_coarse, |i| {
+        if i == 0 || i == n_coarse - 1 {
+            u_fine[2 * i]
+        } else {
+            (0..3).map(|k| WEIGHTS[k] * u_fine[2 * i + k - 1]).sum()
+        }
+    }))
+}
+
+/// Restrict the field `u_fine` of `(2 n_x + 1) \times (2 n_y + 1)` points
+/// to the coarse grid of `(n_x + 1) \times (n_y + 1)` points by the full weighting.
+///
+/// # Errors
+/// Returns an error if `u_fine` doesn't have `2 n + 1` points for `n \ge 1` in each direction.
+pub fn restrict_2d(u_fine: &Array2<f64>) -> Result<Array2<f64>, &'static str> {
+    let (n_x_fine, n_y_fine) = u_fine.dim();
+    let (n_x, n_y) = (coarse_len(n_x_fine)?, coarse_len(n_y_fine)?);
+
+    Ok(Array::from_shape_fn((n_x, n_y), |(i_x, i_y)| {
+        if i_x == 0 || i_x == n_x - 1 || i_y == 0 || i_y == n_y - 1 {
+            u_fine[[2 * i_x, 2 * i_y]]
+        } else {
+            (0..3)
+                .flat_map(|k_x| (0..3).map(move |k_y| (k_x, k_y)))
+                .map(|(k_x, k_y)| {
+                    WEIGHTS[k_x] * WEIGHTS[k_y] * u_fine[[2 * i_x + k_x - 1, 2 * i_y + k_y - 1]]
+                })
+                .sum()
+        }
+    }))
+}
+
+/// Prolong the field `u_coarse` of `n + 1` points to the fine grid of `2 n + 1` points by the linear interpolation.
+///
+/// # Errors
+/// Returns an error if `u_coarse` has less than 2 points.
+pub fn prolong_1d(u_coarse: &Array1<f64>) -> Result<Array1<f64>, &'static str> {
+    let n_fine = fine_len(u_coarse.len())?;
+
+    Ok(Array::from_shape_fn(n_fine, |i| {
+        0.5 * (u_coarse[i / 2] + u_coarse[i.div_ceil(2)])
+    }))
+}
+
+/// Prolong the field `u_coarse` of `(n_x + 1) \times (n_y + 1)` points
+/// to the fine grid of `(2 n_x + 1) \times (2 n_y + 1)` points by the bilinear interpolation.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use numerics::grid_transfer;
+///
+/// let u_coarse = array![[0.0, 2.0], [4.0, 6.0]];
+/// let u_fine = grid_transfer::prolong_2d(&u_coarse).unwrap();
+///
+/// assert_eq!(u_fine, array![[0.0, 1.0, 2.0], [2.0, 3.0, 4.0], [4.0, 5.0, 6.0]]);
+/// ```
+///
+/// # Errors
+/// Returns an error if `u_coarse` has less than 2 points in each direction.
+pub fn prolong_2d(u_coarse: &Array2<f64>) -> Result<Array2<f64>, &'static str> {
+    let (n_x, n_y) = u_coarse.dim();
+    let dim_fine = (fine_len(n_x)?, fine_len(n_y)?);
+
+    Ok(Array::from_shape_fn(dim_fine, |(i_x, i_y)| {
+        let (i_x_lower, i_x_upper) = (i_x / 2, i_x.div_ceil(2));
+        let (i_y_lower, i_y_upper) = (i_y / 2, i_y.div_ceil(2));
+        0.25 * (u_coarse[[i_x_lower, i_y_lower]]
+            + u_coarse[[i_x_lower, i_y_upper]]
+            + u_coarse[[i_x_upper, i_y_lower]]
+            + u_coarse[[i_x_upper, i_y_upper]])
+    }))
+}
+
+/// Restrict the field `u_fine` of `2 n + 1` points along `axis` to the coarse grid of `n + 1` points along it
+/// by the full weighting in the direction, keeping the other direction.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use numerics::grid_transfer;
+///
+/// let u_fine = array![[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.0, 0.0, 0.0]];
+/// let u_coarse = grid_transfer::restrict_2d_along(&u_fine, Axis(0)).unwrap();
+///
+/// assert_eq!(u_coarse, array![[0.0, 0.0, 0.0], [0.0, 0.5, 0.0], [0.0, 0.0, 0.0]]);
+/// ```
+///
+/// # Errors
+/// Returns an error if `u_fine` doesn't have `2 n + 1` points for `n \ge 1` along `axis`.
+pub fn restrict_2d_along(u_fine: &Array2<f64>, axis: Axis) -> Result<Array2<f64>, &'static str> {
+    let mut dim = u_fine.raw_dim();
+    dim[axis.index()] = coarse_len(u_fine.len_of(axis))?;
+    let n_lanes = u_fine.len() / u_fine.len_of(axis);
+
+    let mut u_coarse = Array::zeros(dim);
+    for (i, (lane_fine, mut lane_coarse)) in u_fine
+        .lanes(axis)
+        .into_iter()
+        .zip(u_coarse.lanes_mut(axis))
+        .enumerate()
+    {
+        if i == 0 || i == n_lanes - 1 {
+            lane_coarse.assign(&lane_fine.slice(s![..;2]));
+        } else {
+            lane_coarse.assign(&restrict_1d(&lane_fine.to_owned())?);
+        }
+    }
+
+    Ok(u_coarse)
+}
+
+/// Prolong the field `u_coarse` of `n + 1` points along `axis` to the fine grid of `2 n + 1` points along it
+/// by the linear interpolation in the direction, keeping the other direction.
+///
+/// # Errors
+/// Returns an error if `u_coarse` has less than 2 points along `axis`.
+pub fn prolong_2d_along(u_coarse: &Array2<f64>, axis: Axis) -> Result<Array2<f64>, &'static str> {
+    let mut dim = u_coarse.raw_dim();
+    dim[axis.index()] = fine_len(u_coarse.len_of(axis))?;
+
+    let mut u_fine = Array::zeros(dim);
+    for (lane_coarse, mut lane_fine) in u_coarse.lanes(axis).into_iter().zip(u_fine.lanes_mut(axis))
+    {
+        lane_fine.assign(&prolong_1d(&lane_coarse.to_owned())?);
+    }
+
+    Ok(u_fine)
+}
+
+/// Return the number of the coarse points `n + 1` for the `n_fine = 2 n + 1` fine points.
+fn coarse_len(n_fine: usize) -> Result<usize, &'static str> {
+    if n_fine < 3 || n_fine.is_multiple_of(2) {
+        return Err("the fine grid must have 2 n + 1 points with n >= 1 in each direction");
+    }
+
+    Ok(n_fine.div_ceil(2))
+}
+
+/// Return the number of the fine points `2 n + 1` for the `n_coarse = n + 1` coarse points.
+fn fine_len(n_coarse: usize) -> Result<usize, &'static str> {
+    if n_coarse < 2 {
+        return Err("the coarse grid must have at least 2 points in each direction");
+    }
+
+    Ok(2 * n_coarse - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_restrict_and_prolong_preserve_linear_fields() {
+        // setup the linear fields on the coarse grid of 5 x 4 points and the fine grid of 9 x 7 points
+        let linear = |x: f64, y: f64| 1.0 + 2.0 * x - 3.0 * y;
+        let u_coarse = Array::from_shape_fn((5, 4), |(i_x, i_y)| linear(i_x as f64, i_y as f64));
+        let u_fine = Array::from_shape_fn((9, 7), |(i_x, i_y)| {
+            linear(0.5 * i_x as f64, 0.5 * i_y as f64)
+        });
+
+        // check if the linear fields are transferred exactly
+        assert_eq!(restrict_2d(&u_fine).unwrap(), u_coarse);
+        assert_eq!(prolong_2d(&u_coarse).unwrap(), u_fine);
+        assert_eq!(
+            restrict_1d(&u_fine.column(0).to_owned()).unwrap(),
+            u_coarse.column(0)
+        );
+        assert_eq!(
+            prolong_1d(&u_coarse.column(0).to_owned()).unwrap(),
+            u_fine.column(0)
+        );
+    }
+
+    #[test]
+    fn fn_restrict_2d_is_scaled_transpose_of_prolong_2d() {
+        // setup the fields vanishing at the edges on the coarse grid of 4 x 5 points and the fine grid of 7 x 9 points
+        let u_coarse = Array::from_shape_fn((4, 5), |(i_x, i_y)| {
+            if i_x == 0 || i_x == 3 || i_y == 0 || i_y == 4 {
+                0.0
+            } else {
+                (i_x * 3 + i_y) as f64 - 2.5
+            }
+        });
+        let u_fine = Array::from_shape_fn((7, 9), |(i_x, i_y)| {
+            if i_x == 0 || i_x == 6 || i_y == 0 || i_y == 8 {
+                0.0
+            } else {
+                ((i_x * 5 + i_y * 7) % 11) as f64 - 4.0
+            }
+        });
+
+        // check if <R u_f, u_c> = <u_f, P u_c> / 4
+        let lhs = (restrict_2d(&u_fine).unwrap() * &u_coarse).sum();
+        let rhs = (&u_fine * &prolong_2d(&u_coarse).unwrap()).sum() / 4.0;
+        assert!((lhs - rhs).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fn_transfers_along_both_axes_compose_2d_transfers() {
+        // setup the fields on the coarse grid of 4 x 5 points and the fine grid of 7 x 9 points
+        let u_coarse = Array::from_shape_fn((4, 5), |(i_x, i_y)| ((i_x * 3 + i_y * 2) % 5) as f64);
+        let u_fine = Array::from_shape_fn((7, 9), |(i_x, i_y)| ((i_x * 5 + i_y * 7) % 11) as f64);
+
+        // check if the semi-coarsening in x and then in y is the full coarsening, and so is the prolongation
+        let u_restricted =
+            restrict_2d_along(&restrict_2d_along(&u_fine, Axis(0)).unwrap(), Axis(1));
+        let is_restriction_composed = (u_restricted.unwrap() - restrict_2d(&u_fine).unwrap())
+            .iter()
+            .all(|u| u.abs() < 1e-12);
+        assert!(is_restriction_composed);
+        let u_prolonged = prolong_2d_along(&prolong_2d_along(&u_coarse, Axis(1)).unwrap(), Axis(0));
+        let is_prolongation_composed = (u_prolonged.unwrap() - prolong_2d(&u_coarse).unwrap())
+            .iter()
+            .all(|u| u.abs() < 1e-12);
+        assert!(is_prolongation_composed);
+
+        // check if the other direction is kept
+        assert_eq!(restrict_2d_along(&u_fine, Axis(1)).unwrap().dim(), (7, 5));
+        assert_eq!(prolong_2d_along(&u_coarse, Axis(0)).unwrap().dim(), (7, 5));
+    }
+
+    #[test]
+    fn fn_restrict_fails_with_even_number_of_points() {
+        // check if the restriction fails for the fine grid of 2 n points
+        assert_eq!(
+            restrict_1d(&Array::zeros(4)).unwrap_err(),
+            "the fine grid must have 2 n + 1 points with n >= 1 in each direction"
+        );
+        assert!(restrict_2d(&Array::zeros((5, 4))).is_err());
+        assert!(restrict_2d_along(&Array::zeros((5, 4)), Axis(1)).is_err());
+        assert!(restrict_2d_along(&Array::zeros((5, 4)), Axis(0)).is_ok());
+    }
+}
@@ -0,0 +1,197 @@
+//! Module to create the initial condition on the 1D grid, given either as an analytic profile or read from a file.
+//!
+//! The analytic profiles are specific to the equations of each package and implement [AnalyticProfile],
+//! while the profile read from a file by [read] is shared as [FileProfile].
+//! Both are selected in the input files by the `profile` field as [InitialCondition].
+
+use crate::interpolation::{Interpolator, Method};
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+/// Analytic profile of `u` given by a function of `x`.
+pub trait AnalyticProfile {
+    /// Return the profile evaluated on the coordinate array `x`.
+    fn create(&self, x: &Array1<f64>) -> Array1<f64>;
+    /// Validate the parameters of the profile.
+    fn validate(&self) -> Result<(), &'static str>;
+}
+
+/// Initial condition of `u`, given as one of the analytic profiles `P` or read from a file, e.g.
+/// ```yaml
+/// profile: from_file
+/// path: path/to/u_init.dat
+/// ```
+/// which is the default profile of `P` if omitted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InitialCondition<P> {
+    /// Analytic profile.
+    Profile(P),
+    /// Profile read from a file.
+    File(FileProfile),
+}
+
+/// Profile of `u` read from a file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "profile", rename_all = "snake_case")]
+pub enum FileProfile {
+    /// Values of `u` read from the file at `path` by [read] and interpolated onto the grid.
+    FromFile {
+        /// Path of the file.
+        path: PathBuf,
+    },
+}
+
+impl<P: Default> Default for InitialCondition<P> {
+    fn default() -> Self {
+        InitialCondition::Profile(Default::default())
+    }
+}
+
+impl<P: AnalyticProfile> InitialCondition<P> {
+    /// Return the initial condition evaluated on the coordinate array `x`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or doesn't cover `x`.
+    pub fn create(&self, x: &Array1<f64>) -> Result<Array1<f64>, Box<dyn Error>> {
+        match self {
+            InitialCondition::Profile(profile) => Ok(profile.create(x)),
+            InitialCondition::File(FileProfile::FromFile { path }) => {
+                let file = File::open(path)
+                    .map_err(|err| format!("cannot open {}: {}", path.display(), err))?;
+                read(&mut BufReader::new(file), x)
+            }
+        }
+    }
+
+    /// Validate the parameters of the initial condition, where the file is not read yet.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            InitialCondition::Profile(profile) => profile.validate(),
+            InitialCondition::File(_) => Ok(()),
+        }
+    }
+}
+
+/// Read the profile of `u` from the input and interpolate it linearly onto the coordinate array `x`.
+///
+/// # Input Format
+/// Each line consists of `x` and `u` in the last two columns,
+/// so that a single step of the solution output by the packages can be read as well, e.g.
+/// ```text
+/// -1.0 0.0
+/// 0.0 1.0
+/// 1.0 0.0
+/// ```
+/// where `x` must be strictly increasing and cover the range of the grid.
+/// The empty lines and the lines starting with `#` are skipped.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use numerics::initial_condition;
+///
+/// let input = "# x u\n-1.0 0.0\n0.0 1.0\n1.0 0.0\n";
+/// let u = initial_condition::read(&mut input.as_bytes(), &array![-1.0, -0.5, 0.5]).unwrap();
+///
+/// assert_eq!(u, array![0.0, 0.5, 0.5]);
+/// ```
+///
+/// # Errors
+/// Returns an error if the input cannot be read or parsed, `x` in the input is not strictly increasing,
+/// or any point of the grid is out of its range.
+pub fn read(
+    inputstream: &mut impl BufRead,
+    x: &Array1<f64>,
+) -> Result<Array1<f64>, Box<dyn Error>> {
+    let (mut x_read, mut u_read) = (Vec::new(), Vec::new());
+    for line in inputstream.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let values = line
+            .split_whitespace()
+            .map(str::parse::<f64>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("cannot parse `{}`: {}", line, err))?;
+        match values[..] {
+            [.., x, u] => {
+                x_read.push(x);
+                u_read.push(u);
+            }
+            _ => return Err(format!("`{}` must consist of x and u", line).into()),
+        }
+    }
+
+    let interpolator =
+        Interpolator::new(Array1::from(x_read), Array1::from(u_read), Method::Linear)?;
+    Ok(interpolator.eval_all(x)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Constant profile for testing.
+    #[derive(Debug, Default)]
+    struct Constant(f64);
+
+    impl AnalyticProfile for Constant {
+        fn create(&self, x: &Array1<f64>) -> Array1<f64> {
+            Array1::from_elem(x.len(), self.0)
+        }
+
+        fn validate(&self) -> Result<(), &'static str> {
+            if self.0 < 0.0 {
+                return Err("value must be non-negative");
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn fn_initial_condition_works() {
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+
+        // check if the analytic profile is evaluated and validated
+        let initial_condition = InitialCondition::Profile(Constant(2.0));
+        assert_eq!(
+            initial_condition.create(&x).unwrap(),
+            Array1::from_elem(5, 2.0)
+        );
+        assert!(initial_condition.validate().is_ok());
+        assert!(InitialCondition::Profile(Constant(-1.0))
+            .validate()
+            .is_err());
+
+        // check if the missing file is reported on creation but not on validation
+        let initial_condition = InitialCondition::<Constant>::File(FileProfile::FromFile {
+            path: PathBuf::from("no/such/u_init.dat"),
+        });
+        assert!(initial_condition.validate().is_ok());
+        assert!(initial_condition.create(&x).is_err());
+    }
+
+    #[test]
+    fn fn_read_works() {
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+
+        // check if a step of the output is read and interpolated
+        let input = "0 -1.0 0.0\n0 0.0 1.0\n0 1.0 0.0\n\n\n";
+        let u = read(&mut input.as_bytes(), &x).unwrap();
+        assert_eq!(u, array![0.0, 0.5, 1.0, 0.5, 0.0]);
+
+        // check if the inputs not covering the grid or without u are rejected
+        assert!(read(&mut "-1.0 1.0\n0.5 0.0\n".as_bytes(), &x).is_err());
+        assert!(read(&mut "-1.0\n1.0\n".as_bytes(), &x).is_err());
+    }
+}
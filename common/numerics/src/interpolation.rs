@@ -0,0 +1,236 @@
+//! Module for the interpolation of the values given at the points of the nonuniform 1D grid,
+//! which is used for the departure points of the semi-Lagrangian and CIP schemes and for sampling the fields at the probes.
+//!
+//! The following methods are available, see [Method]:
+//! - the linear interpolation between the two neighboring points, which is monotone but of first order,
+//! - the cubic Lagrange interpolation through the four nearest points, which is of third order
+//!   but overshoots around the discontinuities,
+//! - the monotone cubic Hermite interpolation, whose slopes are limited by Fritsch and Carlson's condition
+//!   with the weighted harmonic means of the neighboring secants, so that it never overshoots the data.
+
+use ndarray::prelude::*;
+
+/// Method of the interpolation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Linear interpolation, which needs at least 2 points.
+    Linear,
+    /// Cubic Lagrange interpolation through the 4 nearest points, which needs at least 4 points.
+    Cubic,
+    /// Monotone cubic Hermite interpolation, which needs at least 2 points.
+    MonotoneCubic,
+}
+
+/// Interpolator of the values on the nonuniform 1D grid.
+#[derive(Debug)]
+pub struct Interpolator {
+    x: Array1<f64>,
+    y: Array1<f64>,
+    method: Method,
+    slopes: Option<Array1<f64>>,
+}
+
+impl Interpolator {
+    /// Create a new `Interpolator` instance.
+    ///
+    /// # Arguments
+    /// * `x` - coordinates of the grid points, which must be strictly increasing.
+    /// * `y` - values at the grid points.
+    /// * `method` - method of the interpolation.
+    ///
+    /// # Errors
+    /// Returns an error if the lengths of `x` and `y` are different, `x` is not strictly increasing,
+    /// or the number of the points is too small for the `method`.
+    pub fn new(x: Array1<f64>, y: Array1<f64>, method: Method) -> Result<Self, &'static str> {
+        if x.len() != y.len() {
+            return Err("the lengths of x and y must be equal");
+        }
+        let n_points_min = match method {
+            Method::Linear | Method::MonotoneCubic => 2,
+            Method::Cubic => 4,
+        };
+        if x.len() < n_points_min {
+            return Err(
+                "the number of the points is too small for the method of the interpolation",
+            );
+        }
+        if !x.iter().all(|x| x.is_finite()) || x.windows(2).into_iter().any(|w| w[0] >= w[1]) {
+            return Err("x must be finite and strictly increasing");
+        }
+
+        let slopes = match method {
+            Method::MonotoneCubic => Some(Self::monotone_slopes(&x, &y)),
+            _ => None,
+        };
+
+        Ok(Self {
+            x,
+            y,
+            method,
+            slopes,
+        })
+    }
+
+    /// Return the interpolated value at `x`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::interpolation::{Interpolator, Method};
+    ///
+    /// let x = array![0.0, 0.5, 1.5, 2.0, 3.0];
+    /// let y = x.mapv(|x| x * x * x - 2.0 * x);
+    /// let interpolator = Interpolator::new(x, y, Method::Cubic).unwrap();
+    ///
+    /// assert!((interpolator.eval(1.2).unwrap() - (1.2f64.powi(3) - 2.4)).abs() < 1e-12);
+    /// assert!(interpolator.eval(3.5).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `x` is out of the range of the grid.
+    pub fn eval(&self, x: f64) -> Result<f64, &'static str> {
+        let n_points = self.x.len();
+        if !(self.x[0] <= x && x <= self.x[n_points - 1]) {
+            return Err("x must be in the range of the grid for the interpolation");
+        }
+
+        // index of the interval [x_i, x_{i+1}] containing x
+        let i = self
+            .x
+            .as_slice()
+            .map_or_else(
+                || self.x.iter().take_while(|&&x_i| x_i <= x).count(),
+                |x_slice| x_slice.partition_point(|&x_i| x_i <= x),
+            )
+            .clamp(1, n_points - 1)
+            - 1;
+
+        let value = match self.method {
+            Method::Linear => {
+                let t = (x - self.x[i]) / (self.x[i + 1] - self.x[i]);
+                (1.0 - t) * self.y[i] + t * self.y[i + 1]
+            }
+            Method::Cubic => {
+                let j_start = i.saturating_sub(1).min(n_points - 4);
+                (j_start..j_start + 4)
+                    .map(|j| {
+                        let basis: f64 = (j_start..j_start + 4)
+                            .filter(|&k| k != j)
+                            .map(|k| (x - self.x[k]) / (self.x[j] - self.x[k]))
+                            .product();
+                        basis * self.y[j]
+                    })
+                    .sum()
+            }
+            Method::MonotoneCubic => {
+                let slopes = self.slopes.as_ref().unwrap();
+                let h = self.x[i + 1] - self.x[i];
+                let t = (x - self.x[i]) / h;
+                let h00 = (1.0 + 2.0 * t) * (1.0 - t) * (1.0 - t);
+                let h10 = t * (1.0 - t) * (1.0 - t);
+                let h01 = t * t * (3.0 - 2.0 * t);
+                let h11 = t * t * (t - 1.0);
+                h00 * self.y[i]
+                    + h10 * h * slopes[i]
+                    + h01 * self.y[i + 1]
+                    + h11 * h * slopes[i + 1]
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// Return the interpolated values at each of `x`.
+    ///
+    /// # Errors
+    /// Returns an error if any of `x` is out of the range of the grid.
+    pub fn eval_all(&self, x: &Array1<f64>) -> Result<Array1<f64>, &'static str> {
+        x.iter()
+            .map(|x| self.eval(*x))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Array::from)
+    }
+
+    /// Return the slopes at the grid points for the monotone cubic Hermite interpolation.
+    fn monotone_slopes(x: &Array1<f64>, y: &Array1<f64>) -> Array1<f64> {
+        let n_points = x.len();
+        let h = Array::from_shape_fn(n_points - 1, |i| x[i + 1] - x[i]);
+        let secants = Array::from_shape_fn(n_points - 1, |i| (y[i + 1] - y[i]) / h[i]);
+
+        Array::from_shape_fn(n_points, |i| {
+            if i == 0 {
+                secants[0]
+            } else if i == n_points - 1 {
+                secants[n_points - 2]
+            } else if secants[i - 1] * secants[i] <= 0.0 {
+                // flat at the local extrema
+                0.0
+            } else {
+                // weighted harmonic mean of the secants
+                let w_lower = 2.0 * h[i] + h[i - 1];
+                let w_upper = h[i] + 2.0 * h[i - 1];
+                (w_lower + w_upper) / (w_lower / secants[i - 1] + w_upper / secants[i])
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_interpolator_eval_is_exact_for_polynomials_on_nonuniform_grid() {
+        // setup the nonuniform grid
+        let x = array![-1.0, -0.7, 0.1, 0.2, 0.9, 1.5];
+        let x_probe = array![-1.0, -0.85, 0.0, 0.15, 0.6, 1.5];
+
+        // check if the linear data is interpolated exactly by every method
+        let linear = |x: f64| 2.0 - 3.0 * x;
+        for method in [Method::Linear, Method::Cubic, Method::MonotoneCubic] {
+            let interpolator = Interpolator::new(x.clone(), x.mapv(linear), method).unwrap();
+            let error = interpolator.eval_all(&x_probe).unwrap() - x_probe.mapv(linear);
+            assert!(error.iter().all(|e| e.abs() < 1e-12));
+        }
+
+        // check if the cubic data is interpolated exactly by the cubic Lagrange interpolation
+        let cubic = |x: f64| 1.0 + x - 2.0 * x * x + 0.5 * x * x * x;
+        let interpolator = Interpolator::new(x.clone(), x.mapv(cubic), Method::Cubic).unwrap();
+        let error = interpolator.eval_all(&x_probe).unwrap() - x_probe.mapv(cubic);
+        assert!(error.iter().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn fn_interpolator_eval_does_not_overshoot_with_monotone_cubic() {
+        // setup the step data on the nonuniform grid
+        let x = array![0.0, 1.0, 1.5, 2.0, 2.2, 3.0, 4.0];
+        let y = array![0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        let x_probe = Array::linspace(0.0, 4.0, 81);
+
+        // check if the cubic Lagrange interpolation overshoots while the monotone one stays in [0, 1]
+        let cubic = Interpolator::new(x.clone(), y.clone(), Method::Cubic).unwrap();
+        let y_cubic = cubic.eval_all(&x_probe).unwrap();
+        assert!(y_cubic.iter().any(|y| *y < 0.0 || *y > 1.0));
+
+        let monotone = Interpolator::new(x, y, Method::MonotoneCubic).unwrap();
+        let y_monotone = monotone.eval_all(&x_probe).unwrap();
+        assert!(y_monotone.iter().all(|y| (0.0..=1.0).contains(y)));
+        assert!(y_monotone
+            .windows(2)
+            .into_iter()
+            .all(|w| w[0] <= w[1] + 1e-12));
+    }
+
+    #[test]
+    fn fn_interpolator_new_fails_with_non_increasing_x() {
+        // setup the grid with the duplicated point
+        let x = array![0.0, 1.0, 1.0, 2.0];
+        let y = array![0.0, 1.0, 2.0, 3.0];
+
+        // check if the creation fails
+        assert_eq!(
+            Interpolator::new(x, y, Method::Linear).unwrap_err(),
+            "x must be finite and strictly increasing"
+        );
+    }
+}
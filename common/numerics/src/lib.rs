@@ -0,0 +1,27 @@
+//! This crate provides the numerical utilities shared by the packages of the sections,
+//! e.g. the linear solvers which arise from the implicit schemes and the direct methods of the book.
+//!
+//! The solvers are the following:
+//! - [trinomial_eq]: the tridiagonal equations by the Thomas algorithm,
+//! - [cyclic_trinomial_eq]: the tridiagonal equations with the corner components for the periodic boundary,
+//! - [pentanomial_eq]: the pentadiagonal equations,
+//! - [block_trinomial_eq]: the block tridiagonal equations for the coupled systems,
+//! - [banded_cholesky]: the symmetric positive definite banded equations.
+//!
+//! The discrete norms of the fields for the error reports and the convergence criteria are provided by [norms],
+//! the restriction and prolongation between the grids of two resolutions by [grid_transfer],
+//! the interpolation on the nonuniform 1D grids by [interpolation],
+//! and the initial condition given as an analytic profile or read from a file by [initial_condition].
+//!
+//! # Features
+//! - `parallel`: Solve the lanes of [trinomial_eq::TrinomialEq::solve_lanes] in parallel by rayon.
+
+pub mod banded_cholesky;
+pub mod block_trinomial_eq;
+pub mod cyclic_trinomial_eq;
+pub mod grid_transfer;
+pub mod initial_condition;
+pub mod interpolation;
+pub mod norms;
+pub mod pentanomial_eq;
+pub mod trinomial_eq;
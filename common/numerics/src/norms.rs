@@ -0,0 +1,166 @@
+//! Module for the discrete norms of the fields on the uniform grids,
+//! which are used for the error reports, the convergence studies and the convergence criteria.
+//!
+//! The norms are weighted by the volume of a cell `w`, e.g. `w = \Delta x` in 1D and `w = \Delta x \Delta y` in 2D,
+//! ```math
+//! \|e\|_1 = \sum_j |e_j| w, \|e\|_2 = \sqrt{\sum_j e_j^2 w}, \|e\|_\infty = \max_j |e_j|,
+//! ```
+//! so that they approximate the continuous norms and are comparable among the grids of different resolutions.
+//! Pass `w = 1` for the plain norms of the vectors.
+
+use ndarray::prelude::*;
+use ndarray::Data;
+
+/// Kind of the discrete norm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    /// L1 norm, see [l1].
+    L1,
+    /// L2 norm, see [l2].
+    L2,
+    /// L∞ norm, see [linf].
+    Linf,
+}
+
+impl Norm {
+    /// Return the norm of `a` with the cell volume `weight`.
+    pub fn eval<S, D>(&self, a: &ArrayBase<S, D>, weight: f64) -> f64
+    where
+        S: Data<Elem = f64>,
+        D: Dimension,
+    {
+        match self {
+            Norm::L1 => l1(a, weight),
+            Norm::L2 => l2(a, weight),
+            Norm::Linf => linf(a),
+        }
+    }
+
+    /// Return the relative norm of `a` against the reference `a_ref`, `\|a - a_{ref}\| / \|a_{ref}\|`,
+    /// with the cell volume `weight`.
+    ///
+    /// If the norm of `a_ref` is zero, the absolute norm `\|a - a_{ref}\|` is returned instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::norms::Norm;
+    ///
+    /// let u = array![1.0, 2.0, 3.5];
+    /// let u_exact = array![1.0, 2.0, 4.0];
+    ///
+    /// assert!((Norm::Linf.relative(&u, &u_exact, 1.0) - 0.125).abs() < 1e-10);
+    /// assert!((Norm::L1.relative(&u, &u_exact, 0.5) - 0.5 / 7.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the shapes of `a` and `a_ref` are different.
+    pub fn relative<S, T, D>(
+        &self,
+        a: &ArrayBase<S, D>,
+        a_ref: &ArrayBase<T, D>,
+        weight: f64,
+    ) -> f64
+    where
+        S: Data<Elem = f64>,
+        T: Data<Elem = f64>,
+        D: Dimension,
+    {
+        let norm_error = self.eval(&(a - a_ref), weight);
+        let norm_ref = self.eval(a_ref, weight);
+
+        if norm_ref == 0.0 {
+            norm_error
+        } else {
+            norm_error / norm_ref
+        }
+    }
+}
+
+/// Return the L1 norm of `a` with the cell volume `weight`, `\sum_j |a_j| w`.
+pub fn l1<S, D>(a: &ArrayBase<S, D>, weight: f64) -> f64
+where
+    S: Data<Elem = f64>,
+    D: Dimension,
+{
+    a.fold(0.0, |acc, a| acc + a.abs()) * weight
+}
+
+/// Return the L2 norm of `a` with the cell volume `weight`, `\sqrt{\sum_j a_j^2 w}`.
+pub fn l2<S, D>(a: &ArrayBase<S, D>, weight: f64) -> f64
+where
+    S: Data<Elem = f64>,
+    D: Dimension,
+{
+    (a.fold(0.0, |acc, a| acc + a * a) * weight).sqrt()
+}
+
+/// Return the L∞ norm of `a`, `\max_j |a_j|`, which is `0` for the empty `a`.
+///
+/// Unlike `f64::max`, `NaN` is propagated, so that a diverged field never satisfies the convergence criteria.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use numerics::norms;
+///
+/// let u_next = array![[1.0, 2.0], [3.0, 4.0]];
+/// let u = array![[1.0, 2.0], [3.0, 4.5]];
+/// assert_eq!(norms::linf(&(&u_next - &u)), 0.5);
+///
+/// let u_nan = array![[1.0, f64::NAN], [3.0, 4.0]];
+/// assert!(norms::linf(&(&u_nan - &u)).is_nan());
+/// ```
+pub fn linf<S, D>(a: &ArrayBase<S, D>) -> f64
+where
+    S: Data<Elem = f64>,
+    D: Dimension,
+{
+    a.fold(0.0, |acc: f64, a| {
+        if acc.is_nan() || a.is_nan() {
+            f64::NAN
+        } else {
+            acc.max(a.abs())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_norms_work_with_grid_spacing() {
+        // setup the error on the grid with the spacing 0.5
+        let error = array![0.0, -0.5, 0.0, 1.0];
+
+        // check if the norms are correctly calculated
+        assert_eq!(l1(&error, 0.5), 0.75);
+        assert_eq!(l2(&error, 0.5), 0.625_f64.sqrt());
+        assert_eq!(linf(&error), 1.0);
+    }
+
+    #[test]
+    fn fn_norms_work_with_2d_fields() {
+        // setup the error on the 2D grid with the spacings 0.5 and 0.25
+        let error = array![[1.0, -2.0], [0.0, 2.0]];
+        let weight = 0.5 * 0.25;
+
+        // check if the norms of Array2 agree with those of the flattened Array1
+        let error_flat = Array::from_iter(error.iter().cloned());
+        for norm in [Norm::L1, Norm::L2, Norm::Linf] {
+            assert_eq!(norm.eval(&error, weight), norm.eval(&error_flat, weight));
+        }
+        assert_eq!(Norm::L2.eval(&error, weight), (9.0 * weight).sqrt());
+    }
+
+    #[test]
+    fn fn_norm_relative_falls_back_to_absolute_with_zero_reference() {
+        // setup the field against the zero reference
+        let u = array![0.0, -2.0, 1.0];
+        let u_ref = Array::zeros(3);
+
+        // check if the absolute norm is returned
+        assert_eq!(Norm::L1.relative(&u, &u_ref, 1.0), 3.0);
+    }
+}
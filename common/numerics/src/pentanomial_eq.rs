@@ -0,0 +1,136 @@
+//! Module for solving the pentanomial equations, whose coefficient matrices are pentadiagonal,
+//! which arise from the higher-order implicit discretizations, e.g. the fourth-order central difference of `u_{xx}`.
+
+use ndarray::prelude::*;
+
+/// Solver for the pentanomial equations by the LU decomposition without pivoting.
+#[derive(Debug)]
+pub struct PentanomialEq {
+    mat_coef: Array1<(f64, f64, f64, f64, f64)>,
+}
+
+impl PentanomialEq {
+    /// Create a new `PentanomialEq` instance.
+    ///
+    /// # Arguments
+    /// * `mat_coef` - coefficient matrix of the pentanomial equation.
+    ///   The 2nd component of each element is the diagonal component of the coefficient matrix,
+    ///   the 0th and 1st components are the second lower and lower components
+    ///   and the 3rd and 4th components are the upper and second upper components, respectively.
+    pub fn new(mut mat_coef: Array1<(f64, f64, f64, f64, f64)>) -> Self {
+        Self::decompose_mat_coef(&mut mat_coef);
+
+        Self { mat_coef }
+    }
+
+    /// Solve the pentanomial equation.
+    ///
+    /// # Arguments
+    /// * `vec_rhs` - right-hand side vector of the pentanomial equation.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::norms;
+    /// use numerics::pentanomial_eq::PentanomialEq;
+    ///
+    /// let mat_coef = array![
+    ///   (0.0, 0.0, 6.0, -4.0, 1.0),
+    ///   (0.0, -4.0, 6.0, -4.0, 1.0),
+    ///   (1.0, -4.0, 6.0, -4.0, 1.0),
+    ///   (1.0, -4.0, 6.0, -4.0, 0.0),
+    ///   (1.0, -4.0, 6.0, 0.0, 0.0),
+    /// ];
+    /// let pentanomial_eq = PentanomialEq::new(mat_coef);
+    /// let mut vec_rhs = array![1.0, 0.0, 0.0, -6.0, 17.0];
+    /// pentanomial_eq.solve(&mut vec_rhs).unwrap();
+    ///
+    /// let exact_solution = array![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the length of `vec_rhs` is not equal to the length of `mat_coef`.
+    pub fn solve(&self, vec_rhs: &mut Array1<f64>) -> Result<(), &'static str> {
+        if vec_rhs.len() != self.mat_coef.len() {
+            return Err("The length of vec_rhs must be equal to the length of mat_coef");
+        }
+
+        let len = vec_rhs.len();
+
+        // Forward elimination
+        for i in 1..len {
+            vec_rhs[i] -= self.mat_coef[i].1 * vec_rhs[i - 1];
+            if i >= 2 {
+                vec_rhs[i] -= self.mat_coef[i].0 * vec_rhs[i - 2];
+            }
+        }
+
+        // Back substitution
+        for i in (0..len).rev() {
+            if i + 1 < len {
+                vec_rhs[i] -= self.mat_coef[i].3 * vec_rhs[i + 1];
+            }
+            if i + 2 < len {
+                vec_rhs[i] -= self.mat_coef[i].4 * vec_rhs[i + 2];
+            }
+            vec_rhs[i] /= self.mat_coef[i].2;
+        }
+
+        Ok(())
+    }
+
+    fn decompose_mat_coef(mat_coef: &mut Array1<(f64, f64, f64, f64, f64)>) {
+        // Forward elimination, where the second upper components are unchanged
+        for i in 1..mat_coef.len() {
+            if i >= 2 {
+                mat_coef[i].0 /= mat_coef[i - 2].2;
+                mat_coef[i].1 -= mat_coef[i].0 * mat_coef[i - 2].3;
+                mat_coef[i].2 -= mat_coef[i].0 * mat_coef[i - 2].4;
+            }
+            mat_coef[i].1 /= mat_coef[i - 1].2;
+            mat_coef[i].2 -= mat_coef[i].1 * mat_coef[i - 1].3;
+            mat_coef[i].3 -= mat_coef[i].1 * mat_coef[i - 1].4;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::norms;
+
+    #[test]
+    fn fn_pentanomial_eq_solve_works_with_asymmetric_matrix() {
+        // setup the pentadiagonal matrix with the distinct components
+        let mat_coef = array![
+            (0.0, 0.0, 5.0, 1.0, -0.5),
+            (0.0, 2.0, -6.0, 1.5, 0.3),
+            (0.7, -1.0, 7.0, 2.0, -1.0),
+            (-0.2, 0.5, 4.0, -1.0, 0.6),
+            (1.0, 1.2, -5.0, 0.4, 0.0),
+            (0.3, -0.8, 3.0, 0.0, 0.0),
+        ];
+        let n_dim = mat_coef.len();
+        let mat_dense =
+            Array::from_shape_fn((n_dim, n_dim), |(i, j)| match j as isize - i as isize {
+                -2 => mat_coef[i].0,
+                -1 => mat_coef[i].1,
+                0 => mat_coef[i].2,
+                1 => mat_coef[i].3,
+                2 => mat_coef[i].4,
+                _ => 0.0,
+            });
+        let x_exact = array![1.0, -2.0, 0.5, 3.0, -1.5, 2.0];
+        let mut vec_rhs = mat_dense.dot(&x_exact);
+
+        // solve the pentanomial equation
+        let pentanomial_eq = PentanomialEq::new(mat_coef);
+        pentanomial_eq.solve(&mut vec_rhs).unwrap();
+
+        // check if the solution is correct
+        let is_correctly_solved = norms::linf(&(&vec_rhs - x_exact)) < 1e-10;
+        assert!(is_correctly_solved);
+    }
+}
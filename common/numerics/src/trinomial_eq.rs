@@ -0,0 +1,255 @@
+//! Module for solving the trinomial equations.
+
+use ndarray::prelude::*;
+use ndarray::{DataMut, Zip};
+use num_traits::Float;
+
+/// Solver for the trinomial equations of the floating-point type `F`.
+#[derive(Debug)]
+pub struct TrinomialEq<F = f64> {
+    mat_coef: Array1<(F, F, F)>,
+}
+
+impl<F: Float + Send + Sync> TrinomialEq<F> {
+    /// Create a new `TrinomialEq` instance.
+    ///
+    /// # Arguments
+    /// * `mat_coef` - coefficient matrix of the trinomial equation.
+    ///   The 1st component of each element is the diagonal component of the coefficient matrix
+    ///   and the 0th and 2nd components are the lower and upper components, respectively.
+    ///
+    /// The decomposition is performed without pivoting, which is stable for the diagonally dominant matrices.
+    /// Since the matrices of the implicit schemes are not necessarily diagonally dominant, e.g. for the large CFL numbers,
+    /// the diagonal dominance is not required here but can be checked by [TrinomialEq::check_diagonal_dominance].
+    ///
+    /// # Errors
+    /// Returns an error if a pivot of the decomposition is zero, nearly zero relative to its row or not finite,
+    /// in which case the solution would be filled with `NaN` or the rounding errors.
+    pub fn new(mut mat_coef: Array1<(F, F, F)>) -> Result<Self, &'static str> {
+        Self::decompose_mat_coef(&mut mat_coef)?;
+
+        Ok(Self { mat_coef })
+    }
+
+    /// Check if the coefficient matrix `mat_coef` in the format of [TrinomialEq::new] is diagonally dominant,
+    /// that is, `|b_i| \ge |a_i| + |c_i|` for every row with the strict inequality for at least one row,
+    /// for which the decomposition without pivoting never breaks down.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::trinomial_eq::TrinomialEq;
+    ///
+    /// assert!(TrinomialEq::check_diagonal_dominance(&array![(0.0, 2.0, -1.0), (-1.0, 2.0, 0.0)]).is_ok());
+    /// assert_eq!(
+    ///     TrinomialEq::check_diagonal_dominance(&array![(0.0, 1.0, -2.0), (-1.0, 2.0, 0.0)]).unwrap_err(),
+    ///     "mat_coef must be diagonally dominant, |b_i| >= |a_i| + |c_i| for every row i",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `mat_coef` is not diagonally dominant.
+    pub fn check_diagonal_dominance(mat_coef: &Array1<(F, F, F)>) -> Result<(), &'static str> {
+        let len = mat_coef.len();
+        let off_diagonal = |i: usize| {
+            let (a, _, c) = mat_coef[i];
+            (if i > 0 { a.abs() } else { F::zero() })
+                + (if i + 1 < len { c.abs() } else { F::zero() })
+        };
+
+        if (0..len).any(|i| mat_coef[i].1.abs() < off_diagonal(i)) {
+            return Err(
+                "mat_coef must be diagonally dominant, |b_i| >= |a_i| + |c_i| for every row i",
+            );
+        }
+        if len > 0 && (0..len).all(|i| mat_coef[i].1.abs() == off_diagonal(i)) {
+            return Err("mat_coef must be strictly diagonally dominant for at least one row");
+        }
+
+        Ok(())
+    }
+
+    /// Solve the trinomial equation.
+    ///
+    /// # Arguments
+    /// * `vec_rhs` - right-hand side vector of the trinomial equation, which is overwritten by the solution.
+    ///   A view, e.g. a slice of a longer array, can be given as well as an owned array.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::norms;
+    /// use numerics::trinomial_eq::TrinomialEq;
+    ///
+    /// let mat_coef = array![
+    ///   (0.0, 1.0, 2.0),
+    ///   (3.0, 4.0, 5.0),
+    ///   (6.0, 7.0, 0.0),
+    /// ];
+    /// let trinomial_eq = TrinomialEq::new(mat_coef).unwrap();
+    /// let mut vec_rhs = array![8.0, 9.0, 10.0];
+    /// trinomial_eq.solve(&mut vec_rhs).unwrap();
+    ///
+    /// let exact_solution = array![21.0 / 22.0, 155.0 / 44.0, -35.0 / 22.0];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the length of `vec_rhs` is not equal to the length of `mat_coef`.
+    pub fn solve<S>(&self, vec_rhs: &mut ArrayBase<S, Ix1>) -> Result<(), &'static str>
+    where
+        S: DataMut<Elem = F>,
+    {
+        if vec_rhs.len() != self.mat_coef.len() {
+            return Err("The length of vec_rhs must be equal to the length of mat_coef");
+        }
+
+        self.substitute(vec_rhs.view_mut());
+
+        Ok(())
+    }
+
+    /// Solve the trinomial equations with the same coefficient matrix for every lane of `vec_rhs` along `axis`,
+    /// e.g. for the rows or the columns of the 2D grid in the ADI methods,
+    /// reusing the decomposition of the coefficient matrix.
+    ///
+    /// With the `parallel` feature, the lanes are solved in parallel by rayon.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use numerics::norms;
+    /// use numerics::trinomial_eq::TrinomialEq;
+    ///
+    /// let mat_coef = array![(0.0, 2.0, -1.0), (-1.0, 2.0, -1.0), (-1.0, 2.0, 0.0)];
+    /// let trinomial_eq = TrinomialEq::new(mat_coef).unwrap();
+    /// let mut vec_rhs = array![[0.0, 2.0], [0.0, 0.0], [4.0, 2.0]];
+    /// trinomial_eq.solve_lanes(&mut vec_rhs, Axis(0)).unwrap();
+    ///
+    /// let exact_solution = array![[1.0, 2.0], [2.0, 2.0], [3.0, 2.0]];
+    /// let is_correctly_solved = norms::linf(&(&vec_rhs - exact_solution)) < 1e-10;
+    /// assert!(is_correctly_solved);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the length of `vec_rhs` along `axis` is not equal to the length of `mat_coef`.
+    ///
+    /// # Panics
+    /// Panics if `axis` is out of the dimensions of `vec_rhs`.
+    pub fn solve_lanes<S, D>(
+        &self,
+        vec_rhs: &mut ArrayBase<S, D>,
+        axis: Axis,
+    ) -> Result<(), &'static str>
+    where
+        S: DataMut<Elem = F>,
+        D: Dimension,
+    {
+        if vec_rhs.len_of(axis) != self.mat_coef.len() {
+            return Err("The length of vec_rhs along axis must be equal to the length of mat_coef");
+        }
+
+        // every lane is solved independently with the shared decomposition
+        #[cfg(feature = "parallel")]
+        Zip::from(vec_rhs.lanes_mut(axis)).par_for_each(|lane| self.substitute(lane));
+        #[cfg(not(feature = "parallel"))]
+        Zip::from(vec_rhs.lanes_mut(axis)).for_each(|lane| self.substitute(lane));
+
+        Ok(())
+    }
+
+    /// Overwrite `vec_rhs` of the length of `mat_coef` with the solution by the forward elimination
+    /// and the back substitution with the decomposed coefficient matrix.
+    fn substitute(&self, mut vec_rhs: ArrayViewMut1<F>) {
+        let len = vec_rhs.len();
+
+        // Forward elimination
+        for i in 1..len {
+            vec_rhs[i] = vec_rhs[i] - self.mat_coef[i].0 * vec_rhs[i - 1];
+        }
+
+        // Back substitution
+        for i in (0..len).rev() {
+            if i == len - 1 {
+                vec_rhs[i] = vec_rhs[i] / self.mat_coef[i].1;
+                continue;
+            }
+
+            vec_rhs[i] = (vec_rhs[i] - self.mat_coef[i].2 * vec_rhs[i + 1]) / self.mat_coef[i].1;
+        }
+    }
+
+    fn decompose_mat_coef(mat_coef: &mut Array1<(F, F, F)>) -> Result<(), &'static str> {
+        // Forward elimination, checking each pivot against the scale of its row before the elimination
+        for i in 0..mat_coef.len() {
+            let (a, b, c) = mat_coef[i];
+            let scale = a.abs().max(b.abs()).max(c.abs());
+            if i > 0 {
+                mat_coef[i].0 = mat_coef[i].0 / mat_coef[i - 1].1;
+                mat_coef[i].1 = mat_coef[i].1 - mat_coef[i].0 * mat_coef[i - 1].2;
+            }
+
+            let pivot = mat_coef[i].1;
+            if !pivot.is_finite() {
+                return Err("a pivot of mat_coef is not finite in the decomposition");
+            }
+            if pivot.abs() <= F::epsilon() * scale {
+                return Err("a pivot of mat_coef is zero or nearly zero in the decomposition without pivoting");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_trinomial_eq_new_fails_with_zero_pivot() {
+        // setup the nonsingular matrix whose second pivot vanishes without pivoting, 2 - (1 / 1) * 2 = 0
+        let mat_coef = array![(0.0, 1.0, 2.0), (1.0, 2.0, 3.0), (0.0, 1.0, 0.0)];
+
+        // check if the creation fails instead of propagating NaN
+        assert_eq!(
+            TrinomialEq::new(mat_coef).unwrap_err(),
+            "a pivot of mat_coef is zero or nearly zero in the decomposition without pivoting"
+        );
+    }
+
+    #[test]
+    fn fn_trinomial_eq_solve_lanes_works_along_each_axis() {
+        // setup the trinomial equation and the right-hand sides of 5 x 4 x 3 with the distinct values
+        let mat_coef = array![
+            (0.0, 4.0, 1.0),
+            (-1.0, 5.0, 2.0),
+            (0.5, 3.0, -1.0),
+            (1.0, 6.0, 2.0),
+            (-2.0, 4.0, 0.0),
+        ];
+        let trinomial_eq = TrinomialEq::new(mat_coef).unwrap();
+        let rhs = Array::from_shape_fn((5, 4, 3), |(i, j, k)| (i * 12 + j * 3 + k) as f64 - 30.0);
+
+        // check if the lanes along each axis of the length 5 agree with the solutions one by one
+        let mut vec_rhs = rhs.clone();
+        trinomial_eq.solve_lanes(&mut vec_rhs, Axis(0)).unwrap();
+        for (lane, lane_rhs) in vec_rhs.lanes(Axis(0)).into_iter().zip(rhs.lanes(Axis(0))) {
+            let mut lane_expected = lane_rhs.to_owned();
+            trinomial_eq.solve(&mut lane_expected).unwrap();
+            assert_eq!(lane, lane_expected);
+        }
+
+        let mut vec_rhs = rhs.clone().permuted_axes([1, 2, 0]);
+        trinomial_eq.solve_lanes(&mut vec_rhs, Axis(2)).unwrap();
+        for (lane, lane_rhs) in vec_rhs.lanes(Axis(2)).into_iter().zip(rhs.lanes(Axis(0))) {
+            let mut lane_expected = lane_rhs.to_owned();
+            trinomial_eq.solve(&mut lane_expected).unwrap();
+            assert_eq!(lane, lane_expected);
+        }
+
+        // check if the lanes of the wrong length are rejected
+        assert!(trinomial_eq.solve_lanes(&mut rhs.clone(), Axis(1)).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "x"
+set ylabel "u"
+
+set output "outputs/section_1/bad_upwind/solve_transport_eq_with_sign_changing_velocity/solution_backward.png"
+plot [-1:1] for [i=0:*] "outputs/section_1/bad_upwind/solve_transport_eq_with_sign_changing_velocity/solution_backward.dat" index i u 2:3 w l lw 3 title columnhead(1)
+
+set output "outputs/section_1/bad_upwind/solve_transport_eq_with_sign_changing_velocity/solution_auto.png"
+plot [-1:1] for [i=0:*] "outputs/section_1/bad_upwind/solve_transport_eq_with_sign_changing_velocity/solution_auto.dat" index i u 2:3 w l lw 3 title columnhead(1)
@@ -0,0 +1,9 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "iteration"
+set ylabel "residual"
+set logscale y
+set format y "10^{%L}"
+
+set output "outputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method/residual.png"
+plot "outputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method/residual.dat" u 1:2 w lp lw 3 notitle
@@ -0,0 +1,18 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "x"
+set ylabel "y"
+unset xtics
+unset ytics
+
+set size ratio -1
+set pm3d map
+set palette rgbformulae 21,22,23
+set cbrange [0:1]
+
+data = "outputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method/snapshots.dat"
+stats data u 2 nooutput
+do for [i=0:STATS_blocks-1] {
+    set output sprintf("outputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method/snapshot_%d.png", i)
+    splot data index i u 2:3:4 notitle
+}
@@ -5,6 +5,7 @@ set ylabel "y"
 unset xtics
 unset ytics
 
+set size ratio -1
 set pm3d map
 set palette rgbformulae 21,22,23
 
@@ -0,0 +1,14 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "k{/Symbol D}x"
+set key left bottom
+
+set output "outputs/section_2/linear_hyperbolic/analyze_dispersion_of_wave_eq_solvers/damping_factor.png"
+set ylabel "|g|"
+plot [0:pi] "outputs/section_2/linear_hyperbolic/analyze_dispersion_of_wave_eq_solvers/dispersion.dat" u 1:2 w lp lw 3 title columnhead(2), \
+    1 w l lw 1 dt 2 lc "black" notitle
+
+set output "outputs/section_2/linear_hyperbolic/analyze_dispersion_of_wave_eq_solvers/phase_speed_ratio.png"
+set ylabel "c_{num} / c"
+plot [0:pi] "outputs/section_2/linear_hyperbolic/analyze_dispersion_of_wave_eq_solvers/dispersion.dat" u 1:3 w lp lw 3 title columnhead(3), \
+    1 w l lw 1 dt 2 lc "black" notitle
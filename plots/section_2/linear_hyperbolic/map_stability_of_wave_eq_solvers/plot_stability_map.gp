@@ -0,0 +1,11 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "n_cfl"
+set ylabel "error"
+set logscale y
+set format y "10^{%L}"
+set datafile missing "-"
+set key left top
+
+set output "outputs/section_2/linear_hyperbolic/map_stability_of_wave_eq_solvers/stability_map.png"
+plot for [i=5:7] "outputs/section_2/linear_hyperbolic/map_stability_of_wave_eq_solvers/stability_map.dat" u 1:i w lp lw 3 title columnhead(i)
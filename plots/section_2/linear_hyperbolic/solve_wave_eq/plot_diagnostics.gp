@@ -0,0 +1,12 @@
+# usage: gnuplot -e "scheme='laxwendroff'" plots/section_2/linear_hyperbolic/solve_wave_eq/plot_diagnostics.gp
+if (!exists("scheme")) scheme = "upwind"
+dir = "outputs/section_2/linear_hyperbolic/solve_wave_eq/".scheme
+
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "step"
+set ylabel "diagnostics"
+set key left top
+
+set output dir."/diagnostics.png"
+plot for [i=2:5] dir."/diagnostics.dat" u 1:i w lp lw 3 title columnhead(i)
@@ -0,0 +1,11 @@
+# usage: gnuplot -e "scheme='laxwendroff'" plots/section_2/linear_hyperbolic/solve_wave_eq/plot_solution.gp
+if (!exists("scheme")) scheme = "upwind"
+dir = "outputs/section_2/linear_hyperbolic/solve_wave_eq/".scheme
+
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "x"
+set ylabel "u"
+
+set output dir."/solution.png"
+plot [-1:1] for [i=0:*] dir."/solution.dat" index i u 2:3 w l lw 3 title columnhead(1)
@@ -0,0 +1,8 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "ct"
+set ylabel "error"
+set key left top
+
+set output "outputs/section_2/linear_hyperbolic/solve_wave_eq_by_beamwarming_method_at_large_cfl/errors.png"
+plot for [i=3:5] "outputs/section_2/linear_hyperbolic/solve_wave_eq_by_beamwarming_method_at_large_cfl/errors.dat" u 2:i w lp lw 3 title columnhead(i)
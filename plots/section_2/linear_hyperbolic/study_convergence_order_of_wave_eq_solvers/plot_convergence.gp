@@ -0,0 +1,10 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "dx"
+set ylabel "error"
+set logscale xy
+set format xy "10^{%L}"
+set key left top
+
+set output "outputs/section_2/linear_hyperbolic/study_convergence_order_of_wave_eq_solvers/convergence.png"
+plot for [i=3:7:2] "outputs/section_2/linear_hyperbolic/study_convergence_order_of_wave_eq_solvers/convergence.dat" u 2:i w lp lw 3 title columnhead(i)
@@ -0,0 +1,17 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "x"
+set ylabel "y"
+set zlabel "u"
+set zrange [0:1]
+set cbrange [0:1]
+
+set pm3d
+set palette rgbformulae 21,22,23
+
+data = "outputs/section_2/parabolic/solve_diffusion_eq_by_ftcs_2d_method/solution.dat"
+stats data u 1 nooutput
+do for [i=0:STATS_blocks-1] {
+    set output sprintf("outputs/section_2/parabolic/solve_diffusion_eq_by_ftcs_2d_method/solution_%d.png", i)
+    splot data index i u 2:3:4 w pm3d notitle
+}
@@ -0,0 +1,10 @@
+set terminal pngcairo size 1280, 960 enhanced font ",24"
+
+set xlabel "dx"
+set ylabel "error"
+set logscale xy
+set format xy "10^{%L}"
+set key left top
+
+set output "outputs/section_2/parabolic/study_convergence_order_of_diffusion_eq_solvers/convergence.png"
+plot for [i=4:7:3] "outputs/section_2/parabolic/study_convergence_order_of_diffusion_eq_solvers/convergence.dat" u 2:i w lp lw 3 title columnhead(i)
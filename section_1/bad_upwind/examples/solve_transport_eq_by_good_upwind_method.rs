@@ -1,82 +1,114 @@
-//! Solve the transport equation by the good upwind method, in this case, [DiffMethod::Backward].
+//! Solve the transport equation by the good upwind method, in this case, [DiffMethod::Auto].
 //!
 //! # Formulation
 //! The transport equation is given by
 //! ```math
 //! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0 (x \in [-1, 1])),
 //! ```
-//! where `u` is the transported quantity and `c` (`> 0`) is the advection velocity.
+//! where `u` is the transported quantity and `c` is the advection velocity, which may be negative.
 //!
 //! The initial condition is given by
 //! ```math
 //! u(x, 0) = 0 (x \ge 0), u(x, 0) = 1 (x < 0).
 //! ```
 //!
-//! For the boundary condition, see [bad_upwind::upwind_solver].
+//! For the boundary condition, see [bad_upwind::solver::upwind_solver::Boundary].
 //!
 //! # Scheme
-//! See [DiffMethod::Backward].
+//! See [DiffMethod::Auto], which is [DiffMethod::Backward] for `c > 0`.
 //!
 //! # Input Format
 //! See [input::read_input_params].
 //!
 //! # Output Format
 //! See [bad_upwind::output::output].
+//! If the solution diverges, the analysis of the divergence is written to the standard error,
+//! see [bad_upwind::output::output_analysis].
 
-use bad_upwind::input;
-use bad_upwind::upwind_solver::{DiffMethod, UpwindSolver};
+use bad_upwind::solver::upwind_solver::{DiffMethod, UpwindSolver, UpwindSolverNewParams};
+use bad_upwind::solver::Solver;
+use bad_upwind::{input, output};
+use cli::check::Report;
 use ndarray::prelude::*;
 use std::fs::{self, File};
+use std::io;
 use std::process;
 
 /// Solve the equation with the given input parameters and output the result to a file.
 fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_1/bad_upwind/solve_transport_eq_by_good_upwind_method");
+
     // read input parameters
-    let mut inputfile = File::open(
-        "inputs/section_1/bad_upwind/solve_transport_eq_by_good_upwind_method/input.yml",
-    )
-    .unwrap_or_else(|err| {
-        eprintln!("Problem opening input file: {}", err);
+    let input_params = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+
+    // initialize the upwind solver
+    let new_params = UpwindSolverNewParams {
+        u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+        v_adv: input_params.velocity(&x),
+        dx: x[1] - x[0],
+        dt: input_params.time_step(x[1] - x[0]),
+        t_max: input_params.t_max,
+        diff_method: DiffMethod::Auto,
+        boundary: input_params.boundary,
+    };
+    let mut upwind_solver = UpwindSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
         process::exit(1);
     });
-    let input_params = input::read_input_params(&mut inputfile).unwrap_or_else(|err| {
-        eprintln!("Problem reading input parameters: {}", err);
-        process::exit(1);
+
+    // report the derived quantities and exit if only the input parameters are checked,
+    // where |g| is compared with 1 allowing the rounding errors
+    exec_args.exit_if_check(|| {
+        let amplification_factor = upwind_solver.get_amplification_factor();
+        Report::new()
+            .quantity("dx", format!("{:.10e}", x[1] - x[0]))
+            .quantity("dt", format!("{:.10e}", upwind_solver.get_dt()))
+            .quantity("nu", format!("{:.10e}", upwind_solver.get_cfl()))
+            .quantity("|g|", format!("{:.10e}", amplification_factor))
+            .stability(if amplification_factor > 1.0 + 1e-12 {
+                "unstable since |g| > 1"
+            } else {
+                "stable since |g| <= 1"
+            })
     });
 
     // setup output files
-    let dir_str = "outputs/section_1/bad_upwind/solve_transport_eq_by_good_upwind_method";
-    fs::create_dir_all(dir_str).unwrap_or_else(|err| {
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
         eprintln!("Problem creating output directory: {}", err);
         process::exit(1);
     });
-    let mut outputfile = File::create(format!("{}/solution.dat", dir_str)).unwrap_or_else(|err| {
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
         eprintln!("Problem creating output files: {}", err);
         process::exit(1);
     });
 
-    // setup coordinates
-    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
-
-    // initialize the upwind solver
-    let mut upwind_solver = UpwindSolver::new(
-        x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
-        input_params.v_adv,
-        x[1] - x[0],
-        input_params.dt,
-        input_params.t_max,
-        DiffMethod::Backward,
-    );
-
-    // run
-    bad_upwind::run(
+    // run and explain the divergence if any
+    let divergence = bad_upwind::run(
         &x,
         &mut upwind_solver,
         &mut outputfile,
         input_params.ncycle_out,
+        input_params.growth_threshold,
+        input_params.stop_on_divergence,
     )
     .unwrap_or_else(|err| {
         eprintln!("Application error: {}", err);
         process::exit(1);
     });
+    if let Some(divergence) = divergence {
+        output::output_analysis(&mut io::stderr(), &divergence).unwrap_or_else(|err| {
+            eprintln!("Problem writing the divergence analysis: {}", err);
+            process::exit(1);
+        });
+    }
 }
@@ -0,0 +1,141 @@
+//! Solve the transport equation with the advection velocity changing its sign
+//! by the backward difference method, [DiffMethod::Backward], and the hybrid upwind method, [DiffMethod::Auto].
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c(x) \frac{\partial u}{\partial x} = 0 (x \in [-1, 1])),
+//! ```
+//! where `u` is the transported quantity and `c(x)` is the advection velocity given by the input,
+//! e.g. `c(x) = v_{adv} x` for `v_profile: linear`, which carries `u` away from `x = 0` for `v_{adv} > 0`.
+//!
+//! The initial condition is given by
+//! ```math
+//! u(x, 0) = 1 (|x| < 0.5), u(x, 0) = 0 (|x| \ge 0.5).
+//! ```
+//!
+//! For the boundary condition, see [bad_upwind::solver::upwind_solver::Boundary].
+//!
+//! # Scheme
+//! See [DiffMethod::Backward] and [DiffMethod::Auto].
+//! [DiffMethod::Backward] is the good upwind method only where `c(x) > 0`
+//! and the bad one where `c(x) < 0`, so that the solution diverges from the left edge of the box for `v_{adv} > 0`.
+//! [DiffMethod::Auto] selects the upwind side at each point, so that the solution remains good everywhere.
+//!
+//! # Input Format
+//! See [input::read_input_params].
+//!
+//! # Output Format
+//! See [bad_upwind::output::output].
+//! The results of [DiffMethod::Backward] and [DiffMethod::Auto] are written to `solution_backward.dat`
+//! and `solution_auto.dat` respectively.
+//! If the solution diverges, the analysis of the divergence is written to the standard error,
+//! see [bad_upwind::output::output_analysis].
+
+use bad_upwind::solver::upwind_solver::{DiffMethod, UpwindSolver, UpwindSolverNewParams};
+use bad_upwind::{input, output};
+use cli::check::Report;
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::io;
+use std::process;
+
+/// Solve the equation with the given input parameters by each method and output the results to files.
+fn main() {
+    // parse command line arguments
+    let exec_args =
+        cli::parse("section_1/bad_upwind/solve_transport_eq_with_sign_changing_velocity");
+
+    // read input parameters
+    let input_params = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+
+    // report the derived quantities and exit if only the input parameters are checked,
+    // where |g| is compared with 1 allowing the rounding errors
+    let methods = [
+        ("backward", DiffMethod::Backward),
+        ("auto", DiffMethod::Auto),
+    ];
+    exec_args.exit_if_check(|| {
+        let (dx, dt) = (x[1] - x[0], input_params.time_step(x[1] - x[0]));
+        let mut report = Report::new()
+            .quantity("dx", format!("{:.10e}", dx))
+            .quantity("dt", format!("{:.10e}", dt))
+            .quantity("nu", format!("{:.10e}", input_params.v_adv.abs() * dt / dx));
+        let mut stability = vec![];
+        for (name, diff_method) in &methods {
+            let amplification_factor = input_params.velocity(&x).fold(0.0f64, |acc, v| {
+                acc.max(diff_method.amplification_factor(v * dt / dx))
+            });
+            report = report.quantity(
+                &format!("|g| ({})", name),
+                format!("{:.10e}", amplification_factor),
+            );
+            stability.push(if amplification_factor > 1.0 + 1e-12 {
+                format!("{} is unstable since |g| > 1", name)
+            } else {
+                format!("{} is stable since |g| <= 1", name)
+            });
+        }
+        report.stability(stability.join(", "))
+    });
+
+    // setup output directory
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+
+    for (name, diff_method) in methods {
+        // setup output file
+        let mut outputfile = File::create(output_dir.join(format!("solution_{}.dat", name)))
+            .unwrap_or_else(|err| {
+                eprintln!("Problem creating output files: {}", err);
+                process::exit(1);
+            });
+
+        // initialize the upwind solver
+        let new_params = UpwindSolverNewParams {
+            u: x.map(|x| if x.abs() < 0.5 { 1.0 } else { 0.0 }),
+            v_adv: input_params.velocity(&x),
+            dx: x[1] - x[0],
+            dt: input_params.time_step(x[1] - x[0]),
+            t_max: input_params.t_max,
+            diff_method,
+            boundary: input_params.boundary,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap_or_else(|err| {
+            eprintln!("Problem creating solver: {}", err);
+            process::exit(1);
+        });
+
+        // run and explain the divergence if any
+        let divergence = bad_upwind::run(
+            &x,
+            &mut upwind_solver,
+            &mut outputfile,
+            input_params.ncycle_out,
+            input_params.growth_threshold,
+            input_params.stop_on_divergence,
+        )
+        .unwrap_or_else(|err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        });
+        if let Some(divergence) = divergence {
+            eprintln!("# {}", name);
+            output::output_analysis(&mut io::stderr(), &divergence).unwrap_or_else(|err| {
+                eprintln!("Problem writing the divergence analysis: {}", err);
+                process::exit(1);
+            });
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Module to detect the divergence of the solution.
+//!
+//! The solution of the transport equation is only advected, so that `\max |u|` never grows in the exact solution.
+//! The solution is regarded as diverging when `\max |u|` first exceeds the growth threshold times its initial value,
+//! which is [DEFAULT_GROWTH_THRESHOLD] by default.
+
+use crate::solver::Solver;
+use ndarray::prelude::*;
+
+/// Default ratio of `\max |u|` to its initial value, beyond which the solution is regarded as diverging.
+pub const DEFAULT_GROWTH_THRESHOLD: f64 = 2.0;
+
+/// Divergence of the solution, recorded at the step where `\max |u|` first exceeded the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// Step where the divergence is detected.
+    pub step: usize,
+    /// Time where the divergence is detected.
+    pub t: f64,
+    /// Ratio of `\max |u|` to its initial value, beyond which the solution is regarded as diverging.
+    pub growth_threshold: f64,
+    /// Initial `\max |u|`.
+    pub u_max_init: f64,
+    /// `\max |u|` at the step.
+    pub u_max: f64,
+    /// CFL number `\nu = |c| \Delta t / \Delta x`.
+    pub cfl: f64,
+    /// Maximum amplification factor of the scheme over the Fourier modes.
+    pub amplification_factor: f64,
+}
+
+impl Divergence {
+    /// Return the growth rate of `\max |u|` per step estimated as `(\max |u| / \max |u_0|)^{1 / n}` at the step `n`,
+    /// which is to be compared with the amplification factor.
+    ///
+    /// # Examples
+    /// ```
+    /// use bad_upwind::divergence::Divergence;
+    ///
+    /// let divergence = Divergence {
+    ///     step: 2,
+    ///     t: 0.2,
+    ///     growth_threshold: 2.0,
+    ///     u_max_init: 1.0,
+    ///     u_max: 4.0,
+    ///     cfl: 1.0,
+    ///     amplification_factor: 3.0,
+    /// };
+    ///
+    /// assert_eq!(divergence.growth_rate(), 2.0);
+    /// ```
+    pub fn growth_rate(&self) -> f64 {
+        (self.u_max / self.u_max_init).powf(1.0 / self.step as f64)
+    }
+}
+
+/// Monitor of `\max |u|` to detect the divergence of the solution.
+#[derive(Debug)]
+pub struct DivergenceMonitor {
+    growth_threshold: f64,
+    u_max_init: f64,
+    divergence: Option<Divergence>,
+}
+
+impl DivergenceMonitor {
+    /// Create a new `DivergenceMonitor` instance with the initial value of `u`
+    /// and the ratio `growth_threshold` of `\max |u|` to its initial value.
+    pub fn new(u_init: &Array1<f64>, growth_threshold: f64) -> Self {
+        Self {
+            growth_threshold,
+            u_max_init: max_abs(u_init),
+            divergence: None,
+        }
+    }
+
+    /// Check `\max |u|` of the current solution of the `solver`
+    /// and return the divergence if it has been detected at this step or before.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use bad_upwind::divergence::{DivergenceMonitor, DEFAULT_GROWTH_THRESHOLD};
+    /// use bad_upwind::solver::upwind_solver::{Boundary, DiffMethod, UpwindSolver, UpwindSolverNewParams};
+    /// use bad_upwind::solver::Solver;
+    ///
+    /// let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+    /// let mut monitor = DivergenceMonitor::new(&u_init, DEFAULT_GROWTH_THRESHOLD);
+    /// let mut solver = UpwindSolver::new(UpwindSolverNewParams {
+    ///     u: u_init,
+    ///     v_adv: Array1::from_elem(5, 1.0),
+    ///     dx: 0.1,
+    ///     dt: 0.1,
+    ///     t_max: 1.0,
+    ///     diff_method: DiffMethod::Forward,
+    ///     boundary: Boundary::Fixed,
+    /// })
+    /// .unwrap();
+    ///
+    /// // u_1 = 1 - (0 - 1) = 2 is not greater than 2 max |u(t = 0)|
+    /// solver.integrate().unwrap();
+    /// assert!(monitor.check(&solver).is_none());
+    ///
+    /// // u_1 = 2 - (0 - 2) = 4 is
+    /// solver.integrate().unwrap();
+    /// let divergence = monitor.check(&solver).unwrap();
+    /// assert_eq!(divergence.step, 2);
+    /// assert_eq!(divergence.amplification_factor, 3.0);
+    /// ```
+    pub fn check(&mut self, solver: &impl Solver) -> Option<&Divergence> {
+        if self.divergence.is_none() {
+            let u_max = max_abs(solver.borrow_u());
+            if u_max > self.growth_threshold * self.u_max_init {
+                self.divergence = Some(Divergence {
+                    step: solver.get_step(),
+                    t: solver.get_t(),
+                    growth_threshold: self.growth_threshold,
+                    u_max_init: self.u_max_init,
+                    u_max,
+                    cfl: solver.get_cfl(),
+                    amplification_factor: solver.get_amplification_factor(),
+                });
+            }
+        }
+
+        self.divergence.as_ref()
+    }
+
+    /// Return the divergence if it has been detected.
+    pub fn get_divergence(&self) -> Option<&Divergence> {
+        self.divergence.as_ref()
+    }
+}
+
+fn max_abs(u: &Array1<f64>) -> f64 {
+    u.fold(0.0, |acc, u| acc.max(u.abs()))
+}
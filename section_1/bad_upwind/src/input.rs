@@ -1,43 +1,175 @@
 //! Module to read the input parameters.
 
+use crate::divergence::DEFAULT_GROWTH_THRESHOLD;
+use crate::solver::upwind_solver::Boundary;
+use ndarray::prelude::*;
 use serde_derive::{Deserialize, Serialize};
 use std::error::Error;
 use std::io::prelude::*;
 
+pub use cli::validation::{ValidationError, Violation};
+
 /// Input parameters.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct InputParams {
-    /// Advection velocity.
+    /// Advection velocity, which is negative for the advection to the left.
     pub v_adv: f64,
+    /// Profile of the advection velocity scaled by `v_adv`, which is [VelocityProfile::Uniform] if omitted.
+    #[serde(default)]
+    pub v_profile: VelocityProfile,
     /// Number of cells.
     pub n_x: usize,
     /// Maximum time.
     pub t_max: f64,
-    /// Time step.
-    pub dt: f64,
-    /// Number of cycles between outputs.
+    /// Time step, derived from `cfl` if omitted.
+    #[serde(default)]
+    pub dt: Option<f64>,
+    /// Target CFL number `\nu = |c| \Delta t / \Delta x`, from which `dt` is derived if `dt` is omitted.
+    #[serde(default)]
+    pub cfl: Option<f64>,
+    /// Boundary condition, which is [Boundary::Fixed] if omitted.
+    #[serde(default)]
+    pub boundary: Boundary,
+    /// Ratio of `\max |u|` to its initial value, beyond which the solution is regarded as diverging,
+    /// which is [DEFAULT_GROWTH_THRESHOLD] if omitted.
+    #[serde(default = "default_growth_threshold")]
+    pub growth_threshold: f64,
+    /// Stop the calculation on the divergence if `true`, which is `false` if omitted.
+    #[serde(default)]
+    pub stop_on_divergence: bool,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "default_ncycle_out")]
     pub ncycle_out: usize,
 }
 
+fn default_growth_threshold() -> f64 {
+    DEFAULT_GROWTH_THRESHOLD
+}
+
+fn default_ncycle_out() -> usize {
+    1
+}
+
+/// Profiles of the advection velocity `c(x)` on `x \in [-1, 1]`, whose maximum `|c(x)|` is `|v_{adv}|`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VelocityProfile {
+    /// Uniform velocity, `c(x) = v_{adv}`.
+    #[default]
+    Uniform,
+    /// Linear velocity, `c(x) = v_{adv} x`, which changes its sign at `x = 0`.
+    Linear,
+}
+
 impl InputParams {
-    fn validate_params(&self) -> Result<(), &'static str> {
-        if self.v_adv <= 0.0 {
-            return Err("v_adv must be positive");
-        }
-        if self.n_x == 0 {
-            return Err("n_x must be positive");
-        }
-        if self.t_max < self.dt {
-            return Err("t_max must be greater than or equal to dt");
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(
+            self.v_adv.is_finite(),
+            "v_adv",
+            self.v_adv,
+            "must be finite",
+        );
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        match (self.dt, self.cfl) {
+            (Some(dt), None) => {
+                errors.check(dt > 0.0, "dt", dt, "must be positive");
+                errors.check(
+                    dt <= 0.0 || self.t_max >= dt,
+                    "t_max",
+                    self.t_max,
+                    "must be greater than or equal to dt",
+                );
+            }
+            (None, Some(cfl)) => {
+                errors.check(cfl > 0.0, "cfl", cfl, "must be positive");
+                errors.check(
+                    self.v_adv != 0.0,
+                    "v_adv",
+                    self.v_adv,
+                    "must be nonzero to derive dt from cfl",
+                );
+                errors.check(self.t_max > 0.0, "t_max", self.t_max, "must be positive");
+            }
+            (None, None) => errors.check(false, "dt", self.dt, "must be given if cfl is not given"),
+            (Some(dt), Some(_)) => errors.check(false, "dt", dt, "must not be given with cfl"),
         }
-        if self.dt <= 0.0 {
-            return Err("dt must be positive");
-        }
-        if self.ncycle_out == 0 {
-            return Err("ncycle_out must be positive");
+        errors.check(
+            self.growth_threshold > 1.0,
+            "growth_threshold",
+            self.growth_threshold,
+            "must be greater than 1",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+impl InputParams {
+    /// Return the advection velocity at each point of `x` given by `v_profile`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use bad_upwind::divergence::DEFAULT_GROWTH_THRESHOLD;
+    /// use bad_upwind::input::{InputParams, VelocityProfile};
+    /// use bad_upwind::solver::upwind_solver::Boundary;
+    ///
+    /// let input_params = InputParams {
+    ///   v_adv: 2.0,
+    ///   v_profile: VelocityProfile::Linear,
+    ///   n_x: 2,
+    ///   t_max: 1.0,
+    ///   dt: Some(0.1),
+    ///   cfl: None,
+    ///   boundary: Boundary::Fixed,
+    ///   growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+    ///   stop_on_divergence: false,
+    ///   ncycle_out: 1,
+    /// };
+    ///
+    /// assert_eq!(input_params.velocity(&array![-1.0, 0.0, 1.0]), array![-2.0, 0.0, 2.0]);
+    /// ```
+    pub fn velocity(&self, x: &Array1<f64>) -> Array1<f64> {
+        match self.v_profile {
+            VelocityProfile::Uniform => Array1::from_elem(x.len(), self.v_adv),
+            VelocityProfile::Linear => x * self.v_adv,
         }
+    }
 
-        Ok(())
+    /// Return the time step, which is `dt` if given, or derived from `cfl` as `\Delta t = \nu \Delta x / |c|`
+    /// for the grid spacing `dx`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bad_upwind::input::{InputParams, VelocityProfile};
+    /// use bad_upwind::divergence::DEFAULT_GROWTH_THRESHOLD;
+    /// use bad_upwind::solver::upwind_solver::Boundary;
+    ///
+    /// let input_params = InputParams {
+    ///   v_adv: -2.0,
+    ///   v_profile: VelocityProfile::Uniform,
+    ///   n_x: 100,
+    ///   t_max: 1.0,
+    ///   dt: None,
+    ///   cfl: Some(0.5),
+    ///   boundary: Boundary::Fixed,
+    ///   growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+    ///   stop_on_divergence: false,
+    ///   ncycle_out: 1,
+    /// };
+    ///
+    /// assert!((input_params.time_step(0.02) - 0.005).abs() < 1e-12);
+    /// ```
+    pub fn time_step(&self, dx: f64) -> f64 {
+        self.dt
+            .unwrap_or_else(|| self.cfl.unwrap_or(0.0) * dx / self.v_adv.abs())
     }
 }
 
@@ -47,23 +179,38 @@ impl InputParams {
 /// The input must be formatted as follows:
 /// ```yaml
 /// v_adv: 1.0
+/// v_profile: uniform
 /// n_x: 100
 /// t_max: 1.0
 /// dt: 0.01
+/// boundary: fixed
+/// growth_threshold: 2.0
+/// stop_on_divergence: false
 /// ncycle_out: 1
 /// ```
+/// where `dt` can be replaced with the target CFL number, e.g. `cfl: 0.5`,
+/// and `boundary` is either `fixed` or `periodic`.
+/// `v_profile` is either `uniform` or `linear`,
+/// and `v_profile`, `boundary`, `growth_threshold`, `stop_on_divergence` and `ncycle_out` can be omitted.
 ///
 /// For the meaning of each parameter, see [InputParams].
 ///
 /// # Examples
 /// ```
-/// use bad_upwind::input::{self, InputParams};
+/// use bad_upwind::input::{self, InputParams, VelocityProfile};
+/// use bad_upwind::divergence::DEFAULT_GROWTH_THRESHOLD;
+/// use bad_upwind::solver::upwind_solver::Boundary;
 ///
 /// let input_params = InputParams {
 ///   v_adv: 1.0,
+///   v_profile: VelocityProfile::Uniform,
 ///   n_x: 100,
 ///   t_max: 1.0,
-///   dt: 0.01,
+///   dt: Some(0.01),
+///   cfl: None,
+///   boundary: Boundary::Fixed,
+///   growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+///   stop_on_divergence: false,
 ///   ncycle_out: 1,
 /// };
 /// let input_str = serde_yaml::to_string(&input_params).unwrap();
@@ -73,7 +220,8 @@ impl InputParams {
 /// ```
 ///
 /// # Errors
-/// Returns an error if the input is invalid.
+/// Returns an error if the input cannot be read or deserialized,
+/// or a [ValidationError] listing all the invalid parameters.
 pub fn read_input_params(inputstream: &mut impl Read) -> Result<InputParams, Box<dyn Error>> {
     let mut contents = String::new();
     inputstream.read_to_string(&mut contents)?;
@@ -82,3 +230,31 @@ pub fn read_input_params(inputstream: &mut impl Read) -> Result<InputParams, Box
 
     Ok(input_params)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_read_input_params_completes_minimal_input_by_defaults() {
+        let input_params =
+            read_input_params(&mut "v_adv: 1.0\nn_x: 100\nt_max: 1.0\ndt: 0.01".as_bytes())
+                .unwrap();
+
+        assert_eq!(
+            input_params,
+            InputParams {
+                v_adv: 1.0,
+                v_profile: VelocityProfile::Uniform,
+                n_x: 100,
+                t_max: 1.0,
+                dt: Some(0.01),
+                cfl: None,
+                boundary: Boundary::Fixed,
+                growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+                stop_on_divergence: false,
+                ncycle_out: 1,
+            }
+        );
+    }
+}
@@ -6,58 +6,89 @@
 //! We define the upwind method that uses information on the upwind side of the advection as a good upwind method and the one
 //! that uses information on the downwind side as a bad upwind method.
 //!
-//! Both the good and bad upwind methods are implemented in this crate.
+//! Both the good and bad upwind methods are implemented in this crate,
+//! together with the central difference method, which is unstable regardless of the direction of the advection.
+//! The second-order Lax-Wendroff method is also implemented as a reference,
+//! to compare the first-order good upwind method with a second-order good method.
+//!
+//! The advection velocity may vary in space and change its sign, in which case any fixed direction of the difference
+//! is bad on a part of the domain, while the hybrid upwind method selecting the direction at each point remains good.
 //!
 //! Using this crate, you can actually compute and see the difference between the good and bad upwind methods.
 
+pub mod divergence;
 pub mod input;
 pub mod output;
-pub mod upwind_solver;
+pub mod solver;
 
+use divergence::{Divergence, DivergenceMonitor};
 use ndarray::prelude::*;
+use solver::Solver;
 use std::error::Error;
 use std::io::Write;
-use upwind_solver::UpwindSolver;
 
-/// Run the solver and output the results.
+/// Run the solver and output the results, preceded by the header with the time step and the CFL number,
+/// see [output::output_header].
+///
+/// Returns the divergence of the solution if `\max |u|` has exceeded `growth_threshold` times its initial value,
+/// see [divergence], which can be explained by [output::output_analysis].
+/// If `stop_on_divergence` is `true`, the calculation is stopped at the divergence
+/// after the output of the current results and the summary, see [output::output_summary].
 pub fn run(
     x: &Array1<f64>,
-    upwind_solver: &mut UpwindSolver,
+    solver: &mut impl Solver,
     outputstream: &mut impl Write,
     ncycle_out: usize,
-) -> Result<(), Box<dyn Error>> {
+    growth_threshold: f64,
+    stop_on_divergence: bool,
+) -> Result<Option<Divergence>, Box<dyn Error>> {
+    let mut monitor = DivergenceMonitor::new(solver.borrow_u(), growth_threshold);
+
     // calculate and output
-    output::output(outputstream, 0.0, x, upwind_solver.borrow_u())?;
-    while !upwind_solver.is_completed() {
-        upwind_solver.integrate()?;
-
-        if upwind_solver.get_step() % ncycle_out == 0 {
-            output::output(
-                outputstream,
-                upwind_solver.get_t(),
-                x,
-                upwind_solver.borrow_u(),
-            )?;
+    output::output_header(outputstream, solver.get_dt(), solver.get_cfl())?;
+    output::output(outputstream, 0.0, x, solver.borrow_u())?;
+    while !solver.is_completed() {
+        solver.integrate()?;
+        let is_output_step = solver.get_step().is_multiple_of(ncycle_out);
+
+        if is_output_step {
+            output::output(outputstream, solver.get_t(), x, solver.borrow_u())?;
+        }
+
+        if let Some(divergence) = monitor.check(solver) {
+            if stop_on_divergence {
+                if !is_output_step {
+                    output::output(outputstream, solver.get_t(), x, solver.borrow_u())?;
+                }
+                output::output_summary(outputstream, divergence)?;
+                break;
+            }
         }
     }
 
-    Ok(())
+    Ok(monitor.get_divergence().cloned())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use input::InputParams;
-    use upwind_solver::DiffMethod;
+    use divergence::DEFAULT_GROWTH_THRESHOLD;
+    use input::{InputParams, VelocityProfile};
+    use solver::upwind_solver::{Boundary, DiffMethod, UpwindSolver, UpwindSolverNewParams};
 
     #[test]
     fn fn_run_works_with_good_upwind_method() {
         // setup input parameters
         let input_params = InputParams {
             v_adv: 1.0,
+            v_profile: VelocityProfile::Uniform,
             n_x: 20,
             t_max: 0.5,
-            dt: 0.1,
+            dt: Some(0.1),
+            cfl: None,
+            boundary: Boundary::Fixed,
+            growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+            stop_on_divergence: false,
             ncycle_out: 5,
         };
 
@@ -68,26 +99,31 @@ mod tests {
         let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
 
         // initialize the upwind solver
-        let mut upwind_solver = UpwindSolver::new(
-            x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
-            input_params.v_adv,
-            x[1] - x[0],
-            input_params.dt,
-            input_params.t_max,
-            DiffMethod::Backward,
-        );
+        let new_params = UpwindSolverNewParams {
+            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            v_adv: input_params.velocity(&x),
+            dx: x[1] - x[0],
+            dt: input_params.time_step(x[1] - x[0]),
+            t_max: input_params.t_max,
+            diff_method: DiffMethod::Backward,
+            boundary: input_params.boundary,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
 
         // execute run()
-        run(
+        let divergence = run(
             &x,
             &mut upwind_solver,
             &mut outputstream,
             input_params.ncycle_out,
+            input_params.growth_threshold,
+            input_params.stop_on_divergence,
         )
         .unwrap();
 
         // check if the output is correct
         let output_expected = "\
+# dt 1.0000000000e-1 nu 1.0000000000e0
 0.00 -1.0000000000 1.0000000000
 0.00 -0.9000000000 1.0000000000
 0.00 -0.8000000000 1.0000000000
@@ -136,6 +172,9 @@ mod tests {
 
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+
+        // check if no divergence is detected
+        assert!(divergence.is_none());
     }
 
     #[test]
@@ -143,9 +182,14 @@ mod tests {
         // setup input parameters
         let input_params = InputParams {
             v_adv: 1.0,
+            v_profile: VelocityProfile::Uniform,
             n_x: 20,
             t_max: 0.5,
-            dt: 0.1,
+            dt: Some(0.1),
+            cfl: None,
+            boundary: Boundary::Fixed,
+            growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+            stop_on_divergence: false,
             ncycle_out: 5,
         };
 
@@ -156,26 +200,31 @@ mod tests {
         let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
 
         // initialize the upwind solver
-        let mut upwind_solver = UpwindSolver::new(
-            x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
-            input_params.v_adv,
-            x[1] - x[0],
-            input_params.dt,
-            input_params.t_max,
-            DiffMethod::Forward,
-        );
+        let new_params = UpwindSolverNewParams {
+            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            v_adv: input_params.velocity(&x),
+            dx: x[1] - x[0],
+            dt: input_params.time_step(x[1] - x[0]),
+            t_max: input_params.t_max,
+            diff_method: DiffMethod::Forward,
+            boundary: input_params.boundary,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
 
         // execute run()
-        run(
+        let divergence = run(
             &x,
             &mut upwind_solver,
             &mut outputstream,
             input_params.ncycle_out,
+            input_params.growth_threshold,
+            input_params.stop_on_divergence,
         )
         .unwrap();
 
         // check if the output is correct
         let output_expected = "\
+# dt 1.0000000000e-1 nu 1.0000000000e0
 0.00 -1.0000000000 1.0000000000
 0.00 -0.9000000000 1.0000000000
 0.00 -0.8000000000 1.0000000000
@@ -224,5 +273,53 @@ mod tests {
 
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+
+        // check if the divergence is detected where max|u| first exceeds 2
+        let divergence = divergence.unwrap();
+        assert_eq!(divergence.step, 2);
+        assert_eq!(divergence.u_max, 4.0);
+        assert!((divergence.amplification_factor - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn fn_run_stops_on_divergence_with_bad_upwind_method() {
+        // setup coordinates and the bad upwind solver
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 21);
+        let new_params = UpwindSolverNewParams {
+            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            v_adv: Array1::from_elem(21, 1.0),
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Forward,
+            boundary: Boundary::Fixed,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+
+        // execute run() stopping on the divergence with the output every 5 steps
+        let mut outputstream: Vec<u8> = Vec::new();
+        let divergence = run(
+            &x,
+            &mut upwind_solver,
+            &mut outputstream,
+            5,
+            DEFAULT_GROWTH_THRESHOLD,
+            true,
+        )
+        .unwrap()
+        .unwrap();
+
+        // check if the calculation is stopped at step 2 after the output of the results and the summary
+        let output = String::from_utf8(outputstream).unwrap();
+        assert_eq!(divergence.step, 2);
+        assert_eq!(upwind_solver.get_step(), 2);
+        assert!(!upwind_solver.is_completed());
+        assert!(output.contains("0.20 -0.1000000000 4.0000000000\n"));
+        assert!(!output.contains("\n0.50 "));
+        assert!(output.ends_with(
+            "# stopped on divergence at step 2 (t = 2.0000000000e-1)\n\
+             # growth rate of max|u| per step (max|u| / max|u_0|)^(1 / 2) = 2.0000000000e0, \
+             amplification factor |g| = 3.0000000000e0\n"
+        ));
     }
 }
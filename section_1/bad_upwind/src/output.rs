@@ -1,8 +1,168 @@
 //! Module to output the results.
 
+use crate::divergence::Divergence;
 use ndarray::prelude::*;
 use std::io::{Error, Write};
 
+/// Output the header with the time step `dt` and the CFL number `nu`.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// # dt dt nu nu
+/// ```
+/// which is a comment line ignored by gnuplot.
+///
+/// # Examples
+/// ```
+/// use bad_upwind::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// output::output_header(&mut outputstream, 0.05, 0.5).unwrap();
+///
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), "# dt 5.0000000000e-2 nu 5.0000000000e-1\n");
+/// ```
+///
+/// # Errors
+/// Returns an error if output fails.
+pub fn output_header(outputstream: &mut impl Write, dt: f64, nu: f64) -> Result<(), Error> {
+    writeln!(outputstream, "# dt {:.10e} nu {:.10e}", dt, nu)?;
+
+    Ok(())
+}
+
+/// Output the analysis of the `divergence`, explaining why the solution diverged.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// # divergence analysis
+/// #   max|u| exceeded growth_threshold times its initial value max|u_0| at step step (t = t): max|u| = u_max, max|u_0| = u_max_init
+/// #   CFL number nu = |c| dt / dx = cfl
+/// #   maximum amplification factor |g| = amplification_factor over the Fourier modes
+/// #   ...
+/// ```
+/// where the last lines explain whether the amplification factor accounts for the divergence.
+///
+/// # Examples
+/// ```
+/// use bad_upwind::divergence::Divergence;
+/// use bad_upwind::output;
+///
+/// let divergence = Divergence {
+///     step: 2,
+///     t: 0.2,
+///     growth_threshold: 2.0,
+///     u_max_init: 1.0,
+///     u_max: 4.0,
+///     cfl: 1.0,
+///     amplification_factor: 3.0,
+/// };
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// output::output_analysis(&mut outputstream, &divergence).unwrap();
+///
+/// let output = String::from_utf8(outputstream).unwrap();
+/// assert!(output.starts_with("# divergence analysis\n"));
+/// assert!(output.contains("at step 2 (t = 2.0000000000e-1)"));
+/// assert!(output.contains("|g| = 3.0000000000e0"));
+/// ```
+///
+/// # Errors
+/// Returns an error if output fails.
+pub fn output_analysis(
+    outputstream: &mut impl Write,
+    divergence: &Divergence,
+) -> Result<(), Error> {
+    writeln!(outputstream, "# divergence analysis")?;
+    writeln!(
+        outputstream,
+        "#   max|u| exceeded {} times its initial value max|u_0| at step {} (t = {:.10e}): max|u| = {:.10e}, max|u_0| = {:.10e}",
+        divergence.growth_threshold, divergence.step, divergence.t, divergence.u_max, divergence.u_max_init
+    )?;
+    writeln!(
+        outputstream,
+        "#   CFL number nu = |c| dt / dx = {:.10e}",
+        divergence.cfl
+    )?;
+    writeln!(
+        outputstream,
+        "#   maximum amplification factor |g| = {:.10e} over the Fourier modes",
+        divergence.amplification_factor
+    )?;
+    if divergence.amplification_factor > 1.0 {
+        writeln!(
+            outputstream,
+            "#   the scheme is unstable since |g| > 1, so that the Fourier modes of the largest |g| grow as |g|^n with the step n"
+        )?;
+        writeln!(
+            outputstream,
+            "#   (|g|^{} = {:.10e}); use the upwind side of the advection with nu <= 1",
+            divergence.step,
+            divergence.amplification_factor.powi(divergence.step as i32)
+        )?;
+    } else {
+        writeln!(
+            outputstream,
+            "#   the scheme is stable since |g| <= 1, so that the growth comes from the boundary or the initial condition"
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Output the summary of the calculation stopped on the `divergence`.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// # stopped on divergence at step step (t = t)
+/// # growth rate of max|u| per step (max|u| / max|u_0|)^(1 / step) = growth_rate, amplification factor |g| = amplification_factor
+/// ```
+/// which are comment lines ignored by gnuplot.
+///
+/// # Examples
+/// ```
+/// use bad_upwind::divergence::Divergence;
+/// use bad_upwind::output;
+///
+/// let divergence = Divergence {
+///     step: 2,
+///     t: 0.2,
+///     growth_threshold: 2.0,
+///     u_max_init: 1.0,
+///     u_max: 4.0,
+///     cfl: 1.0,
+///     amplification_factor: 3.0,
+/// };
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// output::output_summary(&mut outputstream, &divergence).unwrap();
+///
+/// let output_expected = "\
+/// ## stopped on divergence at step 2 (t = 2.0000000000e-1)
+/// ## growth rate of max|u| per step (max|u| / max|u_0|)^(1 / 2) = 2.0000000000e0, amplification factor |g| = 3.0000000000e0
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if output fails.
+pub fn output_summary(outputstream: &mut impl Write, divergence: &Divergence) -> Result<(), Error> {
+    writeln!(
+        outputstream,
+        "# stopped on divergence at step {} (t = {:.10e})",
+        divergence.step, divergence.t
+    )?;
+    writeln!(
+        outputstream,
+        "# growth rate of max|u| per step (max|u| / max|u_0|)^(1 / {}) = {:.10e}, amplification factor |g| = {:.10e}",
+        divergence.step,
+        divergence.growth_rate(),
+        divergence.amplification_factor
+    )?;
+
+    Ok(())
+}
+
 /// Output the results.
 ///
 /// # Output Format
@@ -0,0 +1,36 @@
+//! Solvers for the transport equation.
+
+pub mod upwind_solver;
+
+use ndarray::prelude::*;
+use std::error::Error;
+
+/// Solver for the transport equation.
+pub trait Solver {
+    /// Return a reference to the current `u`.
+    fn borrow_u(&self) -> &Array1<f64>;
+    /// Return the current `t`.
+    fn get_t(&self) -> f64;
+    /// Return the time step `dt`.
+    fn get_dt(&self) -> f64;
+    /// Return the CFL number `\nu = |c| \Delta t / \Delta x`.
+    fn get_cfl(&self) -> f64;
+    /// Return the maximum amplification factor `\max_k |g(k)|` of the scheme over the Fourier modes `e^{i k x}`,
+    /// which is greater than `1` if the scheme is unstable.
+    fn get_amplification_factor(&self) -> f64;
+    /// Return the current `step`.
+    fn get_step(&self) -> usize;
+    /// Return `true` if the calculation has been completed.
+    fn is_completed(&self) -> bool;
+    /// Integrate the transport equation by one time step.
+    ///
+    /// # Errors
+    /// Returns an error if the calculation has already been completed.
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>>;
+}
+
+/// Parameters for creating a new solver.
+pub trait NewParams {
+    /// Validate the parameters for creating a new solver.
+    fn validate_new_params(&self) -> Result<(), &'static str>;
+}
@@ -0,0 +1,537 @@
+//! Solver for the transport equation using upwind method.
+//!
+//! # Scheme
+//! See [DiffMethod].
+//! The advection velocity `c_j` is given at each point and may change its sign over the domain,
+//! in which case `c` in the schemes is replaced with `c_j` at the point to be updated.
+//!
+//! # Boundary Condition
+//! See [Boundary].
+
+use super::{NewParams, Solver};
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+
+/// Solver for the transport equation using upwind method.
+#[derive(Debug)]
+pub struct UpwindSolver {
+    u: Array1<f64>,
+    v_adv: Array1<f64>,
+    dx: f64,
+    dt: f64,
+    t_max: f64,
+    t: f64,
+    step: usize,
+    diff_method: DiffMethod,
+    boundary: Boundary,
+    completed: bool,
+}
+
+impl UpwindSolver {
+    /// Create a new `UpwindSolver` instance.
+    pub fn new(new_params: UpwindSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let mut u = new_params.u;
+        if new_params.boundary == Boundary::Periodic {
+            let last = u.len() - 1;
+            u[last] = u[0];
+        }
+
+        Ok(Self {
+            u,
+            v_adv: new_params.v_adv,
+            dx: new_params.dx,
+            dt: new_params.dt,
+            t_max: new_params.t_max,
+            t: 0.0,
+            step: 0,
+            diff_method: new_params.diff_method,
+            boundary: new_params.boundary,
+            completed: false,
+        })
+    }
+}
+
+impl Solver for UpwindSolver {
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_t(&self) -> f64 {
+        self.t
+    }
+
+    fn get_dt(&self) -> f64 {
+        self.dt
+    }
+
+    fn get_cfl(&self) -> f64 {
+        self.v_adv.fold(0.0f64, |acc, v| acc.max(v.abs())) * self.dt / self.dx
+    }
+
+    fn get_amplification_factor(&self) -> f64 {
+        self.v_adv.fold(0.0f64, |acc, v| {
+            acc.max(self.diff_method.amplification_factor(v * self.dt / self.dx))
+        })
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.diff_method.calculate_u_next(
+            &self.u,
+            &self.v_adv,
+            self.dx,
+            self.dt,
+            self.boundary,
+        );
+        self.t += self.dt;
+        self.step += 1;
+
+        if self.t >= self.t_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `UpwindSolver` instance.
+pub struct UpwindSolverNewParams {
+    /// Initial value of `u`.
+    pub u: Array1<f64>,
+    /// Advection velocity at each point, which may change its sign.
+    pub v_adv: Array1<f64>,
+    /// Grid spacing.
+    pub dx: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Maximum time.
+    pub t_max: f64,
+    /// Difference method.
+    pub diff_method: DiffMethod,
+    /// Boundary condition.
+    pub boundary: Boundary,
+}
+
+impl NewParams for UpwindSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.v_adv.len() != self.u.len() {
+            return Err("v_adv must have the same length as u");
+        }
+        if !self.v_adv.iter().all(|v| v.is_finite()) {
+            return Err("v_adv must be finite");
+        }
+        if self.dx <= 0.0 {
+            return Err("dx must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+        if self.boundary == Boundary::Periodic && self.u.len() < 3 {
+            return Err("u must have at least 3 points for the periodic boundary condition");
+        }
+
+        Ok(())
+    }
+}
+
+/// Boundary conditions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Boundary {
+    /// Fixed boundary condition,
+    /// ```math
+    /// u(x_{\pm}, t) = u(x_{\pm}, 0),
+    /// ```
+    /// in which the end points are not updated.
+    #[default]
+    Fixed,
+    /// Periodic boundary condition,
+    /// ```math
+    /// u(x_{-}, t) = u(x_{+}, t),
+    /// ```
+    /// in which the end points are updated as well, with the neighbors wrapped around the domain.
+    ///
+    /// The first and last points are identified, so the initial value of the last point is replaced with that of the first point.
+    Periodic,
+}
+
+impl Boundary {
+    /// Return the indices of the left and right neighbors of the `i`-th point out of `len` points,
+    /// or `None` if the point is not updated.
+    fn neighbors(&self, i: usize, len: usize) -> Option<(usize, usize)> {
+        match self {
+            Boundary::Fixed if i == 0 || i == len - 1 => None,
+            Boundary::Fixed => Some((i - 1, i + 1)),
+            Boundary::Periodic => Some((
+                if i == 0 { len - 2 } else { i - 1 },
+                if i == len - 1 { 1 } else { i + 1 },
+            )),
+        }
+    }
+}
+
+/// Difference methods.
+#[derive(Debug)]
+pub enum DiffMethod {
+    /// Forward difference method.
+    ///
+    /// This method is given by
+    /// ```math
+    /// u_j^{n+1} = u_j^n -  c \frac{\Delta t}{\Delta x} (u_{j+1}^n - u_{j}^n).
+    /// ```
+    Forward,
+    /// Backward difference method.
+    ///
+    /// This method is given by
+    /// ```math
+    /// u_j^{n+1} = u_j^n -  c \frac{\Delta t}{\Delta x} (u_j^n - u_{j-1}^n).
+    /// ```
+    Backward,
+    /// Central difference method.
+    ///
+    /// This method is given by
+    /// ```math
+    /// u_j^{n+1} = u_j^n -  c \frac{\Delta t}{2 \Delta x} (u_{j+1}^n - u_{j-1}^n),
+    /// ```
+    /// which is unstable for any `\Delta t`, since the amplification factor
+    /// `1 - i c \frac{\Delta t}{\Delta x} \sin(k \Delta x)` of each Fourier mode is greater than `1` in magnitude.
+    Central,
+    /// Upwind method choosing the direction from the sign of the advection velocity.
+    ///
+    /// This method is [DiffMethod::Backward] for `c_j \ge 0` and [DiffMethod::Forward] for `c_j < 0`,
+    /// selected at each point `j`, that is, the good upwind method for either direction of the advection.
+    /// If `c_j` changes its sign over the domain, this hybrid upwind method remains good everywhere,
+    /// whereas either of the fixed directions is bad on the part of the domain where the advection goes the other way.
+    Auto,
+    /// Lax-Wendroff method.
+    ///
+    /// This method is given by
+    /// ```math
+    /// u_j^{n+1} = u_j^n - \frac{1}{2} \nu (u_{j+1}^n - u_{j-1}^n) + \frac{1}{2} \nu^2 (u_{j+1}^n - 2 u_j^n + u_{j-1}^n),
+    /// ```
+    /// where `\nu = c \frac{\Delta t}{\Delta x}`,
+    /// which is of the second order in both space and time for the uniform `c` and stable for `|\nu| \le 1` in either direction.
+    /// Unlike the first-order upwind methods, it is less diffusive but causes oscillations around the discontinuities.
+    ///
+    /// This is the same scheme as `linear_hyperbolic::solver::laxwendroff_solver` in section 2,
+    /// written in the single-step form here to be compared with the upwind methods.
+    Laxwendroff,
+}
+
+impl DiffMethod {
+    /// Return the maximum amplification factor over the Fourier modes for `\nu = c \frac{\Delta t}{\Delta x}`,
+    /// which is given by
+    /// - `\max(1, |1 + 2 \nu|)` for [DiffMethod::Forward],
+    /// - `\max(1, |1 - 2 \nu|)` for [DiffMethod::Backward],
+    /// - `\sqrt{1 + \nu^2}` for [DiffMethod::Central],
+    /// - `\max(1, |1 - 2 |\nu||)` for [DiffMethod::Auto],
+    /// - `\max(1, |1 - 2 \nu^2|)` for [DiffMethod::Laxwendroff],
+    ///
+    /// where the factors greater than `1` are attained by the shortest wave `k \Delta x = \pi`
+    /// except for [DiffMethod::Central], whose largest factor is attained by `k \Delta x = \pi / 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use bad_upwind::solver::upwind_solver::DiffMethod;
+    ///
+    /// assert_eq!(DiffMethod::Forward.amplification_factor(1.0), 3.0);
+    /// assert_eq!(DiffMethod::Backward.amplification_factor(1.0), 1.0);
+    /// assert_eq!(DiffMethod::Auto.amplification_factor(-1.0), 1.0);
+    /// ```
+    pub fn amplification_factor(&self, nu: f64) -> f64 {
+        match self {
+            DiffMethod::Forward => (1.0 + 2.0 * nu).abs().max(1.0),
+            DiffMethod::Backward => (1.0 - 2.0 * nu).abs().max(1.0),
+            DiffMethod::Central => (1.0 + nu * nu).sqrt(),
+            DiffMethod::Auto => (1.0 - 2.0 * nu.abs()).abs().max(1.0),
+            DiffMethod::Laxwendroff => (1.0 - 2.0 * nu * nu).abs().max(1.0),
+        }
+    }
+
+    fn calculate_u_next(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        match self {
+            DiffMethod::Forward => self.calculate_u_next_by_forward(u, v_adv, dx, dt, boundary),
+            DiffMethod::Backward => self.calculate_u_next_by_backward(u, v_adv, dx, dt, boundary),
+            DiffMethod::Central => self.calculate_u_next_by_central(u, v_adv, dx, dt, boundary),
+            DiffMethod::Auto => self.calculate_u_next_by_auto(u, v_adv, dx, dt, boundary),
+            DiffMethod::Laxwendroff => {
+                self.calculate_u_next_by_laxwendroff(u, v_adv, dx, dt, boundary)
+            }
+        }
+    }
+
+    fn calculate_u_next_by_forward(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        u.indexed_iter()
+            .map(|(i, _)| match boundary.neighbors(i, u.len()) {
+                Some((_, i_r)) => u[i] - v_adv[i] * dt / dx * (u[i_r] - u[i]),
+                None => u[i],
+            })
+            .collect()
+    }
+
+    fn calculate_u_next_by_backward(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        u.indexed_iter()
+            .map(|(i, _)| match boundary.neighbors(i, u.len()) {
+                Some((i_l, _)) => u[i] - v_adv[i] * dt / dx * (u[i] - u[i_l]),
+                None => u[i],
+            })
+            .collect()
+    }
+
+    fn calculate_u_next_by_central(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        u.indexed_iter()
+            .map(|(i, _)| match boundary.neighbors(i, u.len()) {
+                Some((i_l, i_r)) => u[i] - 0.5 * v_adv[i] * dt / dx * (u[i_r] - u[i_l]),
+                None => u[i],
+            })
+            .collect()
+    }
+
+    fn calculate_u_next_by_auto(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        u.indexed_iter()
+            .map(|(i, _)| match boundary.neighbors(i, u.len()) {
+                Some((i_l, _)) if v_adv[i] >= 0.0 => u[i] - v_adv[i] * dt / dx * (u[i] - u[i_l]),
+                Some((_, i_r)) => u[i] - v_adv[i] * dt / dx * (u[i_r] - u[i]),
+                None => u[i],
+            })
+            .collect()
+    }
+
+    fn calculate_u_next_by_laxwendroff(
+        &self,
+        u: &Array1<f64>,
+        v_adv: &Array1<f64>,
+        dx: f64,
+        dt: f64,
+        boundary: Boundary,
+    ) -> Array1<f64> {
+        u.indexed_iter()
+            .map(|(i, _)| match boundary.neighbors(i, u.len()) {
+                Some((i_l, i_r)) => {
+                    let nu = v_adv[i] * dt / dx;
+                    u[i] - 0.5 * nu * (u[i_r] - u[i_l])
+                        + 0.5 * nu * nu * (u[i_r] - 2.0 * u[i] + u[i_l])
+                }
+                None => u[i],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_upwind_integrate_works() {
+        // setup upwind solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: Array1::from_elem(5, 1.0),
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Backward,
+            boundary: Boundary::Fixed,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.0, 1.0, 0.0, 0.0];
+        let is_u_correctly_updated = (&upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert!((upwind_solver.t - 0.1).abs() < 1e-10);
+        assert_eq!(upwind_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_central_integrate_works() {
+        // setup central solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: Array1::from_elem(5, 1.0),
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Central,
+            boundary: Boundary::Fixed,
+        };
+        let mut central_solver = UpwindSolver::new(new_params).unwrap();
+        central_solver.integrate().unwrap();
+
+        // check if u is updated from both sides of each point
+        let u_exact = array![1.0, 1.5, 0.5, 0.0, 0.0];
+        let is_u_correctly_updated = (&central_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_auto_integrate_works_with_negative_v_adv() {
+        // setup upwind solver with the leftward advection and run integrate()
+        let u_init = array![0.0, 0.0, 0.0, 1.0, 1.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: Array1::from_elem(5, -1.0),
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Auto,
+            boundary: Boundary::Fixed,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u is advected to the left by the forward difference
+        let u_exact = array![0.0, 0.0, 1.0, 1.0, 1.0];
+        let is_u_correctly_updated = (&upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_upwind_new_fails_with_invalid_dx() {
+        // setup the parameters with the zero grid spacing
+        let new_params = UpwindSolverNewParams {
+            u: array![1.0, 0.0],
+            v_adv: array![1.0, 1.0],
+            dx: 0.0,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Backward,
+            boundary: Boundary::Fixed,
+        };
+
+        // check if the creation fails
+        assert_eq!(
+            UpwindSolver::new(new_params).unwrap_err(),
+            "dx must be positive"
+        );
+    }
+
+    #[test]
+    fn fn_upwind_integrate_works_with_periodic_boundary() {
+        // setup upwind solver with the pulse next to the right end and run integrate()
+        let u_init = array![0.0, 0.0, 0.0, 1.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: Array1::from_elem(5, 1.0),
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Backward,
+            boundary: Boundary::Periodic,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if the pulse wraps around to the left end, which is identified with the right end
+        let u_exact = array![1.0, 0.0, 0.0, 0.0, 1.0];
+        let is_u_correctly_updated = (&upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_laxwendroff_integrate_works() {
+        // setup Lax-Wendroff solver with the CFL number 0.5 and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: Array1::from_elem(5, 1.0),
+            dx: 0.1,
+            dt: 0.05,
+            t_max: 0.5,
+            diff_method: DiffMethod::Laxwendroff,
+            boundary: Boundary::Fixed,
+        };
+        let mut laxwendroff_solver = UpwindSolver::new(new_params).unwrap();
+        laxwendroff_solver.integrate().unwrap();
+
+        // check if u overshoots behind the step, which the first-order upwind methods never do
+        let u_exact = array![1.0, 1.125, 0.375, 0.0, 0.0];
+        let is_u_correctly_updated = (&laxwendroff_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_auto_integrate_works_with_sign_changing_v_adv() {
+        // setup upwind solver with the advection away from the center and run integrate()
+        let u_init = array![0.0, 1.0, 0.0, 1.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            v_adv: array![-1.0, -0.5, 0.0, 0.5, 1.0],
+            dx: 0.1,
+            dt: 0.1,
+            t_max: 0.5,
+            diff_method: DiffMethod::Auto,
+            boundary: Boundary::Fixed,
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u is taken from the upwind side at each point, that is, from the center
+        let u_exact = array![0.0, 0.5, 0.0, 0.5, 0.0];
+        let is_u_correctly_updated = (&upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert!((upwind_solver.get_cfl() - 1.0).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,75 @@
+//! Benchmark the red-black SOR sweeps on the CPU and the GPU on the grid of `2049 \times 2049` points.
+//!
+//! Run with the `gpu` feature, and with the `parallel` feature to compare with the parallel sweeps on the CPU:
+//! ```text
+//! cargo bench -p elliptic --bench gpu_sweeps --features gpu
+//! cargo bench -p elliptic --bench gpu_sweeps --features gpu,parallel
+//! ```
+
+use elliptic::solver::gpu_solver::{GpuSolver, GpuSolverNewParams, Sweep};
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::red_black_sor_solver::{RedBlackSorSolver, RedBlackSorSolverNewParams};
+use elliptic::solver::relaxation_parameter::RelaxationParameter;
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::time::Instant;
+
+const N: usize = 2049;
+const N_ITER: usize = 10;
+const N_SWEEPS: usize = 100;
+
+/// Time the sweeps of the red-black SOR method on the CPU and the GPU and print the time per sweep.
+fn main() {
+    let mut u_init: Array2<f64> = Array::zeros((N, N));
+    u_init.slice_mut(s![.., N - 1]).fill(1.0);
+    let grid = Grid::uniform((N, N), (1.0 / (N - 1) as f64, 1.0 / (N - 1) as f64)).unwrap();
+
+    let red_black_sor_solver = RedBlackSorSolver::new(RedBlackSorSolverNewParams {
+        u_init: u_init.clone(),
+        grid: grid.clone(),
+        n_iter_max: N_ITER,
+        initial_guess: InitialGuess::Zero,
+        source: None,
+        k_squared: 0.0,
+        boundary_conditions: Default::default(),
+        obstacle: None,
+        omega: 1.9,
+    })
+    .unwrap();
+    bench("red_black_sor", red_black_sor_solver, 1);
+
+    let gpu_solver = GpuSolver::new(GpuSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: N_ITER,
+        initial_guess: InitialGuess::Zero,
+        source: None,
+        k_squared: 0.0,
+        boundary_conditions: Default::default(),
+        obstacle: None,
+        sweep: Sweep::RedBlackSor(RelaxationParameter::Fixed(1.9)),
+        n_sweeps: N_SWEEPS,
+    });
+    match gpu_solver {
+        Ok(gpu_solver) => bench("gpu_red_black_sor", gpu_solver, N_SWEEPS),
+        Err(err) => println!("gpu_red_black_sor: skipped, {}.", err),
+    }
+}
+
+/// Run the `solver` up to the maximum number of iterations of `n_sweeps` sweeps and print the time per sweep.
+fn bench(name: &str, mut solver: impl Solver, n_sweeps: usize) {
+    let start = Instant::now();
+    // the solver stops at the maximum number of iterations without converging
+    let _ = solver.exec();
+    let elapsed = start.elapsed();
+    let n_sweeps = solver.get_n_iter() * n_sweeps;
+    println!(
+        "{}: {} sweeps of {} x {} points, {:.3} ms per sweep.",
+        name,
+        n_sweeps,
+        N,
+        N,
+        elapsed.as_secs_f64() * 1e3 / n_sweeps as f64
+    );
+}
@@ -0,0 +1,68 @@
+//! Benchmark the sweeps of the point Jacobi and red-black SOR solvers on the grid of `1025 \times 1025` points.
+//!
+//! Run with and without the `parallel` feature to compare the sequential and parallel sweeps:
+//! ```text
+//! cargo bench -p elliptic --bench parallel_sweeps
+//! cargo bench -p elliptic --bench parallel_sweeps --features parallel
+//! ```
+
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+use elliptic::solver::red_black_sor_solver::{RedBlackSorSolver, RedBlackSorSolverNewParams};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::time::Instant;
+
+const N: usize = 1025;
+const N_ITER: usize = 10;
+
+/// Time `N_ITER` sweeps of each solver and print the time per sweep.
+fn main() {
+    let mut u_init: Array2<f64> = Array::zeros((N, N));
+    u_init.slice_mut(s![.., N - 1]).fill(1.0);
+    let grid = Grid::uniform((N, N), (1.0 / (N - 1) as f64, 1.0 / (N - 1) as f64)).unwrap();
+
+    let point_jacobi_solver = PointJacobiSolver::new(PointJacobiSolverNewParams {
+        u_init: u_init.clone(),
+        grid: grid.clone(),
+        n_iter_max: N_ITER,
+        initial_guess: InitialGuess::Zero,
+        source: None,
+        k_squared: 0.0,
+        boundary_conditions: Default::default(),
+        obstacle: None,
+    })
+    .unwrap();
+    bench("point_jacobi", point_jacobi_solver);
+
+    let red_black_sor_solver = RedBlackSorSolver::new(RedBlackSorSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: N_ITER,
+        initial_guess: InitialGuess::Zero,
+        source: None,
+        k_squared: 0.0,
+        boundary_conditions: Default::default(),
+        obstacle: None,
+        omega: 1.9,
+    })
+    .unwrap();
+    bench("red_black_sor", red_black_sor_solver);
+}
+
+/// Run the `solver` up to the maximum number of iterations and print the time per sweep.
+fn bench(name: &str, mut solver: impl Solver) {
+    let start = Instant::now();
+    // the solver stops at the maximum number of iterations without converging
+    let _ = solver.exec();
+    let elapsed = start.elapsed();
+    println!(
+        "{}: {} sweeps of {} x {} points, {:.3} ms per sweep.",
+        name,
+        solver.get_n_iter(),
+        N,
+        N,
+        elapsed.as_secs_f64() * 1e3 / solver.get_n_iter() as f64
+    );
+}
@@ -0,0 +1,177 @@
+//! Compare the number of iterations of the relaxation methods for each strategy of [InitialGuess].
+//!
+//! # Formulation
+//! The same problem as [elliptic::solver::point_jacobi_solver] and [elliptic::solver::sor_solver] is solved,
+//! that is, the Laplace's equation
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! ```
+//! with the boundary condition
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 20
+//! n_y: 20
+//! n_iter_max: 10000
+//! omega: 1.5
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecCompareInitialGuessesInputParams].
+//!
+//! # Output Format
+//! The output is formatted as follows:
+//! ```text
+//! initial_guess n_iter_point_jacobi n_iter_saved_point_jacobi n_iter_sor n_iter_saved_sor
+//! zero 100 0 50 0
+//! bilinear 80 20 40 10
+//! coarse_grid 60 40 30 20
+//! ```
+//! where `n_iter_saved_*` is the number of iterations saved compared to [InitialGuess::Zero].
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecCompareInitialGuessesInputParams;
+use elliptic::solver::boundary::BoundaryConditions;
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+use elliptic::solver::relaxation_parameter::{self, RelaxationParameter};
+use elliptic::solver::sor_solver::{SorSolver, SorSolverNewParams};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Solve the Laplace's equation with each initial guess and output the number of iterations to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/compare_initial_guesses_for_laplace_eq");
+
+    // read input parameters
+    let input_params: ExecCompareInitialGuessesInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let grid = Grid::uniform((input_params.n_x + 1, input_params.n_y + 1), (1.0, 1.0))
+            .unwrap_or_else(|err| {
+                eprintln!("Problem creating grid: {}", err);
+                process::exit(1);
+            });
+        let rho_jacobi = BoundaryConditions::default().jacobi_spectral_radius(&grid, 0.0);
+        let omega = match input_params.omega {
+            RelaxationParameter::Fixed(omega) => format!(
+                "{:.10e} (rho_SOR = {:.10e})",
+                omega,
+                relaxation_parameter::sor_spectral_radius(rho_jacobi, omega)
+            ),
+            RelaxationParameter::Estimated(estimation) => format!("{:?}", estimation),
+        };
+        Report::new()
+            .quantity(
+                "grid points",
+                format!("{} x {}", grid.dim().0, grid.dim().1),
+            )
+            .quantity("rho_J", format!("{:.10e}", rho_jacobi))
+            .quantity("omega", omega)
+            .convergence(if rho_jacobi < 1.0 {
+                "convergent since rho_J < 1"
+            } else {
+                "divergent since rho_J >= 1"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("iterations.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    compare(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Solve the problem with each initial guess and output the number of iterations.
+fn compare(
+    input_params: &ExecCompareInitialGuessesInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    // setup boundary conditions
+    let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
+    u_init
+        .slice_mut(s![.., input_params.n_y])
+        .assign(&Array::ones(input_params.n_x + 1));
+
+    // calculate and output
+    writeln!(
+        outputstream,
+        "initial_guess n_iter_point_jacobi n_iter_saved_point_jacobi n_iter_sor n_iter_saved_sor"
+    )?;
+    let mut n_iter_zero: Option<(usize, usize)> = None;
+    for (name, initial_guess) in [
+        ("zero", InitialGuess::Zero),
+        ("bilinear", InitialGuess::Bilinear),
+        ("coarse_grid", InitialGuess::CoarseGrid),
+    ] {
+        let mut point_jacobi_solver = PointJacobiSolver::new(PointJacobiSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0))?,
+            n_iter_max: input_params.n_iter_max,
+            initial_guess,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        })?;
+        point_jacobi_solver.exec()?;
+
+        let mut sor_solver = SorSolver::new(SorSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0))?,
+            n_iter_max: input_params.n_iter_max,
+            initial_guess,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: input_params.omega,
+        })?;
+        sor_solver.exec()?;
+
+        let n_iter = (point_jacobi_solver.get_n_iter(), sor_solver.get_n_iter());
+        let n_iter_zero = *n_iter_zero.get_or_insert(n_iter);
+        writeln!(
+            outputstream,
+            "{} {} {} {} {}",
+            name,
+            n_iter.0,
+            n_iter_zero.0 as i64 - n_iter.0 as i64,
+            n_iter.1,
+            n_iter_zero.1 as i64 - n_iter.1 as i64,
+        )?;
+        println!(
+            "{}: Point Jacobi {} iterations, SOR {} iterations.",
+            name, n_iter.0, n_iter.1
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,372 @@
+//! Compare the number of iterations, the wall time, the final residual and the error of the solvers for the same problem.
+//!
+//! # Formulation
+//! The same problem as [elliptic::solver::point_jacobi_solver] and [elliptic::solver::sor_solver] is solved,
+//! that is, the Laplace's equation
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! ```
+//! with the boundary condition
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//!
+//! The problem is solved by the point Jacobi method, the Gauss-Seidel method,
+//! the SOR, red-black SOR and line SOR methods with each relaxation parameter in `omega_list`,
+//! the SOR method with the optimal and adaptive relaxation parameters,
+//! the conjugate gradient method without and with the incomplete Cholesky preconditioner
+//! and the direct method.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 40
+//! n_y: 40
+//! n_iter_max: 100000
+//! omega_list: [1.5, 1.8]
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecCompareMethodsInputParams].
+//!
+//! # Output Format
+//! The output is formatted as follows:
+//! ```text
+//! method omega n_iter time_ms residual error_l2
+//! point_jacobi - 3000 100.000 1.0000e-8 1.0000e-3
+//! gauss_seidel 1.0000 1500 60.000 1.0000e-8 1.0000e-3
+//! sor 1.5000 500 20.000 1.0000e-8 1.0000e-3
+//! ...
+//! direct - 1 5.000 1.0000e-13 1.0000e-3
+//! ```
+//! where `time_ms` is the wall time in milliseconds including the construction of the solver
+//! and `residual` is the L2 norm of the final residual, see [Solver::borrow_residual_history].
+//! `error_l2` is the L2 norm of the error from the exact solution given by [exact::box_with_unit_top],
+//! which is dominated by the discretization error once the iteration has converged,
+//! so that it is common to the converged solvers.
+//! `omega` is the relaxation parameter used by the solver, `-` for the solvers without it.
+//! If a solver doesn't converge within `n_iter_max` iterations, the error is reported and its row is output anyway.
+//!
+//! Finally, the direct solutions on the grid and the grid halving the spacings are combined
+//! by the Richardson extrapolation of [elliptic::extrapolation] with `p = 2`,
+//! and the L2 errors of the solutions and the extrapolation are printed together with the L2 norm of the error indicator.
+
+use cli::check::Report;
+use elliptic::exact::{self, ErrorNorms};
+use elliptic::extrapolation::Extrapolation;
+use elliptic::input;
+use elliptic::input::examples::ExecCompareMethodsInputParams;
+use elliptic::solver::boundary::BoundaryConditions;
+use elliptic::solver::conjugate_gradient_solver::{
+    ConjugateGradientSolver, ConjugateGradientSolverNewParams,
+};
+use elliptic::solver::direct_solver::{DirectSolver, DirectSolverNewParams};
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::line_sor_solver::{LineSorSolver, LineSorSolverNewParams};
+use elliptic::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+use elliptic::solver::preconditioner::PreconditionerKind;
+use elliptic::solver::red_black_sor_solver::{RedBlackSorSolver, RedBlackSorSolverNewParams};
+use elliptic::solver::relaxation_parameter::{self, Estimation, RelaxationParameter};
+use elliptic::solver::sor_solver::{SorSolver, SorSolverNewParams};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+use std::time::Instant;
+
+/// Number of terms of the series of the exact solution.
+const N_TERMS: usize = 10000;
+
+/// Solve the Laplace's equation with each method and output the summary to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/compare_methods_for_laplace_eq");
+
+    // read input parameters
+    let input_params: ExecCompareMethodsInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let grid = Grid::uniform((input_params.n_x + 1, input_params.n_y + 1), (1.0, 1.0))
+            .unwrap_or_else(|err| {
+                eprintln!("Problem creating grid: {}", err);
+                process::exit(1);
+            });
+        let rho_jacobi = BoundaryConditions::default().jacobi_spectral_radius(&grid, 0.0);
+        let rho_sor: Vec<String> = input_params
+            .omega_list
+            .iter()
+            .map(|&omega| {
+                format!(
+                    "{:.10e}",
+                    relaxation_parameter::sor_spectral_radius(rho_jacobi, omega)
+                )
+            })
+            .collect();
+        Report::new()
+            .quantity(
+                "grid points",
+                format!("{} x {}", grid.dim().0, grid.dim().1),
+            )
+            .quantity("rho_J", format!("{:.10e}", rho_jacobi))
+            .quantity(
+                "optimal omega",
+                format!("{:.10e}", relaxation_parameter::optimal_omega(rho_jacobi)),
+            )
+            .quantity("rho_SOR", format!("[{}]", rho_sor.join(", ")))
+            .convergence(if rho_jacobi < 1.0 {
+                "convergent since rho_J < 1"
+            } else {
+                "divergent since rho_J >= 1"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("summary.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    compare(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Solve the problem with each method and output the summary.
+fn compare(
+    input_params: &ExecCompareMethodsInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    // setup boundary conditions
+    let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
+    u_init
+        .slice_mut(s![.., input_params.n_y])
+        .assign(&Array::ones(input_params.n_x + 1));
+    let grid = Grid::uniform(u_init.dim(), (1.0, 1.0))?;
+    let boundary_conditions = BoundaryConditions::default();
+    let n_iter_max = input_params.n_iter_max;
+    let u_exact = exact::box_with_unit_top(&grid, N_TERMS);
+
+    // calculate and output
+    writeln!(
+        outputstream,
+        "method omega n_iter time_ms residual error_l2"
+    )?;
+    measure(outputstream, &u_exact, "point_jacobi", None, || {
+        PointJacobiSolver::new(PointJacobiSolverNewParams {
+            u_init: u_init.clone(),
+            grid: grid.clone(),
+            n_iter_max,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: None,
+        })
+    })?;
+
+    let create_sor_solver = |omega: RelaxationParameter| {
+        SorSolver::new(SorSolverNewParams {
+            u_init: u_init.clone(),
+            grid: grid.clone(),
+            n_iter_max,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: None,
+            omega,
+        })
+    };
+    measure(outputstream, &u_exact, "gauss_seidel", Some(1.0), || {
+        create_sor_solver(RelaxationParameter::Fixed(1.0))
+    })?;
+    for &omega in &input_params.omega_list {
+        measure(outputstream, &u_exact, "sor", Some(omega), || {
+            create_sor_solver(RelaxationParameter::Fixed(omega))
+        })?;
+        measure(outputstream, &u_exact, "red_black_sor", Some(omega), || {
+            RedBlackSorSolver::new(RedBlackSorSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions,
+                obstacle: None,
+                omega,
+            })
+        })?;
+        measure(outputstream, &u_exact, "line_sor", Some(omega), || {
+            LineSorSolver::new(LineSorSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions,
+                obstacle: None,
+                omega,
+            })
+        })?;
+    }
+    for (name, estimation) in [
+        ("sor_optimal", Estimation::Optimal),
+        ("sor_adaptive", Estimation::Adaptive),
+    ] {
+        // the relaxation parameter is known only after the iteration
+        let start = Instant::now();
+        let mut solver = create_sor_solver(RelaxationParameter::Estimated(estimation))?;
+        let result = solver.exec();
+        output(
+            outputstream,
+            &u_exact,
+            name,
+            Some(solver.get_omega()),
+            &solver,
+            start,
+            result,
+        )?;
+    }
+
+    for preconditioner in [
+        PreconditionerKind::Identity,
+        PreconditionerKind::IncompleteCholesky,
+    ] {
+        let name = format!("conjugate_gradient_{}", preconditioner.name());
+        measure(outputstream, &u_exact, &name, None, || {
+            ConjugateGradientSolver::new(ConjugateGradientSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions,
+                obstacle: None,
+                preconditioner: preconditioner.create(u_init.dim())?,
+            })
+        })?;
+    }
+
+    measure(outputstream, &u_exact, "direct", None, || {
+        DirectSolver::new(DirectSolverNewParams {
+            u_init: u_init.clone(),
+            grid: grid.clone(),
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: None,
+        })
+    })?;
+
+    extrapolate(input_params)?;
+
+    Ok(())
+}
+
+/// Extrapolate the direct solutions on the grid and the grid halving the spacings, and print the errors.
+fn extrapolate(input_params: &ExecCompareMethodsInputParams) -> Result<(), Box<dyn Error>> {
+    let solve = |refinement: usize| -> Result<(Array2<f64>, Grid), Box<dyn Error>> {
+        let (n_x, n_y) = (refinement * input_params.n_x, refinement * input_params.n_y);
+        let mut u_init: Array2<f64> = Array::zeros((n_x + 1, n_y + 1));
+        u_init.slice_mut(s![.., n_y]).fill(1.0);
+        let spacing = 1.0 / refinement as f64;
+        let grid = Grid::uniform(u_init.dim(), (spacing, spacing))?;
+        let mut solver = DirectSolver::new(DirectSolverNewParams {
+            u_init,
+            grid: grid.clone(),
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        })?;
+        solver.exec()?;
+
+        Ok((solver.borrow_u().clone(), grid))
+    };
+    let (u_coarse, grid_coarse) = solve(1)?;
+    let (u_fine, grid_fine) = solve(2)?;
+    let extrapolation = Extrapolation::new(&u_coarse, &u_fine, 2.0)?;
+
+    let u_exact_coarse = exact::box_with_unit_top(&grid_coarse, N_TERMS);
+    let u_exact_fine = exact::box_with_unit_top(&grid_fine, N_TERMS);
+    let error_l2 =
+        |u: &Array2<f64>, u_exact: &Array2<f64>, grid: &Grid| ErrorNorms::new(u, u_exact, grid).l2;
+    println!(
+        "Richardson extrapolation: L2 errors {:.4e} (coarse), {:.4e} (fine), {:.4e} (extrapolated), indicator {:.4e}.",
+        error_l2(&u_coarse, &u_exact_coarse, &grid_coarse),
+        error_l2(&u_fine, &u_exact_fine, &grid_fine),
+        error_l2(&extrapolation.u, &u_exact_coarse, &grid_coarse),
+        error_l2(&extrapolation.error, &Array::zeros(u_coarse.dim()), &grid_coarse),
+    );
+
+    Ok(())
+}
+
+/// Create the solver by `create_solver`, execute it and output the summary,
+/// measuring the wall time of both the creation and the execution.
+fn measure<S: Solver>(
+    outputstream: &mut impl Write,
+    u_exact: &Array2<f64>,
+    name: &str,
+    omega: Option<f64>,
+    create_solver: impl FnOnce() -> Result<S, &'static str>,
+) -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+    let mut solver = create_solver()?;
+    let result = solver.exec();
+    output(outputstream, u_exact, name, omega, &solver, start, result)
+}
+
+/// Output the summary of the executed solver, reporting the error of the execution if any.
+fn output(
+    outputstream: &mut impl Write,
+    u_exact: &Array2<f64>,
+    name: &str,
+    omega: Option<f64>,
+    solver: &impl Solver,
+    start: Instant,
+    result: Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let time_ms = start.elapsed().as_secs_f64() * 1e3;
+    if let Err(err) = result {
+        eprintln!("Warning: {} failed: {}", name, err);
+    }
+
+    let n_iter = solver.get_n_iter();
+    let residual = solver
+        .borrow_residual_history()
+        .last()
+        .copied()
+        .unwrap_or(0.0);
+    let error_l2 = ErrorNorms::new(solver.borrow_u(), u_exact, solver.borrow_grid()).l2;
+    let omega_str = omega.map_or_else(|| "-".to_string(), |omega| format!("{:.4}", omega));
+    writeln!(
+        outputstream,
+        "{} {} {} {:.3} {:.4e} {:.4e}",
+        name, omega_str, n_iter, time_ms, residual, error_l2
+    )?;
+    println!(
+        "{} (omega = {}): {} iterations, {:.3} ms, residual {:.4e}, L2 error {:.4e}.",
+        name, omega_str, n_iter, time_ms, residual, error_l2
+    );
+
+    Ok(())
+}
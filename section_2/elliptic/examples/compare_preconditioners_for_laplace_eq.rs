@@ -0,0 +1,154 @@
+//! Compare the number of iterations of the conjugate gradient method for each [PreconditionerKind].
+//!
+//! # Formulation
+//! The same problem as [elliptic::solver::conjugate_gradient_solver] is solved,
+//! that is, the Laplace's equation
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! ```
+//! with the boundary condition
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 40
+//! n_y: 40
+//! n_iter_max: 10000
+//! omega: 1.5
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecComparePreconditionersInputParams].
+//!
+//! # Output Format
+//! The output is formatted as follows:
+//! ```text
+//! preconditioner n_iter n_iter_saved
+//! identity 100 0
+//! jacobi 100 0
+//! ssor 40 60
+//! incomplete_cholesky 50 50
+//! ```
+//! where `n_iter_saved` is the number of iterations saved compared to [PreconditionerKind::Identity].
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecComparePreconditionersInputParams;
+use elliptic::solver::boundary::BoundaryConditions;
+use elliptic::solver::conjugate_gradient_solver::{
+    ConjugateGradientSolver, ConjugateGradientSolverNewParams,
+};
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::preconditioner::PreconditionerKind;
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Solve the Laplace's equation with each preconditioner and output the number of iterations to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/compare_preconditioners_for_laplace_eq");
+
+    // read input parameters
+    let input_params: ExecComparePreconditionersInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let grid = Grid::uniform((input_params.n_x + 1, input_params.n_y + 1), (1.0, 1.0))
+            .unwrap_or_else(|err| {
+                eprintln!("Problem creating grid: {}", err);
+                process::exit(1);
+            });
+        let rho_jacobi = BoundaryConditions::default().jacobi_spectral_radius(&grid, 0.0);
+        Report::new()
+            .quantity(
+                "grid points",
+                format!("{} x {}", grid.dim().0, grid.dim().1),
+            )
+            .quantity("rho_J", format!("{:.10e}", rho_jacobi))
+            .quantity("omega", format!("{:.10e}", input_params.omega))
+            .convergence(if rho_jacobi < 1.0 {
+                "convergent since rho_J < 1"
+            } else {
+                "divergent since rho_J >= 1"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("iterations.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    compare(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Solve the problem with each preconditioner and output the number of iterations.
+fn compare(
+    input_params: &ExecComparePreconditionersInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    // setup boundary conditions
+    let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
+    u_init
+        .slice_mut(s![.., input_params.n_y])
+        .assign(&Array::ones(input_params.n_x + 1));
+
+    // calculate and output
+    writeln!(outputstream, "preconditioner n_iter n_iter_saved")?;
+    let mut n_iter_identity: Option<usize> = None;
+    for preconditioner in [
+        PreconditionerKind::Identity,
+        PreconditionerKind::Jacobi,
+        PreconditionerKind::Ssor {
+            omega: input_params.omega,
+        },
+        PreconditionerKind::IncompleteCholesky,
+    ] {
+        let mut solver = ConjugateGradientSolver::new(ConjugateGradientSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0))?,
+            n_iter_max: input_params.n_iter_max,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            preconditioner: preconditioner.create(u_init.dim())?,
+        })?;
+        solver.exec()?;
+
+        let n_iter = solver.get_n_iter();
+        let n_iter_identity = *n_iter_identity.get_or_insert(n_iter);
+        writeln!(
+            outputstream,
+            "{} {} {}",
+            preconditioner.name(),
+            n_iter,
+            n_iter_identity as i64 - n_iter as i64,
+        )?;
+        println!("{}: {} iterations.", preconditioner.name(), n_iter);
+    }
+
+    Ok(())
+}
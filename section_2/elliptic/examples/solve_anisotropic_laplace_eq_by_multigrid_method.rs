@@ -0,0 +1,156 @@
+//! Solve the anisotropic diffusion equation by the [elliptic::solver::multigrid_solver].
+//!
+//! # Formulation
+//! The diffusion equation is given by
+//! ```math
+//! \epsilon \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} + k^2 u = f,
+//! ```
+//! where `u` is the diffusion quantity, `f` is the uniform source term, which is zero for the Laplace's equation,
+//! `k^2` is the coefficient of the Helmholtz term, which is zero for the Laplace's and Poisson's equations,
+//! and `\epsilon` is the anisotropy coefficient, which is one for the isotropic equation.
+//!
+//! The boundary condition is given by
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//! See also [elliptic::solver::multigrid_solver] for the boundary condition.
+//! The boundary condition at each edge can be replaced in the input file, see [BoundaryConditions](elliptic::solver::boundary::BoundaryConditions),
+//! where the fixed edges keep the values above.
+//!
+//! # Scheme
+//! See [elliptic::solver::multigrid_solver], which compares [Coarsening](elliptic::solver::multigrid_solver::Coarsening) and [Smoother](elliptic::solver::multigrid_solver::Smoother) for `\epsilon \ll 1`.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 64
+//! n_y: 64
+//! dx: 0.015625
+//! dy: 0.015625
+//! n_iter_max: 100
+//! coarsening: semi_y
+//! smoother: gauss_seidel
+//! initial_guess: zero
+//! source: 0.0
+//! k_squared: 0.0
+//! anisotropy: 0.001
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecMultigridInputParams].
+//! The grid is uniform with the spacings `dx` and `dy` unless the coordinates of the grid points are given as
+//! ```yaml
+//! x: [0.0, 0.01, 0.03, ...]
+//! y: [0.0, 0.01, 0.03, ...]
+//! ```
+//! with `n_x + 1` and `n_y + 1` elements, which must be strictly increasing.
+//!
+//! # Output Format
+//! The solution is output to `solution.dat`, see [elliptic::output::output].
+//! The history of the norms of the residuals at every V-cycle is output to `residual.dat`,
+//! see [elliptic::output::output_residual_history].
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecMultigridInputParams;
+use elliptic::solver::grid::Grid;
+use elliptic::solver::multigrid_solver::{MultigridSolver, MultigridSolverNewParams};
+use elliptic::solver::{Convergence, Solver};
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the anisotropic diffusion equation with the given input parameters and output the results to files.
+fn main() {
+    // parse command line arguments
+    let exec_args =
+        cli::parse("section_2/elliptic/solve_anisotropic_laplace_eq_by_multigrid_method");
+
+    // read input parameters
+    let input_params: ExecMultigridInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup initial and boundary conditions
+    let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
+    u_init
+        .slice_mut(s![.., input_params.n_y])
+        .assign(&Array::ones(input_params.n_x + 1));
+
+    // setup the grid
+    let create_coords = |coords: &Option<Vec<f64>>, n: usize, spacing: f64| {
+        coords.clone().map_or_else(
+            || Array::from_shape_fn(n + 1, |i| i as f64 * spacing),
+            Array::from,
+        )
+    };
+    let grid = Grid::new(
+        create_coords(&input_params.x, input_params.n_x, input_params.dx),
+        create_coords(&input_params.y, input_params.n_y, input_params.dy),
+    )
+    .and_then(|grid| grid.with_anisotropy(input_params.anisotropy))
+    .unwrap_or_else(|err| {
+        eprintln!("Problem creating grid: {}", err);
+        process::exit(1);
+    });
+
+    // initialize the solver
+    let new_params = MultigridSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: input_params.n_iter_max,
+        initial_guess: input_params.initial_guess,
+        source: Some(Array::from_elem(
+            (input_params.n_x + 1, input_params.n_y + 1),
+            input_params.source,
+        )),
+        k_squared: input_params.k_squared,
+        boundary_conditions: input_params.boundary_conditions,
+        obstacle: None,
+        coarsening: input_params.coarsening,
+        smoother: input_params.smoother,
+    };
+    let mut solver = MultigridSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+    if let Convergence::Divergent(reason) = solver.get_convergence() {
+        eprintln!("Warning: {}", reason);
+    }
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (n_x, n_y) = solver.borrow_grid().dim();
+        let (dx_min, dy_min) = solver.borrow_grid().min_spacing();
+        Report::new()
+            .quantity("grid points", format!("{} x {}", n_x, n_y))
+            .quantity("min dx", format!("{:.10e}", dx_min))
+            .quantity("min dy", format!("{:.10e}", dy_min))
+            .quantity("levels", solver.get_n_levels())
+            .convergence(solver.get_convergence())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+    let mut residualfile = File::create(output_dir.join("residual.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    elliptic::run_with_residual_history(&mut solver, &mut outputfile, &mut residualfile)
+        .unwrap_or_else(|err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        });
+}
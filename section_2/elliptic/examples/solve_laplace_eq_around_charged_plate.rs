@@ -0,0 +1,132 @@
+//! Solve the Laplace's equation around the charged plate in the grounded box by the [elliptic::solver::sor_solver].
+//!
+//! # Formulation
+//! The Laplace's equation is given by
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! ```
+//! where `u` is the electric potential.
+//!
+//! The boundary condition is given by
+//! ```math
+//! u(x, y) = 0 (x = x_{\pm} or y = y_{\pm}),
+//! ```
+//! and the plate `x_{p-} \le x \le x_{p+}, y = y_p` inside the box is charged to the potential `u_p`,
+//! which is given as the obstacle.
+//! See also [elliptic::solver::sor_solver] for the boundary condition.
+//!
+//! # Scheme
+//! See [elliptic::solver::sor_solver].
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 40
+//! n_y: 40
+//! dx: 0.025
+//! dy: 0.025
+//! n_iter_max: 10000
+//! omega: 1.8
+//! initial_guess: zero
+//! plate_x_min: 0.25
+//! plate_x_max: 0.75
+//! plate_y: 0.5
+//! plate_potential: 1.0
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecChargedPlateInputParams].
+//!
+//! # Output Format
+//! See [elliptic::output::output].
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecChargedPlateInputParams;
+use elliptic::solver::grid::Grid;
+use elliptic::solver::sor_solver::{SorSolver, SorSolverNewParams};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the Laplace's equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/solve_laplace_eq_around_charged_plate");
+
+    // read input parameters
+    let input_params: ExecChargedPlateInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup the grid
+    let dim = (input_params.n_x + 1, input_params.n_y + 1);
+    let grid = Grid::uniform(dim, (input_params.dx, input_params.dy)).unwrap_or_else(|err| {
+        eprintln!("Problem creating grid: {}", err);
+        process::exit(1);
+    });
+
+    // setup the charged plate as the obstacle in the grounded box
+    let obstacle = Array::from_shape_fn(dim, |(i_x, i_y)| {
+        let (x, y) = (grid.borrow_x()[i_x], grid.borrow_y()[i_y]);
+        input_params.plate_x_min <= x
+            && x <= input_params.plate_x_max
+            && (y - input_params.plate_y).abs() <= 0.5 * input_params.dy
+    });
+    let u_init = obstacle.mapv(|is_plate| {
+        if is_plate {
+            input_params.plate_potential
+        } else {
+            0.0
+        }
+    });
+
+    // initialize the solver
+    let new_params = SorSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: input_params.n_iter_max,
+        initial_guess: input_params.initial_guess,
+        source: None,
+        k_squared: 0.0,
+        boundary_conditions: Default::default(),
+        obstacle: Some(obstacle),
+        omega: input_params.omega,
+    };
+    let mut solver = SorSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (n_x, n_y) = solver.borrow_grid().dim();
+        let (dx_min, dy_min) = solver.borrow_grid().min_spacing();
+        Report::new()
+            .quantity("grid points", format!("{} x {}", n_x, n_y))
+            .quantity("min dx", format!("{:.10e}", dx_min))
+            .quantity("min dy", format!("{:.10e}", dy_min))
+            .quantity("omega", format!("{:.10e}", solver.get_omega()))
+            .convergence(solver.get_convergence())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    elliptic::run(&mut solver, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
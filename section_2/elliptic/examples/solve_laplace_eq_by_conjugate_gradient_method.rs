@@ -0,0 +1,161 @@
+//! Solve the diffusion equation by the [elliptic::solver::conjugate_gradient_solver].
+//!
+//! # Formulation
+//! The diffusion equation is given by
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} + k^2 u = f,
+//! ```
+//! where `u` is the diffusion quantity, `f` is the uniform source term, which is zero for the Laplace's equation,
+//! and `k^2` is the coefficient of the Helmholtz term, which is zero for the Laplace's and Poisson's equations.
+//!
+//! The boundary condition is given by
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//! See also [elliptic::solver::conjugate_gradient_solver] for the boundary condition.
+//! The boundary condition at each edge can be replaced in the input file, see [BoundaryConditions](elliptic::solver::boundary::BoundaryConditions),
+//! where the fixed edges keep the values above.
+//!
+//! # Scheme
+//! See [elliptic::solver::conjugate_gradient_solver].
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 20
+//! n_y: 20
+//! dx: 0.05
+//! dy: 0.05
+//! n_iter_max: 10000
+//! initial_guess: zero
+//! source: 0.0
+//! k_squared: 0.0
+//! preconditioner: ssor
+//! omega: 1.5
+//! ```
+//!
+//! The preconditioner is one of [PreconditionerKind](elliptic::solver::preconditioner::PreconditionerKind), of which only `ssor` takes `omega`.
+//!
+//! For the meaning of each parameter, see [ExecConjugateGradientInputParams].
+//! The grid is uniform with the spacings `dx` and `dy` unless the coordinates of the grid points are given as
+//! ```yaml
+//! x: [0.0, 0.01, 0.03, ...]
+//! y: [0.0, 0.01, 0.03, ...]
+//! ```
+//! with `n_x + 1` and `n_y + 1` elements, which must be strictly increasing.
+//!
+//! # Output Format
+//! The solution is output to `solution.dat`, see [elliptic::output::output].
+//! The history of the norms of the residuals is output to `residual.dat`,
+//! see [elliptic::output::output_residual_history].
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecConjugateGradientInputParams;
+use elliptic::solver::conjugate_gradient_solver::{
+    ConjugateGradientSolver, ConjugateGradientSolverNewParams,
+};
+use elliptic::solver::grid::Grid;
+use elliptic::solver::{Convergence, Solver};
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the diffusion equation with the given input parameters and output the results to files.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/solve_laplace_eq_by_conjugate_gradient_method");
+
+    // read input parameters
+    let input_params: ExecConjugateGradientInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup initial and boundary conditions
+    let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
+    u_init
+        .slice_mut(s![.., input_params.n_y])
+        .assign(&Array::ones(input_params.n_x + 1));
+
+    // setup the grid
+    let create_coords = |coords: &Option<Vec<f64>>, n: usize, spacing: f64| {
+        coords.clone().map_or_else(
+            || Array::from_shape_fn(n + 1, |i| i as f64 * spacing),
+            Array::from,
+        )
+    };
+    let grid = Grid::new(
+        create_coords(&input_params.x, input_params.n_x, input_params.dx),
+        create_coords(&input_params.y, input_params.n_y, input_params.dy),
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Problem creating grid: {}", err);
+        process::exit(1);
+    });
+
+    // initialize the solver
+    let preconditioner = input_params
+        .preconditioner
+        .create(u_init.dim())
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating preconditioner: {}", err);
+            process::exit(1);
+        });
+    let new_params = ConjugateGradientSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: input_params.n_iter_max,
+        initial_guess: input_params.initial_guess,
+        source: Some(Array::from_elem(
+            (input_params.n_x + 1, input_params.n_y + 1),
+            input_params.source,
+        )),
+        k_squared: input_params.k_squared,
+        boundary_conditions: input_params.boundary_conditions,
+        obstacle: None,
+        preconditioner,
+    };
+    let mut solver = ConjugateGradientSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+    if let Convergence::Divergent(reason) = solver.get_convergence() {
+        eprintln!("Warning: {}", reason);
+    }
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (n_x, n_y) = solver.borrow_grid().dim();
+        let (dx_min, dy_min) = solver.borrow_grid().min_spacing();
+        Report::new()
+            .quantity("grid points", format!("{} x {}", n_x, n_y))
+            .quantity("min dx", format!("{:.10e}", dx_min))
+            .quantity("min dy", format!("{:.10e}", dy_min))
+            .convergence(solver.get_convergence())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+    let mut residualfile = File::create(output_dir.join("residual.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    elliptic::run_with_residual_history(&mut solver, &mut outputfile, &mut residualfile)
+        .unwrap_or_else(|err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        });
+}
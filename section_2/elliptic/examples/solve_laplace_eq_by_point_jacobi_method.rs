@@ -3,15 +3,18 @@
 //! # Formulation
 //! The diffusion equation is given by
 //! ```math
-//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} + k^2 u = f,
 //! ```
-//! where `u` is the diffusion quantity.
+//! where `u` is the diffusion quantity, `f` is the uniform source term, which is zero for the Laplace's equation,
+//! and `k^2` is the coefficient of the Helmholtz term, which is zero for the Laplace's and Poisson's equations.
 //!
 //! The boundary condition is given by
 //! ```math
 //! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
 //! ```
 //! See also [elliptic::solver::point_jacobi_solver] for the boundary condition.
+//! The boundary condition at each edge can be replaced in the input file, see [BoundaryConditions](elliptic::solver::boundary::BoundaryConditions),
+//! where the fixed edges keep the values above.
 //!
 //! # Scheme
 //! See [elliptic::solver::point_jacobi_solver].
@@ -21,94 +24,195 @@
 //! ```yaml
 //! n_x: 20
 //! n_y: 20
+//! dx: 0.05
+//! dy: 0.05
 //! n_iter_max: 10000
+//! initial_guess: zero
+//! source: 0.0
+//! k_squared: 0.0
 //! ```
 //!
 //! For the meaning of each parameter, see [ExecPointJacobiInputParams].
+//! The grid is uniform with the spacings `dx` and `dy` unless the coordinates of the grid points are given as
+//! ```yaml
+//! x: [0.0, 0.01, 0.03, ...]
+//! y: [0.0, 0.01, 0.03, ...]
+//! ```
+//! with `n_x + 1` and `n_y + 1` elements, which must be strictly increasing.
+//! The snapshots of `u` during the iteration are output every `ncycle_out` iterations if given as
+//! ```yaml
+//! ncycle_out: 50
+//! ```
+//! The sweeps are executed on the GPU by `elliptic::solver::gpu_solver` with the `gpu` feature if given as
+//! ```yaml
+//! backend: gpu
+//! n_sweeps: 100
+//! ```
+//! where each iteration consists of `n_sweeps` sweeps.
 //!
 //! # Output Format
-//! See [elliptic::output::output].
+//! The solution is output to `solution.dat`, see [elliptic::output::output].
+//! The history of the norms of the residuals is output to `residual.dat`,
+//! see [elliptic::output::output_residual_history].
+//! The snapshots are output to `snapshots.dat` if `ncycle_out` is given, see [elliptic::output::output_snapshot].
 
+use cli::check::Report;
+use cli::ExecArgs;
 use elliptic::input;
-use elliptic::input::InputParams;
+use elliptic::input::examples::ExecPointJacobiInputParams;
+use elliptic::solver::backend::Backend;
+#[cfg(feature = "gpu")]
+use elliptic::solver::gpu_solver::{GpuSolver, GpuSolverNewParams, Sweep};
+use elliptic::solver::grid::Grid;
 use elliptic::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+use elliptic::solver::{Convergence, RelaxationSolver};
 use ndarray::prelude::*;
-use serde_derive::{Deserialize, Serialize};
 use std::fs::{self, File};
 use std::process;
 
-/// Solve the diffusion equation with the given input parameters and output the results to a file.
+/// Solve the diffusion equation with the given input parameters and output the results to files.
 fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/solve_laplace_eq_by_point_jacobi_method");
+
     // read input parameters
-    let mut inputfile =
-        File::open("inputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method/input.yml")
-            .unwrap_or_else(|err| {
-                eprintln!("Problem opening input file: {}", err);
-                process::exit(1);
-            });
-    let input_params: ExecPointJacobiInputParams = input::read_input_params(&mut inputfile)
+    let input_params: ExecPointJacobiInputParams = exec_args
+        .read_input_params(input::read_input_params)
         .unwrap_or_else(|err| {
             eprintln!("Problem reading input parameters: {}", err);
             process::exit(1);
         });
 
-    // setup output files
-    let dir_str = "outputs/section_2/elliptic/solve_laplace_eq_by_point_jacobi_method";
-    fs::create_dir_all(dir_str).unwrap_or_else(|err| {
-        eprintln!("Problem creating output directory: {}", err);
-        process::exit(1);
-    });
-    let mut outputfile = File::create(format!("{}/solution.dat", dir_str)).unwrap_or_else(|err| {
-        eprintln!("Problem creating output files: {}", err);
-        process::exit(1);
-    });
-
     // setup initial and boundary conditions
     let mut u_init: Array2<f64> = Array::zeros((input_params.n_x + 1, input_params.n_y + 1));
     u_init
         .slice_mut(s![.., input_params.n_y])
         .assign(&Array::ones(input_params.n_x + 1));
 
-    // initialize the solver
-    let new_params = PointJacobiSolverNewParams {
-        u_init,
-        n_iter_max: input_params.n_iter_max,
+    // setup the grid
+    let create_coords = |coords: &Option<Vec<f64>>, n: usize, spacing: f64| {
+        coords.clone().map_or_else(
+            || Array::from_shape_fn(n + 1, |i| i as f64 * spacing),
+            Array::from,
+        )
     };
-    let mut solver = PointJacobiSolver::new(new_params).unwrap_or_else(|err| {
-        eprintln!("Problem creating solver: {}", err);
+    let grid = Grid::new(
+        create_coords(&input_params.x, input_params.n_x, input_params.dx),
+        create_coords(&input_params.y, input_params.n_y, input_params.dy),
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Problem creating grid: {}", err);
         process::exit(1);
     });
 
-    // run
-    elliptic::run(&mut solver, &mut outputfile).unwrap_or_else(|err| {
-        eprintln!("Application error: {}", err);
-        process::exit(1);
-    });
-}
-
-/// Input parameters.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ExecPointJacobiInputParams {
-    /// Number of grids in x direction.
-    pub n_x: usize,
-    /// Number of grids in y direction.
-    pub n_y: usize,
-    /// Maximum number of iterations.
-    pub n_iter_max: usize,
-}
-
-impl InputParams for ExecPointJacobiInputParams {
-    fn validate_params(&self) -> Result<(), &'static str> {
-        if self.n_x == 0 {
-            return Err("n_x must be positive");
+    // initialize the solver on the backend and run
+    let source = Array::from_elem(
+        (input_params.n_x + 1, input_params.n_y + 1),
+        input_params.source,
+    );
+    match input_params.backend {
+        Backend::Cpu => {
+            let new_params = PointJacobiSolverNewParams {
+                u_init,
+                grid,
+                n_iter_max: input_params.n_iter_max,
+                initial_guess: input_params.initial_guess,
+                source: Some(source),
+                k_squared: input_params.k_squared,
+                boundary_conditions: input_params.boundary_conditions,
+                obstacle: None,
+            };
+            let solver = PointJacobiSolver::new(new_params).unwrap_or_else(|err| {
+                eprintln!("Problem creating solver: {}", err);
+                process::exit(1);
+            });
+            execute(solver, &exec_args, &input_params);
         }
-        if self.n_y == 0 {
-            return Err("n_y must be positive");
+        #[cfg(feature = "gpu")]
+        Backend::Gpu => {
+            let new_params = GpuSolverNewParams {
+                u_init,
+                grid,
+                n_iter_max: input_params.n_iter_max,
+                initial_guess: input_params.initial_guess,
+                source: Some(source),
+                k_squared: input_params.k_squared,
+                boundary_conditions: input_params.boundary_conditions,
+                obstacle: None,
+                sweep: Sweep::PointJacobi,
+                n_sweeps: input_params.n_sweeps,
+            };
+            let solver = GpuSolver::new(new_params).unwrap_or_else(|err| {
+                eprintln!("Problem creating solver: {}", err);
+                process::exit(1);
+            });
+            execute(solver, &exec_args, &input_params);
         }
-        if self.n_iter_max == 0 {
-            return Err("n_iter_max must be positive");
+        #[cfg(not(feature = "gpu"))]
+        Backend::Gpu => {
+            unreachable!("backend gpu is rejected by the validation without the gpu feature")
         }
+    }
+}
 
-        Ok(())
+/// Execute the `solver` as given by the command line arguments and output the results to files.
+fn execute(
+    mut solver: impl RelaxationSolver,
+    exec_args: &ExecArgs,
+    input_params: &ExecPointJacobiInputParams,
+) {
+    if let Convergence::Divergent(reason) = solver.get_convergence() {
+        eprintln!("Warning: {}", reason);
     }
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (n_x, n_y) = solver.borrow_grid().dim();
+        let (dx_min, dy_min) = solver.borrow_grid().min_spacing();
+        Report::new()
+            .quantity("grid points", format!("{} x {}", n_x, n_y))
+            .quantity("min dx", format!("{:.10e}", dx_min))
+            .quantity("min dy", format!("{:.10e}", dy_min))
+            .convergence(solver.get_convergence())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+    let mut residualfile = File::create(output_dir.join("residual.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    let result = match input_params.ncycle_out {
+        Some(ncycle_out) => {
+            let mut snapshotfile =
+                File::create(output_dir.join("snapshots.dat")).unwrap_or_else(|err| {
+                    eprintln!("Problem creating output files: {}", err);
+                    process::exit(1);
+                });
+            elliptic::run_with_snapshots(
+                &mut solver,
+                &mut outputfile,
+                &mut residualfile,
+                &mut snapshotfile,
+                ncycle_out,
+            )
+        }
+        None => {
+            elliptic::run_with_residual_history(&mut solver, &mut outputfile, &mut residualfile)
+        }
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
 }
@@ -0,0 +1,151 @@
+//! Solve the Laplace's equation in the disk by the [elliptic::solver::polar_sor_solver]
+//! and compare the solution with the series solution.
+//!
+//! # Formulation
+//! The Laplace's equation in the polar coordinates is given by
+//! ```math
+//! \frac{1}{r} \frac{\partial}{\partial r} \left(r \frac{\partial u}{\partial r}\right)
+//!     + \frac{1}{r^2} \frac{\partial^2 u}{\partial \theta^2} = 0,
+//! ```
+//! where `u` is the diffusion quantity.
+//!
+//! The boundary condition at the circle `r = R` is given by the Fourier series
+//! ```math
+//! u(R, \theta) = \sum_n (a_n \cos n \theta + b_n \sin n \theta),
+//! ```
+//! for which the series solution is
+//! ```math
+//! u(r, \theta) = \sum_n \left(\frac{r}{R}\right)^n (a_n \cos n \theta + b_n \sin n \theta).
+//! ```
+//! See also [elliptic::solver::polar_sor_solver] for the boundary condition.
+//!
+//! # Scheme
+//! See [elliptic::solver::polar_sor_solver].
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_r: 20
+//! n_theta: 64
+//! r_max: 1.0
+//! n_iter_max: 10000
+//! omega: 1.8
+//! cos_coefs: [0.0, 1.0, 0.0, 0.5]
+//! sin_coefs: [0.0, 0.0, 0.5]
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecPolarSorInputParams].
+//!
+//! # Output Format
+//! See [elliptic::output::output], where the x and y coordinates are `r` and `\theta`, respectively.
+//! The maximum difference from the series solution is printed to the standard output.
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecPolarSorInputParams;
+use elliptic::solver::grid::Grid;
+use elliptic::solver::polar_sor_solver::{PolarSorSolver, PolarSorSolverNewParams};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the Laplace's equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/solve_laplace_eq_in_disk_by_polar_sor_method");
+
+    // read input parameters
+    let input_params: ExecPolarSorInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup the grid
+    let grid = Grid::polar(
+        Array::linspace(0.0, input_params.r_max, input_params.n_r + 1),
+        input_params.n_theta,
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Problem creating grid: {}", err);
+        process::exit(1);
+    });
+
+    // setup the series solution and the boundary condition
+    let u_series = Array::from_shape_fn(grid.dim(), |(i_r, i_theta)| {
+        let (r, theta) = (grid.borrow_x()[i_r], grid.borrow_y()[i_theta]);
+        series_solution(&input_params, r, theta)
+    });
+    let mut u_init: Array2<f64> = Array::zeros(grid.dim());
+    u_init
+        .row_mut(input_params.n_r)
+        .assign(&u_series.row(input_params.n_r));
+
+    // initialize the solver
+    let new_params = PolarSorSolverNewParams {
+        u_init,
+        grid,
+        n_iter_max: input_params.n_iter_max,
+        source: None,
+        omega: input_params.omega,
+    };
+    let mut solver = PolarSorSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (n_x, n_y) = solver.borrow_grid().dim();
+        let (dx_min, dy_min) = solver.borrow_grid().min_spacing();
+        Report::new()
+            .quantity("grid points", format!("{} x {}", n_x, n_y))
+            .quantity("min dr", format!("{:.10e}", dx_min))
+            .quantity("min dtheta", format!("{:.10e}", dy_min))
+            .quantity("omega", format!("{:.10e}", input_params.omega))
+            .convergence(solver.get_convergence())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    elliptic::run(&mut solver, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+    let error_max = (solver.borrow_u() - &u_series)
+        .iter()
+        .fold(0.0, |error_max: f64, error| error_max.max(error.abs()));
+    println!(
+        "The maximum difference from the series solution is {:.3e}.",
+        error_max
+    );
+}
+
+/// Return the series solution at `(r, \theta)`.
+fn series_solution(input_params: &ExecPolarSorInputParams, r: f64, theta: f64) -> f64 {
+    let r_normalized = r / input_params.r_max;
+    let cos_terms = input_params
+        .cos_coefs
+        .iter()
+        .enumerate()
+        .map(|(n, a)| a * r_normalized.powi(n as i32) * (n as f64 * theta).cos());
+    let sin_terms = input_params
+        .sin_coefs
+        .iter()
+        .enumerate()
+        .map(|(n, b)| b * r_normalized.powi(n as i32) * (n as f64 * theta).sin());
+
+    cos_terms.chain(sin_terms).sum()
+}
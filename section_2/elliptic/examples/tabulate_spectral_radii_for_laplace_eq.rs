@@ -0,0 +1,203 @@
+//! Tabulate the spectral radii of the iteration matrices of the relaxation methods predicted by the theory
+//! and the power iteration of [elliptic::solver::spectral_radius] against the convergence rates observed in the solvers.
+//!
+//! # Formulation
+//! The same problem as [elliptic::solver::point_jacobi_solver] and [elliptic::solver::sor_solver] is solved
+//! on the square of `n \times n` cells for each `n`, that is, the Laplace's equation
+//! ```math
+//! \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} = 0,
+//! ```
+//! with the boundary condition
+//! ```math
+//! u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+//! ```
+//!
+//! The theoretical spectral radius is `\rho_J = \cos(\pi / n)` for the point Jacobi method,
+//! `\rho_J^2` for the Gauss-Seidel method and that given by
+//! [sor_spectral_radius](elliptic::solver::relaxation_parameter::sor_spectral_radius) for the SOR method.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_list: [8, 16, 32]
+//! n_iter_max: 100000
+//! n_iter_power: 2000
+//! omega: 1.5
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecTabulateSpectralRadiiInputParams].
+//!
+//! # Output Format
+//! The output is formatted as follows:
+//! ```text
+//! n method rho_theory rho_power rate_observed
+//! 8 point_jacobi 0.923880 0.923880 0.923880
+//! 8 gauss_seidel 0.853553 0.853553 0.853553
+//! 8 sor 0.500000 0.500185 0.517519
+//! ```
+//! where `rate_observed` is the mean ratio of the successive norms of the residuals given by
+//! [convergence_rate](elliptic::solver::spectral_radius::convergence_rate).
+
+use cli::check::Report;
+use elliptic::input;
+use elliptic::input::examples::ExecTabulateSpectralRadiiInputParams;
+use elliptic::solver::boundary::BoundaryConditions;
+use elliptic::solver::grid::Grid;
+use elliptic::solver::initial_guess::InitialGuess;
+use elliptic::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+use elliptic::solver::relaxation_parameter::{self, RelaxationParameter};
+use elliptic::solver::sor_solver::{SorSolver, SorSolverNewParams};
+use elliptic::solver::spectral_radius::{self, RelaxationMethod};
+use elliptic::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Estimate the spectral radii for each grid size and output them to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/elliptic/tabulate_spectral_radii_for_laplace_eq");
+
+    // read input parameters
+    let input_params: ExecTabulateSpectralRadiiInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let rho_jacobi: Vec<f64> = input_params
+            .n_list
+            .iter()
+            .map(|&n| {
+                let grid = Grid::uniform((n + 1, n + 1), (1.0, 1.0)).unwrap_or_else(|err| {
+                    eprintln!("Problem creating grid: {}", err);
+                    process::exit(1);
+                });
+                BoundaryConditions::default().jacobi_spectral_radius(&grid, 0.0)
+            })
+            .collect();
+        let format_list = |values: Vec<f64>| {
+            let values: Vec<String> = values.iter().map(|v| format!("{:.10e}", v)).collect();
+            format!("[{}]", values.join(", "))
+        };
+        Report::new()
+            .quantity("n", format!("{:?}", input_params.n_list))
+            .quantity("rho_J", format_list(rho_jacobi.clone()))
+            .quantity(
+                "rho_SOR",
+                format_list(
+                    rho_jacobi
+                        .iter()
+                        .map(|&rho| {
+                            relaxation_parameter::sor_spectral_radius(rho, input_params.omega)
+                        })
+                        .collect(),
+                ),
+            )
+            .convergence(if rho_jacobi.iter().all(|&rho| rho < 1.0) {
+                "convergent since rho_J < 1"
+            } else {
+                "divergent since rho_J >= 1"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile =
+        File::create(output_dir.join("spectral_radii.dat")).unwrap_or_else(|err| {
+            eprintln!("Problem creating output files: {}", err);
+            process::exit(1);
+        });
+
+    // run
+    tabulate(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Estimate the spectral radii of each method for each grid size and output them.
+fn tabulate(
+    input_params: &ExecTabulateSpectralRadiiInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    writeln!(outputstream, "n method rho_theory rho_power rate_observed")?;
+    for &n in &input_params.n_list {
+        // setup boundary conditions
+        let mut u_init: Array2<f64> = Array::zeros((n + 1, n + 1));
+        u_init.slice_mut(s![.., n]).fill(1.0);
+        let grid = Grid::uniform(u_init.dim(), (1.0, 1.0))?;
+        let boundary_conditions = BoundaryConditions::default();
+        let rho_jacobi = boundary_conditions.jacobi_spectral_radius(&grid, 0.0);
+
+        // calculate and output
+        let mut point_jacobi_solver = PointJacobiSolver::new(PointJacobiSolverNewParams {
+            u_init: u_init.clone(),
+            grid: grid.clone(),
+            n_iter_max: input_params.n_iter_max,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: None,
+        })?;
+        point_jacobi_solver.exec()?;
+        let mut rows = vec![(
+            "point_jacobi",
+            rho_jacobi,
+            RelaxationMethod::PointJacobi,
+            spectral_radius::convergence_rate(point_jacobi_solver.borrow_residual_history()),
+        )];
+
+        for (name, omega) in [("gauss_seidel", 1.0), ("sor", input_params.omega)] {
+            let mut sor_solver = SorSolver::new(SorSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max: input_params.n_iter_max,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions,
+                obstacle: None,
+                omega: RelaxationParameter::Fixed(omega),
+            })?;
+            sor_solver.exec()?;
+            rows.push((
+                name,
+                relaxation_parameter::sor_spectral_radius(rho_jacobi, omega),
+                RelaxationMethod::Sor(omega),
+                spectral_radius::convergence_rate(sor_solver.borrow_residual_history()),
+            ));
+        }
+
+        for (name, rho_theory, method, rate_observed) in rows {
+            let rho_power = spectral_radius::estimate(
+                method,
+                &grid,
+                &boundary_conditions,
+                0.0,
+                input_params.n_iter_power,
+            )?;
+            writeln!(
+                outputstream,
+                "{} {} {:.6} {:.6} {:.6}",
+                n, name, rho_theory, rho_power, rate_observed
+            )?;
+            println!(
+                "n = {}, {}: theory {:.6}, power iteration {:.6}, observed {:.6}.",
+                n, name, rho_theory, rho_power, rate_observed
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! Module for the exact solution of the Laplace's equation in the box and the errors of the numerical solutions.
+
+use crate::solver::grid::Grid;
+use ndarray::prelude::*;
+use std::f64::consts::PI;
+
+/// Return the exact solution on the `grid` for the benchmark problem of the book,
+/// the Laplace's equation in the box `[x_0, x_0 + a] \times [y_0, y_0 + b]` spanned by the `grid` with
+/// ```math
+/// u(x, y) = 1 (y = y_{+}), u(x, y) = 0 (x = x_{\pm} or y = y_{-}).
+/// ```
+///
+/// The solution is given by the separation of variables as
+/// ```math
+/// u(x, y) = \sum_{k = 1, 3, 5, \cdots} \frac{4}{k \pi} \sin \frac{k \pi (x - x_0)}{a} \frac{\sinh (k \pi (y - y_0) / a)}{\sinh (k \pi b / a)},
+/// ```
+/// which is truncated after the first `n_terms` odd `k`.
+/// The terms decay as `\exp(-k \pi (y_{+} - y) / a)`, so that the series converges slowly only near the top edge.
+/// Because of the discontinuities of the boundary values at the top corners,
+/// the error of the 5-point Laplacian converges only at the first order in the L2 norm.
+/// On the top edge, `1` is returned as the boundary value of the numerical solutions including the corners.
+///
+/// # Examples
+/// ```
+/// use elliptic::exact;
+/// use elliptic::solver::grid::Grid;
+///
+/// let grid = Grid::uniform((5, 5), (0.25, 0.25)).unwrap();
+/// let u = exact::box_with_unit_top(&grid, 100);
+///
+/// // the solutions with u = 1 on each of the 4 edges sum up to 1
+/// assert!((u[[2, 2]] - 0.25).abs() < 1e-10);
+/// assert_eq!(u[[0, 4]], 1.0);
+/// assert_eq!(u[[2, 0]], 0.0);
+/// ```
+pub fn box_with_unit_top(grid: &Grid, n_terms: usize) -> Array2<f64> {
+    let x = grid.borrow_x();
+    let y = grid.borrow_y();
+    let (n_x, n_y) = grid.dim();
+    let width = x[n_x - 1] - x[0];
+    let height = y[n_y - 1] - y[0];
+
+    Array::from_shape_fn((n_x, n_y), |(i_x, i_y)| {
+        if i_y == n_y - 1 {
+            return 1.0;
+        }
+
+        let xi = (x[i_x] - x[0]) / width;
+        let eta = (y[i_y] - y[0]) / width;
+        let eta_top = height / width;
+        (0..n_terms)
+            .map(|n| {
+                let k_pi = (2 * n + 1) as f64 * PI;
+                // sinh(k pi eta) / sinh(k pi eta_top) without the overflow
+                let sinh_ratio = (k_pi * (eta - eta_top)).exp() * (1.0 - (-2.0 * k_pi * eta).exp())
+                    / (1.0 - (-2.0 * k_pi * eta_top).exp());
+                4.0 / k_pi * (k_pi * xi).sin() * sinh_ratio
+            })
+            .sum()
+    })
+}
+
+/// Norms of the error of the numerical solution at the interior points,
+/// since the boundary values are given by the boundary condition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorNorms {
+    /// Discrete L2 norm, `\sqrt{\sum_{j,k} (u_{j,k} - u_{exact,j,k})^2 \Delta x_j \Delta y_k}`,
+    /// where `\Delta x_j = (x_{j+1} - x_{j-1}) / 2` and `\Delta y_k = (y_{k+1} - y_{k-1}) / 2`.
+    pub l2: f64,
+    /// Maximum norm, `\max_{j,k} |u_{j,k} - u_{exact,j,k}|`.
+    pub linf: f64,
+}
+
+impl ErrorNorms {
+    /// Calculate the norms of `u - u_exact` on the `grid`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::exact::ErrorNorms;
+    /// use elliptic::solver::grid::Grid;
+    ///
+    /// let grid = Grid::uniform((3, 4), (0.5, 0.5)).unwrap();
+    /// let u = array![[9.0, 9.0, 9.0, 9.0], [9.0, 0.5, 1.0, 9.0], [9.0, 9.0, 9.0, 9.0]];
+    /// let u_exact = array![[0.0, 0.0, 0.0, 0.0], [0.0, 0.2, 0.6, 0.0], [0.0, 0.0, 0.0, 0.0]];
+    /// let error_norms = ErrorNorms::new(&u, &u_exact, &grid);
+    ///
+    /// assert!((error_norms.l2 - 0.0625f64.sqrt()).abs() < 1e-10);
+    /// assert!((error_norms.linf - 0.4).abs() < 1e-10);
+    /// ```
+    pub fn new(u: &Array2<f64>, u_exact: &Array2<f64>, grid: &Grid) -> Self {
+        let x = grid.borrow_x();
+        let y = grid.borrow_y();
+        let (n_x, n_y) = grid.dim();
+
+        let mut l2_squared = 0.0;
+        let mut linf: f64 = 0.0;
+        for i_x in 1..n_x.saturating_sub(1) {
+            for i_y in 1..n_y.saturating_sub(1) {
+                let error = u[[i_x, i_y]] - u_exact[[i_x, i_y]];
+                let area = 0.25 * (x[i_x + 1] - x[i_x - 1]) * (y[i_y + 1] - y[i_y - 1]);
+                l2_squared += error * error * area;
+                linf = linf.max(error.abs());
+            }
+        }
+
+        Self {
+            l2: l2_squared.sqrt(),
+            linf,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::direct_solver::{DirectSolver, DirectSolverNewParams};
+    use crate::solver::Solver;
+
+    #[test]
+    fn fn_box_with_unit_top_works_with_rectangle() {
+        // setup the rectangle [1, 3] x [0, 1] with a non-uniform grid
+        let grid = Grid::new(array![1.0, 1.5, 2.0, 2.9, 3.0], array![0.0, 0.1, 0.7, 1.0]).unwrap();
+        let u = box_with_unit_top(&grid, 1000);
+
+        // check if u satisfies the boundary conditions
+        assert!(u.slice(s![0, ..3]).iter().all(|u| u.abs() < 1e-12));
+        assert!(u.slice(s![4, ..3]).iter().all(|u| u.abs() < 1e-12));
+        assert!(u.column(0).iter().all(|u| u.abs() < 1e-12));
+        assert!(u.column(3).iter().all(|&u| u == 1.0));
+
+        // check if u is symmetric about x = 2
+        let u_mirror = box_with_unit_top(
+            &Grid::new(array![1.0, 2.5, 3.0], array![0.0, 0.1, 0.7, 1.0]).unwrap(),
+            1000,
+        );
+        assert!((u.slice(s![1, ..]).to_owned() - u_mirror.slice(s![1, ..]))
+            .iter()
+            .all(|u| u.abs() < 1e-12));
+    }
+
+    #[test]
+    fn fn_error_norms_new_works_with_discrete_solutions() {
+        // solve the problem on the grids of 8 x 8 and 16 x 16 cells by the direct method
+        let errors: Vec<ErrorNorms> = [8, 16]
+            .iter()
+            .map(|&n| {
+                let mut u_init: Array2<f64> = Array::zeros((n + 1, n + 1));
+                u_init.slice_mut(s![.., n]).fill(1.0);
+                let grid = Grid::uniform(u_init.dim(), (1.0 / n as f64, 1.0 / n as f64)).unwrap();
+                let mut solver = DirectSolver::new(DirectSolverNewParams {
+                    u_init,
+                    grid: grid.clone(),
+                    source: None,
+                    k_squared: 0.0,
+                    boundary_conditions: Default::default(),
+                    obstacle: None,
+                })
+                .unwrap();
+                solver.exec().unwrap();
+
+                ErrorNorms::new(solver.borrow_u(), &box_with_unit_top(&grid, 10000), &grid)
+            })
+            .collect();
+
+        // check if the L2 error converges at the first order,
+        // which is limited by the discontinuities of the boundary values at the top corners
+        let ratio = errors[0].l2 / errors[1].l2;
+        assert!(errors[1].l2 < 2e-3);
+        assert!(ratio > 1.8 && ratio < 2.2);
+    }
+}
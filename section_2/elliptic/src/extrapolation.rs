@@ -0,0 +1,132 @@
+//! Module for the Richardson extrapolation of the solutions on two grid resolutions.
+//!
+//! If the discretization error of the solution `u_h` on the grid with the spacing `h` behaves as
+//! ```math
+//! u_h = u + C h^p + O(h^{p+1}),
+//! ```
+//! the solutions `u_h` on the coarse grid and `u_{h/2}` on the fine grid halving the spacing give
+//! ```math
+//! u \approx u_{h/2} + \frac{u_{h/2} - u_h}{2^p - 1},
+//! ```
+//! which is of higher order than both of them, at the points of the coarse grid.
+//! The correction `(u_{h/2} - u_h) / (2^p - 1)` itself estimates the error of `u_{h/2}`.
+//!
+//! `p = 2` for the 5-point Laplacian with smooth solutions,
+//! but it may be reduced by the singularities of the solution, e.g. at the corners with discontinuous boundary values.
+
+use ndarray::prelude::*;
+
+/// Richardson extrapolation of the solutions on the coarse and fine grids, at the points of the coarse grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extrapolation {
+    /// Extrapolated solution, `u_{h/2} + (u_{h/2} - u_h) / (2^p - 1)`.
+    pub u: Array2<f64>,
+    /// Error indicator of the fine solution, `(u_{h/2} - u_h) / (2^p - 1)`.
+    pub error: Array2<f64>,
+}
+
+impl Extrapolation {
+    /// Create a new `Extrapolation` instance from the solution `u_coarse` of `(n_x + 1) \times (n_y + 1)` points
+    /// and `u_fine` of `(2 n_x + 1) \times (2 n_y + 1)` points on the grid halving each spacing of the coarse grid,
+    /// whose discretization errors are of the `order` `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::extrapolation::Extrapolation;
+    ///
+    /// // u = x^2 + y^2 with the errors h^2 on the coarse grid of h = 1 and h^2 / 4 on the fine grid
+    /// let u_coarse = Array::from_shape_fn((3, 2), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64 + 1.0);
+    /// let u_fine = Array::from_shape_fn((5, 3), |(i_x, i_y)| {
+    ///     let (x, y) = (0.5 * i_x as f64, 0.5 * i_y as f64);
+    ///     x * x + y * y + 0.25
+    /// });
+    /// let extrapolation = Extrapolation::new(&u_coarse, &u_fine, 2.0).unwrap();
+    ///
+    /// let u_exact = Array::from_shape_fn((3, 2), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+    /// assert!((&extrapolation.u - &u_exact).iter().all(|u| u.abs() < 1e-12));
+    /// assert!(extrapolation.error.iter().all(|e| (e + 0.25).abs() < 1e-12));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the shapes of `u_coarse` and `u_fine` don't match or the `order` is not positive.
+    pub fn new(
+        u_coarse: &Array2<f64>,
+        u_fine: &Array2<f64>,
+        order: f64,
+    ) -> Result<Self, &'static str> {
+        let (n_x, n_y) = u_coarse.dim();
+        if n_x == 0 || n_y == 0 {
+            return Err("u_coarse must not be empty");
+        }
+        if u_fine.dim() != (2 * n_x - 1, 2 * n_y - 1) {
+            return Err(
+                "u_fine must have 2 n + 1 points in each direction for u_coarse of n + 1 points",
+            );
+        }
+        if order <= 0.0 {
+            return Err("order must be positive");
+        }
+
+        let u_fine_at_coarse = u_fine.slice(s![..;2, ..;2]);
+        let error = (&u_fine_at_coarse - u_coarse) / (2f64.powf(order) - 1.0);
+
+        Ok(Self {
+            u: &u_fine_at_coarse + &error,
+            error,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::direct_solver::{DirectSolver, DirectSolverNewParams};
+    use crate::solver::grid::Grid;
+    use crate::solver::Solver;
+
+    #[test]
+    fn fn_extrapolation_new_works_with_direct_solutions() {
+        // solve the Laplace's equation for the harmonic u = e^x \sin y on the unit square with 8 x 8 and 16 x 16 cells
+        let u_exact = |n: usize| {
+            Array::from_shape_fn((n + 1, n + 1), |(i_x, i_y)| {
+                (i_x as f64 / n as f64).exp() * (i_y as f64 / n as f64).sin()
+            })
+        };
+        let solve = |n: usize| {
+            let mut solver = DirectSolver::new(DirectSolverNewParams {
+                u_init: u_exact(n),
+                grid: Grid::uniform((n + 1, n + 1), (1.0 / n as f64, 1.0 / n as f64)).unwrap(),
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+            })
+            .unwrap();
+            solver.exec().unwrap();
+            solver.borrow_u().clone()
+        };
+        let u_coarse = solve(8);
+        let u_fine = solve(16);
+        let extrapolation = Extrapolation::new(&u_coarse, &u_fine, 2.0).unwrap();
+
+        // check if the extrapolation reduces the error and the indicator estimates the error of the fine solution
+        let max_abs = |u: &Array2<f64>| u.fold(0.0f64, |acc, u| acc.max(u.abs()));
+        let error_fine = &u_fine.slice(s![..;2, ..;2]) - &u_exact(8);
+        assert!(max_abs(&(&extrapolation.u - &u_exact(8))) < 0.1 * max_abs(&error_fine));
+        assert!(max_abs(&(&extrapolation.error + &error_fine)) < 0.1 * max_abs(&error_fine));
+    }
+
+    #[test]
+    fn fn_extrapolation_new_fails_with_mismatched_shapes() {
+        // setup the fine solution with the doubled number of points instead of cells
+        let u_coarse: Array2<f64> = Array::zeros((3, 3));
+        let u_fine: Array2<f64> = Array::zeros((6, 6));
+
+        // check if the extrapolation fails
+        assert_eq!(
+            Extrapolation::new(&u_coarse, &u_fine, 2.0).unwrap_err(),
+            "u_fine must have 2 n + 1 points in each direction for u_coarse of n + 1 points"
+        );
+    }
+}
@@ -0,0 +1,827 @@
+//! Input parameters of the examples.
+//!
+//! Each example reads its input file into one of the structs here, which fills the omitted parameters with the defaults
+//! of [super], so that the minimal input files are tested against the same types as the examples use.
+
+use super::{InputParams, ValidationError};
+use crate::solver::backend::Backend;
+use crate::solver::boundary::BoundaryConditions;
+use crate::solver::initial_guess::InitialGuess;
+use crate::solver::multigrid_solver::{Coarsening, Smoother};
+use crate::solver::preconditioner::PreconditionerKind;
+use crate::solver::relaxation_parameter::{Estimation, RelaxationParameter};
+use serde_derive::{Deserialize, Serialize};
+
+/// Input parameters of the `compare_initial_guesses_for_laplace_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecCompareInitialGuessesInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter of the SOR method, given as a number or estimated by the solver, `1.5` if omitted.
+    #[serde(default)]
+    pub omega: RelaxationParameter,
+}
+
+impl InputParams for ExecCompareInitialGuessesInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check_result(self.omega.validate(), "omega", self.omega);
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `compare_methods_for_laplace_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecCompareMethodsInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameters of the SOR, red-black SOR and line SOR methods to compare.
+    pub omega_list: Vec<f64>,
+}
+
+impl InputParams for ExecCompareMethodsInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.omega_list
+                .iter()
+                .all(|omega| (1.0..=2.0).contains(omega)),
+            "omega_list",
+            &self.omega_list,
+            "must consist of numbers between 1 and 2",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `compare_preconditioners_for_laplace_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecComparePreconditionersInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter of the SSOR preconditioner, `1.5` if omitted.
+    #[serde(default = "super::default_omega")]
+    pub omega: f64,
+}
+
+impl InputParams for ExecComparePreconditionersInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.omega > 0.0 && self.omega < 2.0,
+            "omega",
+            self.omega,
+            "must be between 0 and 2",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_anisotropic_laplace_eq_by_multigrid_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecMultigridInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Maximum number of the V-cycles, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Directions in which the grids are coarsened, `full` if omitted.
+    #[serde(default)]
+    pub coarsening: Coarsening,
+    /// Relaxation smoothing the error on each grid, `gauss_seidel` if omitted.
+    #[serde(default)]
+    pub smoother: Smoother,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Anisotropy coefficient of the second derivative in x direction, `1` if omitted.
+    #[serde(default = "super::default_anisotropy")]
+    pub anisotropy: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+}
+
+impl InputParams for ExecMultigridInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.anisotropy > 0.0 && self.anisotropy.is_finite(),
+            "anisotropy",
+            self.anisotropy,
+            "must be positive and finite",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_around_charged_plate` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecChargedPlateInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter, given as a number or estimated by the solver, `1.5` if omitted.
+    #[serde(default)]
+    pub omega: RelaxationParameter,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Smallest x coordinate of the plate.
+    pub plate_x_min: f64,
+    /// Largest x coordinate of the plate.
+    pub plate_x_max: f64,
+    /// y coordinate of the plate.
+    pub plate_y: f64,
+    /// Potential of the plate.
+    pub plate_potential: f64,
+}
+
+impl InputParams for ExecChargedPlateInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x >= 2, "n_x", self.n_x, "must be at least 2");
+        errors.check(self.n_y >= 2, "n_y", self.n_y, "must be at least 2");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check_result(self.omega.validate(), "omega", self.omega);
+        errors.check(
+            self.plate_x_min <= self.plate_x_max,
+            "plate_x_min",
+            self.plate_x_min,
+            "must not be greater than plate_x_max",
+        );
+        errors.check(
+            self.plate_x_min > 0.0,
+            "plate_x_min",
+            self.plate_x_min,
+            "must be positive to put the plate inside the box",
+        );
+        errors.check(
+            self.plate_x_max < self.n_x as f64 * self.dx,
+            "plate_x_max",
+            self.plate_x_max,
+            "must be less than n_x * dx to put the plate inside the box",
+        );
+        errors.check(
+            self.plate_y > 0.0 && self.plate_y < self.n_y as f64 * self.dy,
+            "plate_y",
+            self.plate_y,
+            "must be between 0 and n_y * dy to put the plate inside the box",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_by_conjugate_gradient_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecConjugateGradientInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+    /// Preconditioner, with the parameters specific to it.
+    #[serde(flatten)]
+    pub preconditioner: PreconditionerKind,
+}
+
+impl InputParams for ExecConjugateGradientInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_by_direct_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecDirectInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+}
+
+impl InputParams for ExecDirectInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_by_line_sor_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecLineSorInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter, `1.5` if omitted.
+    #[serde(default = "super::default_omega")]
+    pub omega: f64,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Anisotropy coefficient of the second derivative in x direction, `1` if omitted.
+    #[serde(default = "super::default_anisotropy")]
+    pub anisotropy: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+}
+
+impl InputParams for ExecLineSorInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.omega >= 1.0 && self.omega <= 2.0,
+            "omega",
+            self.omega,
+            "must be between 1 and 2",
+        );
+        errors.check(
+            self.anisotropy > 0.0 && self.anisotropy.is_finite(),
+            "anisotropy",
+            self.anisotropy,
+            "must be positive and finite",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_by_point_jacobi_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecPointJacobiInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+    /// Number of iterations between the snapshots of `u`, which are not output if omitted.
+    #[serde(default)]
+    pub ncycle_out: Option<usize>,
+    /// Backend on which the sweeps are executed, `cpu` if omitted.
+    #[serde(default)]
+    pub backend: Backend,
+    /// Number of the sweeps per iteration on the `gpu` backend, `100` if omitted.
+    #[serde(default = "super::default_n_sweeps")]
+    pub n_sweeps: usize,
+}
+
+impl InputParams for ExecPointJacobiInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.ncycle_out != Some(0),
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(self.backend.validate(), "backend", self.backend);
+        errors.check(
+            self.n_sweeps > 0,
+            "n_sweeps",
+            self.n_sweeps,
+            "must be positive",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_by_sor_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecSorInputParams {
+    /// Number of grids in x direction.
+    pub n_x: usize,
+    /// Number of grids in y direction.
+    pub n_y: usize,
+    /// Grid spacing in x direction.
+    pub dx: f64,
+    /// Grid spacing in y direction.
+    pub dy: f64,
+    /// Coordinates of the grid points in x direction, overriding `dx` if given.
+    #[serde(default)]
+    pub x: Option<Vec<f64>>,
+    /// Coordinates of the grid points in y direction, overriding `dy` if given.
+    #[serde(default)]
+    pub y: Option<Vec<f64>>,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter, given as a number or estimated by the solver, `1.5` if omitted.
+    #[serde(default)]
+    pub omega: RelaxationParameter,
+    /// Strategy to construct the initial guess of the interior values, `zero` if omitted.
+    #[serde(default)]
+    pub initial_guess: InitialGuess,
+    /// Uniform source term of the Poisson's equation, `0` if omitted.
+    #[serde(default)]
+    pub source: f64,
+    /// Coefficient of the Helmholtz term, `0` if omitted.
+    #[serde(default)]
+    pub k_squared: f64,
+    /// Boundary conditions at the edges, all Dirichlet if omitted.
+    #[serde(default)]
+    pub boundary_conditions: BoundaryConditions,
+    /// Number of iterations between the snapshots of `u`, which are not output if omitted.
+    #[serde(default)]
+    pub ncycle_out: Option<usize>,
+    /// Backend on which the sweeps are executed, `cpu` if omitted.
+    #[serde(default)]
+    pub backend: Backend,
+    /// Number of the sweeps per iteration on the `gpu` backend, `100` if omitted.
+    #[serde(default = "super::default_n_sweeps")]
+    pub n_sweeps: usize,
+}
+
+impl InputParams for ExecSorInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.n_y > 0, "n_y", self.n_y, "must be positive");
+        errors.check(self.dx > 0.0, "dx", self.dx, "must be positive");
+        errors.check(self.dy > 0.0, "dy", self.dy, "must be positive");
+        errors.check(
+            self.x.as_ref().is_none_or(|x| x.len() == self.n_x + 1),
+            "x",
+            &self.x,
+            "must have n_x + 1 elements",
+        );
+        errors.check(
+            self.y.as_ref().is_none_or(|y| y.len() == self.n_y + 1),
+            "y",
+            &self.y,
+            "must have n_y + 1 elements",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.ncycle_out != Some(0),
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(self.omega.validate(), "omega", self.omega);
+        errors.check_result(self.backend.validate(), "backend", self.backend);
+        errors.check(
+            self.backend == Backend::Cpu
+                || self.omega != RelaxationParameter::Estimated(Estimation::Adaptive),
+            "omega",
+            self.omega,
+            "must be a number or optimal on the gpu backend",
+        );
+        errors.check(
+            self.n_sweeps > 0,
+            "n_sweeps",
+            self.n_sweeps,
+            "must be positive",
+        );
+        errors.check_result(
+            self.boundary_conditions
+                .validate((self.n_x + 1, self.n_y + 1)),
+            "boundary_conditions",
+            self.boundary_conditions,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_laplace_eq_in_disk_by_polar_sor_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecPolarSorInputParams {
+    /// Number of grids in r direction.
+    pub n_r: usize,
+    /// Number of grids in theta direction.
+    pub n_theta: usize,
+    /// Radius of the disk.
+    pub r_max: f64,
+    /// Maximum number of iterations, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Relaxation parameter, `1.5` if omitted.
+    #[serde(default = "super::default_omega")]
+    pub omega: f64,
+    /// Fourier coefficients `a_n` of the boundary values, starting from `n = 0`.
+    #[serde(default)]
+    pub cos_coefs: Vec<f64>,
+    /// Fourier coefficients `b_n` of the boundary values, starting from `n = 0`.
+    #[serde(default)]
+    pub sin_coefs: Vec<f64>,
+}
+
+impl InputParams for ExecPolarSorInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_r > 0, "n_r", self.n_r, "must be positive");
+        errors.check(
+            self.n_theta >= 3,
+            "n_theta",
+            self.n_theta,
+            "must be at least 3",
+        );
+        errors.check(self.r_max > 0.0, "r_max", self.r_max, "must be positive");
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.omega >= 1.0 && self.omega <= 2.0,
+            "omega",
+            self.omega,
+            "must be between 1 and 2",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `tabulate_spectral_radii_for_laplace_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecTabulateSpectralRadiiInputParams {
+    /// Numbers of grids in x and y directions to tabulate.
+    pub n_list: Vec<usize>,
+    /// Maximum number of iterations of the solvers, `10000` if omitted.
+    #[serde(default = "super::default_n_iter_max")]
+    pub n_iter_max: usize,
+    /// Number of iterations of the power iteration.
+    pub n_iter_power: usize,
+    /// Relaxation parameter of the SOR method, `1.5` if omitted.
+    #[serde(default = "super::default_omega")]
+    pub omega: f64,
+}
+
+impl InputParams for ExecTabulateSpectralRadiiInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(
+            self.n_list.iter().all(|&n| n >= 2),
+            "n_list",
+            &self.n_list,
+            "must consist of numbers of at least 2",
+        );
+        errors.check(
+            self.n_iter_max > 0,
+            "n_iter_max",
+            self.n_iter_max,
+            "must be positive",
+        );
+        errors.check(
+            self.n_iter_power >= 2,
+            "n_iter_power",
+            self.n_iter_power,
+            "must be at least 2",
+        );
+        errors.check(
+            self.omega >= 1.0 && self.omega <= 2.0,
+            "omega",
+            self.omega,
+            "must be between 1 and 2",
+        );
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_input_params;
+
+    #[test]
+    fn fn_minimal_input_params_are_completed_by_defaults() {
+        let input_params: ExecSorInputParams = read_input_params(
+            &mut include_str!(
+                "../../../../inputs/section_2/elliptic/solve_laplace_eq_by_sor_method/minimal_input.yml"
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!((input_params.n_x, input_params.n_y), (20, 20));
+        assert_eq!(input_params.n_iter_max, 10000);
+        assert_eq!(input_params.omega, RelaxationParameter::Fixed(1.5));
+        assert_eq!(input_params.initial_guess, InitialGuess::Zero);
+        assert_eq!(input_params.source, 0.0);
+        assert_eq!(input_params.k_squared, 0.0);
+        assert_eq!(
+            input_params.boundary_conditions,
+            BoundaryConditions::default()
+        );
+        assert_eq!(input_params.ncycle_out, None);
+        assert_eq!(input_params.backend, Backend::Cpu);
+        assert_eq!(input_params.n_sweeps, 100);
+
+        // check if omega defaults to the same value whether it can be estimated or not
+        let input_params: ExecLineSorInputParams =
+            read_input_params(&mut "n_x: 20\nn_y: 20\ndx: 0.05\ndy: 0.05".as_bytes()).unwrap();
+
+        assert_eq!(input_params.omega, 1.5);
+    }
+}
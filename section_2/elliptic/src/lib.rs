@@ -5,12 +5,21 @@
 //! All of the methods mentioned in the book are implemented in this crate.
 //!
 //! Using this crate, you can actually compute and see the convergence of each method.
-
+//!
+//! # Features
+//! * `parallel` - The sweeps of [solver::point_jacobi_solver] and [solver::red_black_sor_solver]
+//!   are parallelized by rayon. See `benches/parallel_sweeps.rs` for the benchmark on the grid of `1025 \times 1025` points.
+//! * `gpu` - `solver::gpu_solver` runs the sweeps of the point Jacobi and red-black SOR methods on the GPU by wgpu
+//!   in `f32`, refining the solution in `f64` on the host. See `benches/gpu_sweeps.rs` for the benchmark
+//!   on the grid of `2049 \times 2049` points.
+
+pub mod exact;
+pub mod extrapolation;
 pub mod input;
 pub mod output;
 pub mod solver;
 
-use solver::Solver;
+use solver::{RelaxationSolver, Solver};
 use std::error::Error;
 use std::io::Write;
 
@@ -18,7 +27,7 @@ use std::io::Write;
 pub fn run(solver: &mut impl Solver, outputstream: &mut impl Write) -> Result<(), Box<dyn Error>> {
     // calculate and output
     solver.exec()?;
-    output::output(outputstream, solver.borrow_u())?;
+    output::output(outputstream, solver.borrow_u(), solver.borrow_grid())?;
     println!(
         "The solution is converged at {} iterations.",
         solver.get_n_iter()
@@ -27,11 +36,73 @@ pub fn run(solver: &mut impl Solver, outputstream: &mut impl Write) -> Result<()
     Ok(())
 }
 
+/// Run the solver and output the results,
+/// together with the history of the norms of the residuals to `residual_outputstream`.
+pub fn run_with_residual_history(
+    solver: &mut impl Solver,
+    outputstream: &mut impl Write,
+    residual_outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    run(solver, outputstream)?;
+    output::output_residual_history(residual_outputstream, solver.borrow_residual_history())?;
+
+    Ok(())
+}
+
+/// Run the relaxation solver and output the results and the history of the norms of the residuals
+/// as [run_with_residual_history], together with the snapshots of `u` to `snapshot_outputstream`
+/// every `ncycle_out` iterations, including the initial guess and the converged solution.
+///
+/// See [output::output_snapshot] for the format of the snapshots.
+pub fn run_with_snapshots(
+    solver: &mut impl RelaxationSolver,
+    outputstream: &mut impl Write,
+    residual_outputstream: &mut impl Write,
+    snapshot_outputstream: &mut impl Write,
+    ncycle_out: usize,
+) -> Result<(), Box<dyn Error>> {
+    // calculate and output
+    output_snapshot(snapshot_outputstream, solver)?;
+    while !solver.is_converged() {
+        solver.step()?;
+
+        if solver.is_converged() || solver.get_n_iter().is_multiple_of(ncycle_out) {
+            output_snapshot(snapshot_outputstream, solver)?;
+        }
+    }
+    output::output(outputstream, solver.borrow_u(), solver.borrow_grid())?;
+    output::output_residual_history(residual_outputstream, solver.borrow_residual_history())?;
+    println!(
+        "The solution is converged at {} iterations.",
+        solver.get_n_iter()
+    );
+
+    Ok(())
+}
+
+/// Output the snapshot of `u` at the current iteration of the solver.
+fn output_snapshot(
+    outputstream: &mut impl Write,
+    solver: &impl Solver,
+) -> Result<(), Box<dyn Error>> {
+    output::output_snapshot(
+        outputstream,
+        solver.get_n_iter(),
+        solver.borrow_u(),
+        solver.borrow_grid(),
+    )?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ndarray::prelude::*;
+    use solver::grid::Grid;
+    use solver::initial_guess::InitialGuess;
     use solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+    use solver::relaxation_parameter::RelaxationParameter;
     use solver::sor_solver::{SorSolver, SorSolverNewParams};
 
     #[test]
@@ -49,8 +120,14 @@ mod tests {
 
         // initialize the solver
         let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
             u_init,
             n_iter_max: 300,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
         };
         let mut solver = PointJacobiSolver::new(new_params).unwrap();
 
@@ -59,95 +136,95 @@ mod tests {
 
         // check if the output is correct
         let output_expected = "\
-0 0 0.0000000000
-0 1 0.0000000000
-0 2 0.0000000000
-0 3 0.0000000000
-0 4 0.0000000000
-0 5 0.0000000000
-0 6 0.0000000000
-0 7 0.0000000000
-0 8 1.0000000000
-
-1 0 0.0000000000
-1 1 0.0174130457
-1 2 0.0376833281
-1 3 0.0643733244
-1 4 0.1029411760
-1 5 0.1635678513
-1 6 0.2693019654
-1 7 0.4825869540
-1 8 1.0000000000
-
-2 0 0.0000000000
-2 1 0.0319688546
-2 2 0.0689469426
-2 3 0.1168687935
-2 4 0.1838235286
-2 5 0.2820282638
-2 6 0.4310530562
-2 7 0.6610458507
-2 8 1.0000000000
-
-3 0 0.0000000000
-3 1 0.0415154301
-3 2 0.0892667944
-3 3 0.1503313786
-3 4 0.2334558813
-3 5 0.3496686194
-3 6 0.5118361453
-3 7 0.7305433926
-3 8 1.0000000000
-
-4 0 0.0000000000
-4 1 0.0448260716
-4 2 0.0962734264
-4 3 0.1617340455
-4 4 0.2499999988
-4 5 0.3713541876
-4 6 0.5360795131
-4 7 0.7492915745
-4 8 1.0000000000
-
-5 0 0.0000000000
-5 1 0.0415154301
-5 2 0.0892667944
-5 3 0.1503313786
-5 4 0.2334558813
-5 5 0.3496686194
-5 6 0.5118361453
-5 7 0.7305433926
-5 8 1.0000000000
-
-6 0 0.0000000000
-6 1 0.0319688546
-6 2 0.0689469426
-6 3 0.1168687935
-6 4 0.1838235286
-6 5 0.2820282638
-6 6 0.4310530562
-6 7 0.6610458507
-6 8 1.0000000000
-
-7 0 0.0000000000
-7 1 0.0174130457
-7 2 0.0376833281
-7 3 0.0643733244
-7 4 0.1029411760
-7 5 0.1635678513
-7 6 0.2693019654
-7 7 0.4825869540
-7 8 1.0000000000
-
-8 0 0.0000000000
-8 1 0.0000000000
-8 2 0.0000000000
-8 3 0.0000000000
-8 4 0.0000000000
-8 5 0.0000000000
-8 6 0.0000000000
-8 7 0.0000000000
-8 8 1.0000000000
+0.0000000000 0.0000000000 0.0000000000
+0.0000000000 1.0000000000 0.0000000000
+0.0000000000 2.0000000000 0.0000000000
+0.0000000000 3.0000000000 0.0000000000
+0.0000000000 4.0000000000 0.0000000000
+0.0000000000 5.0000000000 0.0000000000
+0.0000000000 6.0000000000 0.0000000000
+0.0000000000 7.0000000000 0.0000000000
+0.0000000000 8.0000000000 1.0000000000
+
+1.0000000000 0.0000000000 0.0000000000
+1.0000000000 1.0000000000 0.0174130457
+1.0000000000 2.0000000000 0.0376833281
+1.0000000000 3.0000000000 0.0643733244
+1.0000000000 4.0000000000 0.1029411760
+1.0000000000 5.0000000000 0.1635678513
+1.0000000000 6.0000000000 0.2693019654
+1.0000000000 7.0000000000 0.4825869540
+1.0000000000 8.0000000000 1.0000000000
+
+2.0000000000 0.0000000000 0.0000000000
+2.0000000000 1.0000000000 0.0319688546
+2.0000000000 2.0000000000 0.0689469426
+2.0000000000 3.0000000000 0.1168687935
+2.0000000000 4.0000000000 0.1838235286
+2.0000000000 5.0000000000 0.2820282638
+2.0000000000 6.0000000000 0.4310530562
+2.0000000000 7.0000000000 0.6610458507
+2.0000000000 8.0000000000 1.0000000000
+
+3.0000000000 0.0000000000 0.0000000000
+3.0000000000 1.0000000000 0.0415154301
+3.0000000000 2.0000000000 0.0892667944
+3.0000000000 3.0000000000 0.1503313786
+3.0000000000 4.0000000000 0.2334558813
+3.0000000000 5.0000000000 0.3496686194
+3.0000000000 6.0000000000 0.5118361453
+3.0000000000 7.0000000000 0.7305433926
+3.0000000000 8.0000000000 1.0000000000
+
+4.0000000000 0.0000000000 0.0000000000
+4.0000000000 1.0000000000 0.0448260716
+4.0000000000 2.0000000000 0.0962734264
+4.0000000000 3.0000000000 0.1617340455
+4.0000000000 4.0000000000 0.2499999988
+4.0000000000 5.0000000000 0.3713541876
+4.0000000000 6.0000000000 0.5360795131
+4.0000000000 7.0000000000 0.7492915745
+4.0000000000 8.0000000000 1.0000000000
+
+5.0000000000 0.0000000000 0.0000000000
+5.0000000000 1.0000000000 0.0415154301
+5.0000000000 2.0000000000 0.0892667944
+5.0000000000 3.0000000000 0.1503313786
+5.0000000000 4.0000000000 0.2334558813
+5.0000000000 5.0000000000 0.3496686194
+5.0000000000 6.0000000000 0.5118361453
+5.0000000000 7.0000000000 0.7305433926
+5.0000000000 8.0000000000 1.0000000000
+
+6.0000000000 0.0000000000 0.0000000000
+6.0000000000 1.0000000000 0.0319688546
+6.0000000000 2.0000000000 0.0689469426
+6.0000000000 3.0000000000 0.1168687935
+6.0000000000 4.0000000000 0.1838235286
+6.0000000000 5.0000000000 0.2820282638
+6.0000000000 6.0000000000 0.4310530562
+6.0000000000 7.0000000000 0.6610458507
+6.0000000000 8.0000000000 1.0000000000
+
+7.0000000000 0.0000000000 0.0000000000
+7.0000000000 1.0000000000 0.0174130457
+7.0000000000 2.0000000000 0.0376833281
+7.0000000000 3.0000000000 0.0643733244
+7.0000000000 4.0000000000 0.1029411760
+7.0000000000 5.0000000000 0.1635678513
+7.0000000000 6.0000000000 0.2693019654
+7.0000000000 7.0000000000 0.4825869540
+7.0000000000 8.0000000000 1.0000000000
+
+8.0000000000 0.0000000000 0.0000000000
+8.0000000000 1.0000000000 0.0000000000
+8.0000000000 2.0000000000 0.0000000000
+8.0000000000 3.0000000000 0.0000000000
+8.0000000000 4.0000000000 0.0000000000
+8.0000000000 5.0000000000 0.0000000000
+8.0000000000 6.0000000000 0.0000000000
+8.0000000000 7.0000000000 0.0000000000
+8.0000000000 8.0000000000 1.0000000000
 
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
@@ -168,9 +245,15 @@ mod tests {
 
         // initialize the solver
         let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
             u_init,
             n_iter_max: 300,
-            omega: 1.5,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
         };
         let mut solver = SorSolver::new(new_params).unwrap();
 
@@ -179,97 +262,202 @@ mod tests {
 
         // check if the output is correct
         let output_expected = "\
-0 0 0.0000000000
-0 1 0.0000000000
-0 2 0.0000000000
-0 3 0.0000000000
-0 4 0.0000000000
-0 5 0.0000000000
-0 6 0.0000000000
-0 7 0.0000000000
-0 8 1.0000000000
-
-1 0 0.0000000000
-1 1 0.0174130458
-1 2 0.0376833284
-1 3 0.0643733247
-1 4 0.1029411764
-1 5 0.1635678517
-1 6 0.2693019657
-1 7 0.4825869542
-1 8 1.0000000000
-
-2 0 0.0000000000
-2 1 0.0319688548
-2 2 0.0689469431
-2 3 0.1168687942
-2 4 0.1838235294
-2 5 0.2820282646
-2 6 0.4310530568
-2 7 0.6610458510
-2 8 1.0000000000
-
-3 0 0.0000000000
-3 1 0.0415154305
-3 2 0.0892667951
-3 3 0.1503313795
-3 4 0.2334558823
-3 5 0.3496686204
-3 6 0.5118361460
-3 7 0.7305433930
-3 8 1.0000000000
-
-4 0 0.0000000000
-4 1 0.0448260720
-4 2 0.0962734272
-4 3 0.1617340466
-4 4 0.2500000000
-4 5 0.3713541887
-4 6 0.5360795139
-4 7 0.7492915750
-4 8 1.0000000000
-
-5 0 0.0000000000
-5 1 0.0415154305
-5 2 0.0892667951
-5 3 0.1503313796
-5 4 0.2334558823
-5 5 0.3496686204
-5 6 0.5118361460
-5 7 0.7305433930
-5 8 1.0000000000
-
-6 0 0.0000000000
-6 1 0.0319688549
-6 2 0.0689469432
-6 3 0.1168687942
-6 4 0.1838235294
-6 5 0.2820282646
-6 6 0.4310530568
-6 7 0.6610458510
-6 8 1.0000000000
-
-7 0 0.0000000000
-7 1 0.0174130458
-7 2 0.0376833284
-7 3 0.0643733248
-7 4 0.1029411765
-7 5 0.1635678517
-7 6 0.2693019657
-7 7 0.4825869542
-7 8 1.0000000000
-
-8 0 0.0000000000
-8 1 0.0000000000
-8 2 0.0000000000
-8 3 0.0000000000
-8 4 0.0000000000
-8 5 0.0000000000
-8 6 0.0000000000
-8 7 0.0000000000
-8 8 1.0000000000
+0.0000000000 0.0000000000 0.0000000000
+0.0000000000 1.0000000000 0.0000000000
+0.0000000000 2.0000000000 0.0000000000
+0.0000000000 3.0000000000 0.0000000000
+0.0000000000 4.0000000000 0.0000000000
+0.0000000000 5.0000000000 0.0000000000
+0.0000000000 6.0000000000 0.0000000000
+0.0000000000 7.0000000000 0.0000000000
+0.0000000000 8.0000000000 1.0000000000
+
+1.0000000000 0.0000000000 0.0000000000
+1.0000000000 1.0000000000 0.0174130458
+1.0000000000 2.0000000000 0.0376833284
+1.0000000000 3.0000000000 0.0643733247
+1.0000000000 4.0000000000 0.1029411764
+1.0000000000 5.0000000000 0.1635678517
+1.0000000000 6.0000000000 0.2693019657
+1.0000000000 7.0000000000 0.4825869542
+1.0000000000 8.0000000000 1.0000000000
+
+2.0000000000 0.0000000000 0.0000000000
+2.0000000000 1.0000000000 0.0319688548
+2.0000000000 2.0000000000 0.0689469431
+2.0000000000 3.0000000000 0.1168687942
+2.0000000000 4.0000000000 0.1838235294
+2.0000000000 5.0000000000 0.2820282646
+2.0000000000 6.0000000000 0.4310530568
+2.0000000000 7.0000000000 0.6610458510
+2.0000000000 8.0000000000 1.0000000000
+
+3.0000000000 0.0000000000 0.0000000000
+3.0000000000 1.0000000000 0.0415154305
+3.0000000000 2.0000000000 0.0892667951
+3.0000000000 3.0000000000 0.1503313795
+3.0000000000 4.0000000000 0.2334558823
+3.0000000000 5.0000000000 0.3496686204
+3.0000000000 6.0000000000 0.5118361460
+3.0000000000 7.0000000000 0.7305433930
+3.0000000000 8.0000000000 1.0000000000
+
+4.0000000000 0.0000000000 0.0000000000
+4.0000000000 1.0000000000 0.0448260720
+4.0000000000 2.0000000000 0.0962734272
+4.0000000000 3.0000000000 0.1617340466
+4.0000000000 4.0000000000 0.2500000000
+4.0000000000 5.0000000000 0.3713541887
+4.0000000000 6.0000000000 0.5360795139
+4.0000000000 7.0000000000 0.7492915750
+4.0000000000 8.0000000000 1.0000000000
+
+5.0000000000 0.0000000000 0.0000000000
+5.0000000000 1.0000000000 0.0415154305
+5.0000000000 2.0000000000 0.0892667951
+5.0000000000 3.0000000000 0.1503313796
+5.0000000000 4.0000000000 0.2334558823
+5.0000000000 5.0000000000 0.3496686204
+5.0000000000 6.0000000000 0.5118361460
+5.0000000000 7.0000000000 0.7305433930
+5.0000000000 8.0000000000 1.0000000000
+
+6.0000000000 0.0000000000 0.0000000000
+6.0000000000 1.0000000000 0.0319688549
+6.0000000000 2.0000000000 0.0689469432
+6.0000000000 3.0000000000 0.1168687942
+6.0000000000 4.0000000000 0.1838235294
+6.0000000000 5.0000000000 0.2820282646
+6.0000000000 6.0000000000 0.4310530568
+6.0000000000 7.0000000000 0.6610458510
+6.0000000000 8.0000000000 1.0000000000
+
+7.0000000000 0.0000000000 0.0000000000
+7.0000000000 1.0000000000 0.0174130458
+7.0000000000 2.0000000000 0.0376833284
+7.0000000000 3.0000000000 0.0643733248
+7.0000000000 4.0000000000 0.1029411765
+7.0000000000 5.0000000000 0.1635678517
+7.0000000000 6.0000000000 0.2693019657
+7.0000000000 7.0000000000 0.4825869542
+7.0000000000 8.0000000000 1.0000000000
+
+8.0000000000 0.0000000000 0.0000000000
+8.0000000000 1.0000000000 0.0000000000
+8.0000000000 2.0000000000 0.0000000000
+8.0000000000 3.0000000000 0.0000000000
+8.0000000000 4.0000000000 0.0000000000
+8.0000000000 5.0000000000 0.0000000000
+8.0000000000 6.0000000000 0.0000000000
+8.0000000000 7.0000000000 0.0000000000
+8.0000000000 8.0000000000 1.0000000000
 
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
     }
+
+    #[test]
+    fn fn_run_with_residual_history_works_with_sor_solver() {
+        // setup input parameters
+        let n_x = 8;
+        let n_y = 8;
+
+        // setup output streams
+        let mut outputstream: Vec<u8> = Vec::new();
+        let mut residual_outputstream: Vec<u8> = Vec::new();
+
+        // setup initial and boundary conditions
+        let mut u_init: Array2<f64> = Array::zeros((n_x + 1, n_y + 1));
+        u_init.slice_mut(s![.., n_y]).assign(&Array::ones(n_x + 1));
+
+        // initialize the solver
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 300,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+
+        // execute run_with_residual_history()
+        run_with_residual_history(&mut solver, &mut outputstream, &mut residual_outputstream)
+            .unwrap();
+
+        // check if the residual is output at every iteration including the initial one,
+        // which is \sqrt{7} from the 7 points next to the boundary `u = 1`
+        let residual_output = String::from_utf8(residual_outputstream).unwrap();
+        let lines: Vec<&str> = residual_output.lines().collect();
+        assert_eq!(lines.len(), solver.get_n_iter() + 1);
+        assert_eq!(lines[0], "0 2.6457513111e0");
+    }
+
+    #[test]
+    fn fn_run_with_snapshots_works_with_point_jacobi_solver() {
+        // setup input parameters
+        let n_x = 8;
+        let n_y = 8;
+        let ncycle_out = 50;
+
+        // setup output streams
+        let mut outputstream: Vec<u8> = Vec::new();
+        let mut residual_outputstream: Vec<u8> = Vec::new();
+        let mut snapshot_outputstream: Vec<u8> = Vec::new();
+
+        // setup initial and boundary conditions
+        let mut u_init: Array2<f64> = Array::zeros((n_x + 1, n_y + 1));
+        u_init.slice_mut(s![.., n_y]).assign(&Array::ones(n_x + 1));
+
+        // initialize the solver
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 300,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+
+        // execute run_with_snapshots()
+        run_with_snapshots(
+            &mut solver,
+            &mut outputstream,
+            &mut residual_outputstream,
+            &mut snapshot_outputstream,
+            ncycle_out,
+        )
+        .unwrap();
+
+        // check if the snapshots are output at the initial guess, every ncycle_out iterations and the convergence
+        let snapshot_output = String::from_utf8(snapshot_outputstream).unwrap();
+        let n_iters: Vec<usize> = snapshot_output
+            .split("\n\n\n")
+            .filter(|snapshot| !snapshot.is_empty())
+            .map(|snapshot| snapshot.split(' ').next().unwrap().parse().unwrap())
+            .collect();
+        let mut n_iters_expected: Vec<usize> =
+            (0..solver.get_n_iter()).step_by(ncycle_out).collect();
+        n_iters_expected.push(solver.get_n_iter());
+        assert_eq!(n_iters, n_iters_expected);
+
+        // check if the last snapshot is the solution
+        let solution_output = String::from_utf8(outputstream).unwrap();
+        let last_snapshot = snapshot_output.trim_end().rsplit("\n\n\n").next().unwrap();
+        let prefix = format!("{} ", solver.get_n_iter());
+        let last_snapshot_without_n_iter: Vec<&str> = last_snapshot
+            .lines()
+            .map(|line| line.strip_prefix(&prefix).unwrap_or(line))
+            .collect();
+        assert_eq!(
+            last_snapshot_without_n_iter,
+            solution_output.trim_end().lines().collect::<Vec<&str>>()
+        );
+    }
 }
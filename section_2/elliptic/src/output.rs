@@ -1,9 +1,10 @@
 //! Module to output the results.
 
+use crate::solver::grid::Grid;
 use ndarray::prelude::*;
 use std::io::{Error, Write};
 
-/// Output the results.
+/// Output the results with the coordinates of the `grid`.
 ///
 /// # Output Format
 /// The output is formatted as follows:
@@ -32,23 +33,25 @@ use std::io::{Error, Write};
 /// ```
 /// use ndarray::prelude::*;
 /// use elliptic::output;
+/// use elliptic::solver::grid::Grid;
 ///
 /// let mut outputstream: Vec<u8> = Vec::new();
 /// let u = array![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]];
-/// output::output(&mut outputstream, &u).unwrap();
+/// let grid = Grid::new(array![0.0, 0.5, 1.0], array![0.0, 0.25, 0.5]).unwrap();
+/// output::output(&mut outputstream, &u, &grid).unwrap();
 ///
 /// let output_expected = "\
-/// 0 0 0.0000000000
-/// 0 1 1.0000000000
-/// 0 2 2.0000000000
+/// 0.0000000000 0.0000000000 0.0000000000
+/// 0.0000000000 0.2500000000 1.0000000000
+/// 0.0000000000 0.5000000000 2.0000000000
 ///
-/// 1 0 3.0000000000
-/// 1 1 4.0000000000
-/// 1 2 5.0000000000
+/// 0.5000000000 0.0000000000 3.0000000000
+/// 0.5000000000 0.2500000000 4.0000000000
+/// 0.5000000000 0.5000000000 5.0000000000
 ///
-/// 2 0 6.0000000000
-/// 2 1 7.0000000000
-/// 2 2 8.0000000000
+/// 1.0000000000 0.0000000000 6.0000000000
+/// 1.0000000000 0.2500000000 7.0000000000
+/// 1.0000000000 0.5000000000 8.0000000000
 ///
 /// ";
 /// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
@@ -56,13 +59,116 @@ use std::io::{Error, Write};
 ///
 /// # Errors
 /// Returns an error if the output fails.
-pub fn output(outputstream: &mut impl Write, u: &Array2<f64>) -> Result<(), Error> {
-    for (i_x, u_at_x) in u.outer_iter().enumerate() {
-        for (i_y, u_val) in u_at_x.iter().enumerate() {
-            writeln!(outputstream, "{} {} {:.10}", i_x, i_y, u_val)?;
+pub fn output(outputstream: &mut impl Write, u: &Array2<f64>, grid: &Grid) -> Result<(), Error> {
+    for (x, u_at_x) in grid.borrow_x().iter().zip(u.outer_iter()) {
+        for (y, u_val) in grid.borrow_y().iter().zip(u_at_x.iter()) {
+            writeln!(outputstream, "{:.10} {:.10} {:.10}", x, y, u_val)?;
         }
         writeln!(outputstream)?;
     }
 
     Ok(())
 }
+
+/// Output the snapshot of `u` after `n_iter` iterations with the coordinates of the `grid`.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// n_iter x0 y0 u_x0_y0
+/// ...
+/// n_iter x0 ym u_x0_ym
+///
+/// n_iter x1 y0 u_x1_y0
+/// ...
+/// n_iter xn ym u_xn_ym
+///
+///
+/// ```
+/// That is, the rows of the same `x` are separated by a blank line and the snapshots are separated by two blank lines,
+/// so that each snapshot can be plotted by `splot` of gnuplot with `index`.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use elliptic::output;
+/// use elliptic::solver::grid::Grid;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let u = array![[0.0, 1.0], [2.0, 3.0]];
+/// let grid = Grid::new(array![0.0, 0.5], array![0.0, 0.25]).unwrap();
+/// output::output_snapshot(&mut outputstream, 5, &u, &grid).unwrap();
+///
+/// let output_expected = "\
+/// 5 0.0000000000 0.0000000000 0.0000000000
+/// 5 0.0000000000 0.2500000000 1.0000000000
+///
+/// 5 0.5000000000 0.0000000000 2.0000000000
+/// 5 0.5000000000 0.2500000000 3.0000000000
+///
+///
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_snapshot(
+    outputstream: &mut impl Write,
+    n_iter: usize,
+    u: &Array2<f64>,
+    grid: &Grid,
+) -> Result<(), Error> {
+    for (i, (x, u_at_x)) in grid.borrow_x().iter().zip(u.outer_iter()).enumerate() {
+        if i > 0 {
+            writeln!(outputstream)?;
+        }
+        for (y, u_val) in grid.borrow_y().iter().zip(u_at_x.iter()) {
+            writeln!(outputstream, "{} {:.10} {:.10} {:.10}", n_iter, x, y, u_val)?;
+        }
+    }
+    writeln!(outputstream)?;
+    writeln!(outputstream)?;
+
+    Ok(())
+}
+
+/// Output the history of the norms of the residuals.
+///
+/// # Output Format
+/// The output is formatted as follows, where `r_n` is the norm of the residual after `n` iterations:
+/// ```text
+/// 0 r_0
+/// 1 r_1
+/// ...
+/// n r_n
+/// ```
+///
+/// # Examples
+/// ```
+/// use elliptic::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let residual_history = vec![1.0, 0.5, 0.125];
+/// output::output_residual_history(&mut outputstream, &residual_history).unwrap();
+///
+/// let output_expected = "\
+/// 0 1.0000000000e0
+/// 1 5.0000000000e-1
+/// 2 1.2500000000e-1
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_residual_history(
+    outputstream: &mut impl Write,
+    residual_history: &[f64],
+) -> Result<(), Error> {
+    for (n_iter, residual) in residual_history.iter().enumerate() {
+        writeln!(outputstream, "{} {:.10e}", n_iter, residual)?;
+    }
+
+    Ok(())
+}
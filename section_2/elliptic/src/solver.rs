@@ -1,10 +1,26 @@
 //! Solvers for the diffusion equation.
 
+pub mod backend;
+pub mod boundary;
+pub mod conjugate_gradient_solver;
+pub mod direct_solver;
+#[cfg(feature = "gpu")]
+pub mod gpu_solver;
+pub mod grid;
+pub mod initial_guess;
+pub mod line_sor_solver;
+pub mod multigrid_solver;
 pub mod point_jacobi_solver;
+pub mod polar_sor_solver;
+pub mod preconditioner;
+pub mod red_black_sor_solver;
+pub mod relaxation_parameter;
 pub mod sor_solver;
+pub mod spectral_radius;
 
 use ndarray::prelude::*;
 use std::error::Error;
+use std::fmt;
 
 /// Solver for the diffusion equation.
 pub trait Solver {
@@ -14,6 +30,73 @@ pub trait Solver {
     fn borrow_u(&self) -> &Array2<f64>;
     /// Return the number of iterations.
     fn get_n_iter(&self) -> usize;
+    /// Return a reference to the grid.
+    fn borrow_grid(&self) -> &grid::Grid;
+    /// Return a reference to the history of the L2 norms of the residuals,
+    /// whose `n`-th element is that after `n` iterations.
+    fn borrow_residual_history(&self) -> &Vec<f64>;
+    /// Return the convergence expected from the parameters of the equation.
+    fn get_convergence(&self) -> Convergence;
+}
+
+/// Solver iterating the relaxation, whose intermediate iterations can be observed.
+///
+/// [Solver::exec] of these solvers repeats [RelaxationSolver::step] until the iteration converges.
+pub trait RelaxationSolver: Solver {
+    /// Advance the iteration by one.
+    ///
+    /// # Errors
+    /// Returns an error if the maximum number of iterations has been reached.
+    fn step(&mut self) -> Result<(), Box<dyn Error>>;
+    /// Return `true` if the iteration has converged.
+    fn is_converged(&self) -> bool;
+}
+
+/// Convergence of the iteration expected from the parameters of the equation, which is evaluated at the construction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Convergence {
+    /// The iteration is expected to converge.
+    Convergent,
+    /// The iteration is expected to diverge, with the explanation.
+    Divergent(&'static str),
+}
+
+impl Convergence {
+    /// Return the convergence for the Helmholtz term `k^2 u`,
+    /// where `eigenvalue_range` is the smallest and largest eigenvalues of `-\nabla^2` given by
+    /// [BoundaryConditions::laplacian_eigenvalue_range](boundary::BoundaryConditions::laplacian_eigenvalue_range).
+    ///
+    /// The discrete operator `\nabla^2 + k^2` has the eigenvalues `k^2 - \lambda` for the eigenvalues `\lambda` of `-\nabla^2`,
+    /// which lie in `[\lambda_{min}, \lambda_{max}]`.
+    /// The operator is indefinite and the iteration is expected to diverge
+    /// if `\lambda_{min} < k^2 < \lambda_{max}`,
+    /// in which case the relaxation diverges, the conjugate gradient method may break down
+    /// and the Cholesky decomposition fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use elliptic::solver::Convergence;
+    ///
+    /// assert_eq!(Convergence::for_helmholtz(0.0, (0.05, 7.95)), Convergence::Convergent);
+    /// assert!(matches!(Convergence::for_helmholtz(1.0, (0.05, 7.95)), Convergence::Divergent(_)));
+    /// ```
+    pub fn for_helmholtz(k_squared: f64, eigenvalue_range: (f64, f64)) -> Self {
+        if eigenvalue_range.0 < k_squared && k_squared < eigenvalue_range.1 {
+            Convergence::Divergent("k^2 makes the discrete Helmholtz operator indefinite")
+        } else {
+            Convergence::Convergent
+        }
+    }
+}
+
+impl fmt::Display for Convergence {
+    /// Format the convergence as `convergent` or `divergent: explanation`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Convergence::Convergent => write!(f, "convergent"),
+            Convergence::Divergent(reason) => write!(f, "divergent: {}", reason),
+        }
+    }
 }
 
 /// Parameters for creating a new solver.
@@ -0,0 +1,39 @@
+//! Module for the backend on which the relaxation is executed.
+//!
+//! The backend can be given in the input files of the examples as
+//! ```yaml
+//! backend: gpu
+//! ```
+//! which is [Backend::Cpu] if omitted.
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Backend on which the sweeps of the relaxation are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Backend {
+    /// The sweeps are executed on the CPU, in parallel with the `parallel` feature.
+    #[default]
+    Cpu,
+    /// The sweeps are executed on the GPU by the `gpu_solver` module, which requires the `gpu` feature.
+    Gpu,
+}
+
+impl Backend {
+    /// Validate that the backend is available with the enabled features.
+    ///
+    /// # Examples
+    /// ```
+    /// use elliptic::solver::backend::Backend;
+    ///
+    /// assert_eq!(Backend::Cpu.validate(), Ok(()));
+    /// assert_eq!(Backend::Gpu.validate().is_ok(), cfg!(feature = "gpu"));
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if *self == Backend::Gpu && !cfg!(feature = "gpu") {
+            return Err("backend requires the gpu feature");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,666 @@
+//! Boundary conditions for the solvers.
+//!
+//! A [BoundaryCondition] is given for each edge of the domain, as the [BoundaryConditions] for `x_\pm` and `y_\pm`.
+//! The points on the edges with the flux boundary conditions are unknown and solved together with the interior points,
+//! where the ghost points outside the domain are given by the central difference.
+//! The corner points are fixed if either of the edges sharing them is fixed, and unknown otherwise.
+//!
+//! The boundary conditions can be given in the input files for each edge, e.g.
+//! ```yaml
+//! boundary_conditions:
+//!   x_minus: !neumann 0.0
+//!   y_plus: !robin { a: 1.0, b: 1.0, g: 0.0 }
+//! ```
+//! where the omitted edges are [BoundaryCondition::Dirichlet].
+
+use super::grid::{Grid, SecondDifference};
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Boundary condition at one edge of the domain.
+///
+/// The derivative `\frac{\partial u}{\partial \xi}` is that in the direction of increasing `\xi`,
+/// where `\xi` is `x` at the edges `x_\pm` and `y` at the edges `y_\pm`
+/// with the grid spacing `\Delta \xi` between the edge point and its inner point.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryCondition {
+    /// Dirichlet boundary condition, where the value is fixed as
+    /// ```math
+    /// u(\xi_{\pm}) = u_init(\xi_{\pm}).
+    /// ```
+    #[default]
+    Dirichlet,
+    /// Neumann boundary condition, where the flux is prescribed as
+    /// ```math
+    /// \frac{\partial u}{\partial \xi}(\xi_{\pm}) = g_{\pm},
+    /// ```
+    /// with `g_{\pm} = 0` for the insulated boundary.
+    ///
+    /// The edge points are solved with the ghost point given by the central difference,
+    /// `u_{-1} = u_1 - 2 \Delta \xi g_{-}` and `u_{N+1} = u_{N-1} + 2 \Delta \xi g_{+}`.
+    Neumann(f64),
+    /// Robin boundary condition, where the linear combination of the value and the flux is prescribed as
+    /// ```math
+    /// a u(\xi_{\pm}) + b \frac{\partial u}{\partial \xi}(\xi_{\pm}) = g.
+    /// ```
+    ///
+    /// The ghost point is given by the central difference as for the Neumann boundary condition,
+    /// e.g. `u_{-1} = u_1 + 2 \Delta \xi (a u_0 - g) / b`.
+    /// For `b = 0`, the value is fixed as `u(\xi_{\pm}) = g / a`.
+    Robin {
+        /// Coefficient of `u`.
+        a: f64,
+        /// Coefficient of `u_\xi`.
+        b: f64,
+        /// Right-hand side.
+        g: f64,
+    },
+}
+
+/// Treatment of an edge point derived from its [BoundaryCondition].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Edge {
+    /// The edge point is fixed to the value.
+    Fixed(f64),
+    /// The edge point is solved with the ghost point `u_{ghost} = u_{inner} + \alpha u_{edge} + \beta`,
+    /// given as `(\alpha, \beta)`.
+    Ghost(f64, f64),
+}
+
+impl BoundaryCondition {
+    /// Return the treatment of the edge point whose current value is `u_edge`,
+    /// where `outward` is `-1` at `\xi_-` and `1` at `\xi_+` and `spacing` is `\Delta \xi`.
+    pub(crate) fn edge(&self, u_edge: f64, outward: f64, spacing: f64) -> Edge {
+        let outward = outward * spacing;
+        match *self {
+            Self::Dirichlet => Edge::Fixed(u_edge),
+            Self::Neumann(g) => Edge::Ghost(0.0, 2.0 * outward * g),
+            Self::Robin { a, b: 0.0, g } => Edge::Fixed(g / a),
+            Self::Robin { a, b, g } => Edge::Ghost(-2.0 * outward * a / b, 2.0 * outward * g / b),
+        }
+    }
+
+    fn is_fixed(&self) -> bool {
+        matches!(self.edge(0.0, 1.0, 1.0), Edge::Fixed(_))
+    }
+}
+
+/// Boundary conditions at the edges `x_-`, `x_+`, `y_-` and `y_+`, which are all Dirichlet by default.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoundaryConditions {
+    /// Boundary condition at `x = x_-`.
+    pub x_minus: BoundaryCondition,
+    /// Boundary condition at `x = x_+`.
+    pub x_plus: BoundaryCondition,
+    /// Boundary condition at `y = y_-`.
+    pub y_minus: BoundaryCondition,
+    /// Boundary condition at `y = y_+`.
+    pub y_plus: BoundaryCondition,
+}
+
+/// Weighted 5-point stencil at an unknown point, with the ghost points substituted.
+///
+/// The discrete Helmholtz equation at the point `u_c` is
+/// `neighbors - (diag - k^2) u_c + offset = f`,
+/// which is multiplied by `weight` to make the system symmetric.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Stencil {
+    /// Sum of the values of the neighbors weighted by the coefficients of the second differences,
+    /// in which a ghost point is replaced by its inner point.
+    pub(crate) neighbors: f64,
+    /// Coefficient of `-u_c`, that is, the sum of the coefficients of the neighbors minus the weighted `\alpha` of the ghost points.
+    pub(crate) diag: f64,
+    /// Sum of the weighted `\beta` of the ghost points.
+    pub(crate) offset: f64,
+    /// Coefficients of the neighbors `u_{j-1,k}` and `u_{j+1,k}` in `neighbors`,
+    /// which are zero outside the domain and include the coefficient of the ghost point on the other side.
+    pub(crate) coefs_x: (f64, f64),
+    /// Coefficients of the neighbors `u_{j,k-1}` and `u_{j,k+1}` in `neighbors`,
+    /// which are zero outside the domain and include the coefficient of the ghost point on the other side.
+    pub(crate) coefs_y: (f64, f64),
+    /// Area of the cell around the point divided by the mean spacings and halved for each flux edge the point is on,
+    /// which is `1/2` for each such edge on the uniform grid.
+    pub(crate) weight: f64,
+}
+
+impl BoundaryConditions {
+    /// Validate the boundary conditions for `u` with `dim` points.
+    pub fn validate(&self, dim: (usize, usize)) -> Result<(), &'static str> {
+        for (bc, len) in [
+            (self.x_minus, dim.0),
+            (self.x_plus, dim.0),
+            (self.y_minus, dim.1),
+            (self.y_plus, dim.1),
+        ] {
+            if let BoundaryCondition::Robin { a, b, .. } = bc {
+                if a == 0.0 && b == 0.0 {
+                    return Err("a and b of the Robin boundary condition must not be both zero");
+                }
+            }
+            if !bc.is_fixed() && len < 2 {
+                return Err("u must have at least 2 points for the flux boundary condition");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the compatibility condition of the Neumann problem with the `source` on the `grid`.
+    ///
+    /// If the Neumann boundary condition is given at all the edges and `k^2 = 0`,
+    /// the solution is determined only up to a constant and exists only if the source balances the fluxes,
+    /// ```math
+    /// \sum_{j,k} w_{j,k} f_{j,k} = \sum_{j,k} w_{j,k} \beta_{j,k},
+    /// ```
+    /// which is the discrete counterpart of `\int f dS = \oint \frac{\partial u}{\partial n} dl`,
+    /// where `w_{j,k}` is the weight of [Stencil] and `\beta_{j,k}` is the sum of the weighted `\beta` of the ghost points.
+    /// The condition is not required if any point is fixed by the `obstacle`.
+    pub fn validate_compatibility(
+        &self,
+        grid: &Grid,
+        source: Option<&Array2<f64>>,
+        k_squared: f64,
+        obstacle: Option<&Array2<bool>>,
+    ) -> Result<(), &'static str> {
+        if k_squared != 0.0
+            || obstacle.is_some_and(|obstacle| obstacle.iter().any(|&is_obstacle| is_obstacle))
+            || !self.is_all_neumann()
+        {
+            return Ok(());
+        }
+
+        let u = Array::zeros(grid.dim());
+        let (mut imbalance, mut scale) = (0.0, 0.0);
+        for ((i_x, i_y), _) in u.indexed_iter() {
+            let stencil = self.stencil(&u, i_x, i_y, grid);
+            let f = source.map_or(0.0, |source| source[[i_x, i_y]]);
+            imbalance += stencil.weight * (f - stencil.offset);
+            scale += stencil.weight * (f.abs() + stencil.offset.abs());
+        }
+        if imbalance.abs() > 1.0e-8 * scale.max(1.0) {
+            return Err(
+                "source and fluxes must satisfy the compatibility condition of the Neumann problem",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Return the smallest and largest eigenvalues of `-\nabla^2` discretized on the `grid` with these boundary conditions.
+    ///
+    /// Since the discrete operator is the sum of the second differences in x and y directions,
+    /// the eigenvalues are the sums of those of the second differences on the unknown points in each direction,
+    /// which are symmetrized by the widths of the cells and found by the bisection with the Sturm sequence.
+    /// On the uniform grid with `N` cells of the width `\Delta \xi` in a direction, the smallest eigenvalue is
+    /// `(2 - 2 \cos(\pi / N)) / \Delta \xi^2` with the fixed values at both ends,
+    /// `(2 - 2 \cos(\pi / 2N)) / \Delta \xi^2` with the flux at one end and `0` with the fluxes at both ends,
+    /// and the largest one is `4 / \Delta \xi^2` minus the smallest one.
+    /// The Robin boundary condition is estimated as the Neumann one and the obstacles are not taken into account,
+    /// so that the estimate is conservative.
+    /// If there is no unknown point, the empty range from infinity to negative infinity is returned.
+    pub fn laplacian_eigenvalue_range(&self, grid: &Grid) -> (f64, f64) {
+        let (x_range, y_range) = self.unknown_ranges(grid.dim());
+        let eigenvalue_range = |range: Range<usize>,
+                                second_difference: &dyn Fn(usize) -> SecondDifference,
+                                minus: BoundaryCondition,
+                                plus: BoundaryCondition| {
+            if range.is_empty() {
+                return None;
+            }
+
+            // the coefficients of the second difference with the ghost points replaced by the inner points
+            let coefs: Vec<(f64, f64)> = range
+                .clone()
+                .map(|i| {
+                    let second_difference = second_difference(i);
+                    let (mut coef_minus, mut coef_plus) =
+                        (second_difference.coef_minus, second_difference.coef_plus);
+                    if i == range.start && !minus.is_fixed() {
+                        (coef_minus, coef_plus) = (0.0, coef_minus + coef_plus);
+                    }
+                    if i == range.end - 1 && !plus.is_fixed() {
+                        (coef_minus, coef_plus) = (coef_minus + coef_plus, 0.0);
+                    }
+                    (coef_minus, coef_plus)
+                })
+                .collect();
+            let diag: Vec<f64> = range
+                .clone()
+                .map(|i| {
+                    let second_difference = second_difference(i);
+                    second_difference.coef_minus + second_difference.coef_plus
+                })
+                .collect();
+            let offdiag: Vec<f64> = coefs
+                .windows(2)
+                .map(|coefs| (coefs[0].1 * coefs[1].0).sqrt())
+                .collect();
+
+            let eigenvalue_min = if minus.is_fixed() || plus.is_fixed() {
+                tridiagonal_eigenvalue(&diag, &offdiag, 0)
+            } else {
+                0.0
+            };
+            let eigenvalue_max = tridiagonal_eigenvalue(&diag, &offdiag, diag.len() - 1);
+
+            Some((eigenvalue_min, eigenvalue_max))
+        };
+
+        match (
+            eigenvalue_range(
+                x_range,
+                &|i_x| grid.second_difference_x(i_x),
+                self.x_minus,
+                self.x_plus,
+            ),
+            eigenvalue_range(
+                y_range,
+                &|i_y| grid.second_difference_y(i_y),
+                self.y_minus,
+                self.y_plus,
+            ),
+        ) {
+            (Some((min_x, max_x)), Some((min_y, max_y))) => (min_x + min_y, max_x + max_y),
+            _ => (f64::INFINITY, f64::NEG_INFINITY),
+        }
+    }
+
+    /// Return the spectral radius of the iteration matrix of the point Jacobi method for `\nabla^2 u + k^2 u = f`
+    /// discretized on the `grid` with these boundary conditions,
+    /// ```math
+    /// \rho_J = \max_\lambda \left|1 - \frac{\lambda - k^2}{d - k^2}\right|,
+    /// ```
+    /// where `\lambda` is either end of [Self::laplacian_eigenvalue_range]
+    /// and `d` is the largest diagonal coefficient of `-\nabla^2` at the unknown points.
+    /// The estimate is exact on the uniform grid with the diagonal coefficient common to all the unknown points,
+    /// e.g. `\rho_J = \cos(\pi / N)` on the square of `N \times N` cells with the fixed values at the edges.
+    /// If there is no unknown point, `0` is returned.
+    pub fn jacobi_spectral_radius(&self, grid: &Grid, k_squared: f64) -> f64 {
+        let (x_range, y_range) = self.unknown_ranges(grid.dim());
+        let diag_max =
+            |range: Range<usize>, second_difference: &dyn Fn(usize) -> SecondDifference| {
+                range
+                    .map(|i| {
+                        let second_difference = second_difference(i);
+                        second_difference.coef_minus + second_difference.coef_plus
+                    })
+                    .fold(0.0, f64::max)
+            };
+        let diag = diag_max(x_range, &|i_x| grid.second_difference_x(i_x))
+            + diag_max(y_range, &|i_y| grid.second_difference_y(i_y));
+
+        let (eigenvalue_min, eigenvalue_max) = self.laplacian_eigenvalue_range(grid);
+        if eigenvalue_min > eigenvalue_max {
+            return 0.0;
+        }
+
+        let radius = |eigenvalue: f64| (1.0 - (eigenvalue - k_squared) / (diag - k_squared)).abs();
+        radius(eigenvalue_min).max(radius(eigenvalue_max))
+    }
+
+    /// Return the homogeneous boundary conditions, where the prescribed fluxes and the right-hand sides are zero,
+    /// which govern the error of the iteration.
+    pub(crate) fn homogeneous(&self) -> Self {
+        let homogeneous = |bc: BoundaryCondition| match bc {
+            BoundaryCondition::Dirichlet => BoundaryCondition::Dirichlet,
+            BoundaryCondition::Neumann(_) => BoundaryCondition::Neumann(0.0),
+            BoundaryCondition::Robin { a, b, .. } => BoundaryCondition::Robin { a, b, g: 0.0 },
+        };
+
+        Self {
+            x_minus: homogeneous(self.x_minus),
+            x_plus: homogeneous(self.x_plus),
+            y_minus: homogeneous(self.y_minus),
+            y_plus: homogeneous(self.y_plus),
+        }
+    }
+
+    /// Overwrite the fixed edge values of `u` given by the Robin boundary condition with `b = 0`.
+    pub(crate) fn apply_fixed(&self, u: &mut Array2<f64>) {
+        let (n_x, n_y) = u.dim();
+        if n_x == 0 || n_y == 0 {
+            return;
+        }
+
+        for (bc, edge, outward) in [
+            (self.x_minus, s![0, ..], -1.0),
+            (self.x_plus, s![n_x - 1, ..], 1.0),
+            (self.y_minus, s![.., 0], -1.0),
+            (self.y_plus, s![.., n_y - 1], 1.0),
+        ] {
+            u.slice_mut(edge)
+                .mapv_inplace(|u| match bc.edge(u, outward, 1.0) {
+                    Edge::Fixed(u) => u,
+                    Edge::Ghost(..) => u,
+                });
+        }
+    }
+
+    /// Return whether the Neumann boundary condition is given at all the edges,
+    /// for which the solution of `\nabla^2 u = f` is determined only up to a constant.
+    pub(crate) fn is_all_neumann(&self) -> bool {
+        let is_neumann = |bc: BoundaryCondition| matches!(bc.edge(0.0, 1.0, 1.0), Edge::Ghost(alpha, _) if alpha == 0.0);
+        [self.x_minus, self.x_plus, self.y_minus, self.y_plus]
+            .into_iter()
+            .all(is_neumann)
+    }
+
+    /// Return the ranges of `i_x` and `i_y` of the unknown points on the grid with `dim` points.
+    pub(crate) fn unknown_ranges(&self, dim: (usize, usize)) -> (Range<usize>, Range<usize>) {
+        let range = |len: usize, minus: BoundaryCondition, plus: BoundaryCondition| {
+            let start = usize::from(minus.is_fixed());
+            let end = len.saturating_sub(usize::from(plus.is_fixed()));
+            start..end.max(start)
+        };
+
+        (
+            range(dim.0, self.x_minus, self.x_plus),
+            range(dim.1, self.y_minus, self.y_plus),
+        )
+    }
+
+    /// Return whether the point `(i_x, i_y)` on the grid with `dim` points is fixed.
+    fn is_fixed(&self, dim: (usize, usize), i_x: usize, i_y: usize) -> bool {
+        (i_x == 0 && self.x_minus.is_fixed())
+            || (i_x == dim.0 - 1 && self.x_plus.is_fixed())
+            || (i_y == 0 && self.y_minus.is_fixed())
+            || (i_y == dim.1 - 1 && self.y_plus.is_fixed())
+    }
+
+    /// Return the mask of the fixed points on the grid with `dim` points,
+    /// which are the fixed edge points and the points of the `obstacle`.
+    pub(crate) fn fixed_mask(
+        &self,
+        dim: (usize, usize),
+        obstacle: Option<&Array2<bool>>,
+    ) -> Array2<bool> {
+        Array::from_shape_fn(dim, |(i_x, i_y)| {
+            self.is_fixed(dim, i_x, i_y) || obstacle.is_some_and(|obstacle| obstacle[[i_x, i_y]])
+        })
+    }
+
+    /// Return the L2 norm of the residual `f - \nabla^2 u - k^2 u` of `u` on the `grid`
+    /// with the `source` `f` over the points not in `fixed`.
+    pub(crate) fn residual_norm(
+        &self,
+        u: &Array2<f64>,
+        grid: &Grid,
+        source: &Array2<f64>,
+        k_squared: f64,
+        fixed: &Array2<bool>,
+    ) -> f64 {
+        let mut residual_squared = 0.0;
+        for ((i_x, i_y), &is_fixed) in fixed.indexed_iter() {
+            if is_fixed {
+                continue;
+            }
+
+            let residual = self.residual_at(u, grid, source, k_squared, i_x, i_y);
+            residual_squared += residual * residual;
+        }
+
+        residual_squared.sqrt()
+    }
+
+    /// Return the residual `f - \nabla^2 u - k^2 u` of `u` on the `grid` with the `source` `f`,
+    /// which is zero at the points in `fixed`.
+    pub(crate) fn residual(
+        &self,
+        u: &Array2<f64>,
+        grid: &Grid,
+        source: &Array2<f64>,
+        k_squared: f64,
+        fixed: &Array2<bool>,
+    ) -> Array2<f64> {
+        Array::from_shape_fn(u.dim(), |(i_x, i_y)| {
+            if fixed[[i_x, i_y]] {
+                0.0
+            } else {
+                self.residual_at(u, grid, source, k_squared, i_x, i_y)
+            }
+        })
+    }
+
+    /// Return the residual at the unknown point `(i_x, i_y)`, see [Self::residual].
+    fn residual_at(
+        &self,
+        u: &Array2<f64>,
+        grid: &Grid,
+        source: &Array2<f64>,
+        k_squared: f64,
+        i_x: usize,
+        i_y: usize,
+    ) -> f64 {
+        let stencil = self.stencil(u, i_x, i_y, grid);
+
+        source[[i_x, i_y]] - stencil.neighbors - stencil.offset
+            + (stencil.diag - k_squared) * u[[i_x, i_y]]
+    }
+
+    /// Relax the unknown values of `u` on the `grid` in place by a Gauss-Seidel sweep in the lexicographic order
+    /// with the `source`, which is zero if `None`, skipping the points in `fixed`.
+    pub(crate) fn gauss_seidel_sweep(
+        &self,
+        u: &mut Array2<f64>,
+        grid: &Grid,
+        source: Option<&Array2<f64>>,
+        k_squared: f64,
+        fixed: &Array2<bool>,
+    ) {
+        for ((i_x, i_y), &is_fixed) in fixed.indexed_iter() {
+            if is_fixed {
+                continue;
+            }
+
+            let stencil = self.stencil(u, i_x, i_y, grid);
+            let f = source.map_or(0.0, |source| source[[i_x, i_y]]);
+            u[[i_x, i_y]] = (stencil.neighbors + stencil.offset - f) / (stencil.diag - k_squared);
+        }
+    }
+
+    /// Return the stencil at the unknown point `(i_x, i_y)` of `u` on the `grid`.
+    pub(crate) fn stencil(&self, u: &Array2<f64>, i_x: usize, i_y: usize, grid: &Grid) -> Stencil {
+        let (n_x, n_y) = u.dim();
+        let (second_difference_x, second_difference_y) =
+            (grid.second_difference_x(i_x), grid.second_difference_y(i_y));
+        let mut stencil = Stencil {
+            neighbors: 0.0,
+            diag: second_difference_x.coef_minus
+                + second_difference_x.coef_plus
+                + second_difference_y.coef_minus
+                + second_difference_y.coef_plus,
+            offset: 0.0,
+            coefs_x: (
+                second_difference_x.coef_minus,
+                second_difference_x.coef_plus,
+            ),
+            coefs_y: (
+                second_difference_y.coef_minus,
+                second_difference_y.coef_plus,
+            ),
+            weight: second_difference_x.width * second_difference_y.width,
+        };
+        let add_ghost = |stencil: &mut Stencil,
+                         bc: BoundaryCondition,
+                         outward: f64,
+                         h: f64,
+                         coef: f64,
+                         inner: f64| {
+            if let Edge::Ghost(alpha, beta) = bc.edge(0.0, outward, h) {
+                stencil.neighbors += coef * inner;
+                stencil.diag -= coef * alpha;
+                stencil.offset += coef * beta;
+                stencil.weight *= 0.5;
+            }
+        };
+
+        if i_x == 0 {
+            add_ghost(
+                &mut stencil,
+                self.x_minus,
+                -1.0,
+                second_difference_x.h_minus,
+                second_difference_x.coef_minus,
+                u[[1, i_y]],
+            );
+            stencil.coefs_x = (0.0, stencil.coefs_x.0 + stencil.coefs_x.1);
+        } else {
+            stencil.neighbors += second_difference_x.coef_minus * u[[i_x - 1, i_y]];
+        }
+        if i_x == n_x - 1 {
+            add_ghost(
+                &mut stencil,
+                self.x_plus,
+                1.0,
+                second_difference_x.h_plus,
+                second_difference_x.coef_plus,
+                u[[n_x - 2, i_y]],
+            );
+            stencil.coefs_x = (stencil.coefs_x.0 + stencil.coefs_x.1, 0.0);
+        } else {
+            stencil.neighbors += second_difference_x.coef_plus * u[[i_x + 1, i_y]];
+        }
+        if i_y == 0 {
+            add_ghost(
+                &mut stencil,
+                self.y_minus,
+                -1.0,
+                second_difference_y.h_minus,
+                second_difference_y.coef_minus,
+                u[[i_x, 1]],
+            );
+            stencil.coefs_y = (0.0, stencil.coefs_y.0 + stencil.coefs_y.1);
+        } else {
+            stencil.neighbors += second_difference_y.coef_minus * u[[i_x, i_y - 1]];
+        }
+        if i_y == n_y - 1 {
+            add_ghost(
+                &mut stencil,
+                self.y_plus,
+                1.0,
+                second_difference_y.h_plus,
+                second_difference_y.coef_plus,
+                u[[i_x, n_y - 2]],
+            );
+            stencil.coefs_y = (stencil.coefs_y.0 + stencil.coefs_y.1, 0.0);
+        } else {
+            stencil.neighbors += second_difference_y.coef_plus * u[[i_x, i_y + 1]];
+        }
+
+        stencil
+    }
+}
+
+/// Return the `k`-th smallest eigenvalue of the symmetric tridiagonal matrix with `diag` and `offdiag`,
+/// which is positive semidefinite, by the bisection with the Sturm sequence.
+fn tridiagonal_eigenvalue(diag: &[f64], offdiag: &[f64], k: usize) -> f64 {
+    // the number of the eigenvalues less than `lambda`, which is that of the negative pivots of the LDL^T decomposition
+    let count_below = |lambda: f64| {
+        let mut pivot = 1.0;
+        let mut count = 0;
+        for (i, d) in diag.iter().enumerate() {
+            let coupling = if i == 0 {
+                0.0
+            } else {
+                offdiag[i - 1] * offdiag[i - 1] / pivot
+            };
+            pivot = d - lambda - coupling;
+            if pivot == 0.0 {
+                pivot = -f64::EPSILON * d.abs().max(1.0);
+            }
+            if pivot < 0.0 {
+                count += 1;
+            }
+        }
+        count
+    };
+
+    // Gershgorin bound of the largest eigenvalue
+    let mut upper = diag
+        .iter()
+        .enumerate()
+        .map(|(i, d)| {
+            let left = if i == 0 { 0.0 } else { offdiag[i - 1].abs() };
+            let right = offdiag.get(i).map_or(0.0, |e| e.abs());
+            d + left + right
+        })
+        .fold(0.0, f64::max);
+    let mut lower = 0.0;
+    loop {
+        let middle = 0.5 * (lower + upper);
+        if middle <= lower || middle >= upper {
+            return middle;
+        }
+        if count_below(middle) > k {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_boundary_conditions_can_be_given_in_input() {
+        // read the boundary conditions from the YAML input, where y_- is omitted
+        let input =
+            "x_minus: !neumann 0.5\nx_plus: dirichlet\ny_plus: !robin { a: 1.0, b: 2.0, g: 0.0 }";
+        let boundary_conditions: BoundaryConditions = serde_yaml::from_str(input).unwrap();
+
+        // check if the boundary conditions are correctly read with the default for the omitted edge
+        assert_eq!(
+            boundary_conditions,
+            BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.5),
+                x_plus: BoundaryCondition::Dirichlet,
+                y_minus: BoundaryCondition::Dirichlet,
+                y_plus: BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: 2.0,
+                    g: 0.0
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn fn_laplacian_eigenvalue_range_works() {
+        // setup the grid with 4 x 5 interior points and the flux at x_+,
+        // for which \lambda_{min} = 4 - 2 \cos(\pi / 8) - 2 \cos(\pi / 6) and \lambda_{max} = 8 - \lambda_{min}
+        let grid = Grid::uniform((5, 7), (1.0, 1.0)).unwrap();
+        let boundary_conditions = BoundaryConditions {
+            x_plus: BoundaryCondition::Neumann(0.0),
+            ..Default::default()
+        };
+        let (eigenvalue_min, eigenvalue_max) =
+            boundary_conditions.laplacian_eigenvalue_range(&grid);
+
+        // check if the eigenvalues agree with the closed forms
+        let eigenvalue_min_expected = 4.0
+            - 2.0 * (std::f64::consts::PI / 8.0).cos()
+            - 2.0 * (std::f64::consts::PI / 6.0).cos();
+        assert!((eigenvalue_min - eigenvalue_min_expected).abs() < 1e-12);
+        assert!((eigenvalue_max - (8.0 - eigenvalue_min_expected)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fn_jacobi_spectral_radius_works() {
+        // setup the rectangle with 8 x 6 cells of dx = 1 and dy = 2,
+        // for which \rho_J = (\cos(\pi / 8) + \cos(\pi / 6) / 4) / (1 + 1 / 4)
+        let grid = Grid::uniform((9, 7), (1.0, 2.0)).unwrap();
+        let boundary_conditions = BoundaryConditions::default();
+
+        // check if the spectral radius agrees with the closed form
+        let rho_expected =
+            ((std::f64::consts::PI / 8.0).cos() + 0.25 * (std::f64::consts::PI / 6.0).cos()) / 1.25;
+        assert!(
+            (boundary_conditions.jacobi_spectral_radius(&grid, 0.0) - rho_expected).abs() < 1e-12
+        );
+    }
+}
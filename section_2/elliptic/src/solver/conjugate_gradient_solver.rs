@@ -0,0 +1,665 @@
+//! Solver for the diffusion equation using the conjugate gradient method.
+//!
+//! # Scheme
+//! The 5-point discretization of the Laplace's equation is written as the linear equation `A u = b`,
+//! where
+//! ```math
+//! (A u)_{j,k} = (d - k^2) u_{j,k} - \frac{u_{j-1,k} + u_{j+1,k}}{\Delta x^2} - \frac{u_{j,k-1} + u_{j,k+1}}{\Delta y^2}
+//! ```
+//! for the interior points, where `d = 2 / \Delta x^2 + 2 / \Delta y^2` with the grid spacings `\Delta x` and `\Delta y`,
+//! and `b` consists of the boundary values adjacent to them divided by the squared spacings,
+//! from which the source term `f` of the Poisson's equation `\nabla^2 u = f` is subtracted.
+//! The coefficient `k^2` of the Helmholtz term of the Helmholtz equation `\nabla^2 u + k^2 u = f`
+//! is zero for the Laplace's and Poisson's equations.
+//! Since `A` is symmetric positive definite unless `k^2` is too large (see [Convergence::for_helmholtz]),
+//! the conjugate gradient method
+//! ```math
+//! \alpha^n = \frac{r^n \cdot r^n}{p^n \cdot A p^n}, u^{n+1} = u^n + \alpha^n p^n, r^{n+1} = r^n - \alpha^n A p^n,
+//! ```
+//! ```math
+//! \beta^n = \frac{r^{n+1} \cdot r^{n+1}}{r^n \cdot r^n}, p^{n+1} = r^{n+1} + \beta^n p^n,
+//! ```
+//! with `r^0 = p^0 = b - A u^0` converges in at most as many iterations as the number of the unknown points.
+//! `A` is applied without being assembled.
+//!
+//! With the [Preconditioner] `M`, the preconditioned residual `z^n = M^{-1} r^n` replaces `r^n` as
+//! ```math
+//! \alpha^n = \frac{r^n \cdot z^n}{p^n \cdot A p^n}, \beta^n = \frac{r^{n+1} \cdot z^{n+1}}{r^n \cdot z^n}, p^{n+1} = z^{n+1} + \beta^n p^n,
+//! ```
+//! with `p^0 = z^0`, which converges in fewer iterations if `M` approximates `A` well.
+//!
+//! The iteration is regarded as converged when `\|r^n\|_2 \le 10^{-10}`.
+//! The norms of the residuals are recorded at every iteration, see [Solver::borrow_residual_history].
+//!
+//! On the non-uniform [Grid], the coefficients `1 / \Delta x^2` and `1 / \Delta y^2` of the neighbors
+//! are replaced with those of the second differences given in [super::grid], and `d` with their sum.
+//! The equations are multiplied by the areas of the cells around the points to keep `A` symmetric.
+//!
+//! # Boundary Condition
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
+//! ```math
+//! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
+//! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The equations at them are multiplied by `1/2` for each edge the point is on,
+//! which cancels the doubled couplings with the ghost points and keeps `A` symmetric.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well,
+//! where the preconditioned residuals are set to zero so that the search directions don't update them.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::preconditioner::Preconditioner;
+use super::{Convergence, NewParams, Solver};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use std::error::Error;
+
+/// Solver for the diffusion equation using the conjugate gradient method.
+#[derive(Debug)]
+pub struct ConjugateGradientSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    n_iter_max: usize,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    convergence: Convergence,
+    preconditioner: Box<dyn Preconditioner>,
+    residual: Array2<f64>,
+    residual_preconditioned: Array2<f64>,
+    direction: Array2<f64>,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+}
+
+impl ConjugateGradientSolver {
+    /// Create a new `ConjugateGradientSolver` instance.
+    pub fn new(new_params: ConjugateGradientSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let grid = new_params.grid;
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&grid);
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+
+        // the residual of the initial guess, whose preconditioned one is the first search direction
+        let boundary_conditions = new_params.boundary_conditions;
+        let mut residual = Self::apply_operator(
+            &u,
+            &grid,
+            new_params.k_squared,
+            &boundary_conditions,
+            &fixed,
+        );
+        for ((i_x, i_y), r) in residual.indexed_iter_mut() {
+            if fixed[[i_x, i_y]] {
+                continue;
+            }
+
+            let stencil = boundary_conditions.stencil(&u, i_x, i_y, &grid);
+            *r = stencil.weight * (stencil.offset - source[[i_x, i_y]]) - *r;
+        }
+        let residual_preconditioned =
+            Self::precondition(new_params.preconditioner.as_ref(), &residual, &fixed);
+        let residual_norm = Self::dot(&residual, &residual).sqrt();
+        let epsilon = 1.0e-10;
+
+        Ok(Self {
+            u,
+            grid,
+            n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            boundary_conditions,
+            fixed,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            preconditioner: new_params.preconditioner,
+            direction: residual_preconditioned.clone(),
+            residual,
+            residual_preconditioned,
+            residual_history: vec![residual_norm],
+            epsilon,
+            n_iter: 0,
+            executed: false,
+            converged: residual_norm <= epsilon,
+        })
+    }
+
+    fn iterate(&mut self) {
+        let a_direction = Self::apply_operator(
+            &self.direction,
+            &self.grid,
+            self.k_squared,
+            &self.boundary_conditions,
+            &self.fixed,
+        );
+        let rz = Self::dot(&self.residual, &self.residual_preconditioned);
+        let alpha = rz / Self::dot(&self.direction, &a_direction);
+
+        self.u.scaled_add(alpha, &self.direction);
+        self.residual.scaled_add(-alpha, &a_direction);
+        self.residual_preconditioned =
+            Self::precondition(self.preconditioner.as_ref(), &self.residual, &self.fixed);
+
+        let rz_next = Self::dot(&self.residual, &self.residual_preconditioned);
+        let beta = rz_next / rz;
+        self.direction *= beta;
+        self.direction += &self.residual_preconditioned;
+
+        let residual_norm = Self::dot(&self.residual, &self.residual).sqrt();
+        self.residual_history.push(residual_norm);
+        self.converged = residual_norm <= self.epsilon;
+        self.n_iter += 1;
+    }
+
+    /// Return `M^{-1} r` at the unknown points and zero at the fixed points,
+    /// so that the fixed values are not updated by the search directions.
+    fn precondition(
+        preconditioner: &dyn Preconditioner,
+        residual: &Array2<f64>,
+        fixed: &Array2<bool>,
+    ) -> Array2<f64> {
+        let mut residual_preconditioned = preconditioner.apply(residual);
+        Zip::from(&mut residual_preconditioned)
+            .and(fixed)
+            .for_each(|z, &is_fixed| {
+                if is_fixed {
+                    *z = 0.0;
+                }
+            });
+
+        residual_preconditioned
+    }
+
+    /// Return `A u` at the unknown points and zero at the fixed points `fixed`,
+    /// where the fixed values of `u` are included as the neighbors.
+    fn apply_operator(
+        u: &Array2<f64>,
+        grid: &Grid,
+        k_squared: f64,
+        boundary_conditions: &BoundaryConditions,
+        fixed: &Array2<bool>,
+    ) -> Array2<f64> {
+        let dim = u.dim();
+        let mut a_u = Array::zeros(dim);
+        for i_x in 0..dim.0 {
+            for i_y in 0..dim.1 {
+                if fixed[[i_x, i_y]] {
+                    continue;
+                }
+
+                let stencil = boundary_conditions.stencil(u, i_x, i_y, grid);
+                a_u[[i_x, i_y]] = stencil.weight
+                    * ((stencil.diag - k_squared) * u[[i_x, i_y]] - stencil.neighbors);
+            }
+        }
+
+        a_u
+    }
+
+    fn dot(a: &Array2<f64>, b: &Array2<f64>) -> f64 {
+        a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+    }
+}
+
+impl Solver for ConjugateGradientSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            if self.n_iter >= self.n_iter_max {
+                return Err(Box::<dyn Error>::from(
+                    "maximum number of iterations reached",
+                ));
+            }
+
+            self.iterate();
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+/// Parameters for creating a new `ConjugateGradientSolver` instance.
+pub struct ConjugateGradientSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Maximum number of iterations.
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Preconditioner, or [Identity](super::preconditioner::Identity) for the plain conjugate gradient method.
+    pub preconditioner: Box<dyn Preconditioner>,
+}
+
+impl NewParams for ConjugateGradientSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::preconditioner::{Identity, PreconditionerKind, Ssor};
+    use crate::solver::relaxation_parameter::RelaxationParameter;
+    use crate::solver::sor_solver::{SorSolver, SorSolverNewParams};
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works() {
+        // setup conjugate gradient solver and run exec()
+        let u_init = array![
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            preconditioner: Box::new(Identity),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged within the number of the interior points
+        let u_exact = array![
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.125, 0.375, 1.0],
+            [0.0, 0.125, 0.375, 1.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert!(solver.n_iter <= 4);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_records_residual_history() {
+        // setup the same problem for the conjugate gradient and sor solvers
+        let n = 17;
+        let mut u_init: Array2<f64> = Array::zeros((n, n));
+        u_init.slice_mut(s![.., n - 1]).fill(1.0);
+
+        let mut conjugate_gradient_solver =
+            ConjugateGradientSolver::new(ConjugateGradientSolverNewParams {
+                u_init: u_init.clone(),
+                grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+                n_iter_max: 10000,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+                preconditioner: Box::new(Identity),
+            })
+            .unwrap();
+        conjugate_gradient_solver.exec().unwrap();
+        let mut sor_solver = SorSolver::new(SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        })
+        .unwrap();
+        sor_solver.exec().unwrap();
+
+        // check if the solutions agree and the residuals are recorded at every iteration
+        let is_u_consistent = (conjugate_gradient_solver.borrow_u() - sor_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_consistent);
+        let residual_history = conjugate_gradient_solver.borrow_residual_history();
+        assert_eq!(residual_history.len(), conjugate_gradient_solver.n_iter + 1);
+        assert!((residual_history[0] - 15.0f64.sqrt()).abs() < 1e-10);
+        assert!(*residual_history.last().unwrap() <= 1e-10);
+        assert!(conjugate_gradient_solver.get_n_iter() < sor_solver.get_n_iter());
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_converges_faster_with_preconditioners() {
+        // setup the problem and solve it with each preconditioner
+        let n = 33;
+        let mut u_init: Array2<f64> = Array::zeros((n, n));
+        u_init.slice_mut(s![.., n - 1]).fill(1.0);
+
+        let solve = |kind: PreconditionerKind| {
+            let mut solver = ConjugateGradientSolver::new(ConjugateGradientSolverNewParams {
+                u_init: u_init.clone(),
+                grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+                n_iter_max: 10000,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+                preconditioner: kind.create(u_init.dim()).unwrap(),
+            })
+            .unwrap();
+            solver.exec().unwrap();
+            solver
+        };
+        let solver_identity = solve(PreconditionerKind::Identity);
+        let solver_jacobi = solve(PreconditionerKind::Jacobi);
+        let solver_ssor = solve(PreconditionerKind::Ssor { omega: 1.5 });
+        let solver_ic = solve(PreconditionerKind::IncompleteCholesky);
+
+        // check if the solutions agree and the iterations are reduced by ssor and ic(0)
+        for solver in [&solver_jacobi, &solver_ssor, &solver_ic] {
+            let is_u_consistent = (solver.borrow_u() - solver_identity.borrow_u())
+                .iter()
+                .all(|u| u.abs() < 1e-8);
+            assert!(is_u_consistent);
+        }
+        assert_eq!(solver_jacobi.n_iter, solver_identity.n_iter);
+        assert!(solver_ssor.n_iter < solver_identity.n_iter);
+        assert!(solver_ic.n_iter < solver_identity.n_iter);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            preconditioner: Box::new(Identity),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_helmholtz_term() {
+        // setup u = j^2 + k^2 on the boundary, the coefficient k^2 = 0.5 of the Helmholtz term
+        // and the source f = 4 + 0.5 u, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: Some(u_exact.mapv(|u| 4.0 + 0.5 * u)),
+            k_squared: 0.5,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            preconditioner: Box::new(Identity),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_rectangular_grid() {
+        // setup u = x^2 + 2 y^2 with the grid spacings dx = 0.5 and dy = 0.25,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (0.5 * i_x as f64, 0.25 * i_y as f64);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (0.5, 0.25)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            preconditioner: Box::new(Identity),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_non_uniform_grid() {
+        // setup u = x^2 + 2 y^2 on the grid clustered near x_- and y_+,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        // as the second difference on the non-uniform grid is exact for the quadratic function
+        let grid = Grid::new(
+            array![0.0, 0.1, 0.3, 0.7, 1.5, 2.5],
+            array![0.0, 0.6, 1.0, 1.25, 1.4, 1.48, 1.5],
+        )
+        .unwrap();
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (grid.borrow_x()[i_x], grid.borrow_y()[i_y]);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = ConjugateGradientSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            preconditioner: Box::new(Identity),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_obstacle() {
+        // setup u = j^2 + k^2 on the boundary and the obstacle at j = 2, 3 and k = 3 and the source f = 4,
+        // for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let obstacle = Array::from_shape_fn((6, 7), |(i_x, i_y)| (2..4).contains(&i_x) && i_y == 3);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        u_init
+            .slice_mut(s![2..4, 3])
+            .assign(&u_exact.slice(s![2..4, 3]));
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Bilinear,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: Some(obstacle),
+            preconditioner: Box::new(Ssor::new(1.5).unwrap()),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution with the obstacle kept fixed
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_neumann_boundary_condition() {
+        // setup u = j^2 + k^2 with the fluxes u_x = 0 at x_- and u_x = 10 at x_+ and the source f = 4,
+        // for which u is the exact solution as the central difference of the quadratic function is exact
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![.., 1..6]).fill(0.0);
+        let new_params = ConjugateGradientSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            preconditioner: Box::new(Ssor::new(1.5).unwrap()),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution including the edges x = x_pm
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_conjugate_gradient_exec_works_with_all_neumann_boundary_conditions() {
+        // setup u = j^2 + k^2 with the fluxes at all the edges and the source f = 4,
+        // which satisfy the compatibility condition and determine u up to a constant
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let new_params = ConjugateGradientSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                y_minus: BoundaryCondition::Neumann(0.0),
+                y_plus: BoundaryCondition::Neumann(12.0),
+            },
+            obstacle: None,
+            preconditioner: Box::new(Ssor::new(1.5).unwrap()),
+        };
+        let mut solver = ConjugateGradientSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u differs from the exact solution by a constant
+        let u_diff = solver.u - u_exact;
+        let is_u_correctly_updated = u_diff.iter().all(|u| (u - u_diff[[0, 0]]).abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+}
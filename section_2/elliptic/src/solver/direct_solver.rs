@@ -0,0 +1,441 @@
+//! Solver for the diffusion equation by the direct method with the banded Cholesky decomposition.
+//!
+//! # Scheme
+//! The 5-point discretization is assembled into the linear equation `A u = b` over the unknown points
+//! in the same way as [super::conjugate_gradient_solver], where the equations are multiplied by the weights of the cells
+//! so that `A` is symmetric.
+//! Numbering the unknown points in the increasing order of `j` and then `k`,
+//! `A` is banded with the bandwidth about the number of the points in y direction,
+//! and the equation is solved exactly up to the rounding errors by [BandedCholesky] in a single step,
+//! which requires the memory and the operations proportional to `N_x N_y^2` and `N_x N_y^3`, respectively.
+//! Therefore the solver is suited for small problems, e.g. to validate the results of the iterative solvers.
+//!
+//! `A` is positive definite if `k^2` is less than the smallest eigenvalue of `-\nabla^2`,
+//! and negative definite if `k^2` is greater than the largest one, for which `-A u = -b` is solved instead.
+//! Otherwise, `A` is indefinite and the decomposition fails, see [Convergence::for_helmholtz].
+//!
+//! # Boundary Condition
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
+//! ```math
+//! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
+//! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well.
+//! With the Neumann boundary conditions at all the edges and `k^2 = 0`, where `A` is singular,
+//! the value at the corner `(x_-, y_-)` is fixed to that of `u_init` to determine the constant of the solution.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::{Convergence, NewParams, Solver};
+use ndarray::prelude::*;
+use numerics::banded_cholesky::BandedCholesky;
+use std::error::Error;
+
+/// Solver for the diffusion equation by the direct method with the banded Cholesky decomposition.
+#[derive(Debug)]
+pub struct DirectSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    eigenvalue_range: (f64, f64),
+    convergence: Convergence,
+    residual_history: Vec<f64>,
+    n_iter: usize,
+    executed: bool,
+}
+
+impl DirectSolver {
+    /// Create a new `DirectSolver` instance.
+    pub fn new(new_params: DirectSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let mut fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        if new_params.k_squared == 0.0
+            && new_params.boundary_conditions.is_all_neumann()
+            && !fixed.iter().any(|&is_fixed| is_fixed)
+        {
+            fixed[[0, 0]] = true;
+        }
+        let mut u = new_params.u_init;
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+
+        Ok(Self {
+            u,
+            grid: new_params.grid,
+            source,
+            k_squared: new_params.k_squared,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            eigenvalue_range,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            residual_history: vec![residual_norm],
+            n_iter: 0,
+            executed: false,
+        })
+    }
+
+    /// Assemble and solve the linear equation, and overwrite the unknown values of `u` with the solution.
+    fn solve(&mut self) -> Result<(), &'static str> {
+        // number the unknown points
+        let mut indices: Array2<Option<usize>> = Array::from_elem(self.u.dim(), None);
+        let mut n_unknown = 0;
+        for (index, &is_fixed) in indices.iter_mut().zip(self.fixed.iter()) {
+            if !is_fixed {
+                *index = Some(n_unknown);
+                n_unknown += 1;
+            }
+        }
+        if n_unknown == 0 {
+            return Ok(());
+        }
+
+        // the coefficients of the neighbors are found by setting each of them to 1 in the zero field,
+        // and the fixed values contribute to the right-hand side
+        let (n_x, n_y) = self.u.dim();
+        let mut u_unit: Array2<f64> = Array::zeros(self.u.dim());
+        let mut u_fixed = self.u.clone();
+        for (u, &is_fixed) in u_fixed.iter_mut().zip(self.fixed.iter()) {
+            if !is_fixed {
+                *u = 0.0;
+            }
+        }
+        let mut rows = Vec::with_capacity(n_unknown);
+        let mut bandwidth = 0;
+        for ((i_x, i_y), &index) in indices.indexed_iter() {
+            let Some(index) = index else {
+                continue;
+            };
+
+            let stencil = self
+                .boundary_conditions
+                .stencil(&u_fixed, i_x, i_y, &self.grid);
+            let mut couplings = Vec::with_capacity(4);
+            for (j_x, j_y) in [
+                (i_x.wrapping_sub(1), i_y),
+                (i_x + 1, i_y),
+                (i_x, i_y.wrapping_sub(1)),
+                (i_x, i_y + 1),
+            ] {
+                if j_x >= n_x || j_y >= n_y {
+                    continue;
+                }
+                // only the lower band is needed as A is symmetric
+                let Some(index_neighbor) = indices[[j_x, j_y]].filter(|&i| i < index) else {
+                    continue;
+                };
+
+                u_unit[[j_x, j_y]] = 1.0;
+                let coef = self
+                    .boundary_conditions
+                    .stencil(&u_unit, i_x, i_y, &self.grid)
+                    .neighbors;
+                u_unit[[j_x, j_y]] = 0.0;
+                bandwidth = bandwidth.max(index - index_neighbor);
+                couplings.push((index_neighbor, -stencil.weight * coef));
+            }
+            rows.push((
+                stencil.weight * (stencil.diag - self.k_squared),
+                couplings,
+                stencil.weight * (stencil.neighbors + stencil.offset - self.source[[i_x, i_y]]),
+            ));
+        }
+
+        // assemble the lower band, which is negated if A is negative definite
+        let sign = if self.k_squared > self.eigenvalue_range.1 {
+            -1.0
+        } else {
+            1.0
+        };
+        let mut mat_coef = Array::zeros((n_unknown, bandwidth + 1));
+        let mut vec_rhs = Array::zeros(n_unknown);
+        for (index, (diag, couplings, rhs)) in rows.into_iter().enumerate() {
+            mat_coef[[index, 0]] = sign * diag;
+            for (index_neighbor, coef) in couplings {
+                mat_coef[[index, index - index_neighbor]] = sign * coef;
+            }
+            vec_rhs[index] = sign * rhs;
+        }
+
+        BandedCholesky::new(mat_coef)?.solve(&mut vec_rhs)?;
+        for (u, &index) in self.u.iter_mut().zip(indices.iter()) {
+            if let Some(index) = index {
+                *u = vec_rhs[index];
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Solver for DirectSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        self.solve()?;
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
+        self.n_iter = 1;
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+/// Parameters for creating a new `DirectSolver` instance.
+pub struct DirectSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+}
+
+impl NewParams for DirectSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::conjugate_gradient_solver::{
+        ConjugateGradientSolver, ConjugateGradientSolverNewParams,
+    };
+    use crate::solver::initial_guess::InitialGuess;
+    use crate::solver::line_sor_solver::{LineSorSolver, LineSorSolverNewParams};
+    use crate::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+    use crate::solver::preconditioner::Identity;
+    use crate::solver::relaxation_parameter::RelaxationParameter;
+    use crate::solver::sor_solver::{SorSolver, SorSolverNewParams};
+
+    #[test]
+    fn fn_direct_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = DirectSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+        let mut solver = DirectSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is the exact solution in a single step
+        let is_u_correctly_updated = (&solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(solver.n_iter, 1);
+        assert!(solver.residual_history[1] < 1e-10);
+    }
+
+    #[test]
+    fn fn_direct_exec_agrees_with_iterative_solvers() {
+        // setup the Helmholtz equation on the non-uniform grid with the Neumann boundary condition at x_+
+        // and the obstacle at the center
+        let x = array![0.0, 0.1, 0.3, 0.6, 1.0, 1.5, 2.1];
+        let y = array![0.0, 0.2, 0.5, 0.7, 1.2, 1.4];
+        let grid = Grid::new(x, y).unwrap();
+        let mut u_init: Array2<f64> = Array::zeros(grid.dim());
+        u_init.slice_mut(s![.., 5]).fill(1.0);
+        u_init[[3, 2]] = 0.5;
+        let mut obstacle = Array::from_elem(grid.dim(), false);
+        obstacle[[3, 2]] = true;
+        let source = Some(Array::from_shape_fn(grid.dim(), |(i_x, i_y)| {
+            (i_x as f64 - i_y as f64) * 0.5
+        }));
+        let k_squared = 0.5;
+        let boundary_conditions = BoundaryConditions {
+            x_plus: BoundaryCondition::Neumann(0.5),
+            ..Default::default()
+        };
+
+        let mut direct_solver = DirectSolver::new(DirectSolverNewParams {
+            u_init: u_init.clone(),
+            grid: grid.clone(),
+            source: source.clone(),
+            k_squared,
+            boundary_conditions,
+            obstacle: Some(obstacle.clone()),
+        })
+        .unwrap();
+        direct_solver.exec().unwrap();
+        let mut iterative_solvers: Vec<Box<dyn Solver>> = vec![
+            Box::new(
+                PointJacobiSolver::new(PointJacobiSolverNewParams {
+                    u_init: u_init.clone(),
+                    grid: grid.clone(),
+                    n_iter_max: 100000,
+                    initial_guess: InitialGuess::Zero,
+                    source: source.clone(),
+                    k_squared,
+                    boundary_conditions,
+                    obstacle: Some(obstacle.clone()),
+                })
+                .unwrap(),
+            ),
+            Box::new(
+                SorSolver::new(SorSolverNewParams {
+                    u_init: u_init.clone(),
+                    grid: grid.clone(),
+                    n_iter_max: 10000,
+                    initial_guess: InitialGuess::Zero,
+                    source: source.clone(),
+                    k_squared,
+                    boundary_conditions,
+                    obstacle: Some(obstacle.clone()),
+                    omega: RelaxationParameter::Fixed(1.5),
+                })
+                .unwrap(),
+            ),
+            Box::new(
+                LineSorSolver::new(LineSorSolverNewParams {
+                    u_init: u_init.clone(),
+                    grid: grid.clone(),
+                    n_iter_max: 10000,
+                    initial_guess: InitialGuess::Zero,
+                    source: source.clone(),
+                    k_squared,
+                    boundary_conditions,
+                    obstacle: Some(obstacle.clone()),
+                    omega: 1.5,
+                })
+                .unwrap(),
+            ),
+            Box::new(
+                ConjugateGradientSolver::new(ConjugateGradientSolverNewParams {
+                    u_init: u_init.clone(),
+                    grid: grid.clone(),
+                    n_iter_max: 10000,
+                    initial_guess: InitialGuess::Zero,
+                    source: source.clone(),
+                    k_squared,
+                    boundary_conditions,
+                    obstacle: Some(obstacle.clone()),
+                    preconditioner: Box::new(Identity),
+                })
+                .unwrap(),
+            ),
+        ];
+
+        // check if the iterative solvers converge to the solution of the direct solver
+        for solver in iterative_solvers.iter_mut() {
+            solver.exec().unwrap();
+            let is_u_consistent = (solver.borrow_u() - direct_solver.borrow_u())
+                .iter()
+                .all(|u| u.abs() < 1e-7);
+            assert!(is_u_consistent);
+        }
+    }
+
+    #[test]
+    fn fn_direct_exec_works_with_all_neumann_boundary_conditions() {
+        // setup u = j^2 + k^2 with the fluxes at all the edges and the source f = 4,
+        // which satisfy the compatibility condition and determine u up to a constant
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let new_params = DirectSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                y_minus: BoundaryCondition::Neumann(0.0),
+                y_plus: BoundaryCondition::Neumann(12.0),
+            },
+            obstacle: None,
+        };
+        let mut solver = DirectSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is the exact solution with the value fixed at the corner
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+}
@@ -0,0 +1,569 @@
+//! Solver for the diffusion equation running the point Jacobi or red-black SOR sweeps on the GPU.
+//!
+//! # Scheme
+//! The sweeps are executed by a compute shader of wgpu in `f32`,
+//! since the double precision is not generally available in the GPU shaders,
+//! while the solution is refined in `f64` on the host by the iterative refinement.
+//! Each iteration computes the residual `r = f - \nabla^2 u - k^2 u` of the current `u` in `f64`,
+//! solves the error equation
+//! ```math
+//! \nabla^2 e + k^2 e = r
+//! ```
+//! for the correction `e` from `e = 0` by `n_sweeps` sweeps on the GPU
+//! with homogeneous boundary conditions, and updates `u \leftarrow u + e` in `f64`.
+//! The sweep is either that of [super::point_jacobi_solver] or that of [super::red_black_sor_solver],
+//! see [Sweep].
+//!
+//! Since the error of `u` is reduced by the sweeps at each iteration, down to the rounding errors of `f32` relative to `e`,
+//! the iteration converges to the solution in `f64` as the CPU solvers do,
+//! with the same criterion `\|e\|_\infty \le 10^{-10}` on the correction.
+//! An iteration costs the transfers of `r` and `e` between the host and the GPU,
+//! which pay off with enough sweeps on large grids, e.g. `2049 \times 2049` points,
+//! see `benches/gpu_sweeps.rs`.
+//!
+//! # Boundary Condition
+//! The boundary conditions are given by [BoundaryConditions] as for the CPU solvers.
+//! The coefficients of the neighbors of each point are computed on the host,
+//! including the ghost points of the flux boundary conditions,
+//! and the fixed points and the obstacles have the zero correction.
+//!
+//! # Feature
+//! This module requires the `gpu` feature.
+//! [GpuSolver::new] returns an error if no GPU adapter is available.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::relaxation_parameter::{self, Estimation, RelaxationParameter};
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use numerics::norms;
+use std::error::Error;
+use std::sync::mpsc;
+use wgpu::util::DeviceExt;
+
+/// Error returned by [GpuSolver::new] if no GPU adapter is available.
+pub const NO_ADAPTER: &str = "no GPU adapter is available";
+
+/// Size of the workgroups of the shader in each direction.
+const WORKGROUP_SIZE: u32 = 16;
+
+/// Sweep executed on the GPU.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sweep {
+    /// Point Jacobi sweep, see [super::point_jacobi_solver].
+    PointJacobi,
+    /// Red-black SOR sweep with the relaxation parameter, see [super::red_black_sor_solver],
+    /// which must be fixed or [Estimation::Optimal].
+    RedBlackSor(RelaxationParameter),
+}
+
+/// Solver for the diffusion equation running the point Jacobi or red-black SOR sweeps on the GPU.
+#[derive(Debug)]
+pub struct GpuSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
+    n_iter_max: usize,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    convergence: Convergence,
+    omega: f64,
+    n_passes: usize,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_groups: [wgpu::BindGroup; 2],
+    residual_buffer: wgpu::Buffer,
+    e_buffers: [wgpu::Buffer; 2],
+    readback_buffer: wgpu::Buffer,
+}
+
+impl GpuSolver {
+    /// Create a new `GpuSolver` instance.
+    ///
+    /// # Errors
+    /// Returns [NO_ADAPTER] if no GPU adapter is available, besides the invalid parameters.
+    pub fn new(new_params: GpuSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let omega = match new_params.sweep {
+            Sweep::PointJacobi => 1.0,
+            Sweep::RedBlackSor(RelaxationParameter::Fixed(omega)) => omega,
+            Sweep::RedBlackSor(RelaxationParameter::Estimated(_)) => {
+                relaxation_parameter::optimal_omega(
+                    new_params
+                        .boundary_conditions
+                        .jacobi_spectral_radius(&new_params.grid, new_params.k_squared),
+                )
+            }
+        };
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+
+        // setup the coefficients of the error equation, which are zero at the fixed points
+        let mut coefs = vec![[0.0f32; 4]; u.len()];
+        let mut inv_diag = vec![0.0f32; u.len()];
+        for (i, ((i_x, i_y), &is_fixed)) in fixed.indexed_iter().enumerate() {
+            if is_fixed {
+                continue;
+            }
+
+            let stencil = new_params
+                .boundary_conditions
+                .stencil(&u, i_x, i_y, &new_params.grid);
+            coefs[i] = [
+                stencil.coefs_x.0 as f32,
+                stencil.coefs_x.1 as f32,
+                stencil.coefs_y.0 as f32,
+                stencil.coefs_y.1 as f32,
+            ];
+            inv_diag[i] = (1.0 / (stencil.diag - new_params.k_squared)) as f32;
+        }
+
+        // setup the device and the buffers
+        let instance = wgpu::Instance::default();
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .map_err(|_| NO_ADAPTER)?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .map_err(|_| "GPU device cannot be created")?;
+        let create_storage_buffer = |contents: &[u8]| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+            })
+        };
+        let coefs_buffer = create_storage_buffer(bytemuck::cast_slice(&coefs));
+        let inv_diag_buffer = create_storage_buffer(bytemuck::cast_slice(&inv_diag));
+        let zeros = vec![0.0f32; u.len()];
+        let residual_buffer = create_storage_buffer(bytemuck::cast_slice(&zeros));
+        let e_buffers = [
+            create_storage_buffer(bytemuck::cast_slice(&zeros)),
+            create_storage_buffer(bytemuck::cast_slice(&zeros)),
+        ];
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (u.len() * std::mem::size_of::<f32>()) as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // the point Jacobi sweep relaxes all the points by one pass,
+        // and the red-black SOR sweep the red and black points by two passes
+        let (n_x, n_y) = u.dim();
+        let colors = match new_params.sweep {
+            Sweep::PointJacobi => [2, 2],
+            Sweep::RedBlackSor(_) => [0, 1],
+        };
+        let params_buffers = colors.map(|color| {
+            let params = [n_x as u32, n_y as u32, color, (omega as f32).to_bits()];
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: bytemuck::cast_slice(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            })
+        });
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(include_str!("gpu_solver.wgsl").into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: None,
+            layout: None,
+            module: &module,
+            entry_point: Some("sweep"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        // each pass reads the correction from one of the buffers and writes it to the other
+        let bind_groups = [0, 1].map(|pass| {
+            let buffers = [
+                &params_buffers[pass],
+                &coefs_buffer,
+                &inv_diag_buffer,
+                &residual_buffer,
+                &e_buffers[pass],
+                &e_buffers[1 - pass],
+            ];
+            let entries: Vec<_> = buffers
+                .iter()
+                .enumerate()
+                .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                })
+                .collect();
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &pipeline.get_bind_group_layout(0),
+                entries: &entries,
+            })
+        });
+
+        Ok(Self {
+            u,
+            grid: new_params.grid,
+            source,
+            n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            omega,
+            n_passes: match new_params.sweep {
+                Sweep::PointJacobi => new_params.n_sweeps,
+                Sweep::RedBlackSor(_) => 2 * new_params.n_sweeps,
+            },
+            residual_history: vec![residual_norm],
+            epsilon: 1.0e-10,
+            n_iter: 0,
+            executed: false,
+            converged: false,
+            device,
+            queue,
+            pipeline,
+            bind_groups,
+            residual_buffer,
+            e_buffers,
+            readback_buffer,
+        })
+    }
+
+    /// Return the relaxation parameter, which is `1` for the point Jacobi sweep.
+    pub fn get_omega(&self) -> f64 {
+        self.omega
+    }
+
+    fn iterate(&mut self) -> Result<(), Box<dyn Error>> {
+        let residual = self.boundary_conditions.residual(
+            &self.u,
+            &self.grid,
+            &self.source,
+            self.k_squared,
+            &self.fixed,
+        );
+        let e = self.solve_error_equation(&residual)?;
+
+        self.converged = norms::linf(&e) <= self.epsilon;
+        self.u += &e;
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
+        self.n_iter += 1;
+
+        Ok(())
+    }
+
+    /// Return the correction `e` given by the sweeps on the GPU for the `residual`.
+    fn solve_error_equation(&self, residual: &Array2<f64>) -> Result<Array2<f64>, Box<dyn Error>> {
+        let residual: Vec<f32> = residual.iter().map(|&r| r as f32).collect();
+        self.queue
+            .write_buffer(&self.residual_buffer, 0, bytemuck::cast_slice(&residual));
+
+        let (n_x, n_y) = self.u.dim();
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.clear_buffer(&self.e_buffers[0], 0, None);
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: None,
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            for i_pass in 0..self.n_passes {
+                pass.set_bind_group(0, &self.bind_groups[i_pass % 2], &[]);
+                pass.dispatch_workgroups(
+                    (n_x as u32).div_ceil(WORKGROUP_SIZE),
+                    (n_y as u32).div_ceil(WORKGROUP_SIZE),
+                    1,
+                );
+            }
+        }
+        encoder.copy_buffer_to_buffer(
+            &self.e_buffers[self.n_passes % 2],
+            0,
+            &self.readback_buffer,
+            0,
+            self.readback_buffer.size(),
+        );
+        self.queue.submit([encoder.finish()]);
+
+        // wait for the sweeps and read the correction back
+        let (sender, receiver) = mpsc::channel();
+        self.readback_buffer
+            .map_async(wgpu::MapMode::Read, .., move |result| {
+                let _ = sender.send(result);
+            });
+        self.device.poll(wgpu::PollType::wait_indefinitely())?;
+        receiver.recv()??;
+        let e = {
+            let view = self.readback_buffer.get_mapped_range(..);
+            let e: &[f32] = bytemuck::cast_slice(&view);
+            Array::from_shape_fn((n_x, n_y), |(i_x, i_y)| e[i_x * n_y + i_y] as f64)
+        };
+        self.readback_buffer.unmap();
+
+        Ok(e)
+    }
+}
+
+impl Solver for GpuSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for GpuSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate()
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Parameters for creating a new `GpuSolver` instance.
+pub struct GpuSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Maximum number of iterations, each of which consists of `n_sweeps` sweeps.
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Sweep executed on the GPU.
+    pub sweep: Sweep,
+    /// Number of the sweeps on the GPU per iteration.
+    pub n_sweeps: usize,
+}
+
+impl NewParams for GpuSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+        if let Sweep::RedBlackSor(omega) = self.sweep {
+            omega.validate()?;
+            if omega == RelaxationParameter::Estimated(Estimation::Adaptive) {
+                return Err("omega must be fixed or optimal on the GPU");
+            }
+        }
+        if self.n_sweeps == 0 {
+            return Err("n_sweeps must be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::red_black_sor_solver::{RedBlackSorSolver, RedBlackSorSolverNewParams};
+
+    #[test]
+    fn fn_gpu_shader_is_valid() {
+        let module = wgpu::naga::front::wgsl::parse_str(include_str!("gpu_solver.wgsl")).unwrap();
+        let mut validator = wgpu::naga::valid::Validator::new(
+            wgpu::naga::valid::ValidationFlags::all(),
+            wgpu::naga::valid::Capabilities::empty(),
+        );
+
+        assert!(validator.validate(&module).is_ok());
+    }
+
+    #[test]
+    fn fn_gpu_new_rejects_adaptive_omega() {
+        let new_params = GpuSolverNewParams {
+            u_init: Array::zeros((5, 5)),
+            grid: Grid::uniform((5, 5), (1.0, 1.0)).unwrap(),
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            sweep: Sweep::RedBlackSor(RelaxationParameter::Estimated(Estimation::Adaptive)),
+            n_sweeps: 10,
+        };
+
+        assert_eq!(
+            GpuSolver::new(new_params).unwrap_err(),
+            "omega must be fixed or optimal on the GPU"
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter, run with `cargo test --features gpu -- --ignored`"]
+    fn fn_gpu_exec_agrees_with_red_black_sor() {
+        // setup the problem with the Neumann boundary condition at x_+, the obstacle and the source
+        // and solve it by the red-black SOR method on the GPU and the CPU
+        let mut u_init: Array2<f64> = Array::zeros((17, 17));
+        u_init.slice_mut(s![.., 16]).fill(1.0);
+        u_init[[8, 8]] = 0.5;
+        let mut obstacle = Array::from_elem((17, 17), false);
+        obstacle[[8, 8]] = true;
+        let boundary_conditions = BoundaryConditions {
+            x_plus: BoundaryCondition::Neumann(0.5),
+            ..Default::default()
+        };
+        let source = Array::from_shape_fn((17, 17), |(i_x, i_y)| (i_x + i_y) as f64 * 1e-2);
+        let omega = 1.7;
+        let mut gpu_solver = GpuSolver::new(GpuSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(source.clone()),
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: Some(obstacle.clone()),
+            sweep: Sweep::RedBlackSor(RelaxationParameter::Fixed(omega)),
+            n_sweeps: 20,
+        })
+        .unwrap();
+        gpu_solver.exec().unwrap();
+        let mut red_black_sor_solver = RedBlackSorSolver::new(RedBlackSorSolverNewParams {
+            u_init,
+            grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(source),
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: Some(obstacle),
+            omega,
+        })
+        .unwrap();
+        red_black_sor_solver.exec().unwrap();
+
+        // check if the solutions agree in f64 despite the sweeps in f32
+        let is_u_consistent = (gpu_solver.borrow_u() - red_black_sor_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_consistent);
+        assert_eq!(
+            gpu_solver.borrow_residual_history().len(),
+            gpu_solver.get_n_iter() + 1
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// Sweep of the error equation of the GPU solver, see gpu_solver.rs.
+//
+// Each invocation relaxes the point (i_x, i_y) of the correction e from e_src to e_dst,
+// or copies it if the point is not of the color to be relaxed.
+// The points are stored in the row-major order of ndarray, i = i_x * n_y + i_y.
+
+struct Params {
+    n_x: u32,
+    n_y: u32,
+    // color (i_x + i_y) % 2 of the points to be relaxed, or 2 for all the points
+    color: u32,
+    omega: f32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+// coefficients of the neighbors (i_x - 1, i_x + 1, i_y - 1, i_y + 1), which are zero outside the domain
+@group(0) @binding(1) var<storage, read> coefs: array<vec4<f32>>;
+// inverse of the diagonal coefficient d - k^2, which is zero at the fixed points
+@group(0) @binding(2) var<storage, read> inv_diag: array<f32>;
+@group(0) @binding(3) var<storage, read> residual: array<f32>;
+@group(0) @binding(4) var<storage, read> e_src: array<f32>;
+@group(0) @binding(5) var<storage, read_write> e_dst: array<f32>;
+
+@compute @workgroup_size(16, 16)
+fn sweep(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i_x = id.x;
+    let i_y = id.y;
+    if (i_x >= params.n_x || i_y >= params.n_y) {
+        return;
+    }
+
+    let i = i_x * params.n_y + i_y;
+    let e_c = e_src[i];
+    if (params.color != 2u && (i_x + i_y) % 2u != params.color) {
+        e_dst[i] = e_c;
+        return;
+    }
+
+    // the neighbors outside the domain are replaced with the point itself, whose coefficient is zero
+    let c = coefs[i];
+    let minus_x = select(i, i - params.n_y, i_x > 0u);
+    let plus_x = select(i, i + params.n_y, i_x + 1u < params.n_x);
+    let minus_y = select(i, i - 1u, i_y > 0u);
+    let plus_y = select(i, i + 1u, i_y + 1u < params.n_y);
+    let neighbors = c.x * e_src[minus_x] + c.y * e_src[plus_x] + c.z * e_src[minus_y] + c.w * e_src[plus_y];
+    e_dst[i] = (1.0 - params.omega) * e_c + params.omega * (neighbors - residual[i]) * inv_diag[i];
+}
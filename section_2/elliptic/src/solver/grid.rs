@@ -0,0 +1,242 @@
+//! Grid on which the solvers discretize the equation.
+//!
+//! The grid is rectilinear, given by the coordinates of the grid points in x and y directions,
+//! which may be non-uniform, e.g. clustered near the boundary to resolve the boundary layer.
+//! On the non-uniform grid, the second derivative at the point `\xi_i` is discretized as
+//! ```math
+//! \frac{\partial^2 u}{\partial \xi^2}(\xi_i) \approx \frac{2}{h_- + h_+} \left(\frac{u_{i+1} - u_i}{h_+} - \frac{u_i - u_{i-1}}{h_-}\right),
+//! ```
+//! where `h_- = \xi_i - \xi_{i-1}` and `h_+ = \xi_{i+1} - \xi_i`,
+//! which is reduced to the central difference on the uniform grid.
+//!
+//! The grid may carry the anisotropy coefficient `\epsilon` of the equation
+//! ```math
+//! \epsilon \frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2} + k^2 u = f,
+//! ```
+//! which multiplies the coefficients of the second difference in x direction, see [Grid::with_anisotropy].
+//! Every solver discretizing the equation by these second differences solves the anisotropic equation.
+
+use ndarray::prelude::*;
+
+/// Rectilinear grid given by the coordinates of the grid points in x and y directions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    x: Array1<f64>,
+    y: Array1<f64>,
+    anisotropy: f64,
+}
+
+impl Grid {
+    /// Create a new `Grid` instance with the coordinates `x` and `y`, which must be strictly increasing.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::solver::grid::Grid;
+    ///
+    /// let grid = Grid::new(array![0.0, 0.1, 0.3, 0.7], array![0.0, 1.0]).unwrap();
+    /// assert_eq!(grid.dim(), (4, 2));
+    ///
+    /// assert!(Grid::new(array![0.0, 0.2, 0.1], array![0.0, 1.0]).is_err());
+    /// ```
+    pub fn new(x: Array1<f64>, y: Array1<f64>) -> Result<Self, &'static str> {
+        let is_increasing = |coords: &Array1<f64>| {
+            coords.iter().all(|xi| xi.is_finite())
+                && coords.windows(2).into_iter().all(|w| w[0] < w[1])
+        };
+        if !is_increasing(&x) {
+            return Err("x must be strictly increasing");
+        }
+        if !is_increasing(&y) {
+            return Err("y must be strictly increasing");
+        }
+
+        Ok(Self {
+            x,
+            y,
+            anisotropy: 1.0,
+        })
+    }
+
+    /// Return the grid with the anisotropy coefficient `\epsilon` of `\epsilon u_{xx} + u_{yy}`, which is `1` by default.
+    ///
+    /// For `\epsilon \ll 1`, the coupling in y direction is much stronger than that in x direction,
+    /// for which the point relaxations smooth the error only in x direction, see [super::multigrid_solver].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::solver::grid::Grid;
+    ///
+    /// let grid = Grid::uniform((5, 5), (0.25, 0.25)).unwrap().with_anisotropy(0.01).unwrap();
+    /// assert_eq!(grid.anisotropy(), 0.01);
+    ///
+    /// assert!(Grid::uniform((5, 5), (0.25, 0.25)).unwrap().with_anisotropy(0.0).is_err());
+    /// ```
+    pub fn with_anisotropy(mut self, anisotropy: f64) -> Result<Self, &'static str> {
+        if !(anisotropy > 0.0 && anisotropy.is_finite()) {
+            return Err("anisotropy must be positive and finite");
+        }
+        self.anisotropy = anisotropy;
+
+        Ok(self)
+    }
+
+    /// Create a new uniform `Grid` instance with `dim` points and the grid spacings `spacing`,
+    /// whose coordinates are `x_j = j \Delta x` and `y_k = k \Delta y`.
+    pub fn uniform(dim: (usize, usize), spacing: (f64, f64)) -> Result<Self, &'static str> {
+        if spacing.0 <= 0.0 {
+            return Err("dx must be positive");
+        }
+        if spacing.1 <= 0.0 {
+            return Err("dy must be positive");
+        }
+
+        Self::new(
+            Array::from_shape_fn(dim.0, |i_x| i_x as f64 * spacing.0),
+            Array::from_shape_fn(dim.1, |i_y| i_y as f64 * spacing.1),
+        )
+    }
+
+    /// Create a new `Grid` instance in the polar coordinates `(r, \theta)`,
+    /// whose x coordinates are the radii `r` and y coordinates are the `n_theta` angles `\theta_k = 2 \pi k / N_\theta`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::solver::grid::Grid;
+    ///
+    /// let grid = Grid::polar(array![0.0, 0.5, 1.0], 4).unwrap();
+    /// assert!((grid.borrow_y()[1] - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn polar(r: Array1<f64>, n_theta: usize) -> Result<Self, &'static str> {
+        if r.iter().any(|&r| r < 0.0) {
+            return Err("r must be non-negative");
+        }
+
+        Self::new(
+            r,
+            Array::from_shape_fn(n_theta, |i_theta| {
+                2.0 * std::f64::consts::PI * i_theta as f64 / n_theta as f64
+            }),
+        )
+    }
+
+    /// Return the numbers of the grid points in x and y directions.
+    pub fn dim(&self) -> (usize, usize) {
+        (self.x.len(), self.y.len())
+    }
+
+    /// Return the smallest grid spacings in x and y directions, which are infinite for a single point.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::solver::grid::Grid;
+    ///
+    /// let grid = Grid::new(array![0.0, 0.1, 0.3, 0.7], array![0.0, 1.0]).unwrap();
+    /// let (dx_min, dy_min) = grid.min_spacing();
+    ///
+    /// assert!((dx_min - 0.1).abs() < 1e-12);
+    /// assert_eq!(dy_min, 1.0);
+    /// ```
+    pub fn min_spacing(&self) -> (f64, f64) {
+        let min_spacing = |coords: &Array1<f64>| {
+            coords
+                .windows(2)
+                .into_iter()
+                .fold(f64::INFINITY, |acc, w| acc.min(w[1] - w[0]))
+        };
+
+        (min_spacing(&self.x), min_spacing(&self.y))
+    }
+
+    /// Return a reference to the coordinates in x direction.
+    pub fn borrow_x(&self) -> &Array1<f64> {
+        &self.x
+    }
+
+    /// Return a reference to the coordinates in y direction.
+    pub fn borrow_y(&self) -> &Array1<f64> {
+        &self.y
+    }
+
+    /// Return the anisotropy coefficient `\epsilon`, see [Grid::with_anisotropy].
+    pub fn anisotropy(&self) -> f64 {
+        self.anisotropy
+    }
+
+    /// Return the grid of the points selected by `step` in x and y directions, e.g. `(2, 1)` for coarsening x direction,
+    /// with the same anisotropy coefficient, or `None` if the selected points don't include the last ones.
+    pub(crate) fn coarsen(&self, step: (usize, usize)) -> Option<Self> {
+        let select = |coords: &Array1<f64>, step: usize| {
+            (coords.len().saturating_sub(1).is_multiple_of(step))
+                .then(|| coords.slice(s![..;step]).to_owned())
+        };
+
+        Some(Self {
+            x: select(&self.x, step.0)?,
+            y: select(&self.y, step.1)?,
+            anisotropy: self.anisotropy,
+        })
+    }
+
+    /// Return the second difference at the point `i_x` in x direction multiplied by the anisotropy coefficient.
+    pub(crate) fn second_difference_x(&self, i_x: usize) -> SecondDifference {
+        let mut second_difference = SecondDifference::new(&self.x, i_x);
+        second_difference.coef_minus *= self.anisotropy;
+        second_difference.coef_plus *= self.anisotropy;
+
+        second_difference
+    }
+
+    /// Return the second difference at the point `i_y` in y direction.
+    pub(crate) fn second_difference_y(&self, i_y: usize) -> SecondDifference {
+        SecondDifference::new(&self.y, i_y)
+    }
+}
+
+/// Coefficients of the second difference at a point in one direction,
+/// `u_{\xi\xi} \approx c_- (u_{i-1} - u_i) + c_+ (u_{i+1} - u_i)`.
+///
+/// At the edges, the spacing to the ghost point outside the domain mirrors that to the inner point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SecondDifference {
+    /// Spacing `h_-` to the point `i - 1`.
+    pub(crate) h_minus: f64,
+    /// Spacing `h_+` to the point `i + 1`.
+    pub(crate) h_plus: f64,
+    /// Coefficient `c_- = 2 / (h_- (h_- + h_+))` of `u_{i-1}`.
+    pub(crate) coef_minus: f64,
+    /// Coefficient `c_+ = 2 / (h_+ (h_- + h_+))` of `u_{i+1}`.
+    pub(crate) coef_plus: f64,
+    /// Width `(h_- + h_+) / 2` of the cell around the point divided by the mean spacing,
+    /// which makes the second difference symmetric when multiplied and is `1` on the uniform grid.
+    pub(crate) width: f64,
+}
+
+impl SecondDifference {
+    fn new(coords: &Array1<f64>, i: usize) -> Self {
+        let n = coords.len();
+        let h_minus = (i > 0).then(|| coords[i] - coords[i - 1]);
+        let h_plus = (i + 1 < n).then(|| coords[i + 1] - coords[i]);
+        let (h_minus, h_plus) = match (h_minus, h_plus) {
+            (Some(h_minus), Some(h_plus)) => (h_minus, h_plus),
+            (None, Some(h)) | (Some(h), None) => (h, h),
+            (None, None) => (1.0, 1.0),
+        };
+        let h_mean = if n < 2 {
+            1.0
+        } else {
+            (coords[n - 1] - coords[0]) / (n - 1) as f64
+        };
+
+        Self {
+            h_minus,
+            h_plus,
+            coef_minus: 2.0 / (h_minus * (h_minus + h_plus)),
+            coef_plus: 2.0 / (h_plus * (h_minus + h_plus)),
+            width: 0.5 * (h_minus + h_plus) / h_mean,
+        }
+    }
+}
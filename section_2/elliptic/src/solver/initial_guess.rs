@@ -0,0 +1,401 @@
+//! Module to construct the initial guess of the interior values.
+//!
+//! The relaxation methods converge faster when the initial guess of the interior values is closer to the solution.
+//! This module provides several strategies to construct the initial guess from the boundary values
+//! and the [Problem] to be solved.
+//! See [InitialGuess].
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::multigrid_solver::{MultigridSolver, MultigridSolverNewParams};
+use super::{RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use numerics::grid_transfer;
+use serde_derive::{Deserialize, Serialize};
+
+/// Number of the V-cycles correcting the guess of [InitialGuess::CoarseGrid] on each grid.
+const N_CYCLES_COARSE_GRID: usize = 3;
+
+/// Strategies to construct the initial guess of the interior values, [InitialGuess::Zero] by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InitialGuess {
+    /// All the interior values are set to zero.
+    #[default]
+    Zero,
+    /// The interior values are interpolated linearly from the boundary values in each direction and averaged,
+    /// ```math
+    /// u_{j,k} = \frac{1}{2} ((1 - \xi) u_{0,k} + \xi u_{N,k} + (1 - \eta) u_{j,0} + \eta u_{j,M}),
+    /// ```
+    /// where `\xi = j / N` and `\eta = k / M`.
+    ///
+    /// The interpolation is exact if the solution is bilinear in `x` and `y`.
+    Bilinear,
+    /// The problem is solved by the full multigrid method from the coarsest grid, that is,
+    /// the problem is restricted to the grid coarsened by a factor of 2,
+    /// whose coordinates, fixed values and obstacles are injected and whose source is restricted by the full weighting
+    /// of [numerics::grid_transfer], and the guess on the coarse grid constructed in the same way
+    /// is prolonged to the unknown points by the bilinear interpolation of [numerics::grid_transfer].
+    /// On each grid, including the original one, the prolonged guess is corrected by 3 V-cycles of
+    /// [super::multigrid_solver] solving its error equation,
+    /// which reduce the error of the smooth components left by the discretization on the coarse grid.
+    ///
+    /// When the number of cells is odd or too small to be coarsened, [InitialGuess::Bilinear] is used instead
+    /// before the correction.
+    /// The V-cycles cost about 30 sweeps on the original grid, which are not counted as iterations.
+    CoarseGrid,
+}
+
+/// Discrete problem `\nabla^2 u + k^2 u = f` of the solvers, for which the initial guess is constructed.
+#[derive(Debug, Clone, Copy)]
+pub struct Problem<'a> {
+    /// Grid with the same shape as `u`.
+    pub grid: &'a Grid,
+    /// Source term `f` at each point, or `None` for the Laplace's equation.
+    pub source: Option<&'a Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<&'a Array2<bool>>,
+}
+
+impl InitialGuess {
+    /// Overwrite the unknown values of `u` of the `problem` with the initial guess.
+    ///
+    /// The fixed edge values and the values of the obstacles of `u` are left unchanged,
+    /// as well as the edge values with the flux boundary conditions by [InitialGuess::Zero] and [InitialGuess::Bilinear].
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use elliptic::solver::grid::Grid;
+    /// use elliptic::solver::initial_guess::{InitialGuess, Problem};
+    ///
+    /// let mut u = array![[0.0, 0.0, 1.0], [0.0, 5.0, 1.0], [0.0, 0.0, 1.0]];
+    /// let grid = Grid::uniform(u.dim(), (1.0, 1.0)).unwrap();
+    /// let problem = Problem {
+    ///     grid: &grid,
+    ///     source: None,
+    ///     k_squared: 0.0,
+    ///     boundary_conditions: Default::default(),
+    ///     obstacle: None,
+    /// };
+    /// InitialGuess::Bilinear.apply(&mut u, &problem);
+    ///
+    /// assert!((u[[1, 1]] - 0.25).abs() < 1e-10);
+    /// ```
+    pub fn apply(&self, u: &mut Array2<f64>, problem: &Problem) {
+        let mut u_guess = u.clone();
+        match self {
+            InitialGuess::Zero => Self::apply_zero(&mut u_guess),
+            InitialGuess::Bilinear => Self::apply_bilinear(&mut u_guess),
+            InitialGuess::CoarseGrid => Self::apply_coarse_grid(&mut u_guess, problem),
+        }
+
+        let fixed = problem
+            .boundary_conditions
+            .fixed_mask(u.dim(), problem.obstacle);
+        Zip::from(u)
+            .and(&u_guess)
+            .and(&fixed)
+            .for_each(|u, &u_guess, &is_fixed| {
+                if !is_fixed {
+                    *u = u_guess;
+                }
+            });
+    }
+
+    fn apply_zero(u: &mut Array2<f64>) {
+        let (n_x, n_y) = u.dim();
+        if n_x < 3 || n_y < 3 {
+            return;
+        }
+
+        u.slice_mut(s![1..n_x - 1, 1..n_y - 1]).fill(0.0);
+    }
+
+    fn apply_bilinear(u: &mut Array2<f64>) {
+        let (n_x, n_y) = u.dim();
+        if n_x < 3 || n_y < 3 {
+            return;
+        }
+
+        let (i_x_max, i_y_max) = (n_x - 1, n_y - 1);
+        for i_x in 1..i_x_max {
+            for i_y in 1..i_y_max {
+                let xi = i_x as f64 / i_x_max as f64;
+                let eta = i_y as f64 / i_y_max as f64;
+
+                u[[i_x, i_y]] = 0.5
+                    * ((1.0 - xi) * u[[0, i_y]]
+                        + xi * u[[i_x_max, i_y]]
+                        + (1.0 - eta) * u[[i_x, 0]]
+                        + eta * u[[i_x, i_y_max]]);
+            }
+        }
+    }
+
+    fn apply_coarse_grid(u: &mut Array2<f64>, problem: &Problem) {
+        let (n_x, n_y) = u.dim();
+        let is_coarsenable = |n: usize| n >= 5 && (n - 1).is_multiple_of(2);
+        let grid_coarse = Some(problem.grid)
+            .filter(|_| is_coarsenable(n_x) && is_coarsenable(n_y))
+            .and_then(|grid| grid.coarsen((2, 2)));
+        match grid_coarse {
+            Some(grid_coarse) => Self::prolong_coarse_grid_guess(u, problem, &grid_coarse),
+            None => Self::apply_bilinear(u),
+        }
+
+        problem.boundary_conditions.apply_fixed(u);
+        Self::correct_by_v_cycles(u, problem);
+    }
+
+    /// Overwrite the unknown values of `u` with the guess of [InitialGuess::CoarseGrid]
+    /// constructed on the `grid_coarse` and prolonged.
+    fn prolong_coarse_grid_guess(u: &mut Array2<f64>, problem: &Problem, grid_coarse: &Grid) {
+        // restrict the problem to the coarse grid and construct the guess there from the further coarsened grid
+        let source_coarse = problem
+            .source
+            .and_then(|source| grid_transfer::restrict_2d(source).ok());
+        let obstacle_coarse = problem
+            .obstacle
+            .map(|obstacle| obstacle.slice(s![..;2, ..;2]).to_owned());
+        let problem_coarse = Problem {
+            grid: grid_coarse,
+            source: source_coarse.as_ref(),
+            obstacle: obstacle_coarse.as_ref(),
+            ..*problem
+        };
+        let mut u_coarse = u.slice(s![..;2, ..;2]).to_owned();
+        problem.boundary_conditions.apply_fixed(&mut u_coarse);
+        InitialGuess::CoarseGrid.apply(&mut u_coarse, &problem_coarse);
+
+        // prolong the coarse grid guess only to the unknown points by the bilinear interpolation,
+        // since the interpolated fixed values may differ from the given ones
+        if let Ok(u_fine) = grid_transfer::prolong_2d(&u_coarse) {
+            let fixed = problem
+                .boundary_conditions
+                .fixed_mask(u.dim(), problem.obstacle);
+            Zip::from(&mut *u)
+                .and(&u_fine)
+                .and(&fixed)
+                .for_each(|u, &u_fine, &is_fixed| {
+                    if !is_fixed {
+                        *u = u_fine;
+                    }
+                });
+        }
+    }
+
+    /// Correct `u` by [N_CYCLES_COARSE_GRID] V-cycles of [MultigridSolver] solving the error equation of the `problem`,
+    /// or leave it as it is if the error equation is rejected by the solver.
+    fn correct_by_v_cycles(u: &mut Array2<f64>, problem: &Problem) {
+        let fixed = problem
+            .boundary_conditions
+            .fixed_mask(u.dim(), problem.obstacle);
+        let source = problem
+            .source
+            .cloned()
+            .unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual = problem.boundary_conditions.residual(
+            u,
+            problem.grid,
+            &source,
+            problem.k_squared,
+            &fixed,
+        );
+        let Ok(mut multigrid_solver) = MultigridSolver::new(MultigridSolverNewParams {
+            u_init: Array::zeros(u.dim()),
+            grid: problem.grid.clone(),
+            n_iter_max: N_CYCLES_COARSE_GRID,
+            initial_guess: InitialGuess::Zero,
+            source: Some(residual),
+            k_squared: problem.k_squared,
+            boundary_conditions: problem.boundary_conditions.homogeneous(),
+            obstacle: problem.obstacle.cloned(),
+            coarsening: Default::default(),
+            smoother: Default::default(),
+        }) else {
+            return;
+        };
+        for _ in 0..N_CYCLES_COARSE_GRID {
+            if multigrid_solver.step().is_err() {
+                return;
+            }
+        }
+
+        Zip::from(u)
+            .and(multigrid_solver.borrow_u())
+            .and(&fixed)
+            .for_each(|u, &error, &is_fixed| {
+                if !is_fixed {
+                    *u += error;
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::point_jacobi_solver::{PointJacobiSolver, PointJacobiSolverNewParams};
+    use crate::solver::relaxation_parameter::RelaxationParameter;
+    use crate::solver::sor_solver::{SorSolver, SorSolverNewParams};
+
+    #[test]
+    fn fn_apply_keeps_boundary_values() {
+        // setup boundary values with a nonzero interior
+        let u_init = array![
+            [0.0, 0.0, 0.0, 0.0, 1.0],
+            [0.0, 3.0, 3.0, 3.0, 1.0],
+            [0.0, 3.0, 3.0, 3.0, 1.0],
+            [0.0, 3.0, 3.0, 3.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0]
+        ];
+        let grid = Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap();
+        let problem = Problem {
+            grid: &grid,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+
+        for initial_guess in [
+            InitialGuess::Zero,
+            InitialGuess::Bilinear,
+            InitialGuess::CoarseGrid,
+        ] {
+            let mut u = u_init.clone();
+            initial_guess.apply(&mut u, &problem);
+
+            // check if the boundary values are unchanged and the interior values are overwritten
+            assert_eq!(u.row(0), u_init.row(0));
+            assert_eq!(u.row(4), u_init.row(4));
+            assert_eq!(u.column(0), u_init.column(0));
+            assert_eq!(u.column(4), u_init.column(4));
+            assert!(u.slice(s![1..4, 1..4]).iter().all(|u| *u < 1.0));
+        }
+    }
+
+    #[test]
+    fn fn_apply_coarse_grid_works() {
+        // setup boundary values of u = x y on [0, 1] x [0, 1], which is harmonic and bilinear
+        let n = 9;
+        let mut u: Array2<f64> = Array::zeros((n, n));
+        for i in 0..n {
+            let s = i as f64 / (n - 1) as f64;
+            u[[i, n - 1]] = s;
+            u[[n - 1, i]] = s;
+        }
+        let grid = Grid::uniform(u.dim(), (0.125, 0.125)).unwrap();
+        let problem = Problem {
+            grid: &grid,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+        InitialGuess::CoarseGrid.apply(&mut u, &problem);
+
+        // check if the exact solution is recovered
+        let is_correctly_guessed = u
+            .indexed_iter()
+            .all(|((i_x, i_y), u)| (u - (i_x * i_y) as f64 / 64.0).abs() < 1e-8);
+        assert!(is_correctly_guessed);
+    }
+
+    #[test]
+    fn fn_apply_coarse_grid_restricts_problem() {
+        // setup u = x^2 + y^2 on [0, 1] x [0, 1] with the source 4 and the flux 2 at x_+,
+        // and an obstacle at the center fixed to the solution
+        let n = 17;
+        let h = 1.0 / (n - 1) as f64;
+        let grid = Grid::uniform((n, n), (h, h)).unwrap();
+        let exact = Array::from_shape_fn((n, n), |(i_x, i_y)| {
+            let (x, y) = (i_x as f64 * h, i_y as f64 * h);
+            x * x + y * y
+        });
+        let mut u_init = exact.clone();
+        u_init.slice_mut(s![1.., 1..n - 1]).fill(0.0);
+        let mut obstacle = Array::from_elem((n, n), false);
+        obstacle[[8, 8]] = true;
+        u_init[[8, 8]] = 0.5;
+        let source = Array::from_elem((n, n), 4.0);
+        let problem = Problem {
+            grid: &grid,
+            source: Some(&source),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(2.0),
+                ..Default::default()
+            },
+            obstacle: Some(&obstacle),
+        };
+        let error_max = |initial_guess: InitialGuess| {
+            let mut u = u_init.clone();
+            initial_guess.apply(&mut u, &problem);
+            assert_eq!(u[[8, 8]], 0.5);
+            (&u - &exact).fold(0.0, |acc: f64, e| acc.max(e.abs()))
+        };
+
+        // check if the source, the flux and the obstacle are taken into account on the coarse grids
+        let (error_bilinear, error_coarse_grid) = (
+            error_max(InitialGuess::Bilinear),
+            error_max(InitialGuess::CoarseGrid),
+        );
+        assert!(error_coarse_grid < 0.02 * error_bilinear);
+    }
+
+    #[test]
+    fn fn_apply_coarse_grid_saves_iterations() {
+        // setup the Laplace's equation of compare_initial_guesses_for_laplace_eq with u = 1 at y_+
+        let n = 33;
+        let mut u_init: Array2<f64> = Array::zeros((n, n));
+        u_init.slice_mut(s![.., n - 1]).fill(1.0);
+        let grid = Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap();
+        let n_iter = |initial_guess: InitialGuess| {
+            let mut point_jacobi_solver = PointJacobiSolver::new(PointJacobiSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max: 10000,
+                initial_guess,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+            })
+            .unwrap();
+            point_jacobi_solver.exec().unwrap();
+
+            let mut sor_solver = SorSolver::new(SorSolverNewParams {
+                u_init: u_init.clone(),
+                grid: grid.clone(),
+                n_iter_max: 10000,
+                initial_guess,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+                omega: RelaxationParameter::Fixed(1.5),
+            })
+            .unwrap();
+            sor_solver.exec().unwrap();
+
+            (point_jacobi_solver.get_n_iter(), sor_solver.get_n_iter())
+        };
+
+        // check if the coarse grid guess needs fewer iterations than the others with each solver
+        let (n_iter_zero, n_iter_bilinear, n_iter_coarse_grid) = (
+            n_iter(InitialGuess::Zero),
+            n_iter(InitialGuess::Bilinear),
+            n_iter(InitialGuess::CoarseGrid),
+        );
+        assert!(n_iter_coarse_grid.0 < n_iter_zero.0.min(n_iter_bilinear.0));
+        assert!(n_iter_coarse_grid.1 < n_iter_zero.1.min(n_iter_bilinear.1));
+    }
+}
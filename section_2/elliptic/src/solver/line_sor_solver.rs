@@ -0,0 +1,757 @@
+//! Solver for the diffusion equation using the line SOR method.
+//!
+//! # Scheme
+//! The line SOR method solves the values on each line `x = x_j` simultaneously by the trinomial equation
+//! ```math
+//! -\frac{\tilde{u}_{j,k-1} + \tilde{u}_{j,k+1}}{\Delta y^2} + (d - k^2) \tilde{u}_{j,k}
+//!     = \frac{u_{j-1,k}^{n+1} + u_{j+1,k}^n}{\Delta x^2} - f_{j,k},
+//! ```
+//! where `d = 2 / \Delta x^2 + 2 / \Delta y^2` is the diagonal coefficient of the 5-point Laplacian
+//! with the grid spacings `\Delta x` and `\Delta y`,
+//! `f` is the source term of the Poisson's equation `\nabla^2 u = f`, which is zero for the Laplace's equation,
+//! and `k^2` is the coefficient of the Helmholtz term of the Helmholtz equation `\nabla^2 u + k^2 u = f`,
+//! which is zero for the Laplace's and Poisson's equations,
+//! and relaxes them as
+//! ```math
+//! u_{j,k}^{n+1} = (1 - \omega) u_{j,k}^n + \omega \tilde{u}_{j,k},
+//! ```
+//! where `\omega \in [1, 2]` is the relaxation parameter.
+//! The lines are swept in the increasing order of `j`, so that the line `j - 1` has already been updated.
+//!
+//! Since the coupling in the `y` direction is solved exactly, the method converges in fewer iterations than
+//! [super::sor_solver] with the same `\omega`, at the cost of a trinomial solve per line.
+//! This is essential for the anisotropic equation `\epsilon u_{xx} + u_{yy} = f` with `\epsilon \ll 1`
+//! given by [Grid::with_anisotropy], whose strong coupling in the `y` direction,
+//! in which the point relaxations converge very slowly, is solved exactly.
+//! Note that the lines must lie in the direction of the strong coupling,
+//! that is, the axes should be swapped for `\epsilon \gg 1`.
+//! The line relaxation is also the smoother of [super::multigrid_solver] for such equations.
+//! The iteration diverges if `k^2` makes the operator indefinite, see [Convergence::for_helmholtz].
+//!
+//! On the non-uniform [Grid], the coefficients `1 / \Delta x^2` and `1 / \Delta y^2` of the neighbors
+//! are replaced with those of the second differences given in [super::grid], and `d` with their sum.
+//!
+//! # Boundary Condition
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
+//! ```math
+//! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
+//! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! On each line, a ghost point doubles the coupling of the edge point with its inner point in the trinomial equation.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well,
+//! which split the lines into the segments decoupled in the trinomial equations.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+use std::ops::Range;
+
+/// Solver for the diffusion equation using the line SOR method.
+#[derive(Debug)]
+pub struct LineSorSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
+    n_iter_max: usize,
+    k_squared: f64,
+    convergence: Convergence,
+    omega: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    line_relaxation: LineRelaxation,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+}
+
+impl LineSorSolver {
+    /// Create a new `LineSorSolver` instance.
+    pub fn new(new_params: LineSorSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+        let line_relaxation = LineRelaxation::new(
+            new_params.boundary_conditions,
+            new_params.k_squared,
+            &new_params.grid,
+            &fixed,
+        )?;
+
+        Ok(Self {
+            u,
+            grid: new_params.grid,
+            source,
+            n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            omega: new_params.omega,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            line_relaxation,
+            residual_history: vec![residual_norm],
+            epsilon: 1.0e-10,
+            n_iter: 0,
+            executed: false,
+            converged: false,
+        })
+    }
+
+    fn iterate(&mut self) -> Result<(), Box<dyn Error>> {
+        let change_max = self.line_relaxation.sweep(
+            &mut self.u,
+            &self.grid,
+            &self.source,
+            &self.fixed,
+            self.omega,
+        )?;
+
+        self.converged = change_max <= self.epsilon;
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
+        self.n_iter += 1;
+
+        Ok(())
+    }
+}
+
+impl Solver for LineSorSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for LineSorSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate()?;
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Parameters for creating a new `LineSorSolver` instance.
+pub struct LineSorSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Maximum number of iterations.
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Relaxation parameter.
+    pub omega: f64,
+}
+
+impl NewParams for LineSorSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+        if self.omega < 1.0 || self.omega > 2.0 {
+            return Err("omega must be between 1 and 2");
+        }
+
+        Ok(())
+    }
+}
+
+/// Relaxation of the unknown points line by line, each line `x = x_j` solved by the trinomial equation in y direction,
+/// which is shared by [LineSorSolver] and the line smoother of [super::multigrid_solver].
+#[derive(Debug)]
+pub(crate) struct LineRelaxation {
+    boundary_conditions: BoundaryConditions,
+    x_range: Range<usize>,
+    y_range: Range<usize>,
+    trinomial_eqs: Vec<TrinomialEq>,
+}
+
+impl LineRelaxation {
+    /// Create a new `LineRelaxation` instance for the equation with `k^2` on the `grid` with the fixed points `fixed`.
+    pub(crate) fn new(
+        boundary_conditions: BoundaryConditions,
+        k_squared: f64,
+        grid: &Grid,
+        fixed: &Array2<bool>,
+    ) -> Result<Self, &'static str> {
+        let (x_range, y_range) = boundary_conditions.unknown_ranges(grid.dim());
+        let trinomial_eqs = x_range
+            .clone()
+            .map(|i_x| {
+                TrinomialEq::new(Self::create_mat_coef(
+                    &boundary_conditions,
+                    k_squared,
+                    grid,
+                    fixed,
+                    i_x,
+                    y_range.clone(),
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            boundary_conditions,
+            x_range,
+            y_range,
+            trinomial_eqs,
+        })
+    }
+
+    /// Return the coefficient matrix on the line `i_x` of the points in `y_range`,
+    /// whose rows of the fixed points `fixed` are those of the identity matrix.
+    fn create_mat_coef(
+        boundary_conditions: &BoundaryConditions,
+        k_squared: f64,
+        grid: &Grid,
+        fixed: &Array2<bool>,
+        i_x: usize,
+        y_range: Range<usize>,
+    ) -> Array1<(f64, f64, f64)> {
+        let u = Array::zeros(grid.dim());
+        y_range
+            .clone()
+            .map(|i_y| {
+                if fixed[[i_x, i_y]] {
+                    return (0.0, 1.0, 0.0);
+                }
+
+                let stencil = boundary_conditions.stencil(&u, i_x, i_y, grid);
+                let (coef_lower, coef_upper) =
+                    Self::coef_offdiag(fixed, i_x, i_y, &y_range, stencil.coefs_y);
+
+                (coef_lower, stencil.diag - k_squared, coef_upper)
+            })
+            .collect()
+    }
+
+    /// Return the lower and upper coefficients of the row `(i_x, i_y)` from the coefficients `coefs_y` of the stencil,
+    /// which vanish outside `y_range` and at the fixed points `fixed`.
+    fn coef_offdiag(
+        fixed: &Array2<bool>,
+        i_x: usize,
+        i_y: usize,
+        y_range: &Range<usize>,
+        coefs_y: (f64, f64),
+    ) -> (f64, f64) {
+        let coef_lower = if i_y == y_range.start || fixed[[i_x, i_y - 1]] {
+            0.0
+        } else {
+            -coefs_y.0
+        };
+        let coef_upper = if i_y == y_range.end - 1 || fixed[[i_x, i_y + 1]] {
+            0.0
+        } else {
+            -coefs_y.1
+        };
+
+        (coef_lower, coef_upper)
+    }
+
+    /// Relax `u` in place by a sweep of the lines in the increasing order of `j` with the `source`
+    /// and the relaxation parameter `omega`, and return the maximum norm of the change, which is NaN if any change is NaN.
+    ///
+    /// Since the values on the line `j` are replaced only after its trinomial equation is solved,
+    /// the sweep is the same as that from the copy of `u`.
+    pub(crate) fn sweep(
+        &self,
+        u: &mut Array2<f64>,
+        grid: &Grid,
+        source: &Array2<f64>,
+        fixed: &Array2<bool>,
+        omega: f64,
+    ) -> Result<f64, Box<dyn Error>> {
+        let mut change_max: f64 = 0.0;
+        if self.y_range.is_empty() {
+            return Ok(change_max);
+        }
+
+        for (i_x, trinomial_eq) in self.x_range.clone().zip(&self.trinomial_eqs) {
+            // assemble the right-hand side from the stencil, cancelling the couplings on the line
+            let mut u_line: Array1<f64> = self
+                .y_range
+                .clone()
+                .map(|i_y| {
+                    if fixed[[i_x, i_y]] {
+                        return u[[i_x, i_y]];
+                    }
+
+                    let stencil = self.boundary_conditions.stencil(u, i_x, i_y, grid);
+                    let (coef_lower, coef_upper) =
+                        Self::coef_offdiag(fixed, i_x, i_y, &self.y_range, stencil.coefs_y);
+                    let mut rhs = stencil.neighbors + stencil.offset - source[[i_x, i_y]];
+                    if coef_lower != 0.0 {
+                        rhs += coef_lower * u[[i_x, i_y - 1]];
+                    }
+                    if coef_upper != 0.0 {
+                        rhs += coef_upper * u[[i_x, i_y + 1]];
+                    }
+
+                    rhs
+                })
+                .collect();
+
+            trinomial_eq.solve(&mut u_line)?;
+
+            for (i_y, u_line) in self.y_range.clone().zip(u_line.iter()) {
+                if fixed[[i_x, i_y]] {
+                    continue;
+                }
+
+                let u_prev = u[[i_x, i_y]];
+                let u_next = (1.0 - omega) * u_prev + omega * u_line;
+                let change = u_next - u_prev;
+                change_max = if change_max.is_nan() || change.is_nan() {
+                    f64::NAN
+                } else {
+                    change_max.max(change.abs())
+                };
+                u[[i_x, i_y]] = u_next;
+            }
+        }
+
+        Ok(change_max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::relaxation_parameter::RelaxationParameter;
+    use crate::solver::sor_solver::{SorSolver, SorSolverNewParams};
+
+    #[test]
+    fn fn_line_sor_exec_works() {
+        // setup line sor solver and run exec()
+        let u_init = array![
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the solution
+        let u_exact = array![
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.125, 0.375, 1.0],
+            [0.0, 0.125, 0.375, 1.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-9);
+        assert!(is_u_correctly_updated);
+
+        // check if the residuals are recorded at every iteration including the initial one
+        let residual_history = &solver.residual_history;
+        assert_eq!(residual_history.len(), solver.n_iter + 1);
+        assert!((residual_history[0] - 2.0f64.sqrt()).abs() < 1e-10);
+        assert!(*residual_history.last().unwrap() < 1e-8);
+    }
+
+    #[test]
+    fn fn_line_sor_converges_faster_than_sor() {
+        // setup the same problem for the line sor and sor solvers
+        let n = 17;
+        let mut u_init: Array2<f64> = Array::zeros((n, n));
+        u_init.slice_mut(s![.., n - 1]).fill(1.0);
+
+        let mut line_sor_solver = LineSorSolver::new(LineSorSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.0,
+        })
+        .unwrap();
+        line_sor_solver.exec().unwrap();
+        let mut sor_solver = SorSolver::new(SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.0),
+        })
+        .unwrap();
+        sor_solver.exec().unwrap();
+
+        // check if the solutions agree and the line sor solver needs fewer iterations
+        let is_u_consistent = (line_sor_solver.borrow_u() - sor_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_consistent);
+        assert!(line_sor_solver.get_n_iter() < sor_solver.get_n_iter());
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_helmholtz_term() {
+        // setup u = j^2 + k^2 on the boundary, the coefficient k^2 = 0.5 of the Helmholtz term
+        // and the source f = 4 + 0.5 u, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(u_exact.mapv(|u| 4.0 + 0.5 * u)),
+            k_squared: 0.5,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_rectangular_grid() {
+        // setup u = x^2 + 2 y^2 with the grid spacings dx = 0.5 and dy = 0.25,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (0.5 * i_x as f64, 0.25 * i_y as f64);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (0.5, 0.25)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_anisotropy() {
+        // setup u = j^2 + k^2 on the boundary, the anisotropy epsilon = 0.01 and the source f = 2 epsilon + 2,
+        // for which u is the exact solution of epsilon u_xx + u_yy = f
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0))
+                .unwrap()
+                .with_anisotropy(0.01)
+                .unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::CoarseGrid,
+            source: Some(Array::from_elem((6, 7), 2.02)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.0,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution in a few iterations as the lines solve the strong coupling
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+        assert!(solver.n_iter < 10);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_non_uniform_grid() {
+        // setup u = x^2 + 2 y^2 on the grid clustered near x_- and y_+,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        // as the second difference on the non-uniform grid is exact for the quadratic function
+        let grid = Grid::new(
+            array![0.0, 0.1, 0.3, 0.7, 1.5, 2.5],
+            array![0.0, 0.6, 1.0, 1.25, 1.4, 1.48, 1.5],
+        )
+        .unwrap();
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (grid.borrow_x()[i_x], grid.borrow_y()[i_y]);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_obstacle() {
+        // setup u = j^2 + k^2 on the boundary and the obstacle at j = 2, 3 and k = 3 and the source f = 4,
+        // for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let obstacle = Array::from_shape_fn((6, 7), |(i_x, i_y)| (2..4).contains(&i_x) && i_y == 3);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        u_init
+            .slice_mut(s![2..4, 3])
+            .assign(&u_exact.slice(s![2..4, 3]));
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Bilinear,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: Some(obstacle),
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution with the obstacle kept fixed
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_neumann_boundary_condition() {
+        // setup u = j^2 + k^2 with the fluxes u_x = 0 at x_- and u_x = 10 at x_+ and the source f = 4,
+        // for which u is the exact solution as the central difference of the quadratic function is exact
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![.., 1..6]).fill(0.0);
+        let new_params = LineSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution including the edges x = x_pm
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_line_sor_exec_works_with_all_neumann_boundary_conditions() {
+        // setup u = j^2 + k^2 with the fluxes at all the edges and the source f = 4,
+        // which satisfy the compatibility condition and determine u up to a constant
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let new_params = LineSorSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                y_minus: BoundaryCondition::Neumann(0.0),
+                y_plus: BoundaryCondition::Neumann(12.0),
+            },
+            obstacle: None,
+            omega: 1.2,
+        };
+        let mut solver = LineSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u differs from the exact solution by a constant
+        let u_diff = solver.u - u_exact;
+        let is_u_correctly_updated = u_diff.iter().all(|u| (u - u_diff[[0, 0]]).abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+}
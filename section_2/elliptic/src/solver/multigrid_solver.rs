@@ -0,0 +1,602 @@
+//! Solver for the diffusion equation using the multigrid method.
+//!
+//! # Scheme
+//! The relaxation methods reduce the oscillatory components of the error quickly but the smooth ones very slowly.
+//! The multigrid method corrects `u` with the error solved on the coarser grids,
+//! on which the smooth error of the finer grid becomes oscillatory and is reduced efficiently by the relaxation.
+//! Each iteration is a V-cycle, which on each grid from the finest one
+//! 1. smooths the error by [N_SWEEPS] sweeps of the [Smoother],
+//! 2. restricts the residual `r = f - \nabla^2 u - k^2 u` to the coarser grid by the full weighting,
+//! 3. solves the error equation `\nabla^2 e + k^2 e = r` on the coarser grid from `e = 0` by the V-cycle recursively,
+//! 4. prolongs the error `e` by the linear interpolation and adds it to `u`, and
+//! 5. smooths the error again by [N_SWEEPS] sweeps of the [Smoother].
+//!
+//! On the coarsest grid, the error equation is relaxed by [N_SWEEPS_COARSEST] sweeps instead.
+//! The grids are coarsened by a factor of 2 in the directions given by [Coarsening] as long as they have
+//! an even number of at least 4 cells in the directions,
+//! and the transfers between them are those of [numerics::grid_transfer].
+//! The operator on each grid is the 5-point stencil discretized on that grid,
+//! and the error equations are given the homogeneous boundary conditions.
+//!
+//! # Anisotropy
+//! For the anisotropic equation `\epsilon u_{xx} + u_{yy} = f` with `\epsilon \ll 1` given by [Grid::with_anisotropy],
+//! the point relaxation smooths the error only in y direction of the strong coupling,
+//! so that the error oscillatory in x direction is not reduced on the grids coarsened in x direction
+//! and the V-cycle with [Smoother::GaussSeidel] and [Coarsening::Full] converges as slowly as the relaxation itself.
+//! Either of the remedies restores the convergence independent of `\epsilon`:
+//! - [Coarsening::SemiY] coarsens the grids only in y direction, in which the error has been smoothed,
+//! - [Smoother::LineGaussSeidel] solves the strong coupling on each line `x = x_j` exactly,
+//!   which smooths the error in both directions.
+//!
+//! The axes should be swapped for `\epsilon \gg 1`.
+//!
+//! # Boundary Condition
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
+//! ```math
+//! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
+//! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well,
+//! which are injected to the coarser grids.
+//! Since the coarser grids miss the obstacle points between theirs,
+//! the convergence degrades for the thin obstacles not lying on the coarse grid points.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::line_sor_solver::LineRelaxation;
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use numerics::grid_transfer;
+use numerics::norms;
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+
+/// Number of the sweeps of the smoother before and after the correction on each grid but the coarsest one.
+pub const N_SWEEPS: usize = 2;
+
+/// Number of the sweeps of the smoother on the coarsest grid.
+pub const N_SWEEPS_COARSEST: usize = 32;
+
+/// Directions in which the grids are coarsened, [Coarsening::Full] by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Coarsening {
+    /// The grids are coarsened in both directions.
+    #[default]
+    Full,
+    /// The grids are coarsened only in x direction, which suits `\epsilon \gg 1`.
+    SemiX,
+    /// The grids are coarsened only in y direction, which suits `\epsilon \ll 1`.
+    SemiY,
+}
+
+impl Coarsening {
+    /// Return the steps of the points of the coarse grid in x and y directions.
+    fn step(&self) -> (usize, usize) {
+        match self {
+            Coarsening::Full => (2, 2),
+            Coarsening::SemiX => (2, 1),
+            Coarsening::SemiY => (1, 2),
+        }
+    }
+
+    /// Return whether the grid with `dim` points can be coarsened, that is,
+    /// it has an even number of at least 4 cells in each direction to be coarsened.
+    fn is_coarsenable(&self, dim: (usize, usize)) -> bool {
+        let is_coarsenable =
+            |n: usize, step: usize| step == 1 || (n >= 5 && (n - 1).is_multiple_of(2));
+        let step = self.step();
+
+        is_coarsenable(dim.0, step.0) && is_coarsenable(dim.1, step.1)
+    }
+
+    fn restrict(&self, u_fine: &Array2<f64>) -> Result<Array2<f64>, &'static str> {
+        match self {
+            Coarsening::Full => grid_transfer::restrict_2d(u_fine),
+            Coarsening::SemiX => grid_transfer::restrict_2d_along(u_fine, Axis(0)),
+            Coarsening::SemiY => grid_transfer::restrict_2d_along(u_fine, Axis(1)),
+        }
+    }
+
+    fn prolong(&self, u_coarse: &Array2<f64>) -> Result<Array2<f64>, &'static str> {
+        match self {
+            Coarsening::Full => grid_transfer::prolong_2d(u_coarse),
+            Coarsening::SemiX => grid_transfer::prolong_2d_along(u_coarse, Axis(0)),
+            Coarsening::SemiY => grid_transfer::prolong_2d_along(u_coarse, Axis(1)),
+        }
+    }
+}
+
+/// Relaxation smoothing the error on each grid, [Smoother::GaussSeidel] by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Smoother {
+    /// The point Gauss-Seidel relaxation in the lexicographic order.
+    #[default]
+    GaussSeidel,
+    /// The line Gauss-Seidel relaxation, which is the line SOR method of [super::line_sor_solver] with `\omega = 1`,
+    /// solving each line `x = x_j` in y direction, which suits `\epsilon \ll 1`.
+    LineGaussSeidel,
+}
+
+/// Grid of the V-cycle with the operator discretized on it.
+#[derive(Debug)]
+struct Level {
+    grid: Grid,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    line_relaxation: Option<LineRelaxation>,
+}
+
+impl Level {
+    fn new(
+        grid: Grid,
+        boundary_conditions: BoundaryConditions,
+        k_squared: f64,
+        obstacle: Option<&Array2<bool>>,
+        smoother: Smoother,
+    ) -> Result<Self, &'static str> {
+        let fixed = boundary_conditions.fixed_mask(grid.dim(), obstacle);
+        let line_relaxation = match smoother {
+            Smoother::GaussSeidel => None,
+            Smoother::LineGaussSeidel => Some(LineRelaxation::new(
+                boundary_conditions,
+                k_squared,
+                &grid,
+                &fixed,
+            )?),
+        };
+
+        Ok(Self {
+            grid,
+            boundary_conditions,
+            fixed,
+            line_relaxation,
+        })
+    }
+
+    /// Relax `u` in place by a sweep of the smoother with the `source`.
+    fn smooth(
+        &self,
+        u: &mut Array2<f64>,
+        source: &Array2<f64>,
+        k_squared: f64,
+    ) -> Result<(), Box<dyn Error>> {
+        match &self.line_relaxation {
+            Some(line_relaxation) => {
+                line_relaxation.sweep(u, &self.grid, source, &self.fixed, 1.0)?;
+            }
+            None => self.boundary_conditions.gauss_seidel_sweep(
+                u,
+                &self.grid,
+                Some(source),
+                k_squared,
+                &self.fixed,
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+/// Solver for the diffusion equation using the multigrid method.
+#[derive(Debug)]
+pub struct MultigridSolver {
+    u: Array2<f64>,
+    source: Array2<f64>,
+    n_iter_max: usize,
+    k_squared: f64,
+    convergence: Convergence,
+    coarsening: Coarsening,
+    levels: Vec<Level>,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+}
+
+impl MultigridSolver {
+    /// Create a new `MultigridSolver` instance.
+    pub fn new(new_params: MultigridSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+
+        // setup the levels from the finest grid, where the error equations on the coarser grids are homogeneous
+        let mut levels = vec![Level::new(
+            new_params.grid,
+            new_params.boundary_conditions,
+            new_params.k_squared,
+            new_params.obstacle.as_ref(),
+            new_params.smoother,
+        )?];
+        let step = new_params.coarsening.step();
+        let mut obstacle = new_params.obstacle;
+        while let Some(grid) = levels
+            .last()
+            .map(|level| &level.grid)
+            .filter(|grid| new_params.coarsening.is_coarsenable(grid.dim()))
+            .and_then(|grid| grid.coarsen(step))
+        {
+            obstacle = obstacle.map(|obstacle| obstacle.slice(s![..;step.0, ..;step.1]).to_owned());
+            levels.push(Level::new(
+                grid,
+                new_params.boundary_conditions.homogeneous(),
+                new_params.k_squared,
+                obstacle.as_ref(),
+                new_params.smoother,
+            )?);
+        }
+
+        let residual_norm = levels[0].boundary_conditions.residual_norm(
+            &u,
+            &levels[0].grid,
+            &source,
+            new_params.k_squared,
+            &levels[0].fixed,
+        );
+
+        Ok(Self {
+            u,
+            source,
+            n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            coarsening: new_params.coarsening,
+            levels,
+            residual_history: vec![residual_norm],
+            epsilon: 1.0e-10,
+            n_iter: 0,
+            executed: false,
+            converged: false,
+        })
+    }
+
+    /// Return the number of the grids of the V-cycle, including the finest one.
+    pub fn get_n_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    fn iterate(&mut self) -> Result<(), Box<dyn Error>> {
+        let u_prev = self.u.clone();
+        let mut u = std::mem::take(&mut self.u);
+        let cycled = self.cycle(0, &mut u, &self.source);
+        self.u = u;
+        cycled?;
+
+        self.converged = norms::linf(&(&self.u - &u_prev)) <= self.epsilon;
+        let level = &self.levels[0];
+        self.residual_history
+            .push(level.boundary_conditions.residual_norm(
+                &self.u,
+                &level.grid,
+                &self.source,
+                self.k_squared,
+                &level.fixed,
+            ));
+        self.n_iter += 1;
+
+        Ok(())
+    }
+
+    /// Solve the equation with the `source` on the grid `i_level` for `u` in place by a V-cycle.
+    fn cycle(
+        &self,
+        i_level: usize,
+        u: &mut Array2<f64>,
+        source: &Array2<f64>,
+    ) -> Result<(), Box<dyn Error>> {
+        let level = &self.levels[i_level];
+        if i_level == self.levels.len() - 1 {
+            for _ in 0..N_SWEEPS_COARSEST {
+                level.smooth(u, source, self.k_squared)?;
+            }
+            return Ok(());
+        }
+
+        for _ in 0..N_SWEEPS {
+            level.smooth(u, source, self.k_squared)?;
+        }
+
+        // solve the error equation on the coarser grid and correct u with the error
+        let residual = level.boundary_conditions.residual(
+            u,
+            &level.grid,
+            source,
+            self.k_squared,
+            &level.fixed,
+        );
+        let residual_coarse = self.coarsening.restrict(&residual)?;
+        let mut error_coarse = Array::zeros(residual_coarse.dim());
+        self.cycle(i_level + 1, &mut error_coarse, &residual_coarse)?;
+        let error = self.coarsening.prolong(&error_coarse)?;
+        Zip::from(&mut *u)
+            .and(&error)
+            .and(&level.fixed)
+            .for_each(|u, &error, &is_fixed| {
+                if !is_fixed {
+                    *u += error;
+                }
+            });
+
+        for _ in 0..N_SWEEPS {
+            level.smooth(u, source, self.k_squared)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Solver for MultigridSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    /// Return the number of the V-cycles.
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.levels[0].grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for MultigridSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate()?;
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Parameters for creating a new `MultigridSolver` instance.
+pub struct MultigridSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Maximum number of the V-cycles.
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Directions in which the grids are coarsened.
+    pub coarsening: Coarsening,
+    /// Relaxation smoothing the error on each grid.
+    pub smoother: Smoother,
+}
+
+impl NewParams for MultigridSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+
+    /// Return the parameters of `\epsilon u_{xx} + u_{yy} = 2 \epsilon + 2` on `[0, 1] \times [0, 1]`
+    /// with `n \times n` cells and the boundary values of the exact solution `u = x^2 + y^2`, together with it.
+    fn create_anisotropic_problem(
+        n: usize,
+        anisotropy: f64,
+        coarsening: Coarsening,
+        smoother: Smoother,
+    ) -> (MultigridSolverNewParams, Array2<f64>) {
+        let h = 1.0 / n as f64;
+        let u_exact = Array::from_shape_fn((n + 1, n + 1), |(i_x, i_y)| {
+            let (x, y) = (i_x as f64 * h, i_y as f64 * h);
+            x * x + y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..n, 1..n]).fill(0.0);
+        let new_params = MultigridSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (h, h))
+                .unwrap()
+                .with_anisotropy(anisotropy)
+                .unwrap(),
+            u_init,
+            n_iter_max: 200,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((n + 1, n + 1), 2.0 * anisotropy + 2.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            coarsening,
+            smoother,
+        };
+
+        (new_params, u_exact)
+    }
+
+    #[test]
+    fn fn_multigrid_exec_works() {
+        for coarsening in [Coarsening::Full, Coarsening::SemiX, Coarsening::SemiY] {
+            for smoother in [Smoother::GaussSeidel, Smoother::LineGaussSeidel] {
+                // setup the isotropic problem on the grid of 32 x 32 cells and run exec()
+                let (new_params, u_exact) =
+                    create_anisotropic_problem(32, 1.0, coarsening, smoother);
+                let mut solver = MultigridSolver::new(new_params).unwrap();
+                solver.exec().unwrap();
+
+                // check if u is converged to the exact solution, within a small number of V-cycles for the full coarsening
+                // while the semi-coarsening relies on the smoother for the error oscillatory in the other direction
+                let is_u_correctly_updated = (&solver.u - &u_exact).iter().all(|u| u.abs() < 1e-8);
+                assert!(is_u_correctly_updated);
+                if coarsening == Coarsening::Full {
+                    assert!(solver.get_n_iter() < 15);
+                }
+
+                // check if the residuals are recorded at every V-cycle including the initial one
+                assert_eq!(solver.residual_history.len(), solver.n_iter + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn fn_multigrid_coarsens_grids_in_given_directions() {
+        // setup the solvers on the grid of 32 x 8 cells
+        let n_levels = |coarsening: Coarsening| {
+            let (mut new_params, _) =
+                create_anisotropic_problem(8, 1.0, coarsening, Smoother::GaussSeidel);
+            new_params.u_init = Array::zeros((33, 9));
+            new_params.grid = Grid::uniform((33, 9), (1.0, 1.0)).unwrap();
+            new_params.source = None;
+            MultigridSolver::new(new_params).unwrap().get_n_levels()
+        };
+
+        // check if the grids are coarsened down to 2 cells in the coarsened directions
+        assert_eq!(n_levels(Coarsening::Full), 3);
+        assert_eq!(n_levels(Coarsening::SemiX), 5);
+        assert_eq!(n_levels(Coarsening::SemiY), 3);
+    }
+
+    #[test]
+    fn fn_multigrid_converges_for_strong_anisotropy_with_semi_coarsening_or_line_smoother() {
+        // setup the anisotropic problem with epsilon = 10^{-3}
+        let n_iter = |coarsening: Coarsening, smoother: Smoother| {
+            let (new_params, u_exact) = create_anisotropic_problem(32, 1e-3, coarsening, smoother);
+            let mut solver = MultigridSolver::new(new_params).unwrap();
+            while !solver.is_converged() && solver.step().is_ok() {}
+            let is_u_correctly_updated = (&solver.u - &u_exact).iter().all(|u| u.abs() < 1e-8);
+            (solver.get_n_iter(), is_u_correctly_updated)
+        };
+
+        // check if the remedies converge as fast as for the isotropic problem,
+        // while the point smoother with the full coarsening stalls
+        for (coarsening, smoother) in [
+            (Coarsening::SemiY, Smoother::GaussSeidel),
+            (Coarsening::Full, Smoother::LineGaussSeidel),
+            (Coarsening::SemiY, Smoother::LineGaussSeidel),
+        ] {
+            let (n_iter, is_u_correctly_updated) = n_iter(coarsening, smoother);
+            assert!(is_u_correctly_updated);
+            assert!(n_iter < 20);
+        }
+        let (n_iter_point_full, _) = n_iter(Coarsening::Full, Smoother::GaussSeidel);
+        assert!(n_iter_point_full > 100);
+    }
+
+    #[test]
+    fn fn_multigrid_exec_works_with_neumann_boundary_condition_and_obstacle() {
+        // setup u = x^2 + y^2 with the flux u_x = 2 at x_+ and an obstacle on the coarse grid points fixed to the solution,
+        // for which u is the exact solution as the central difference of the quadratic function is exact
+        let (mut new_params, u_exact) =
+            create_anisotropic_problem(16, 1.0, Coarsening::Full, Smoother::GaussSeidel);
+        new_params.u_init.slice_mut(s![16, 1..16]).fill(0.0);
+        new_params.boundary_conditions = BoundaryConditions {
+            x_plus: BoundaryCondition::Neumann(2.0),
+            ..Default::default()
+        };
+        let obstacle =
+            Array::from_shape_fn((17, 17), |(i_x, i_y)| (6..11).contains(&i_x) && i_y == 4);
+        Zip::from(&mut new_params.u_init)
+            .and(&u_exact)
+            .and(&obstacle)
+            .for_each(|u, &u_exact, &is_obstacle| {
+                if is_obstacle {
+                    *u = u_exact;
+                }
+            });
+        new_params.obstacle = Some(obstacle);
+        let mut solver = MultigridSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution including the edge x = x_+
+        let is_u_correctly_updated = (&solver.u - &u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+        assert!(solver.get_n_iter() < 30);
+    }
+}
@@ -3,24 +3,56 @@
 //! # Scheme
 //! The Point Jacobi method is given by
 //! ```math
-//! u_{j,k}^{n+1} = \frac{1}{4} (u_{j-1,k}^n + u_{j+1,k}^n + u_{j,k-1}^n + u_{j,k+1}^n).
+//! u_{j,k}^{n+1} = \frac{1}{d - k^2} \left(\frac{u_{j-1,k}^n + u_{j+1,k}^n}{\Delta x^2} + \frac{u_{j,k-1}^n + u_{j,k+1}^n}{\Delta y^2} - f_{j,k}\right),
 //! ```
+//! where `d = 2 / \Delta x^2 + 2 / \Delta y^2` is the diagonal coefficient of the 5-point Laplacian
+//! with the grid spacings `\Delta x` and `\Delta y`,
+//! and `f` is the source term of the Poisson's equation `\nabla^2 u = f`, which is zero for the Laplace's equation.
+//! With the coefficient `k^2` of the Helmholtz term, the Helmholtz equation `\nabla^2 u + k^2 u = f` is solved,
+//! for which the iteration diverges unless `|d - k^2| > d - \lambda_{min}`,
+//! where `d - \lambda_{min}` is the spectral radius of the off-diagonal part,
+//! see [BoundaryConditions::laplacian_eigenvalue_range].
+//! With the flux boundary conditions at all the edges and `k^2 = 0`, the iteration doesn't converge
+//! as the error oscillates between the neighboring points, for which [super::sor_solver] should be used.
+//!
+//! On the non-uniform [Grid], the coefficients `1 / \Delta x^2` and `1 / \Delta y^2` of the neighbors
+//! are replaced with those of the second differences given in [super::grid], and `d` with their sum.
+//! The condition of the convergence is then estimated from the eigenvalues of the Laplacian on the grid.
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
 //! ```math
 //! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
 //! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well and skipped in the iteration.
+//!
+//! # Parallelization
+//! With the `parallel` feature, the points are updated in parallel by rayon,
+//! since each of them depends only on the values of the previous iteration.
 
-use super::{NewParams, Solver};
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
 use ndarray::prelude::*;
+use ndarray::Zip;
+use numerics::norms;
 use std::error::Error;
 
 /// Solver for the diffusion equation using the Point Jacobi method.
 #[derive(Debug)]
 pub struct PointJacobiSolver {
     u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
     n_iter_max: usize,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    convergence: Convergence,
+    residual_history: Vec<f64>,
     epsilon: f64,
     n_iter: usize,
     executed: bool,
@@ -32,9 +64,49 @@ impl PointJacobiSolver {
     pub fn new(new_params: PointJacobiSolverNewParams) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
 
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+
         Ok(Self {
-            u: new_params.u_init,
+            u,
+            grid: new_params.grid,
+            source,
             n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            convergence: if new_params.k_squared < eigenvalue_range.0
+                || new_params.k_squared > eigenvalue_range.1
+            {
+                Convergence::Convergent
+            } else {
+                Convergence::Divergent("k^2 or the boundary conditions make the spectral radius of the Point Jacobi iteration not less than 1, for which the relaxation diverges")
+            },
+            residual_history: vec![residual_norm],
             epsilon: 1.0e-10,
             n_iter: 0,
             executed: false,
@@ -45,32 +117,39 @@ impl PointJacobiSolver {
     fn iterate(&mut self) {
         let u_next = self.calculate_u_next();
 
-        self.converged = (&u_next - &self.u).iter().all(|u| u.abs() <= self.epsilon);
+        self.converged = norms::linf(&(&u_next - &self.u)) <= self.epsilon;
         self.u = u_next;
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
         self.n_iter += 1;
     }
 
     fn calculate_u_next(&self) -> Array2<f64> {
-        let mut u_next = self.u.clone();
-        for i_x in 1..self.u.shape()[0] - 1 {
-            for i_y in 1..self.u.shape()[1] - 1 {
-                if i_x == 0
-                    || i_x == self.u.shape()[0] - 1
-                    || i_y == 0
-                    || i_y == self.u.shape()[1] - 1
-                {
-                    continue;
-                }
-
-                u_next[[i_x, i_y]] = 0.25
-                    * (self.u[[i_x - 1, i_y]]
-                        + self.u[[i_x + 1, i_y]]
-                        + self.u[[i_x, i_y - 1]]
-                        + self.u[[i_x, i_y + 1]]);
+        let update = |(i_x, i_y), &u: &f64, &is_fixed: &bool| {
+            if is_fixed {
+                return u;
             }
-        }
 
-        u_next
+            let stencil = self
+                .boundary_conditions
+                .stencil(&self.u, i_x, i_y, &self.grid);
+            (stencil.neighbors + stencil.offset - self.source[[i_x, i_y]])
+                / (stencil.diag - self.k_squared)
+        };
+
+        // every point is updated independently from the previous values
+        #[cfg(feature = "parallel")]
+        return Zip::indexed(&self.u)
+            .and(&self.fixed)
+            .par_map_collect(update);
+        #[cfg(not(feature = "parallel"))]
+        Zip::indexed(&self.u).and(&self.fixed).map_collect(update)
     }
 }
 
@@ -82,13 +161,7 @@ impl Solver for PointJacobiSolver {
         self.executed = true;
 
         while !self.converged {
-            if self.n_iter >= self.n_iter_max {
-                return Err(Box::<dyn Error>::from(
-                    "maximum number of iterations reached",
-                ));
-            }
-
-            self.iterate();
+            self.step()?;
         }
 
         Ok(())
@@ -101,14 +174,57 @@ impl Solver for PointJacobiSolver {
     fn get_n_iter(&self) -> usize {
         self.n_iter
     }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for PointJacobiSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate();
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
 }
 
 /// Parameters for creating a new `PointJacobiSolver` instance.
 pub struct PointJacobiSolverNewParams {
-    /// Initial values of `u`.
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
     pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
     /// Maximum number of iterations.
     pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
 }
 
 impl NewParams for PointJacobiSolverNewParams {
@@ -116,9 +232,33 @@ impl NewParams for PointJacobiSolverNewParams {
         if self.u_init.is_empty() {
             return Err("u must not be empty");
         }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
         if self.n_iter_max == 0 {
             return Err("n_iter_max must be positive");
         }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
 
         Ok(())
     }
@@ -127,6 +267,7 @@ impl NewParams for PointJacobiSolverNewParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::BoundaryCondition;
 
     #[test]
     fn fn_point_jacobi_exec_works() {
@@ -138,8 +279,14 @@ mod tests {
             [0.0, 0.0, 0.0, 1.0]
         ];
         let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
             u_init,
             n_iter_max: 100,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
         };
         let mut solver = PointJacobiSolver::new(new_params).unwrap();
         solver.exec().unwrap();
@@ -153,5 +300,241 @@ mod tests {
         ];
         let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
+
+        // check if the residuals are recorded at every iteration including the initial one
+        let residual_history = &solver.residual_history;
+        assert_eq!(residual_history.len(), solver.n_iter + 1);
+        assert!((residual_history[0] - 2.0f64.sqrt()).abs() < 1e-10);
+        assert!(*residual_history.last().unwrap() < 1e-8);
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_helmholtz_term() {
+        // setup u = j^2 + k^2 on the boundary, the coefficient k^2 = 0.5 of the Helmholtz term
+        // and the source f = 4 + 0.5 u, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(u_exact.mapv(|u| 4.0 + 0.5 * u)),
+            k_squared: 0.5,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_rectangular_grid() {
+        // setup u = x^2 + 2 y^2 with the grid spacings dx = 0.5 and dy = 0.25,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (0.5 * i_x as f64, 0.25 * i_y as f64);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (0.5, 0.25)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_non_uniform_grid() {
+        // setup u = x^2 + 2 y^2 on the grid clustered near x_- and y_+,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        // as the second difference on the non-uniform grid is exact for the quadratic function
+        let grid = Grid::new(
+            array![0.0, 0.1, 0.3, 0.7, 1.5, 2.5],
+            array![0.0, 0.6, 1.0, 1.25, 1.4, 1.48, 1.5],
+        )
+        .unwrap();
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (grid.borrow_x()[i_x], grid.borrow_y()[i_y]);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = PointJacobiSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 100000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_obstacle() {
+        // setup u = j^2 + k^2 on the boundary and the obstacle at j = 2, 3 and k = 3 and the source f = 4,
+        // for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let obstacle = Array::from_shape_fn((6, 7), |(i_x, i_y)| (2..4).contains(&i_x) && i_y == 3);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        u_init
+            .slice_mut(s![2..4, 3])
+            .assign(&u_exact.slice(s![2..4, 3]));
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Bilinear,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: Some(obstacle),
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution with the obstacle kept fixed
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_get_convergence_works() {
+        // setup the grid with 4 x 5 interior points, for which \lambda_{min} = 4 - 2 \cos(\pi / 5) - 2 \cos(\pi / 6) = 0.65
+        let create_solver = |k_squared: f64| {
+            PointJacobiSolver::new(PointJacobiSolverNewParams {
+                u_init: Array::zeros((6, 7)),
+                grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+                n_iter_max: 1000,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+            })
+            .unwrap()
+        };
+
+        // check if k^2 between \lambda_{min} and 8 - \lambda_{min} is reported to diverge
+        assert_eq!(
+            create_solver(0.5).get_convergence(),
+            Convergence::Convergent
+        );
+        assert!(matches!(
+            create_solver(1.0).get_convergence(),
+            Convergence::Divergent(_)
+        ));
+        assert_eq!(
+            create_solver(7.5).get_convergence(),
+            Convergence::Convergent
+        );
+    }
+
+    #[test]
+    fn fn_point_jacobi_exec_works_with_neumann_boundary_condition() {
+        // setup u = j^2 + k^2 with the fluxes u_x = 0 at x_- and u_x = 10 at x_+ and the source f = 4,
+        // for which u is the exact solution as the central difference of the quadratic function is exact
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![.., 1..6]).fill(0.0);
+        let new_params = PointJacobiSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                ..Default::default()
+            },
+            obstacle: None,
+        };
+        let mut solver = PointJacobiSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution including the edges x = x_pm
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_point_jacobi_new_fails_with_incompatible_neumann_problem() {
+        // setup the insulated edges and the source f = 4, which violate the compatibility condition
+        let new_params = PointJacobiSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(0.0),
+                y_minus: BoundaryCondition::Neumann(0.0),
+                y_plus: BoundaryCondition::Neumann(0.0),
+            },
+            obstacle: None,
+        };
+
+        // check if the solver is not created
+        assert!(PointJacobiSolver::new(new_params).is_err());
     }
 }
@@ -0,0 +1,363 @@
+//! Solver for the diffusion equation in the polar coordinates using the SOR method.
+//!
+//! # Scheme
+//! The Laplacian in the polar coordinates `(r, \theta)`,
+//! ```math
+//! \nabla^2 u = \frac{1}{r} \frac{\partial}{\partial r} \left(r \frac{\partial u}{\partial r}\right)
+//!     + \frac{1}{r^2} \frac{\partial^2 u}{\partial \theta^2},
+//! ```
+//! is discretized in the conservative form as
+//! ```math
+//! (\nabla^2 u)_{j,k} = \frac{1}{r_j \Delta r_j}
+//!     \left(r_{j+1/2} \frac{u_{j+1,k} - u_{j,k}}{h_+} - r_{j-1/2} \frac{u_{j,k} - u_{j-1,k}}{h_-}\right)
+//!     + \frac{u_{j,k-1} - 2 u_{j,k} + u_{j,k+1}}{r_j^2 \Delta \theta^2},
+//! ```
+//! where `h_\pm` are the spacings to the neighbors in `r`, `\Delta r_j = (h_- + h_+) / 2`,
+//! `r_{j \pm 1/2} = r_j \pm h_\pm / 2` and `\Delta \theta = 2 \pi / N_\theta`,
+//! and the indices `k` are periodic in `\theta`.
+//! The Poisson's equation `\nabla^2 u = f` is relaxed by the SOR method as in [super::sor_solver],
+//! sweeping in the increasing order of `j` and then `k`.
+//!
+//! # Axis
+//! If the grid includes the axis `r_0 = 0`, the points `u_{0,k}` are the single point at the center of the disk,
+//! where the Laplacian is given by the integral of the flux over the circle of the radius `r_1` as
+//! ```math
+//! (\nabla^2 u)_0 = \frac{4}{r_1^2} (\bar{u}_1 - u_0),
+//! ```
+//! where `\bar{u}_1` is the mean of `u_{1,k}` over `k`.
+//! The value at the center is relaxed with this Laplacian and the mean of the source over `k`,
+//! and copied to all the points `u_{0,k}`.
+//!
+//! # Boundary Condition
+//! The values at the outer circle `r = r_{max}` and, for the annulus, at the inner circle `r = r_{min} > 0`
+//! are fixed as
+//! ```math
+//! u(r_{min}, \theta) = u_init(r_{min}, \theta), u(r_{max}, \theta) = u_init(r_{max}, \theta).
+//! ```
+//! The grid is given by [Grid::polar], of which the x and y coordinates are `r` and `\theta`, respectively.
+
+use super::grid::Grid;
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use numerics::norms;
+use std::error::Error;
+
+/// Solver for the diffusion equation in the polar coordinates using the SOR method.
+#[derive(Debug)]
+pub struct PolarSorSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
+    n_iter_max: usize,
+    omega: f64,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+}
+
+impl PolarSorSolver {
+    /// Create a new `PolarSorSolver` instance.
+    ///
+    /// The interior values of `u_init` are used as the initial guess.
+    pub fn new(new_params: PolarSorSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let source = new_params
+            .source
+            .unwrap_or_else(|| Array::zeros(new_params.u_init.dim()));
+
+        let mut solver = Self {
+            u: new_params.u_init,
+            grid: new_params.grid,
+            source,
+            n_iter_max: new_params.n_iter_max,
+            omega: new_params.omega,
+            residual_history: Vec::new(),
+            epsilon: 1.0e-10,
+            n_iter: 0,
+            executed: false,
+            converged: false,
+        };
+        solver.residual_history.push(solver.residual_norm());
+
+        Ok(solver)
+    }
+
+    fn iterate(&mut self) {
+        let u_next = self.calculate_u_next();
+
+        self.converged = norms::linf(&(&u_next - &self.u)) <= self.epsilon;
+        self.u = u_next;
+        self.residual_history.push(self.residual_norm());
+        self.n_iter += 1;
+    }
+
+    fn calculate_u_next(&self) -> Array2<f64> {
+        let mut u_next = self.u.clone();
+        let (n_r, n_theta) = self.u.dim();
+        let r = self.grid.borrow_x();
+        let d_theta = 2.0 * std::f64::consts::PI / n_theta as f64;
+
+        // the center of the disk
+        if r[0] == 0.0 {
+            let u_mean = u_next.row(1).mean().unwrap_or(0.0);
+            let f_mean = self.source.row(0).mean().unwrap_or(0.0);
+            let u_center = (1.0 - self.omega) * u_next[[0, 0]]
+                + self.omega * (u_mean - 0.25 * r[1] * r[1] * f_mean);
+            u_next.row_mut(0).fill(u_center);
+        }
+
+        for i_r in 1..n_r - 1 {
+            let (coef_minus, coef_plus, coef_theta) = coefs(r, i_r, d_theta);
+            let diag = coef_minus + coef_plus + 2.0 * coef_theta;
+
+            for i_theta in 0..n_theta {
+                let i_theta_minus = (i_theta + n_theta - 1) % n_theta;
+                let i_theta_plus = (i_theta + 1) % n_theta;
+                let neighbors = coef_minus * u_next[[i_r - 1, i_theta]]
+                    + coef_plus * u_next[[i_r + 1, i_theta]]
+                    + coef_theta * (u_next[[i_r, i_theta_minus]] + u_next[[i_r, i_theta_plus]]);
+                u_next[[i_r, i_theta]] = (1.0 - self.omega) * u_next[[i_r, i_theta]]
+                    + self.omega * (neighbors - self.source[[i_r, i_theta]]) / diag;
+            }
+        }
+
+        u_next
+    }
+
+    /// Return the L2 norm of the residual `f - \nabla^2 u` over the unknown points,
+    /// where the center of the disk is counted once.
+    fn residual_norm(&self) -> f64 {
+        let (n_r, n_theta) = self.u.dim();
+        let r = self.grid.borrow_x();
+        let d_theta = 2.0 * std::f64::consts::PI / n_theta as f64;
+        let mut residual_squared = 0.0;
+
+        // the center of the disk
+        if r[0] == 0.0 {
+            let u_mean = self.u.row(1).mean().unwrap_or(0.0);
+            let f_mean = self.source.row(0).mean().unwrap_or(0.0);
+            let residual = f_mean - 4.0 * (u_mean - self.u[[0, 0]]) / (r[1] * r[1]);
+            residual_squared += residual * residual;
+        }
+
+        for i_r in 1..n_r - 1 {
+            let (coef_minus, coef_plus, coef_theta) = coefs(r, i_r, d_theta);
+            for i_theta in 0..n_theta {
+                let i_theta_minus = (i_theta + n_theta - 1) % n_theta;
+                let i_theta_plus = (i_theta + 1) % n_theta;
+                let u = self.u[[i_r, i_theta]];
+                let laplacian = coef_minus * (self.u[[i_r - 1, i_theta]] - u)
+                    + coef_plus * (self.u[[i_r + 1, i_theta]] - u)
+                    + coef_theta
+                        * (self.u[[i_r, i_theta_minus]] + self.u[[i_r, i_theta_plus]] - 2.0 * u);
+                let residual = self.source[[i_r, i_theta]] - laplacian;
+                residual_squared += residual * residual;
+            }
+        }
+
+        residual_squared.sqrt()
+    }
+}
+
+/// Return the coefficients of `u_{j-1,k}`, `u_{j+1,k}` and `u_{j,k \pm 1}` in the Laplacian at the radius `r_j`.
+fn coefs(r: &Array1<f64>, i_r: usize, d_theta: f64) -> (f64, f64, f64) {
+    let (h_minus, h_plus) = (r[i_r] - r[i_r - 1], r[i_r + 1] - r[i_r]);
+    let dr = 0.5 * (h_minus + h_plus);
+    let coef_minus = (r[i_r] - 0.5 * h_minus) / (r[i_r] * dr * h_minus);
+    let coef_plus = (r[i_r] + 0.5 * h_plus) / (r[i_r] * dr * h_plus);
+    let coef_theta = 1.0 / (r[i_r] * r[i_r] * d_theta * d_theta);
+
+    (coef_minus, coef_plus, coef_theta)
+}
+
+impl Solver for PolarSorSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        Convergence::Convergent
+    }
+}
+
+impl RelaxationSolver for PolarSorSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate();
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Parameters for creating a new `PolarSorSolver` instance.
+pub struct PolarSorSolverNewParams {
+    /// Initial values of `u` at `(r_j, \theta_k)`, of which the values at the circles are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid in the polar coordinates with the same shape as `u_init`, see [Grid::polar].
+    pub grid: Grid,
+    /// Maximum number of iterations.
+    pub n_iter_max: usize,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Relaxation parameter.
+    pub omega: f64,
+}
+
+impl NewParams for PolarSorSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.dim().0 < 2 {
+            return Err("u must have at least 2 points in r direction");
+        }
+        if self.u_init.dim().1 < 3 {
+            return Err("u must have at least 3 points in theta direction");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        let n_theta = self.grid.dim().1;
+        let is_periodic = self
+            .grid
+            .borrow_y()
+            .iter()
+            .enumerate()
+            .all(|(i_theta, theta)| {
+                let theta_expected = 2.0 * std::f64::consts::PI * i_theta as f64 / n_theta as f64;
+                (theta - theta_expected).abs() <= 1.0e-12
+            });
+        if self.grid.borrow_x()[0] < 0.0 || !is_periodic {
+            return Err("grid must be in the polar coordinates");
+        }
+        if self.grid.anisotropy() != 1.0 {
+            return Err("grid must not be anisotropic in the polar coordinates");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self.omega < 1.0 || self.omega > 2.0 {
+            return Err("omega must be between 1 and 2");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_polar_sor_exec_works_with_disk() {
+        // setup u = r^2 on the disk with the source f = 4,
+        // for which u is the exact solution including the center of the disk
+        let grid = Grid::polar(Array::linspace(0.0, 1.0, 11), 16).unwrap();
+        let u_exact = Array::from_shape_fn(grid.dim(), |(i_r, _)| grid.borrow_x()[i_r].powi(2));
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![..10, ..]).fill(0.0);
+        let new_params = PolarSorSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 1000,
+            source: Some(Array::from_elem((11, 16), 4.0)),
+            omega: 1.5,
+        };
+        let mut solver = PolarSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+
+        // check if the residuals are recorded at every iteration including the initial one
+        let residual_history = &solver.residual_history;
+        assert_eq!(residual_history.len(), solver.n_iter + 1);
+        assert!(*residual_history.last().unwrap() < 1e-6);
+    }
+
+    #[test]
+    fn fn_polar_sor_exec_works_with_annulus() {
+        // setup the series solution u = (r + 1 / r) \cos \theta of the Laplace's equation on the annulus
+        let grid = Grid::polar(Array::linspace(0.5, 1.5, 41), 64).unwrap();
+        let u_exact = Array::from_shape_fn(grid.dim(), |(i_r, i_theta)| {
+            let (r, theta) = (grid.borrow_x()[i_r], grid.borrow_y()[i_theta]);
+            (r + 1.0 / r) * theta.cos()
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..40, ..]).fill(0.0);
+        let new_params = PolarSorSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 10000,
+            source: None,
+            omega: 1.8,
+        };
+        let mut solver = PolarSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u agrees with the series solution within the discretization error
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-3);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_polar_sor_new_fails_with_non_polar_grid() {
+        // setup the grid whose y coordinates don't cover the period of \theta
+        let new_params = PolarSorSolverNewParams {
+            u_init: Array::zeros((3, 4)),
+            grid: Grid::uniform((3, 4), (1.0, 1.0)).unwrap(),
+            n_iter_max: 1000,
+            source: None,
+            omega: 1.5,
+        };
+
+        // check if the solver is not created
+        assert_eq!(
+            PolarSorSolver::new(new_params).unwrap_err(),
+            "grid must be in the polar coordinates"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Module for the preconditioners of the conjugate gradient method.
+//!
+//! A preconditioner approximates the 5-point Laplacian `A` of [super::conjugate_gradient_solver] by `M`,
+//! whose inverse is cheap to apply, so that `M^{-1} A` has a smaller condition number than `A`.
+//! The lower and upper triangular parts `L` and `U = L^T` of `A` are those in the order of
+//! increasing `j` and then increasing `k`, that is, `L` couples `u_{j,k}` with `u_{j-1,k}` and `u_{j,k-1}`.
+//!
+//! The coefficients of `A` are taken as those of the Laplacian with the grid spacing taken as 1,
+//! which agree with `A` up to a constant factor on the uniform grid with `\Delta x = \Delta y` and approximate it otherwise.
+//! Since the conjugate gradient method is invariant under the scaling of `M`, the factor doesn't matter.
+//!
+//! The boundary points, which are unknown with the flux boundary conditions, are preconditioned by the diagonal `4`
+//! except for [Identity], so that `M` remains symmetric positive definite.
+
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Preconditioner of the conjugate gradient method.
+pub trait Preconditioner: Debug {
+    /// Return `M^{-1} r`, where `r` is zero at the fixed points.
+    fn apply(&self, residual: &Array2<f64>) -> Array2<f64>;
+}
+
+/// No preconditioning, `M = I`.
+#[derive(Debug, Clone, Copy)]
+pub struct Identity;
+
+impl Preconditioner for Identity {
+    fn apply(&self, residual: &Array2<f64>) -> Array2<f64> {
+        residual.clone()
+    }
+}
+
+/// Jacobi preconditioner, `M = D`, where `D` is the diagonal part of `A`.
+#[derive(Debug, Clone, Copy)]
+pub struct Jacobi;
+
+impl Preconditioner for Jacobi {
+    fn apply(&self, residual: &Array2<f64>) -> Array2<f64> {
+        residual * 0.25
+    }
+}
+
+/// SSOR preconditioner,
+/// ```math
+/// M = \frac{1}{\omega (2 - \omega)} (D + \omega L) D^{-1} (D + \omega U),
+/// ```
+/// where `\omega \in (0, 2)` is the relaxation parameter.
+#[derive(Debug, Clone, Copy)]
+pub struct Ssor {
+    omega: f64,
+}
+
+impl Ssor {
+    /// Create a new `Ssor` instance with the relaxation parameter `omega`.
+    pub fn new(omega: f64) -> Result<Self, &'static str> {
+        if omega <= 0.0 || omega >= 2.0 {
+            return Err("omega must be between 0 and 2");
+        }
+
+        Ok(Self { omega })
+    }
+}
+
+impl Preconditioner for Ssor {
+    fn apply(&self, residual: &Array2<f64>) -> Array2<f64> {
+        let diag = Array::from_elem(residual.dim(), 4.0);
+
+        solve_factorized(residual, &diag, self.omega) * (self.omega * (2.0 - self.omega))
+    }
+}
+
+/// Incomplete Cholesky preconditioner without fill-in, IC(0),
+/// ```math
+/// M = (\tilde{D} + L) \tilde{D}^{-1} (\tilde{D} + U),
+/// ```
+/// where the diagonal `\tilde{D}` is chosen so that `M` agrees with `A` on the diagonal,
+/// ```math
+/// \tilde{d}_{j,k} = 4 - \frac{1}{\tilde{d}_{j-1,k}} - \frac{1}{\tilde{d}_{j,k-1}},
+/// ```
+/// in which the terms of the boundary points are omitted.
+#[derive(Debug, Clone)]
+pub struct IncompleteCholesky {
+    diag: Array2<f64>,
+}
+
+impl IncompleteCholesky {
+    /// Create a new `IncompleteCholesky` instance for the grid with `dim` points including the boundary points.
+    pub fn new(dim: (usize, usize)) -> Self {
+        let (n_x, n_y) = dim;
+        let mut diag = Array::from_elem(dim, 4.0);
+        for i_x in 1..n_x.saturating_sub(1) {
+            for i_y in 1..n_y.saturating_sub(1) {
+                if i_x > 1 {
+                    diag[[i_x, i_y]] -= 1.0 / diag[[i_x - 1, i_y]];
+                }
+                if i_y > 1 {
+                    diag[[i_x, i_y]] -= 1.0 / diag[[i_x, i_y - 1]];
+                }
+            }
+        }
+
+        Self { diag }
+    }
+}
+
+impl Preconditioner for IncompleteCholesky {
+    fn apply(&self, residual: &Array2<f64>) -> Array2<f64> {
+        solve_factorized(residual, &self.diag, 1.0)
+    }
+}
+
+/// Kinds of the preconditioner, with the parameters specific to them.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "preconditioner", rename_all = "snake_case")]
+pub enum PreconditionerKind {
+    /// See [Identity].
+    Identity,
+    /// See [Jacobi].
+    Jacobi,
+    /// See [Ssor].
+    Ssor {
+        /// Relaxation parameter, `1.5` if omitted.
+        #[serde(default = "crate::input::default_omega")]
+        omega: f64,
+    },
+    /// See [IncompleteCholesky].
+    IncompleteCholesky,
+}
+
+impl PreconditionerKind {
+    /// Create the preconditioner for the grid with `dim` points including the boundary points.
+    ///
+    /// # Errors
+    /// Returns an error if the parameters are invalid.
+    pub fn create(&self, dim: (usize, usize)) -> Result<Box<dyn Preconditioner>, &'static str> {
+        Ok(match *self {
+            PreconditionerKind::Identity => Box::new(Identity),
+            PreconditionerKind::Jacobi => Box::new(Jacobi),
+            PreconditionerKind::Ssor { omega } => Box::new(Ssor::new(omega)?),
+            PreconditionerKind::IncompleteCholesky => Box::new(IncompleteCholesky::new(dim)),
+        })
+    }
+
+    /// Return the name of the preconditioner used in the input.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PreconditionerKind::Identity => "identity",
+            PreconditionerKind::Jacobi => "jacobi",
+            PreconditionerKind::Ssor { .. } => "ssor",
+            PreconditionerKind::IncompleteCholesky => "incomplete_cholesky",
+        }
+    }
+}
+
+/// Return `z` solving `(D + \omega L) D^{-1} (D + \omega U) z = r` for the diagonal `D` given by `diag`.
+fn solve_factorized(residual: &Array2<f64>, diag: &Array2<f64>, omega: f64) -> Array2<f64> {
+    let (n_x, n_y) = residual.dim();
+    let mut z = Array::zeros((n_x, n_y));
+    if n_x < 3 || n_y < 3 {
+        return residual * 0.25;
+    }
+
+    // forward substitution of (D + omega L) y = r, where the boundary values of y are zero
+    for i_x in 1..n_x - 1 {
+        for i_y in 1..n_y - 1 {
+            z[[i_x, i_y]] = (residual[[i_x, i_y]]
+                + omega * (z[[i_x - 1, i_y]] + z[[i_x, i_y - 1]]))
+                / diag[[i_x, i_y]];
+        }
+    }
+
+    // backward substitution of (D + omega U) z = D y
+    for i_x in (1..n_x - 1).rev() {
+        for i_y in (1..n_y - 1).rev() {
+            z[[i_x, i_y]] += omega * (z[[i_x + 1, i_y]] + z[[i_x, i_y + 1]]) / diag[[i_x, i_y]];
+        }
+    }
+
+    // the boundary points are decoupled from the interior points and preconditioned by the diagonal
+    for edge in [s![0, ..], s![n_x - 1, ..], s![.., 0], s![.., n_y - 1]] {
+        z.slice_mut(edge).assign(&(&residual.slice(edge) * 0.25));
+    }
+
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_incomplete_cholesky_apply_works() {
+        // setup the residual on the grid with 2 x 2 interior points, where A is 4 I - (couplings of neighbors)
+        let residual = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 2.0, 0.0],
+            [0.0, 3.0, 4.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0]
+        ];
+        let preconditioner = IncompleteCholesky::new(residual.dim());
+        let z = preconditioner.apply(&residual);
+
+        // check if M z = r, where M = A + (fill-in between u_{2,1} and u_{1,2}) = A + (1 / 4) e e^T
+        let m_z = |i_x: usize, i_y: usize| {
+            4.0 * z[[i_x, i_y]]
+                - z[[i_x - 1, i_y]]
+                - z[[i_x + 1, i_y]]
+                - z[[i_x, i_y - 1]]
+                - z[[i_x, i_y + 1]]
+        };
+        assert!((m_z(1, 1) - 1.0).abs() < 1e-10);
+        assert!((m_z(1, 2) + 0.25 * z[[2, 1]] - 2.0).abs() < 1e-10);
+        assert!((m_z(2, 1) + 0.25 * z[[1, 2]] - 3.0).abs() < 1e-10);
+        assert!((m_z(2, 2) - 4.0).abs() < 1e-10);
+        assert!(z.row(0).iter().chain(z.row(3).iter()).all(|z| *z == 0.0));
+    }
+}
@@ -0,0 +1,354 @@
+//! Solver for the diffusion equation using the red-black SOR method.
+//!
+//! # Scheme
+//! The points are colored red and black as a checkerboard by the parity of `j + k`,
+//! so that the 5-point stencil of a point consists only of the points of the other color.
+//! Each iteration relaxes all the red points `(j + k) \bmod 2 = 0` and then all the black points by the SOR method,
+//! ```math
+//! u_{j,k}^{n+1} = (1 - \omega) u_{j,k}^n
+//!     + \frac{\omega}{d} \left(\frac{u_{j-1,k} + u_{j+1,k}}{\Delta x^2} + \frac{u_{j,k-1} + u_{j,k+1}}{\Delta y^2} - f_{j,k}\right),
+//! ```
+//! where the neighbors of the black points have already been updated,
+//! `\omega \in [1, 2]` is the relaxation parameter
+//! and `d = 2 / \Delta x^2 + 2 / \Delta y^2` is the diagonal coefficient of the 5-point Laplacian.
+//! With the Helmholtz term, `d` is replaced with `d - k^2` as in [super::sor_solver].
+//!
+//! The red-black ordering is consistently ordered as well as the lexicographic ordering of [super::sor_solver],
+//! so that the convergence rate and the optimal relaxation parameter are the same,
+//! while all the points of each color can be relaxed independently.
+//!
+//! On the non-uniform [Grid], the coefficients `1 / \Delta x^2` and `1 / \Delta y^2` of the neighbors
+//! are replaced with those of the second differences given in [super::grid], and `d` with their sum.
+//!
+//! # Boundary Condition
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
+//! ```math
+//! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
+//! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well and skipped in the sweeps.
+//!
+//! # Parallelization
+//! With the `parallel` feature, the points of each color are relaxed in parallel by rayon.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
+use ndarray::prelude::*;
+use ndarray::Zip;
+use numerics::norms;
+use std::error::Error;
+
+/// Solver for the diffusion equation using the red-black SOR method.
+#[derive(Debug)]
+pub struct RedBlackSorSolver {
+    u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
+    n_iter_max: usize,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    convergence: Convergence,
+    omega: f64,
+    residual_history: Vec<f64>,
+    epsilon: f64,
+    n_iter: usize,
+    executed: bool,
+    converged: bool,
+}
+
+impl RedBlackSorSolver {
+    /// Create a new `RedBlackSorSolver` instance.
+    pub fn new(new_params: RedBlackSorSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+
+        Ok(Self {
+            u,
+            grid: new_params.grid,
+            source,
+            n_iter_max: new_params.n_iter_max,
+            k_squared: new_params.k_squared,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            omega: new_params.omega,
+            residual_history: vec![residual_norm],
+            epsilon: 1.0e-10,
+            n_iter: 0,
+            executed: false,
+            converged: false,
+        })
+    }
+
+    fn iterate(&mut self) {
+        let u_next = self.calculate_u_next();
+
+        self.converged = norms::linf(&(&u_next - &self.u)) <= self.epsilon;
+        self.u = u_next;
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
+        self.n_iter += 1;
+    }
+
+    fn calculate_u_next(&self) -> Array2<f64> {
+        let u_red = self.calculate_u_half(&self.u, 0);
+        self.calculate_u_half(&u_red, 1)
+    }
+
+    /// Return `u` with the points of the `color`, `(j + k) \bmod 2`, relaxed from the values of the other color.
+    fn calculate_u_half(&self, u: &Array2<f64>, color: usize) -> Array2<f64> {
+        let update = |(i_x, i_y): (usize, usize), &u_c: &f64, &is_fixed: &bool| {
+            if is_fixed || (i_x + i_y) % 2 != color {
+                return u_c;
+            }
+
+            let stencil = self.boundary_conditions.stencil(u, i_x, i_y, &self.grid);
+            (1.0 - self.omega) * u_c
+                + self.omega * (stencil.neighbors + stencil.offset - self.source[[i_x, i_y]])
+                    / (stencil.diag - self.k_squared)
+        };
+
+        // the points of the same color don't depend on each other
+        #[cfg(feature = "parallel")]
+        return Zip::indexed(u).and(&self.fixed).par_map_collect(update);
+        #[cfg(not(feature = "parallel"))]
+        Zip::indexed(u).and(&self.fixed).map_collect(update)
+    }
+}
+
+impl Solver for RedBlackSorSolver {
+    fn exec(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.executed {
+            return Err(Box::<dyn Error>::from("solver has already been executed"));
+        }
+        self.executed = true;
+
+        while !self.converged {
+            self.step()?;
+        }
+
+        Ok(())
+    }
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_n_iter(&self) -> usize {
+        self.n_iter
+    }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for RedBlackSorSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate();
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Parameters for creating a new `RedBlackSorSolver` instance.
+pub struct RedBlackSorSolverNewParams {
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
+    pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
+    /// Maximum number of iterations.
+    pub n_iter_max: usize,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Relaxation parameter.
+    pub omega: f64,
+}
+
+impl NewParams for RedBlackSorSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u_init.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
+        if self.n_iter_max == 0 {
+            return Err("n_iter_max must be positive");
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
+        }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+        if self.omega < 1.0 || self.omega > 2.0 {
+            return Err("omega must be between 1 and 2");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::BoundaryCondition;
+    use crate::solver::relaxation_parameter::RelaxationParameter;
+    use crate::solver::sor_solver::{SorSolver, SorSolverNewParams};
+
+    #[test]
+    fn fn_red_black_sor_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = RedBlackSorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: 1.5,
+        };
+        let mut solver = RedBlackSorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (&solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+        assert_eq!(solver.residual_history.len(), solver.n_iter + 1);
+    }
+
+    #[test]
+    fn fn_red_black_sor_exec_agrees_with_sor() {
+        // setup the problem with the Neumann boundary condition at x_+ and the obstacle
+        // and solve it by the red-black and lexicographic SOR methods with the optimal omega
+        let mut u_init: Array2<f64> = Array::zeros((17, 17));
+        u_init.slice_mut(s![.., 16]).fill(1.0);
+        u_init[[8, 8]] = 0.5;
+        let mut obstacle = Array::from_elem((17, 17), false);
+        obstacle[[8, 8]] = true;
+        let boundary_conditions = BoundaryConditions {
+            x_plus: BoundaryCondition::Neumann(0.0),
+            ..Default::default()
+        };
+        let omega = 1.7;
+        let mut red_black_sor_solver = RedBlackSorSolver::new(RedBlackSorSolverNewParams {
+            u_init: u_init.clone(),
+            grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: Some(obstacle.clone()),
+            omega,
+        })
+        .unwrap();
+        red_black_sor_solver.exec().unwrap();
+        let mut sor_solver = SorSolver::new(SorSolverNewParams {
+            u_init,
+            grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions,
+            obstacle: Some(obstacle),
+            omega: RelaxationParameter::Fixed(omega),
+        })
+        .unwrap();
+        sor_solver.exec().unwrap();
+
+        // check if the solutions agree with the comparable numbers of iterations
+        let is_u_consistent = (red_black_sor_solver.borrow_u() - sor_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_consistent);
+        let n_iter_ratio =
+            red_black_sor_solver.get_n_iter() as f64 / sor_solver.get_n_iter() as f64;
+        assert!((0.5..2.0).contains(&n_iter_ratio));
+    }
+}
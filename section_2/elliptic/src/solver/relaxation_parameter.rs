@@ -0,0 +1,111 @@
+//! Module for the relaxation parameter of the SOR method.
+//!
+//! The SOR method converges fastest with the optimal relaxation parameter
+//! ```math
+//! \omega_{opt} = \frac{2}{1 + \sqrt{1 - \rho_J^2}},
+//! ```
+//! where `\rho_J` is the spectral radius of the iteration matrix of the point Jacobi method,
+//! for which the spectral radius of the SOR method is `\omega_{opt} - 1`.
+//! Instead of guessing `\omega`, the solver can compute it from the grid or estimate it during the iteration.
+//! See [RelaxationParameter].
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Relaxation parameter of the SOR method, given as a number or as one of [Estimation] in the input,
+/// which is [RelaxationParameter::Fixed] with [default_omega](crate::input::default_omega) `1.5` by default,
+/// as for the solvers taking `\omega` only as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RelaxationParameter {
+    /// The given value of `\omega \in [1, 2]`.
+    Fixed(f64),
+    /// The value estimated by the solver.
+    Estimated(Estimation),
+}
+
+impl Default for RelaxationParameter {
+    fn default() -> Self {
+        RelaxationParameter::Fixed(crate::input::default_omega())
+    }
+}
+
+impl RelaxationParameter {
+    /// Validate the relaxation parameter.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if let RelaxationParameter::Fixed(omega) = self {
+            if *omega < 1.0 || *omega > 2.0 {
+                return Err("omega must be between 1 and 2");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Strategies to estimate the relaxation parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Estimation {
+    /// `\omega_{opt}` is computed from `\rho_J` given by
+    /// [BoundaryConditions::jacobi_spectral_radius](super::boundary::BoundaryConditions::jacobi_spectral_radius),
+    /// which depends only on the grid, the boundary conditions and `k^2`.
+    ///
+    /// If `\rho_J \ge 1`, e.g. with the fluxes at all the edges, the Gauss-Seidel method `\omega = 1` is used instead.
+    Optimal,
+    /// The iteration starts with the Gauss-Seidel method `\omega = 1`,
+    /// whose ratio of the norms of the successive changes `\|u^{n+1} - u^n\| / \|u^n - u^{n-1}\|`
+    /// approaches its spectral radius `\rho_J^2`.
+    /// Once the ratio settles within the relative tolerance `10^{-4}`,
+    /// `\omega_{opt}` is computed from it and used for the rest of the iteration.
+    ///
+    /// This also takes into account the obstacles and the Robin boundary conditions,
+    /// which [Estimation::Optimal] doesn't.
+    Adaptive,
+}
+
+/// Return the optimal relaxation parameter `\omega_{opt}` for the spectral radius `rho_jacobi` of the point Jacobi method,
+/// or `1` if `rho_jacobi` is not less than `1`.
+///
+/// # Examples
+/// ```
+/// use elliptic::solver::relaxation_parameter;
+///
+/// assert_eq!(relaxation_parameter::optimal_omega(0.0), 1.0);
+/// assert!((relaxation_parameter::optimal_omega(0.8) - 1.25).abs() < 1e-10);
+/// assert_eq!(relaxation_parameter::optimal_omega(1.0), 1.0);
+/// ```
+pub fn optimal_omega(rho_jacobi: f64) -> f64 {
+    if rho_jacobi >= 1.0 {
+        return 1.0;
+    }
+
+    2.0 / (1.0 + (1.0 - rho_jacobi * rho_jacobi).sqrt())
+}
+
+/// Return the spectral radius of the iteration matrix of the SOR method with the relaxation parameter `omega`
+/// for the spectral radius `rho_jacobi` of the point Jacobi method,
+/// ```math
+/// \rho_{SOR} = \begin{cases}
+///     \frac{1}{4} \left(\omega \rho_J + \sqrt{\omega^2 \rho_J^2 - 4 (\omega - 1)}\right)^2 & (\omega \le \omega_{opt}), \\
+///     \omega - 1 & (\omega > \omega_{opt}),
+/// \end{cases}
+/// ```
+/// which holds for the consistently ordered matrices such as the 5-point Laplacian.
+/// The Gauss-Seidel method `\omega = 1` gives `\rho_J^2`.
+///
+/// # Examples
+/// ```
+/// use elliptic::solver::relaxation_parameter;
+///
+/// assert!((relaxation_parameter::sor_spectral_radius(0.8, 1.0) - 0.64).abs() < 1e-10);
+/// assert!((relaxation_parameter::sor_spectral_radius(0.8, 1.25) - 0.25).abs() < 1e-10);
+/// assert!((relaxation_parameter::sor_spectral_radius(0.8, 1.5) - 0.5).abs() < 1e-10);
+/// ```
+pub fn sor_spectral_radius(rho_jacobi: f64, omega: f64) -> f64 {
+    let discriminant = omega * omega * rho_jacobi * rho_jacobi - 4.0 * (omega - 1.0);
+    if discriminant <= 0.0 {
+        return omega - 1.0;
+    }
+
+    0.25 * (omega * rho_jacobi + discriminant.sqrt()).powi(2)
+}
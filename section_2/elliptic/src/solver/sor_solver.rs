@@ -3,17 +3,40 @@
 //! # Scheme
 //! The SOR method is given by
 //! ```math
-//! u_{j,k}^{n+1} = u_{j,k}^n + \frac{1}{4} \omega (u_{j-1,k}^{n+1} + u_{j+1,k}^n - u_{j,k}^n + u_{j,k-1}^{n+1} + u_{j,k+1}^n),
+//! u_{j,k}^{n+1} = (1 - \omega) u_{j,k}^n
+//!     + \frac{\omega}{d} \left(\frac{u_{j-1,k}^{n+1} + u_{j+1,k}^n}{\Delta x^2} + \frac{u_{j,k-1}^{n+1} + u_{j,k+1}^n}{\Delta y^2}\right),
 //! ```
-//! where `\omega \in [1, 2]` is the relaxation parameter.
+//! where `\omega \in [1, 2]` is the relaxation parameter
+//! and `d = 2 / \Delta x^2 + 2 / \Delta y^2` is the diagonal coefficient of the 5-point Laplacian
+//! with the grid spacings `\Delta x` and `\Delta y`.
+//! The relaxation parameter is either given or estimated by the solver, see [super::relaxation_parameter].
+//!
+//! For the Poisson's equation `\nabla^2 u = f`,
+//! the source term `-\frac{\omega}{d} f_{j,k}` is added to the right-hand side.
+//! For the Helmholtz equation `\nabla^2 u + k^2 u = f`, the factor `\frac{1}{d}` of the Gauss-Seidel update
+//! is replaced with `\frac{1}{d - k^2}`, which diverges if `k^2` makes the operator indefinite,
+//! see [Convergence::for_helmholtz].
+//!
+//! Since `u_{j,k}^{n+1}` overwrites `u_{j,k}^n` as soon as it is computed, `u` is updated in place,
+//! and the change of each point is checked against the tolerance of the convergence during the sweep.
+//!
+//! On the non-uniform [Grid], the coefficients `1 / \Delta x^2` and `1 / \Delta y^2` of the neighbors
+//! are replaced with those of the second differences given in [super::grid], and `d` with their sum.
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
+//! The boundary condition at each edge is given by [BoundaryConditions],
+//! which fixes the boundary values by default as
 //! ```math
 //! u(x_{\pm}, y_{\pm}) = u_init(x_{\pm}, y_{\pm}).
 //! ```
+//! The points on the edges with the flux boundary conditions are solved together with the interior points.
+//! The points of the obstacle, if given, are fixed to the values of `u_init` as well and skipped in the sweeps.
 
-use super::{NewParams, Solver};
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use super::initial_guess::{InitialGuess, Problem};
+use super::relaxation_parameter::{self, Estimation, RelaxationParameter};
+use super::{Convergence, NewParams, RelaxationSolver, Solver};
 use ndarray::prelude::*;
 use std::error::Error;
 
@@ -21,8 +44,16 @@ use std::error::Error;
 #[derive(Debug)]
 pub struct SorSolver {
     u: Array2<f64>,
+    grid: Grid,
+    source: Array2<f64>,
     n_iter_max: usize,
+    k_squared: f64,
+    boundary_conditions: BoundaryConditions,
+    fixed: Array2<bool>,
+    convergence: Convergence,
     omega: f64,
+    omega_adaptation: Option<OmegaAdaptation>,
+    residual_history: Vec<f64>,
     epsilon: f64,
     n_iter: usize,
     executed: bool,
@@ -34,10 +65,59 @@ impl SorSolver {
     pub fn new(new_params: SorSolverNewParams) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
 
+        let eigenvalue_range = new_params
+            .boundary_conditions
+            .laplacian_eigenvalue_range(&new_params.grid);
+        let fixed = new_params
+            .boundary_conditions
+            .fixed_mask(new_params.u_init.dim(), new_params.obstacle.as_ref());
+        let mut u = new_params.u_init;
+        new_params.initial_guess.apply(
+            &mut u,
+            &Problem {
+                grid: &new_params.grid,
+                source: new_params.source.as_ref(),
+                k_squared: new_params.k_squared,
+                boundary_conditions: new_params.boundary_conditions,
+                obstacle: new_params.obstacle.as_ref(),
+            },
+        );
+        new_params.boundary_conditions.apply_fixed(&mut u);
+        let source = new_params.source.unwrap_or_else(|| Array::zeros(u.dim()));
+        let (omega, omega_adaptation) = match new_params.omega {
+            RelaxationParameter::Fixed(omega) => (omega, None),
+            RelaxationParameter::Estimated(Estimation::Optimal) => (
+                relaxation_parameter::optimal_omega(
+                    new_params
+                        .boundary_conditions
+                        .jacobi_spectral_radius(&new_params.grid, new_params.k_squared),
+                ),
+                None,
+            ),
+            RelaxationParameter::Estimated(Estimation::Adaptive) => {
+                (1.0, Some(OmegaAdaptation::default()))
+            }
+        };
+        let residual_norm = new_params.boundary_conditions.residual_norm(
+            &u,
+            &new_params.grid,
+            &source,
+            new_params.k_squared,
+            &fixed,
+        );
+
         Ok(Self {
-            u: new_params.u_init,
+            u,
+            grid: new_params.grid,
+            source,
             n_iter_max: new_params.n_iter_max,
-            omega: new_params.omega,
+            k_squared: new_params.k_squared,
+            boundary_conditions: new_params.boundary_conditions,
+            fixed,
+            convergence: Convergence::for_helmholtz(new_params.k_squared, eigenvalue_range),
+            omega,
+            omega_adaptation,
+            residual_history: vec![residual_norm],
             epsilon: 1.0e-10,
             n_iter: 0,
             executed: false,
@@ -46,36 +126,91 @@ impl SorSolver {
     }
 
     fn iterate(&mut self) {
-        let u_next = self.calculate_u_next();
+        let (change_max, change_norm) = self.sweep();
 
-        self.converged = (&u_next - &self.u).iter().all(|u| u.abs() <= self.epsilon);
-        self.u = u_next;
+        self.converged = change_max <= self.epsilon;
+        if let Some(omega_adaptation) = self.omega_adaptation.as_mut() {
+            if let Some(omega) = omega_adaptation.update(change_norm) {
+                self.omega = omega;
+                self.omega_adaptation = None;
+            }
+        }
+        self.residual_history
+            .push(self.boundary_conditions.residual_norm(
+                &self.u,
+                &self.grid,
+                &self.source,
+                self.k_squared,
+                &self.fixed,
+            ));
         self.n_iter += 1;
     }
 
-    fn calculate_u_next(&self) -> Array2<f64> {
-        let mut u_next = self.u.clone();
-        for i_x in 1..self.u.shape()[0] - 1 {
-            for i_y in 1..self.u.shape()[1] - 1 {
-                if i_x == 0
-                    || i_x == self.u.shape()[0] - 1
-                    || i_y == 0
-                    || i_y == self.u.shape()[1] - 1
-                {
+    /// Update `u` in place by a sweep and return the maximum and L2 norms of the change,
+    /// where the maximum norm is NaN if any change is NaN.
+    fn sweep(&mut self) -> (f64, f64) {
+        let mut change_max: f64 = 0.0;
+        let mut change_norm_squared = 0.0;
+        let dim = self.u.dim();
+        for i_x in 0..dim.0 {
+            for i_y in 0..dim.1 {
+                if self.fixed[[i_x, i_y]] {
                     continue;
                 }
 
-                u_next[[i_x, i_y]] = (1.0 - self.omega) * u_next[[i_x, i_y]]
-                    + 0.25
-                        * self.omega
-                        * (u_next[[i_x - 1, i_y]]
-                            + u_next[[i_x + 1, i_y]]
-                            + u_next[[i_x, i_y - 1]]
-                            + u_next[[i_x, i_y + 1]]);
+                let stencil = self
+                    .boundary_conditions
+                    .stencil(&self.u, i_x, i_y, &self.grid);
+                let u_prev = self.u[[i_x, i_y]];
+                let u_next = (1.0 - self.omega) * u_prev
+                    + self.omega * (stencil.neighbors + stencil.offset - self.source[[i_x, i_y]])
+                        / (stencil.diag - self.k_squared);
+                let change = u_next - u_prev;
+                // NaN is propagated as by numerics::norms::linf, so that a diverged sweep never converges
+                change_max = if change_max.is_nan() || change.is_nan() {
+                    f64::NAN
+                } else {
+                    change_max.max(change.abs())
+                };
+                change_norm_squared += change * change;
+                self.u[[i_x, i_y]] = u_next;
             }
         }
 
-        u_next
+        (change_max, change_norm_squared.sqrt())
+    }
+
+    /// Return the relaxation parameter, which is the current estimate with [Estimation::Adaptive].
+    pub fn get_omega(&self) -> f64 {
+        self.omega
+    }
+}
+
+/// State of the estimation of `\omega` by [Estimation::Adaptive] during the Gauss-Seidel iterations.
+#[derive(Debug, Default)]
+struct OmegaAdaptation {
+    change_norm: Option<f64>,
+    change_ratio: Option<f64>,
+}
+
+impl OmegaAdaptation {
+    /// Update the state with the norm of the latest change and return the estimated `\omega` once the ratio settles.
+    fn update(&mut self, change_norm: f64) -> Option<f64> {
+        let change_ratio = self
+            .change_norm
+            .filter(|&change_norm_prev| change_norm_prev > 0.0)
+            .map(|change_norm_prev| change_norm / change_norm_prev);
+        let is_settled = match (self.change_ratio, change_ratio) {
+            (Some(ratio_prev), Some(ratio)) => {
+                ratio < 1.0 && (ratio - ratio_prev).abs() <= 1.0e-4 * ratio
+            }
+            _ => false,
+        };
+        self.change_norm = Some(change_norm);
+        self.change_ratio = change_ratio;
+
+        // the ratio approaches the spectral radius \rho_J^2 of the Gauss-Seidel method
+        is_settled.then(|| relaxation_parameter::optimal_omega(change_ratio.unwrap_or(0.0).sqrt()))
     }
 }
 
@@ -87,13 +222,7 @@ impl Solver for SorSolver {
         self.executed = true;
 
         while !self.converged {
-            if self.n_iter >= self.n_iter_max {
-                return Err(Box::<dyn Error>::from(
-                    "maximum number of iterations reached",
-                ));
-            }
-
-            self.iterate();
+            self.step()?;
         }
 
         Ok(())
@@ -106,16 +235,59 @@ impl Solver for SorSolver {
     fn get_n_iter(&self) -> usize {
         self.n_iter
     }
+
+    fn borrow_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn borrow_residual_history(&self) -> &Vec<f64> {
+        &self.residual_history
+    }
+
+    fn get_convergence(&self) -> Convergence {
+        self.convergence
+    }
+}
+
+impl RelaxationSolver for SorSolver {
+    fn step(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.n_iter >= self.n_iter_max {
+            return Err(Box::<dyn Error>::from(
+                "maximum number of iterations reached",
+            ));
+        }
+        self.executed = true;
+
+        self.iterate();
+
+        Ok(())
+    }
+
+    fn is_converged(&self) -> bool {
+        self.converged
+    }
 }
 
 /// Parameters for creating a new `SorSolver` instance.
 pub struct SorSolverNewParams {
-    /// Initial values of `u`.
+    /// Initial values of `u`, of which the boundary values are used as the Dirichlet boundary condition.
     pub u_init: Array2<f64>,
+    /// Grid with the same shape as `u_init`.
+    pub grid: Grid,
     /// Maximum number of iterations.
     pub n_iter_max: usize,
-    /// Relaxation parameter.
-    pub omega: f64,
+    /// Strategy to construct the initial guess of the interior values.
+    pub initial_guess: InitialGuess,
+    /// Source term `f` of the Poisson's equation at each point, or `None` for the Laplace's equation.
+    pub source: Option<Array2<f64>>,
+    /// Coefficient `k^2` of the Helmholtz term, `0` for the Laplace's and Poisson's equations.
+    pub k_squared: f64,
+    /// Boundary conditions at the edges.
+    pub boundary_conditions: BoundaryConditions,
+    /// Mask of the points whose values are fixed to those of `u_init` as the obstacles, or `None` for no obstacles.
+    pub obstacle: Option<Array2<bool>>,
+    /// Relaxation parameter, given or estimated.
+    pub omega: RelaxationParameter,
 }
 
 impl NewParams for SorSolverNewParams {
@@ -123,12 +295,34 @@ impl NewParams for SorSolverNewParams {
         if self.u_init.is_empty() {
             return Err("u must not be empty");
         }
+        if self.grid.dim() != self.u_init.dim() {
+            return Err("grid must have the same shape as u");
+        }
         if self.n_iter_max == 0 {
             return Err("n_iter_max must be positive");
         }
-        if self.omega < 1.0 || self.omega > 2.0 {
-            return Err("omega must be between 1 and 2");
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| source.dim() != self.u_init.dim())
+        {
+            return Err("source must have the same shape as u");
         }
+        if self
+            .obstacle
+            .as_ref()
+            .is_some_and(|obstacle| obstacle.dim() != self.u_init.dim())
+        {
+            return Err("obstacle must have the same shape as u");
+        }
+        self.boundary_conditions.validate(self.u_init.dim())?;
+        self.boundary_conditions.validate_compatibility(
+            &self.grid,
+            self.source.as_ref(),
+            self.k_squared,
+            self.obstacle.as_ref(),
+        )?;
+        self.omega.validate()?;
 
         Ok(())
     }
@@ -137,6 +331,7 @@ impl NewParams for SorSolverNewParams {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::BoundaryCondition;
 
     #[test]
     fn fn_sor_exec_works() {
@@ -148,9 +343,15 @@ mod tests {
             [0.0, 0.0, 0.0, 1.0]
         ];
         let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
             u_init,
             n_iter_max: 100,
-            omega: 1.5,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
         };
         let mut solver = SorSolver::new(new_params).unwrap();
         solver.exec().unwrap();
@@ -164,5 +365,339 @@ mod tests {
         ];
         let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
+
+        // check if the residuals are recorded at every iteration including the initial one
+        let residual_history = &solver.residual_history;
+        assert_eq!(residual_history.len(), solver.n_iter + 1);
+        assert!((residual_history[0] - 2.0f64.sqrt()).abs() < 1e-10);
+        assert!(*residual_history.last().unwrap() < 1e-8);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_source() {
+        // setup u = j^2 + k^2 on the boundary and the source f = 4, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_helmholtz_term() {
+        // setup u = j^2 + k^2 on the boundary, the coefficient k^2 = 0.5 of the Helmholtz term
+        // and the source f = 4 + 0.5 u, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(u_exact.mapv(|u| 4.0 + 0.5 * u)),
+            k_squared: 0.5,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_rectangular_grid() {
+        // setup u = x^2 + 2 y^2 with the grid spacings dx = 0.5 and dy = 0.25,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (0.5 * i_x as f64, 0.25 * i_y as f64);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (0.5, 0.25)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_non_uniform_grid() {
+        // setup u = x^2 + 2 y^2 on the grid clustered near x_- and y_+,
+        // the flux u_x = 5 at x_+ and the source f = 6, for which u is the exact solution
+        // as the second difference on the non-uniform grid is exact for the quadratic function
+        let grid = Grid::new(
+            array![0.0, 0.1, 0.3, 0.7, 1.5, 2.5],
+            array![0.0, 0.6, 1.0, 1.25, 1.4, 1.48, 1.5],
+        )
+        .unwrap();
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| {
+            let (x, y) = (grid.borrow_x()[i_x], grid.borrow_y()[i_y]);
+            x * x + 2.0 * y * y
+        });
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1.., 1..6]).fill(0.0);
+        let new_params = SorSolverNewParams {
+            u_init,
+            grid,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 6.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_plus: BoundaryCondition::Neumann(5.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_obstacle() {
+        // setup u = j^2 + k^2 on the boundary and the obstacle at j = 2, 3 and k = 3 and the source f = 4,
+        // for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let obstacle = Array::from_shape_fn((6, 7), |(i_x, i_y)| (2..4).contains(&i_x) && i_y == 3);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![1..5, 1..6]).fill(0.0);
+        u_init
+            .slice_mut(s![2..4, 3])
+            .assign(&u_exact.slice(s![2..4, 3]));
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Bilinear,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: Some(obstacle),
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution with the obstacle kept fixed
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_neumann_boundary_condition() {
+        // setup u = j^2 + k^2 with the fluxes u_x = 0 at x_- and u_x = 10 at x_+ and the source f = 4,
+        // for which u is the exact solution as the central difference of the quadratic function is exact
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let mut u_init = u_exact.clone();
+        u_init.slice_mut(s![.., 1..6]).fill(0.0);
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                ..Default::default()
+            },
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.2),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution including the edges x = x_pm
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_robin_boundary_condition() {
+        // setup u = k^2 with u = 0 at y_-, u + u_y = 48 at y_+, the insulated edges x = x_pm and the source f = 2,
+        // for which u is the exact solution
+        let u_exact = Array::from_shape_fn((6, 7), |(_, i_y)| (i_y * i_y) as f64);
+        let new_params = SorSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 2.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(0.0),
+                y_minus: BoundaryCondition::Dirichlet,
+                y_plus: BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: 1.0,
+                    g: 48.0,
+                },
+            },
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.2),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u is converged to the exact solution
+        let is_u_correctly_updated = (solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_all_neumann_boundary_conditions() {
+        // setup u = j^2 + k^2 with the fluxes at all the edges and the source f = 4,
+        // which satisfy the compatibility condition and determine u up to a constant
+        let u_exact = Array::from_shape_fn((6, 7), |(i_x, i_y)| (i_x * i_x + i_y * i_y) as f64);
+        let new_params = SorSolverNewParams {
+            u_init: Array::zeros((6, 7)),
+            grid: Grid::uniform((6, 7), (1.0, 1.0)).unwrap(),
+            n_iter_max: 10000,
+            initial_guess: InitialGuess::Zero,
+            source: Some(Array::from_elem((6, 7), 4.0)),
+            k_squared: 0.0,
+            boundary_conditions: BoundaryConditions {
+                x_minus: BoundaryCondition::Neumann(0.0),
+                x_plus: BoundaryCondition::Neumann(10.0),
+                y_minus: BoundaryCondition::Neumann(0.0),
+                y_plus: BoundaryCondition::Neumann(12.0),
+            },
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.2),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        solver.exec().unwrap();
+
+        // check if u differs from the exact solution by a constant
+        let u_diff = solver.u - u_exact;
+        let is_u_correctly_updated = u_diff.iter().all(|u| (u - u_diff[[0, 0]]).abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_sor_exec_does_not_converge_when_diverged() {
+        // setup the Helmholtz term with k^2 close to the center of the eigenvalues of -\nabla^2 in [0, 8],
+        // for which the sweeps amplify u until it overflows to inf and NaN
+        let mut u_init: Array2<f64> = Array::zeros((6, 6));
+        u_init.slice_mut(s![.., 5]).fill(1.0);
+        let new_params = SorSolverNewParams {
+            grid: Grid::uniform(u_init.dim(), (1.0, 1.0)).unwrap(),
+            u_init,
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 3.99,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Fixed(1.5),
+        };
+        let mut solver = SorSolver::new(new_params).unwrap();
+        assert!(matches!(
+            solver.get_convergence(),
+            Convergence::Divergent(_)
+        ));
+
+        // check if the NaN does not satisfy the convergence criterion and the iteration runs out
+        assert!(solver.exec().is_err());
+        assert!(!solver.is_converged());
+        assert!(solver.u.iter().any(|u| u.is_nan()));
+    }
+
+    #[test]
+    fn fn_sor_new_works_with_optimal_omega() {
+        // setup the square with 16 x 16 cells, for which \rho_J = \cos(\pi / 16)
+        let new_params = SorSolverNewParams {
+            u_init: Array::zeros((17, 17)),
+            grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+            n_iter_max: 1000,
+            initial_guess: InitialGuess::Zero,
+            source: None,
+            k_squared: 0.0,
+            boundary_conditions: Default::default(),
+            obstacle: None,
+            omega: RelaxationParameter::Estimated(Estimation::Optimal),
+        };
+        let solver = SorSolver::new(new_params).unwrap();
+
+        // check if omega is 2 / (1 + \sin(\pi / 16))
+        let omega_expected = 2.0 / (1.0 + (std::f64::consts::PI / 16.0).sin());
+        assert!((solver.get_omega() - omega_expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn fn_sor_exec_works_with_adaptive_omega() {
+        // setup the problem on the square with 16 x 16 cells and solve it by the Gauss-Seidel and adaptive SOR methods
+        let mut u_init: Array2<f64> = Array::zeros((17, 17));
+        u_init.slice_mut(s![.., 16]).fill(1.0);
+        let solve = |omega: RelaxationParameter| {
+            let mut solver = SorSolver::new(SorSolverNewParams {
+                u_init: u_init.clone(),
+                grid: Grid::uniform((17, 17), (1.0, 1.0)).unwrap(),
+                n_iter_max: 10000,
+                initial_guess: InitialGuess::Zero,
+                source: None,
+                k_squared: 0.0,
+                boundary_conditions: Default::default(),
+                obstacle: None,
+                omega,
+            })
+            .unwrap();
+            solver.exec().unwrap();
+            solver
+        };
+        let gauss_seidel_solver = solve(RelaxationParameter::Fixed(1.0));
+        let adaptive_solver = solve(RelaxationParameter::Estimated(Estimation::Adaptive));
+
+        // check if omega approaches the optimal one and the solution agrees with fewer iterations
+        let omega_optimal = 2.0 / (1.0 + (std::f64::consts::PI / 16.0).sin());
+        assert!((adaptive_solver.get_omega() - omega_optimal).abs() < 0.02);
+        let is_u_consistent = (gauss_seidel_solver.borrow_u() - adaptive_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_consistent);
+        assert!(adaptive_solver.get_n_iter() < gauss_seidel_solver.get_n_iter() / 2);
     }
 }
@@ -0,0 +1,183 @@
+//! Module to estimate the spectral radii of the iteration matrices of the relaxation methods.
+//!
+//! The error `e^n = u^n - u` of a relaxation method is multiplied by the iteration matrix `G` at each iteration,
+//! `e^{n+1} = G e^n`, so that the error decays as `\rho(G)^n` asymptotically,
+//! where `\rho(G)` is the spectral radius of `G`.
+//! This module estimates `\rho(G)` by the power iteration,
+//! that is, by iterating the method for the homogeneous problem `f = 0` with zero boundary values
+//! from the initial error `e^0 = 1` at the unknown points and measuring the decay of `\|e^n\|`.
+//!
+//! For the point Jacobi method on the uniform grid, the estimate can be compared with
+//! [BoundaryConditions::jacobi_spectral_radius], and for the SOR method with
+//! [relaxation_parameter::sor_spectral_radius](super::relaxation_parameter::sor_spectral_radius).
+//! The observed convergence rate of a solver is given by [convergence_rate] from its history of the residuals.
+
+use super::boundary::BoundaryConditions;
+use super::grid::Grid;
+use ndarray::prelude::*;
+
+/// Relaxation method whose iteration matrix is analyzed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelaxationMethod {
+    /// Point Jacobi method, see [super::point_jacobi_solver].
+    PointJacobi,
+    /// SOR method with the relaxation parameter `\omega`, see [super::sor_solver],
+    /// which is the Gauss-Seidel method for `\omega = 1`.
+    Sor(f64),
+}
+
+/// Return the spectral radius of the iteration matrix of the `method` for `\nabla^2 u + k^2 u = f`
+/// discretized on the `grid` with the `boundary_conditions`, estimated by the power iteration of `n_iter` iterations.
+///
+/// The decay rate of `\|e^n\|` is averaged over the latter half of the iterations,
+/// since the components of the error other than the slowest one have to decay first.
+/// If there is no unknown point, `0` is returned.
+///
+/// # Examples
+/// ```
+/// use elliptic::solver::grid::Grid;
+/// use elliptic::solver::spectral_radius::{self, RelaxationMethod};
+///
+/// let grid = Grid::uniform((17, 17), (1.0, 1.0)).unwrap();
+/// let rho = spectral_radius::estimate(RelaxationMethod::PointJacobi, &grid, &Default::default(), 0.0, 1000)
+///     .unwrap();
+/// assert!((rho - (std::f64::consts::PI / 16.0).cos()).abs() < 1e-3);
+/// ```
+///
+/// # Errors
+/// Returns an error if `n_iter` is less than 2 or `\omega` is not in `(0, 2)`.
+pub fn estimate(
+    method: RelaxationMethod,
+    grid: &Grid,
+    boundary_conditions: &BoundaryConditions,
+    k_squared: f64,
+    n_iter: usize,
+) -> Result<f64, &'static str> {
+    if n_iter < 2 {
+        return Err("n_iter must be at least 2");
+    }
+    if let RelaxationMethod::Sor(omega) = method {
+        if omega <= 0.0 || omega >= 2.0 {
+            return Err("omega must be between 0 and 2");
+        }
+    }
+    boundary_conditions.validate(grid.dim())?;
+
+    let boundary_conditions = boundary_conditions.homogeneous();
+    let fixed = boundary_conditions.fixed_mask(grid.dim(), None);
+    let mut error = fixed.mapv(|is_fixed| if is_fixed { 0.0 } else { 1.0 });
+    let mut error_norm = norm(&error);
+    if error_norm == 0.0 {
+        return Ok(0.0);
+    }
+
+    let mut log_ratio_sum = 0.0;
+    for n in 0..n_iter {
+        error /= error_norm;
+        match method {
+            RelaxationMethod::PointJacobi => {
+                let error_prev = error.clone();
+                for ((i_x, i_y), &is_fixed) in fixed.indexed_iter() {
+                    if is_fixed {
+                        continue;
+                    }
+
+                    let stencil = boundary_conditions.stencil(&error_prev, i_x, i_y, grid);
+                    error[[i_x, i_y]] = stencil.neighbors / (stencil.diag - k_squared);
+                }
+            }
+            RelaxationMethod::Sor(omega) => {
+                for ((i_x, i_y), &is_fixed) in fixed.indexed_iter() {
+                    if is_fixed {
+                        continue;
+                    }
+
+                    let stencil = boundary_conditions.stencil(&error, i_x, i_y, grid);
+                    error[[i_x, i_y]] = (1.0 - omega) * error[[i_x, i_y]]
+                        + omega * stencil.neighbors / (stencil.diag - k_squared);
+                }
+            }
+        }
+
+        error_norm = norm(&error);
+        if error_norm == 0.0 {
+            return Ok(0.0);
+        }
+        if n >= n_iter / 2 {
+            log_ratio_sum += error_norm.ln();
+        }
+    }
+
+    Ok((log_ratio_sum / (n_iter - n_iter / 2) as f64).exp())
+}
+
+/// Return the observed convergence rate, the mean ratio of the successive norms of the residuals,
+/// over the latter half of the `residual_history` given by [Solver::borrow_residual_history](super::Solver::borrow_residual_history).
+///
+/// # Examples
+/// ```
+/// use elliptic::solver::spectral_radius;
+///
+/// let residual_history = [1.0, 0.5, 0.25, 0.125, 0.0625];
+/// assert!((spectral_radius::convergence_rate(&residual_history) - 0.5).abs() < 1e-10);
+/// ```
+pub fn convergence_rate(residual_history: &[f64]) -> f64 {
+    let n_iter = residual_history.len().saturating_sub(1);
+    if n_iter == 0 {
+        return 0.0;
+    }
+
+    let n_start = n_iter / 2;
+    (residual_history[n_iter] / residual_history[n_start]).powf(1.0 / (n_iter - n_start) as f64)
+}
+
+fn norm(u: &Array2<f64>) -> f64 {
+    u.iter().map(|u| u * u).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::relaxation_parameter;
+
+    #[test]
+    fn fn_estimate_works_with_sor_method() {
+        // setup the rectangle with 12 x 8 cells, for which \rho_J is given in the closed form
+        let grid = Grid::uniform((13, 9), (1.0, 1.0)).unwrap();
+        let boundary_conditions = BoundaryConditions::default();
+        let rho_jacobi = boundary_conditions.jacobi_spectral_radius(&grid, 0.0);
+
+        // check if the estimates agree with the theory of the Gauss-Seidel and SOR methods
+        for omega in [1.0, 1.3] {
+            let rho = estimate(
+                RelaxationMethod::Sor(omega),
+                &grid,
+                &boundary_conditions,
+                0.0,
+                2000,
+            )
+            .unwrap();
+            let rho_expected = relaxation_parameter::sor_spectral_radius(rho_jacobi, omega);
+            assert!((rho - rho_expected).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn fn_estimate_fails_with_invalid_omega() {
+        // setup the SOR method with omega out of the range of the convergence
+        let grid = Grid::uniform((5, 5), (1.0, 1.0)).unwrap();
+
+        // check if the estimate fails
+        assert_eq!(
+            estimate(
+                RelaxationMethod::Sor(2.0),
+                &grid,
+                &Default::default(),
+                0.0,
+                100
+            )
+            .unwrap_err(),
+            "omega must be between 0 and 2"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Benchmark of a time step of the explicit stencil solvers on large grids.
+//!
+//! Run by
+//! ```shell
+//! cargo bench -p linear_hyperbolic --bench explicit_stencils
+//! ```
+//!
+//! Each solver is advanced by one step of `n_cfl = 0.5` from the Gaussian pulse,
+//! on the grids with `10^4` and `10^6` cells under the Dirichlet and periodic boundary conditions.
+//!
+//! # Results
+//! The time of a step with `10^6` cells, before and after the stencil loops over `indexed_iter()`
+//! were replaced with the slice arithmetic by `azip!`, measured on a single core of x86_64 Linux:
+//!
+//! | scheme        | Dirichlet (before) | Dirichlet (after) | periodic (before) | periodic (after) |
+//! | ------------- | ------------------ | ----------------- | ----------------- | ---------------- |
+//! | lax           | 5.76 ms            | 0.80 ms           | 12.0 ms           | 0.72 ms          |
+//! | laxfriedrichs | 6.56 ms            | 1.07 ms           | 11.2 ms           | 0.84 ms          |
+//! | laxwendroff   | 8.59 ms            | 2.09 ms           | 13.1 ms           | 1.82 ms          |
+//! | leapfrog      | 6.96 ms            | 1.10 ms           | 11.3 ms           | 1.10 ms          |
+//! | maccormack    | 8.47 ms            | 1.85 ms           | 16.2 ms           | 1.75 ms          |
+//! | muscl         | 31.1 ms            | 4.68 ms           | 53.0 ms           | 4.31 ms          |
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use linear_hyperbolic::initial_conditions::Profile;
+use linear_hyperbolic::scheme::{BoundaryConditionKind, SchemeKind, SchemeParams};
+use linear_hyperbolic::solver::muscl_solver::FluxLimiter;
+use ndarray::prelude::*;
+
+fn bench_explicit_stencils(c: &mut Criterion) {
+    let schemes = [
+        SchemeKind::Lax,
+        SchemeKind::Laxfriedrichs { epsilon: 0.75 },
+        SchemeKind::Laxwendroff,
+        SchemeKind::Leapfrog,
+        SchemeKind::Maccormack,
+        SchemeKind::Muscl {
+            limiter: FluxLimiter::Minmod,
+        },
+    ];
+    let profile = Profile::Gaussian {
+        x_0: 0.0,
+        width: 0.1,
+    };
+
+    for boundary_condition in [
+        BoundaryConditionKind::Dirichlet,
+        BoundaryConditionKind::Periodic,
+    ] {
+        let mut group = c.benchmark_group(format!("explicit_stencils/{:?}", boundary_condition));
+        for n_x in [10_000, 1_000_000] {
+            let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+            let u = profile.create(&x);
+            for scheme in schemes {
+                let mut solver = scheme
+                    .create_solver(SchemeParams {
+                        u: u.clone(),
+                        step_max: usize::MAX,
+                        n_cfl: 0.5,
+                        dt: 0.5 * (x[1] - x[0]),
+                        boundary_condition: boundary_condition.create(&u),
+                    })
+                    .unwrap();
+                group.bench_with_input(BenchmarkId::new(scheme.name(), n_x), &n_x, |b, _| {
+                    b.iter(|| solver.integrate().unwrap())
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_explicit_stencils);
+criterion_main!(benches);
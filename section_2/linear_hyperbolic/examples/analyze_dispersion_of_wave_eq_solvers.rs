@@ -0,0 +1,107 @@
+//! Analyze the dispersive and dissipative errors of a scheme for the transport equation.
+//!
+//! The damping factor and the numerical phase speed per step are measured for each wavenumber
+//! by [linear_hyperbolic::dispersion::analyze], which probes the scheme with a single Fourier mode.
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0,
+//! ```
+//! where `u` is the transported quantity and `c` is the advection velocity,
+//! with the periodic boundary condition.
+//!
+//! # Scheme
+//! One of [SchemeKind](linear_hyperbolic::scheme::SchemeKind) is chosen. The schemes which do not support the periodic boundary condition are excluded,
+//! and the results of the nonlinear schemes depend on the amplitude of the probing mode.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! scheme: laxwendroff
+//! n_x: 64
+//! n_cfl: 0.5
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecAnalyzeDispersionInputParams].
+//!
+//! # Output Format
+//! The responses are output to `dispersion.dat`. See [linear_hyperbolic::dispersion::output] for the format.
+
+use cli::check::Report;
+use linear_hyperbolic::dispersion;
+use linear_hyperbolic::input;
+use linear_hyperbolic::input::examples::ExecAnalyzeDispersionInputParams;
+use linear_hyperbolic::scheme::SchemeParams;
+use linear_hyperbolic::solver::boundary::Periodic;
+use linear_hyperbolic::solver::Solver;
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Analyze the scheme with the given input parameters and output the responses to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/linear_hyperbolic/analyze_dispersion_of_wave_eq_solvers");
+
+    // read input parameters
+    let input_params: ExecAnalyzeDispersionInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    // the stability is that of the scheme on the periodic grid of the same resolution
+    exec_args.exit_if_check(|| {
+        let stability = input_params
+            .scheme
+            .create_solver(SchemeParams {
+                u: Array1::<f64>::zeros(input_params.n_x + 1),
+                step_max: 1,
+                n_cfl: input_params.n_cfl,
+                dt: input_params.n_cfl.abs(),
+                boundary_condition: Box::new(Periodic),
+            })
+            .map_or_else(
+                |err| format!("unknown: {}", err),
+                |solver| solver.get_stability().to_string(),
+            );
+        Report::new()
+            .quantity("n_x", input_params.n_x)
+            .quantity("nu", format!("{:.10e}", input_params.n_cfl))
+            .stability(stability)
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("dispersion.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // analyze and output
+    // the grid spacing and the speed of the advection are taken as 1
+    let mode_responses = dispersion::analyze(input_params.n_x, input_params.n_cfl, |u| {
+        input_params.scheme.create_solver(SchemeParams {
+            u,
+            step_max: 1,
+            n_cfl: input_params.n_cfl,
+            dt: input_params.n_cfl.abs(),
+            boundary_condition: Box::new(Periodic),
+        })
+    })
+    .unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+    dispersion::output(&mut outputfile, &mode_responses).unwrap_or_else(|err| {
+        eprintln!("Problem writing output files: {}", err);
+        process::exit(1);
+    });
+}
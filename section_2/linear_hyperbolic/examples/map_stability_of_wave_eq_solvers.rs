@@ -0,0 +1,222 @@
+//! Map the stability of a scheme for the transport equation by sweeping the CFL number.
+//!
+//! The chosen scheme is run for each CFL number of the sweep on the same grid until the wave travels the same distance,
+//! and the solution is regarded as diverged once `\max |u|` exceeds the initial one by the factor `growth_limit`.
+//! The observed stability is recorded together with the stability expected from the known stability bound
+//! (see [linear_hyperbolic::solver::Stability]), so the stability boundaries of the book can be reproduced as data.
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0 (x \in [-1, 1]),
+//! ```
+//! where `u` is the transported quantity and `c` (`> 0`) is the advection velocity.
+//!
+//! The initial condition is given by the Gaussian pulse
+//! ```math
+//! u(x, 0) = \exp (- \frac{(x + 0.5)^2}{0.1^2}),
+//! ```
+//! so that the exact solution is given by
+//! ```math
+//! u(x, t) = u(x - c t, 0)
+//! ```
+//! as long as the pulse stays away from the boundaries, or for the periodic boundary condition.
+//!
+//! The boundary condition is selected in the input file. See [BoundaryConditionKind].
+//!
+//! # Scheme
+//! One of [SchemeKind](linear_hyperbolic::scheme::SchemeKind) is chosen, together with the parameters specific to it.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! scheme: laxwendroff
+//! boundary_condition: dirichlet
+//! n_x: 100
+//! n_cfl_min: 0.1
+//! n_cfl_max: 2.0
+//! n_cfl_count: 20
+//! ct_end: 1.0
+//! growth_limit: 10.0
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecMapStabilityInputParams].
+//!
+//! # Output Format
+//! The stability map is output to `stability_map.dat` in the following format:
+//! ```text
+//! n_cfl expected diverged step_diverged error_l1 error_l2 error_linf
+//! 0.1000000000 stable 0 - 1.2345678900e-2 1.2345678900e-2 1.2345678900e-2
+//! 1.1000000000 unstable 1 37 - - -
+//! ```
+//! where `expected` is the expected stability (`stable`, `unstable` or `unknown`),
+//! `diverged` is 1 if the solution has diverged and 0 otherwise, and `step_diverged` is the step of the divergence.
+//! The errors against the exact solution are given at the end of the calculation unless the solution has diverged.
+//!
+//! The largest CFL number up to which the solution has not diverged is printed to the standard output.
+
+use cli::check::Report;
+use linear_hyperbolic::exact::{self, ErrorNorms};
+use linear_hyperbolic::initial_conditions::Profile;
+use linear_hyperbolic::input;
+use linear_hyperbolic::input::examples::ExecMapStabilityInputParams;
+use linear_hyperbolic::scheme::{BoundaryConditionKind, SchemeParams};
+use linear_hyperbolic::solver::{Solver, Stability};
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Run the scheme for each CFL number and output the stability map to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/linear_hyperbolic/map_stability_of_wave_eq_solvers");
+
+    // read input parameters
+    let input_params: ExecMapStabilityInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    // the stability is not predicted, since it is mapped by the sweep
+    exec_args.exit_if_check(|| {
+        let dx = 2.0 / input_params.n_x as f64;
+        let step_max = |n_cfl: f64| ((input_params.ct_end / (n_cfl * dx)).round() as usize).max(1);
+        Report::new()
+            .quantity("dx", format!("{:.10e}", dx))
+            .quantity(
+                "nu",
+                format!(
+                    "{:.10e} to {:.10e} in {} values",
+                    input_params.n_cfl_min, input_params.n_cfl_max, input_params.n_cfl_count
+                ),
+            )
+            .quantity(
+                "step_max",
+                format!(
+                    "{} to {}",
+                    step_max(input_params.n_cfl_min),
+                    step_max(input_params.n_cfl_max)
+                ),
+            )
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("stability_map.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    map(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Run the scheme for each CFL number and output whether and when the solution diverges and the errors.
+fn map(
+    input_params: &ExecMapStabilityInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    let profile = Profile::Gaussian {
+        x_0: -0.5,
+        width: 0.1,
+    };
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let dx = x[1] - x[0];
+    let u_init = profile.create(&x);
+    let u_bound = input_params.growth_limit * u_init.fold(0.0_f64, |acc, u| acc.max(u.abs()));
+
+    writeln!(
+        outputstream,
+        "n_cfl expected diverged step_diverged error_l1 error_l2 error_linf"
+    )?;
+    let mut n_cfl_bounded = None;
+    let mut is_bounded_so_far = true;
+    for n_cfl in Array1::linspace(
+        input_params.n_cfl_min,
+        input_params.n_cfl_max,
+        input_params.n_cfl_count,
+    ) {
+        let step_max = ((input_params.ct_end / (n_cfl * dx)).round() as usize).max(1);
+        // skip the CFL numbers rejected by the solver, e.g. those limited by the validation
+        let mut solver = match input_params.scheme.create_solver(SchemeParams {
+            u: u_init.clone(),
+            step_max,
+            n_cfl,
+            dt: n_cfl * dx,
+            boundary_condition: input_params.boundary_condition.create(&u_init),
+        }) {
+            Ok(solver) => solver,
+            Err(err) => {
+                eprintln!("Warning: n_cfl = {:.3} is skipped: {}", n_cfl, err);
+                continue;
+            }
+        };
+        let expected = match solver.get_stability() {
+            Stability::Stable => "stable",
+            Stability::Unstable(_) => "unstable",
+            Stability::Unknown => "unknown",
+        };
+
+        // integrate until completed or diverged
+        let mut step_diverged = None;
+        while !solver.is_completed() {
+            solver.integrate()?;
+            if solver
+                .borrow_u()
+                .iter()
+                .any(|u| !u.is_finite() || u.abs() > u_bound)
+            {
+                step_diverged = Some(solver.get_step());
+                break;
+            }
+        }
+
+        // output the result of the CFL number
+        match step_diverged {
+            Some(step) => {
+                writeln!(outputstream, "{:.10} {} 1 {} - - -", n_cfl, expected, step)?;
+                is_bounded_so_far = false;
+            }
+            None => {
+                let ct = step_max as f64 * n_cfl * dx;
+                let u_exact = match input_params.boundary_condition {
+                    BoundaryConditionKind::Periodic => exact::solution_periodic(&profile, &x, ct),
+                    _ => exact::solution(&profile, &x, ct),
+                };
+                let error = ErrorNorms::new(solver.borrow_u(), &u_exact, dx);
+                writeln!(
+                    outputstream,
+                    "{:.10} {} 0 - {:.10e} {:.10e} {:.10e}",
+                    n_cfl, expected, error.l1, error.l2, error.linf
+                )?;
+                if is_bounded_so_far {
+                    n_cfl_bounded = Some(n_cfl);
+                }
+            }
+        }
+    }
+
+    // output the observed stability limit
+    match n_cfl_bounded {
+        Some(n_cfl) => println!(
+            "{}: bounded up to n_cfl = {:.3}.",
+            input_params.scheme.name(),
+            n_cfl
+        ),
+        None => println!("{}: diverged for all n_cfl.", input_params.scheme.name()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//! Solve the transport equation by the scheme selected in the input file.
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0 (x \in [-1, 1]),
+//! ```
+//! where `u` is the transported quantity and `c` is the advection velocity.
+//!
+//! The initial condition is selected in the input file, see [InitialCondition](linear_hyperbolic::initial_conditions::InitialCondition), and defaults to
+//! ```math
+//! u(x, 0) = 0 (x \ge 0), u(x, 0) = 1 (x < 0).
+//! ```
+//!
+//! The boundary condition is selected in the input file. See [BoundaryConditionKind](linear_hyperbolic::scheme::BoundaryConditionKind).
+//! With the periodic boundary condition, the initial condition is a square wave with the period 2.
+//!
+//! # Scheme
+//! One of [SchemeKind](linear_hyperbolic::scheme::SchemeKind) is selected in the input file, together with the parameters specific to it.
+//!
+//! # Usage
+//! The path to the input file is given by the command line argument, e.g.
+//! ```shell
+//! cargo run --example solve_wave_eq -- inputs/section_2/linear_hyperbolic/solve_wave_eq/laxwendroff.yml
+//! ```
+//! and defaults to `inputs/section_2/linear_hyperbolic/solve_wave_eq/input.yml`.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! scheme: laxfriedrichs
+//! epsilon: 0.75
+//! n_x: 20
+//! step_max: 6
+//! n_cfl: 0.5
+//! boundary_condition: dirichlet
+//! ncycle_out: 2
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecWaveEqInputParams].
+//! The initial condition is one of [Profile](linear_hyperbolic::initial_conditions::Profile) or read from a file, e.g.
+//! ```yaml
+//! initial_condition:
+//!   profile: gaussian
+//!   x_0: -0.5
+//!   width: 0.1
+//! ```
+//!
+//! # Output Format
+//! The outputs are written under `outputs/section_2/linear_hyperbolic/solve_wave_eq/<scheme>`,
+//! where `<scheme>` is the name of the scheme, e.g. `laxfriedrichs`.
+//!
+//! The solution is output to `solution.dat`. See [linear_hyperbolic::output::output] for the format.
+//!
+//! The total variation, maximum, minimum and integral of `u` of every step are output to `diagnostics.dat`,
+//! which show the overshoots and the conservation of the scheme.
+//! See [linear_hyperbolic::diagnostics::output_header] for the format.
+
+use cli::check::Report;
+use linear_hyperbolic::input;
+use linear_hyperbolic::input::examples::ExecWaveEqInputParams;
+use linear_hyperbolic::scheme::SchemeParams;
+use linear_hyperbolic::solver::{Solver, Stability};
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the transport equation with the given input parameters and output the results to files.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/linear_hyperbolic/solve_wave_eq");
+
+    // read input parameters
+    let input_params: ExecWaveEqInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let dx = x[1] - x[0];
+
+    // initialize the solver
+    let u = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
+    let boundary_condition = input_params.boundary_condition.create(&u);
+    let mut solver = input_params
+        .scheme
+        .create_solver(SchemeParams {
+            u,
+            step_max: input_params.step_max,
+            n_cfl: input_params.n_cfl,
+            dt: input_params.n_cfl.abs() * dx,
+            boundary_condition,
+        })
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating solver: {}", err);
+            process::exit(1);
+        });
+
+    // check the stability
+    if let Stability::Unstable(reason) = solver.get_stability() {
+        eprintln!("Warning: {}", reason);
+    }
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let dt = input_params.n_cfl.abs() * dx;
+        Report::new()
+            .quantity("dx", format!("{:.10e}", dx))
+            .quantity("dt", format!("{:.10e}", dt))
+            .quantity("nu", format!("{:.10e}", input_params.n_cfl))
+            .quantity(
+                "t_max",
+                format!("{:.10e}", input_params.step_max as f64 * dt),
+            )
+            .stability(solver.get_stability())
+    });
+
+    // setup output files
+    let output_dir = exec_args.output_dir.join(input_params.scheme.name());
+    fs::create_dir_all(&output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let [mut outputfile, mut diagnosticsfile] =
+        ["solution.dat", "diagnostics.dat"].map(|file_name| {
+            File::create(output_dir.join(file_name)).unwrap_or_else(|err| {
+                eprintln!("Problem creating output files: {}", err);
+                process::exit(1);
+            })
+        });
+
+    // run
+    linear_hyperbolic::run_with_diagnostics(
+        &x,
+        &mut solver,
+        &mut outputfile,
+        &mut diagnosticsfile,
+        input_params.ncycle_out,
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
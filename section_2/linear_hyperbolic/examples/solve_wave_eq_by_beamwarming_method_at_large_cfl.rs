@@ -0,0 +1,139 @@
+//! Solve the transport equation by the [linear_hyperbolic::solver::beamwarming_solver] at large CFL numbers
+//! and compare the results with the exact solution.
+//!
+//! The implicit Beam-Warming method is unconditionally stable, so the calculation does not blow up even if the CFL number
+//! is much larger than 1. However, the stability does not imply the accuracy: the errors against the exact solution show
+//! how badly the solution is smeared and distorted at large CFL numbers.
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0 (x \in [-1, 1]),
+//! ```
+//! where `u` is the transported quantity and `c` (`> 0`) is the advection velocity.
+//!
+//! The initial condition is one of [Profile](linear_hyperbolic::initial_conditions::Profile) selected in the input file, and defaults to
+//! ```math
+//! u(x, 0) = 0 (x \ge 0), u(x, 0) = 1 (x < 0).
+//! ```
+//! The profile can't be read from a file, since the exact solution is given by the analytic one.
+//!
+//! The exact solution is given by the shift of the initial condition,
+//! ```math
+//! u(x, t) = u(x - c t, 0),
+//! ```
+//! where the time is given by `c t = n \nu \Delta x` at the `n`-th step.
+//!
+//! For the boundary condition, see [linear_hyperbolic::solver::beamwarming_solver].
+//!
+//! # Scheme
+//! See [linear_hyperbolic::solver::beamwarming_solver].
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 400
+//! step_max: 16
+//! n_cfl: 10.0
+//! lambda: 0.5
+//! ncycle_out: 4
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecBeamwarmingAtLargeCflInputParams].
+//!
+//! # Output Format
+//! The numerical and exact solutions are output to `solution.dat` and `exact.dat`, respectively.
+//! See [linear_hyperbolic::output::output] for the format.
+//!
+//! The errors against the exact solution are output to `errors.dat`.
+//! See [linear_hyperbolic::run_with_errors] for the format.
+
+use cli::check::Report;
+use linear_hyperbolic::exact;
+use linear_hyperbolic::input;
+use linear_hyperbolic::input::examples::ExecBeamwarmingAtLargeCflInputParams;
+use linear_hyperbolic::solver::beamwarming_solver::BeamwarmingSolver;
+use linear_hyperbolic::solver::{Solver, Stability};
+use ndarray::prelude::*;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the transport equation with the given input parameters and output the results and errors to files.
+fn main() {
+    // parse command line arguments
+    let exec_args =
+        cli::parse("section_2/linear_hyperbolic/solve_wave_eq_by_beamwarming_method_at_large_cfl");
+
+    // read input parameters
+    let input_params: ExecBeamwarmingAtLargeCflInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let dx = x[1] - x[0];
+    let profile = &input_params.initial_condition;
+
+    // initialize the solver
+    let mut solver = BeamwarmingSolver::builder()
+        .u(profile.create(&x))
+        .step_max(input_params.step_max)
+        .n_cfl(input_params.n_cfl)
+        .dt(input_params.n_cfl * dx)
+        .lambda(input_params.lambda)
+        .build()
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating solver: {}", err);
+            process::exit(1);
+        });
+
+    // check the stability
+    if let Stability::Unstable(reason) = solver.get_stability() {
+        eprintln!("Warning: {}", reason);
+    }
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let dt = input_params.n_cfl * dx;
+        Report::new()
+            .quantity("dx", format!("{:.10e}", dx))
+            .quantity("dt", format!("{:.10e}", dt))
+            .quantity("nu", format!("{:.10e}", input_params.n_cfl))
+            .quantity(
+                "t_max",
+                format!("{:.10e}", input_params.step_max as f64 * dt),
+            )
+            .stability(solver.get_stability())
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let [mut solution_file, mut exact_file, mut errors_file] =
+        ["solution.dat", "exact.dat", "errors.dat"].map(|file_name| {
+            File::create(output_dir.join(file_name)).unwrap_or_else(|err| {
+                eprintln!("Problem creating output files: {}", err);
+                process::exit(1);
+            })
+        });
+
+    // run
+    linear_hyperbolic::run_with_errors(
+        &x,
+        &mut solver,
+        |ct| exact::solution(profile, &x, ct),
+        input_params.n_cfl * dx,
+        [&mut solution_file, &mut exact_file, &mut errors_file],
+        input_params.ncycle_out,
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
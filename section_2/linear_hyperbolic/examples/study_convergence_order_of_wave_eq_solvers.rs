@@ -0,0 +1,224 @@
+//! Study the order of accuracy of a scheme for the transport equation by refining the grid.
+//!
+//! The chosen scheme is run on a sequence of grids, where the number of cells is doubled for each grid
+//! with the CFL number kept constant, and the errors against the exact solution are calculated at the end.
+//! The observed order of accuracy is given by
+//! ```math
+//! p = \log_2 \frac{e_{\Delta x}}{e_{\Delta x / 2}}
+//! ```
+//! for each pair of the successive grids, and by the least squares fit of `\log e = p \log \Delta x + C` for all grids.
+//!
+//! # Formulation
+//! The transport equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = 0 (x \in [-1, 1]),
+//! ```
+//! where `u` is the transported quantity and `c` (`> 0`) is the advection velocity.
+//!
+//! The initial condition is given by the smooth profile
+//! ```math
+//! u(x, 0) = \sin \pi x,
+//! ```
+//! with the periodic boundary condition, so that the exact solution is given by
+//! ```math
+//! u(x, t) = u(x - c t, 0).
+//! ```
+//!
+//! # Scheme
+//! One of [SchemeKind](linear_hyperbolic::scheme::SchemeKind) is chosen. The schemes which do not support the periodic boundary condition are excluded.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! scheme: laxwendroff
+//! n_x_min: 20
+//! n_grid: 5
+//! n_cfl: 0.5
+//! ct_end: 2.0
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecStudyConvergenceOrderInputParams].
+//!
+//! # Output Format
+//! The convergence table is output to `convergence.dat` in the following format:
+//! ```text
+//! n_x dx error_l1 order_l1 error_l2 order_l2 error_linf order_linf
+//! 20 0.1000000000 1.2345678900e-2 - 1.2345678900e-2 - 1.2345678900e-2 -
+//! 40 0.0500000000 3.0864197250e-3 2.0000000000 3.0864197250e-3 2.0000000000 3.0864197250e-3 2.0000000000
+//! ```
+//! where `order_*` is the observed order of accuracy against the previous grid.
+//! The order fitted for all grids is printed to the standard output.
+
+use cli::check::Report;
+use linear_hyperbolic::exact::{self, ErrorNorms};
+use linear_hyperbolic::initial_conditions::Profile;
+use linear_hyperbolic::input;
+use linear_hyperbolic::input::examples::ExecStudyConvergenceOrderInputParams;
+use linear_hyperbolic::scheme::SchemeParams;
+use linear_hyperbolic::solver::boundary::Periodic;
+use linear_hyperbolic::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Run the scheme on each grid and output the convergence table to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args =
+        cli::parse("section_2/linear_hyperbolic/study_convergence_order_of_wave_eq_solvers");
+
+    // read input parameters
+    let input_params: ExecStudyConvergenceOrderInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    // the stability is that of the coarsest grid, which is the same for all the grids of the same CFL number
+    exec_args.exit_if_check(|| {
+        let n_x_max = input_params.n_x_min << (input_params.n_grid - 1);
+        let [dx_max, dx_min] = [input_params.n_x_min, n_x_max].map(|n_x| 2.0 / n_x as f64);
+        let stability = input_params
+            .scheme
+            .create_solver(SchemeParams {
+                u: Array1::<f64>::zeros(input_params.n_x_min + 1),
+                step_max: 1,
+                n_cfl: input_params.n_cfl,
+                dt: input_params.n_cfl * dx_max,
+                boundary_condition: Box::new(Periodic),
+            })
+            .map_or_else(
+                |err| format!("unknown: {}", err),
+                |solver| solver.get_stability().to_string(),
+            );
+        Report::new()
+            .quantity("dx", format!("{:.10e} to {:.10e}", dx_max, dx_min))
+            .quantity(
+                "dt",
+                format!(
+                    "{:.10e} to {:.10e}",
+                    input_params.n_cfl * dx_max,
+                    input_params.n_cfl * dx_min
+                ),
+            )
+            .quantity("nu", format!("{:.10e}", input_params.n_cfl))
+            .stability(stability)
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("convergence.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    study(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Run the scheme on each grid and output the errors and the observed orders of accuracy.
+fn study(
+    input_params: &ExecStudyConvergenceOrderInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    let profile = Profile::Sine { wavelength: 2.0 };
+
+    // calculate the errors on each grid
+    let mut dxs = Vec::with_capacity(input_params.n_grid);
+    let mut errors = Vec::with_capacity(input_params.n_grid);
+    for i_grid in 0..input_params.n_grid {
+        let n_x = input_params.n_x_min << i_grid;
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let dx = x[1] - x[0];
+        let step_max = ((input_params.ct_end / (input_params.n_cfl * dx)).round() as usize).max(1);
+
+        let mut solver = input_params.scheme.create_solver(SchemeParams {
+            u: profile.create(&x),
+            step_max,
+            n_cfl: input_params.n_cfl,
+            dt: input_params.n_cfl * dx,
+            boundary_condition: Box::new(Periodic),
+        })?;
+        while !solver.is_completed() {
+            solver.integrate()?;
+        }
+
+        let ct = step_max as f64 * input_params.n_cfl * dx;
+        let u_exact = exact::solution_periodic(&profile, &x, ct);
+        dxs.push(dx);
+        errors.push(ErrorNorms::new(solver.borrow_u(), &u_exact, dx));
+    }
+
+    // output the convergence table
+    writeln!(
+        outputstream,
+        "n_x dx error_l1 order_l1 error_l2 order_l2 error_linf order_linf"
+    )?;
+    for (i_grid, (dx, error)) in dxs.iter().zip(errors.iter()).enumerate() {
+        write!(
+            outputstream,
+            "{} {:.10}",
+            input_params.n_x_min << i_grid,
+            dx
+        )?;
+        let norms = [error.l1, error.l2, error.linf];
+        for (i_norm, norm) in norms.iter().enumerate() {
+            if i_grid == 0 {
+                write!(outputstream, " {:.10e} -", norm)?;
+            } else {
+                let error_prev = &errors[i_grid - 1];
+                let norm_prev = [error_prev.l1, error_prev.l2, error_prev.linf][i_norm];
+                write!(
+                    outputstream,
+                    " {:.10e} {:.10}",
+                    norm,
+                    (norm_prev / norm).log2()
+                )?;
+            }
+        }
+        writeln!(outputstream)?;
+    }
+
+    // output the fitted orders
+    let [order_l1, order_l2, order_linf] = [
+        |e: &ErrorNorms| e.l1,
+        |e: &ErrorNorms| e.l2,
+        |e: &ErrorNorms| e.linf,
+    ]
+    .map(|norm| fit_order(&dxs, &errors.iter().map(norm).collect::<Vec<_>>()));
+    println!(
+        "{:?}: fitted order {:.3} (L1), {:.3} (L2), {:.3} (Linf).",
+        input_params.scheme, order_l1, order_l2, order_linf
+    );
+
+    Ok(())
+}
+
+/// Return the slope of the least squares fit of `\log e = p \log \Delta x + C`.
+fn fit_order(dxs: &[f64], errors: &[f64]) -> f64 {
+    let n = dxs.len() as f64;
+    let log_dx: Vec<f64> = dxs.iter().map(|dx| dx.ln()).collect();
+    let log_e: Vec<f64> = errors.iter().map(|e| e.ln()).collect();
+    let mean_dx = log_dx.iter().sum::<f64>() / n;
+    let mean_e = log_e.iter().sum::<f64>() / n;
+
+    let cov: f64 = log_dx
+        .iter()
+        .zip(log_e.iter())
+        .map(|(dx, e)| (dx - mean_dx) * (e - mean_e))
+        .sum();
+    let var: f64 = log_dx.iter().map(|dx| (dx - mean_dx).powi(2)).sum();
+
+    cov / var
+}
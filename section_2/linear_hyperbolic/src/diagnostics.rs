@@ -0,0 +1,96 @@
+//! Module to diagnose the monotonicity of the solution.
+//!
+//! The exact solution of the transport equation neither increases the total variation
+//! ```math
+//! TV(u) = \sum_j |u_{j+1} - u_j|
+//! ```
+//! nor creates new extrema, so the growth of `TV(u)` and the overshoots of `\max u` and `\min u`
+//! beyond the initial range measure the spurious oscillations of the scheme.
+//!
+//! The exact solution also conserves the integral of `u` except for the fluxes through the boundaries,
+//! so the drift of the discrete integral by the trapezoidal rule,
+//! ```math
+//! I(u) = \Delta x (\frac{1}{2} u_0 + \sum_{j=1}^{N-1} u_j + \frac{1}{2} u_N),
+//! ```
+//! measures the non-conservative behavior of the scheme.
+//! For the periodic boundary condition, `I(u)` equals `\Delta x \sum_{j=0}^{N-1} u_j` and is exactly conserved
+//! by the schemes in the conservation form.
+//!
+//! # Examples
+//! ```
+//! use linear_hyperbolic::diagnostics::Diagnostics;
+//! use ndarray::prelude::*;
+//!
+//! let diagnostics = Diagnostics::new(&array![1.0, 1.2, 0.5, -0.1, 0.0], 0.5);
+//!
+//! assert!((diagnostics.total_variation - 1.6).abs() < 1e-10);
+//! assert!((diagnostics.integral - 1.05).abs() < 1e-10);
+//! assert_eq!(diagnostics.u_max, 1.2);
+//! assert_eq!(diagnostics.u_min, -0.1);
+//! ```
+
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::io::{Error, Write};
+
+/// Diagnostics of `u`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostics {
+    /// Total variation `\sum_j |u_{j+1} - u_j|`.
+    pub total_variation: f64,
+    /// Maximum of `u`.
+    pub u_max: f64,
+    /// Minimum of `u`.
+    pub u_min: f64,
+    /// Discrete integral of `u` by the trapezoidal rule.
+    pub integral: f64,
+}
+
+impl Diagnostics {
+    /// Calculate the diagnostics of `u` with the grid spacing `dx`.
+    ///
+    /// `u` of any floating-point type is converted to `f64`, in which the diagnostics are calculated.
+    pub fn new<F: Float>(u: &Array1<F>, dx: f64) -> Self {
+        let u = u.mapv(|u| u.to_f64().unwrap());
+        let len = u.len();
+        let ends = if len > 1 { u[0] + u[len - 1] } else { 0.0 };
+
+        Self {
+            total_variation: u.windows(2).into_iter().map(|w| (w[1] - w[0]).abs()).sum(),
+            u_max: u.fold(f64::NEG_INFINITY, |acc, u| acc.max(*u)),
+            u_min: u.fold(f64::INFINITY, |acc, u| acc.min(*u)),
+            integral: (u.sum() - 0.5 * ends) * dx,
+        }
+    }
+}
+
+/// Output the header of the diagnostics.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// step total_variation u_max u_min integral
+/// ```
+pub fn output_header(outputstream: &mut impl Write) -> Result<(), Error> {
+    writeln!(outputstream, "step total_variation u_max u_min integral")
+}
+
+/// Output the diagnostics of `u` at `step` with the grid spacing `dx`. See [output_header] for the format.
+pub fn output<F: Float>(
+    outputstream: &mut impl Write,
+    step: usize,
+    u: &Array1<F>,
+    dx: f64,
+) -> Result<(), Error> {
+    let diagnostics = Diagnostics::new(u, dx);
+
+    writeln!(
+        outputstream,
+        "{} {:.10} {:.10} {:.10} {:.10}",
+        step,
+        diagnostics.total_variation,
+        diagnostics.u_max,
+        diagnostics.u_min,
+        diagnostics.integral
+    )
+}
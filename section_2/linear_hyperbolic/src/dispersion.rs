@@ -0,0 +1,140 @@
+//! Module to analyze the dispersive and dissipative errors of the schemes.
+//!
+//! For a linear scheme on the periodic domain, each Fourier mode `e^{i k x_j}` is multiplied by
+//! the amplification factor `g(\theta)` in each step, where `\theta = k \Delta x`,
+//! while the exact solution multiplies it by `e^{-i \nu \theta}`.
+//!
+//! The amplification factor is measured by probing the solver with a single mode,
+//! ```math
+//! u_j^0 = \cos \theta j,
+//! ```
+//! advancing it by one step and projecting the result onto the mode,
+//! ```math
+//! g(\theta) = \frac{2}{N} \sum_{j=0}^{N-1} u_j^1 e^{-i \theta j}.
+//! ```
+//! So any solver supporting the periodic boundary condition can be analyzed in the same way,
+//! although only the first step is measured for the multistep schemes.
+//!
+//! The results are given as
+//! - the damping factor `|g|`, which is 1 for the exact solution,
+//! - the ratio of the numerical phase speed to `c`, `-\arg g / (\nu \theta)`, which is 1 for the exact solution.
+
+use crate::solver::Solver;
+use ndarray::prelude::*;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::io::Write;
+
+/// Response of the scheme to a single Fourier mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModeResponse {
+    /// Normalized wavenumber `\theta = k \Delta x`.
+    pub theta: f64,
+    /// Damping factor `|g|` per step.
+    pub damping_factor: f64,
+    /// Ratio of the numerical phase speed to the exact one.
+    pub phase_speed_ratio: f64,
+}
+
+/// Probe the solver with the modes `\theta = 2 \pi m / N` (`0 < m < N / 2`) and return the responses.
+///
+/// `create_solver` should create the solver with the periodic boundary condition,
+/// the given CFL number `n_cfl` and the given initial value of `u` of length `n_x + 1`.
+///
+/// # Errors
+/// Returns an error if `n_x` is less than 3 or `n_cfl` is zero, or if the solver fails.
+pub fn analyze(
+    n_x: usize,
+    n_cfl: f64,
+    create_solver: impl Fn(Array1<f64>) -> Result<Box<dyn Solver>, &'static str>,
+) -> Result<Vec<ModeResponse>, Box<dyn Error>> {
+    if n_x < 3 {
+        return Err(Box::<dyn Error>::from("n_x must be at least 3"));
+    }
+    if n_cfl == 0.0 {
+        return Err(Box::<dyn Error>::from("n_cfl must not be zero"));
+    }
+
+    (1..n_x.div_ceil(2))
+        .map(|m| {
+            let theta = 2.0 * PI * m as f64 / n_x as f64;
+
+            let mut solver = create_solver((0..=n_x).map(|j| (theta * j as f64).cos()).collect())?;
+            solver.integrate()?;
+            let u = solver.borrow_u();
+
+            // project u onto the mode
+            let (g_re, g_im) = (0..n_x).fold((0.0, 0.0), |(re, im), j| {
+                let phase = theta * j as f64;
+                (re + u[j] * phase.cos(), im - u[j] * phase.sin())
+            });
+            let (g_re, g_im) = (2.0 * g_re / n_x as f64, 2.0 * g_im / n_x as f64);
+
+            Ok(ModeResponse {
+                theta,
+                damping_factor: g_re.hypot(g_im),
+                phase_speed_ratio: -g_im.atan2(g_re) / (n_cfl * theta),
+            })
+        })
+        .collect()
+}
+
+/// Output the responses.
+///
+/// # Output Format
+/// The output is formatted as follows:
+/// ```text
+/// theta damping_factor phase_speed_ratio
+/// theta_1 damping_factor_1 phase_speed_ratio_1
+/// ...
+/// ```
+pub fn output(
+    outputstream: &mut impl Write,
+    mode_responses: &[ModeResponse],
+) -> Result<(), std::io::Error> {
+    writeln!(outputstream, "theta damping_factor phase_speed_ratio")?;
+    for mode_response in mode_responses {
+        writeln!(
+            outputstream,
+            "{:.10} {:.10} {:.10}",
+            mode_response.theta, mode_response.damping_factor, mode_response.phase_speed_ratio
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Periodic;
+    use crate::solver::upwind_solver::{UpwindSolver, UpwindSolverNewParams};
+    use rustfft::num_complex::Complex;
+
+    #[test]
+    fn fn_analyze_works() {
+        // analyze the upwind method
+        let n_cfl = 0.5;
+        let mode_responses = analyze(16, n_cfl, |u| {
+            Ok(Box::new(UpwindSolver::new(UpwindSolverNewParams {
+                u,
+                step_max: 1,
+                n_cfl,
+                dt: n_cfl.abs(),
+                boundary_condition: Box::new(Periodic),
+            })?))
+        })
+        .unwrap();
+
+        // check if the responses agree with the amplification factor g = 1 - nu (1 - e^{-i theta})
+        assert_eq!(mode_responses.len(), 7);
+        for mode_response in mode_responses {
+            let g = 1.0 - n_cfl * (1.0 - Complex::from_polar(1.0, -mode_response.theta));
+            assert!((mode_response.damping_factor - g.norm()).abs() < 1e-10);
+            assert!(
+                (mode_response.phase_speed_ratio + g.arg() / (n_cfl * mode_response.theta)).abs()
+                    < 1e-10
+            );
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Module to evaluate the exact solution of the transport equation and the errors against it.
+//!
+//! The exact solution of the transport equation is given by the translation of the initial profile,
+//! ```math
+//! u(x, t) = u_0(x - c t).
+//! ```
+//!
+//! # Examples
+//! ```
+//! use linear_hyperbolic::exact;
+//! use linear_hyperbolic::initial_conditions::Profile;
+//! use ndarray::prelude::*;
+//!
+//! let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+//! let profile = Profile::Step { x_0: 0.0 };
+//!
+//! assert_eq!(exact::solution(&profile, &x, 0.5), array![1.0, 1.0, 1.0, 0.0, 0.0]);
+//! assert_eq!(exact::solution_periodic(&profile, &x, 1.5), array![1.0, 0.0, 0.0, 1.0, 1.0]);
+//! ```
+
+use crate::initial_conditions::Profile;
+use ndarray::prelude::*;
+use num_traits::Float;
+use numerics::norms;
+
+/// Return the exact solution `u_0(x - c t)` on `x`.
+pub fn solution(profile: &Profile, x: &Array1<f64>, ct: f64) -> Array1<f64> {
+    x.map(|x| profile.value(*x - ct))
+}
+
+/// Return the exact solution `u_0(x - c t)` on `x` for the periodic boundary condition.
+///
+/// The period is given by `x_N - x_0`, and `x - c t` is wrapped into `[x_0, x_N)`.
+pub fn solution_periodic(profile: &Profile, x: &Array1<f64>, ct: f64) -> Array1<f64> {
+    let x_min = x[0];
+    let period = x[x.len() - 1] - x_min;
+
+    x.map(|x| profile.value(x_min + (*x - ct - x_min).rem_euclid(period)))
+}
+
+/// Discrete norms of the error against the exact solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorNorms {
+    /// L1 norm, `\sum_j |e_j| \Delta x`.
+    pub l1: f64,
+    /// L2 norm, `\sqrt{\sum_j e_j^2 \Delta x}`.
+    pub l2: f64,
+    /// L∞ norm, `\max_j |e_j|`.
+    pub linf: f64,
+}
+
+impl ErrorNorms {
+    /// Calculate the error norms of `u` against `u_exact` with the grid spacing `dx`.
+    ///
+    /// `u` of any floating-point type is converted to `f64`, in which the errors are calculated.
+    pub fn new<F: Float>(u: &Array1<F>, u_exact: &Array1<f64>, dx: f64) -> Self {
+        let error = u.mapv(|u| u.to_f64().unwrap()) - u_exact;
+
+        Self {
+            l1: norms::l1(&error, dx),
+            l2: norms::l2(&error, dx),
+            linf: norms::linf(&error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_error_norms_new_works() {
+        // setup u and the exact solution
+        let u = array![1.0, 0.5, 0.0, -1.0];
+        let u_exact = array![1.0, 1.0, 0.0, 0.0];
+
+        // check if the norms are correctly calculated
+        let error_norms = ErrorNorms::new(&u, &u_exact, 0.5);
+        assert_eq!(
+            error_norms,
+            ErrorNorms {
+                l1: 0.75,
+                l2: 0.625_f64.sqrt(),
+                linf: 1.0
+            }
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Module to keep the history of `u` in memory.
+//!
+//! [History] stores the snapshots of `u` every `ncycle` steps,
+//! so that the results can be post-processed without parsing the output files.
+//!
+//! # Examples
+//! ```
+//! use linear_hyperbolic::history::History;
+//! use linear_hyperbolic::solver::upwind_solver::UpwindSolver;
+//! use ndarray::prelude::*;
+//!
+//! let mut solver = UpwindSolver::builder()
+//!     .u(array![1.0, 1.0, 0.0, 0.0, 0.0])
+//!     .step_max(4)
+//!     .n_cfl(0.5)
+//!     .build()
+//!     .unwrap();
+//! let mut history = History::new(2).unwrap();
+//! linear_hyperbolic::run_with_history(&mut solver, &mut history).unwrap();
+//!
+//! assert_eq!(history.len(), 3);
+//! assert_eq!(history.at_step(2).unwrap().t, 1.0);
+//! assert_eq!(history.to_array2().dim(), (3, 5));
+//! ```
+
+use crate::solver::Solver;
+use ndarray::prelude::*;
+use num_traits::Float;
+
+/// Snapshot of `u` at a step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// Step of the snapshot.
+    pub step: usize,
+    /// Time of the snapshot.
+    pub t: f64,
+    /// `u` at the step.
+    pub u: Array1<f64>,
+}
+
+/// History of the snapshots of `u`, stored every `ncycle` steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct History {
+    ncycle: usize,
+    snapshots: Vec<Snapshot>,
+}
+
+impl History {
+    /// Create a new empty `History` instance storing the snapshots every `ncycle` steps.
+    ///
+    /// # Errors
+    /// Returns an error if `ncycle` is zero.
+    pub fn new(ncycle: usize) -> Result<Self, &'static str> {
+        if ncycle == 0 {
+            return Err("ncycle must be positive");
+        }
+
+        Ok(Self {
+            ncycle,
+            snapshots: Vec::new(),
+        })
+    }
+
+    /// Store the snapshot of the current `u` of the solver if the current step is a multiple of `ncycle`.
+    /// `u` of any floating-point type is stored as `f64`.
+    ///
+    /// Returns `true` if the snapshot is stored.
+    pub fn record<F: Float>(&mut self, solver: &impl Solver<F>) -> bool {
+        let step = solver.get_step();
+        if !step.is_multiple_of(self.ncycle) {
+            return false;
+        }
+
+        self.snapshots.push(Snapshot {
+            step,
+            t: solver.get_t(),
+            u: solver.borrow_u().mapv(|u| u.to_f64().unwrap()),
+        });
+
+        true
+    }
+
+    /// Return the number of the stored snapshots.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Return `true` if no snapshot is stored.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Return the stored snapshots in order of the steps.
+    pub fn snapshots(&self) -> &[Snapshot] {
+        &self.snapshots
+    }
+
+    /// Return the snapshot at `step`, or `None` if it is not stored.
+    pub fn at_step(&self, step: usize) -> Option<&Snapshot> {
+        self.snapshots.iter().find(|snapshot| snapshot.step == step)
+    }
+
+    /// Return the snapshot whose time is the nearest to `t`, or `None` if no snapshot is stored.
+    pub fn at_time(&self, t: f64) -> Option<&Snapshot> {
+        self.snapshots
+            .iter()
+            .min_by(|a, b| (a.t - t).abs().total_cmp(&(b.t - t).abs()))
+    }
+
+    /// Return the stored `u` as a 2D array, whose `i`-th row is `u` of the `i`-th snapshot.
+    pub fn to_array2(&self) -> Array2<f64> {
+        let n_x = self
+            .snapshots
+            .first()
+            .map_or(0, |snapshot| snapshot.u.len());
+
+        Array2::from_shape_vec(
+            (self.snapshots.len(), n_x),
+            self.snapshots
+                .iter()
+                .flat_map(|snapshot| snapshot.u.iter().copied())
+                .collect(),
+        )
+        .expect("all snapshots should have u of the same length")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+    use crate::solver::upwind_solver::{UpwindSolver, UpwindSolverNewParams};
+
+    #[test]
+    fn fn_history_works() {
+        // setup upwind solver and record the snapshots of every 2 steps
+        let mut upwind_solver = UpwindSolver::new(UpwindSolverNewParams {
+            u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+            step_max: 3,
+            n_cfl: 0.5,
+            dt: 0.1,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        })
+        .unwrap();
+        let mut history = History::new(2).unwrap();
+        assert!(history.record(&upwind_solver));
+        upwind_solver.integrate().unwrap();
+        assert!(!history.record(&upwind_solver));
+        upwind_solver.integrate().unwrap();
+        assert!(history.record(&upwind_solver));
+
+        // check if the snapshots are correctly stored and queried
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history.at_step(0).unwrap().u,
+            array![1.0, 1.0, 0.0, 0.0, 0.0]
+        );
+        assert!(history.at_step(1).is_none());
+        assert_eq!(history.at_time(0.15).unwrap().step, 2);
+        let u_exact = array![[1.0, 1.0, 0.0, 0.0, 0.0], [1.0, 1.0, 0.75, 0.25, 0.0]];
+        let is_u_correctly_stored = (history.to_array2() - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_stored);
+
+        // check if ncycle of zero is rejected
+        assert_eq!(History::new(0), Err("ncycle must be positive"));
+    }
+}
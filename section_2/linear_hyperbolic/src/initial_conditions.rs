@@ -0,0 +1,276 @@
+//! Module to create the initial profiles of `u`.
+//!
+//! Each profile is given by a function of `x` and evaluated on the coordinate array.
+//! The profiles are also available as [Profile], which can be named in the input files,
+//! and together with the profile read from a file as [InitialCondition].
+//!
+//! # Examples
+//! ```
+//! use linear_hyperbolic::initial_conditions::{self, Profile};
+//! use ndarray::prelude::*;
+//!
+//! let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+//!
+//! assert_eq!(initial_conditions::step(&x, 0.0), array![1.0, 1.0, 0.0, 0.0, 0.0]);
+//! assert_eq!(
+//!     Profile::Triangle { x_0: 0.0, half_width: 1.0 }.create(&x),
+//!     array![0.0, 0.5, 1.0, 0.5, 0.0]
+//! );
+//! ```
+
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+pub use numerics::initial_condition::{read, AnalyticProfile, FileProfile};
+
+/// Initial profile of `u`, which is the step function at `x = 0` by default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "profile", rename_all = "snake_case")]
+pub enum Profile {
+    /// Step function,
+    /// ```math
+    /// u(x) = 1 (x < x_0), u(x) = 0 (x \ge x_0).
+    /// ```
+    Step {
+        /// Position of the step.
+        x_0: f64,
+    },
+    /// Sine wave,
+    /// ```math
+    /// u(x) = \sin \frac{2 \pi x}{\lambda}.
+    /// ```
+    Sine {
+        /// Wavelength `\lambda`.
+        wavelength: f64,
+    },
+    /// Gaussian pulse,
+    /// ```math
+    /// u(x) = \exp (- \frac{(x - x_0)^2}{w^2}).
+    /// ```
+    Gaussian {
+        /// Center of the pulse.
+        x_0: f64,
+        /// Width `w` of the pulse.
+        width: f64,
+    },
+    /// Triangle pulse,
+    /// ```math
+    /// u(x) = \max (1 - \frac{|x - x_0|}{w}, 0).
+    /// ```
+    Triangle {
+        /// Center of the pulse.
+        x_0: f64,
+        /// Half width `w` of the pulse.
+        half_width: f64,
+    },
+    /// Wave packet, that is, the sine wave modulated by the Gaussian pulse,
+    /// ```math
+    /// u(x) = \exp (- \frac{(x - x_0)^2}{w^2}) \cos \frac{2 \pi (x - x_0)}{\lambda}.
+    /// ```
+    WavePacket {
+        /// Center of the packet.
+        x_0: f64,
+        /// Width `w` of the envelope.
+        width: f64,
+        /// Wavelength `\lambda` of the carrier.
+        wavelength: f64,
+    },
+}
+
+impl Profile {
+    /// Return the value of the profile at `x`.
+    pub fn value(&self, x: f64) -> f64 {
+        match *self {
+            Profile::Step { x_0 } => {
+                if x < x_0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Profile::Sine { wavelength } => (2.0 * PI * x / wavelength).sin(),
+            Profile::Gaussian { x_0, width } => (-((x - x_0) / width).powi(2)).exp(),
+            Profile::Triangle { x_0, half_width } => (1.0 - (x - x_0).abs() / half_width).max(0.0),
+            Profile::WavePacket {
+                x_0,
+                width,
+                wavelength,
+            } => (-((x - x_0) / width).powi(2)).exp() * (2.0 * PI * (x - x_0) / wavelength).cos(),
+        }
+    }
+
+    /// Return the profile evaluated on the coordinate array `x`.
+    pub fn create(&self, x: &Array1<f64>) -> Array1<f64> {
+        x.map(|x| self.value(*x))
+    }
+
+    /// Validate the parameters of the profile.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match *self {
+            Profile::Step { .. } => Ok(()),
+            Profile::Sine { wavelength } if wavelength <= 0.0 => Err("wavelength must be positive"),
+            Profile::Gaussian { width, .. } if width <= 0.0 => Err("width must be positive"),
+            Profile::Triangle { half_width, .. } if half_width <= 0.0 => {
+                Err("half_width must be positive")
+            }
+            Profile::WavePacket { width, .. } if width <= 0.0 => Err("width must be positive"),
+            Profile::WavePacket { wavelength, .. } if wavelength <= 0.0 => {
+                Err("wavelength must be positive")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile::Step { x_0: 0.0 }
+    }
+}
+
+/// Initial condition of `u`, given as one of [Profile] or read from a file, e.g.
+/// ```yaml
+/// profile: from_file
+/// path: path/to/u_init.dat
+/// ```
+/// which is the default [Profile] if omitted. See [numerics::initial_condition] for the file format.
+pub type InitialCondition = numerics::initial_condition::InitialCondition<Profile>;
+
+impl AnalyticProfile for Profile {
+    fn create(&self, x: &Array1<f64>) -> Array1<f64> {
+        Profile::create(self, x)
+    }
+
+    fn validate(&self) -> Result<(), &'static str> {
+        Profile::validate(self)
+    }
+}
+
+/// Return the step function on `x`. See [Profile::Step].
+pub fn step(x: &Array1<f64>, x_0: f64) -> Array1<f64> {
+    Profile::Step { x_0 }.create(x)
+}
+
+/// Return the sine wave on `x`. See [Profile::Sine].
+pub fn sine(x: &Array1<f64>, wavelength: f64) -> Array1<f64> {
+    Profile::Sine { wavelength }.create(x)
+}
+
+/// Return the Gaussian pulse on `x`. See [Profile::Gaussian].
+pub fn gaussian(x: &Array1<f64>, x_0: f64, width: f64) -> Array1<f64> {
+    Profile::Gaussian { x_0, width }.create(x)
+}
+
+/// Return the triangle pulse on `x`. See [Profile::Triangle].
+pub fn triangle(x: &Array1<f64>, x_0: f64, half_width: f64) -> Array1<f64> {
+    Profile::Triangle { x_0, half_width }.create(x)
+}
+
+/// Return the wave packet on `x`. See [Profile::WavePacket].
+pub fn wave_packet(x: &Array1<f64>, x_0: f64, width: f64, wavelength: f64) -> Array1<f64> {
+    Profile::WavePacket {
+        x_0,
+        width,
+        wavelength,
+    }
+    .create(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn fn_create_works() {
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+
+        // check if each profile is correctly evaluated
+        let cases = [
+            (step(&x, 0.0), array![1.0, 1.0, 0.0, 0.0, 0.0]),
+            (sine(&x, 2.0), array![0.0, -1.0, 0.0, 1.0, 0.0]),
+            (
+                gaussian(&x, 0.0, 0.5),
+                array![
+                    (-4.0_f64).exp(),
+                    (-1.0_f64).exp(),
+                    1.0,
+                    (-1.0_f64).exp(),
+                    (-4.0_f64).exp()
+                ],
+            ),
+            (triangle(&x, 0.5, 0.5), array![0.0, 0.0, 0.0, 1.0, 0.0]),
+            (
+                wave_packet(&x, 0.0, 0.5, 1.0),
+                array![
+                    (-4.0_f64).exp(),
+                    -(-1.0_f64).exp(),
+                    1.0,
+                    -(-1.0_f64).exp(),
+                    (-4.0_f64).exp()
+                ],
+            ),
+        ];
+        for (u, u_exact) in cases {
+            let is_u_correctly_created = (u - u_exact).iter().all(|u| u.abs() < 1e-10);
+            assert!(is_u_correctly_created);
+        }
+    }
+
+    #[test]
+    fn fn_initial_condition_can_be_named_in_input() {
+        // read the initial conditions from the YAML input
+        let cases = [
+            (
+                "profile: triangle\nx_0: 0.5\nhalf_width: 0.25",
+                InitialCondition::Profile(Profile::Triangle {
+                    x_0: 0.5,
+                    half_width: 0.25,
+                }),
+            ),
+            (
+                "profile: from_file\npath: u_init.dat",
+                InitialCondition::File(FileProfile::FromFile {
+                    path: PathBuf::from("u_init.dat"),
+                }),
+            ),
+        ];
+
+        // check if the initial conditions are correctly read and written back
+        for (input, initial_condition) in cases {
+            let initial_condition_read: InitialCondition = serde_yaml::from_str(input).unwrap();
+            assert_eq!(initial_condition_read, initial_condition);
+            assert_eq!(
+                serde_yaml::to_string(&initial_condition).unwrap().trim(),
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn fn_profile_can_be_named_in_input() {
+        // read a profile from the YAML input
+        let profile: Profile =
+            serde_yaml::from_str("profile: gaussian\nx_0: 0.0\nwidth: 0.1").unwrap();
+
+        // check if the profile is correctly read and validated
+        assert_eq!(
+            profile,
+            Profile::Gaussian {
+                x_0: 0.0,
+                width: 0.1
+            }
+        );
+        assert!(profile.validate().is_ok());
+        assert_eq!(
+            Profile::Gaussian {
+                x_0: 0.0,
+                width: 0.0
+            }
+            .validate(),
+            Err("width must be positive")
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! Module to read the input parameters.
 
+pub mod examples;
+
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::error::Error;
 use std::io::prelude::*;
 
+pub use cli::validation::{ValidationError, Violation};
+
 /// Read the input parameters from the input.
 ///
 /// The format of the input should be defined by a struct that implements [InputParams], [Serialize] and [DeserializeOwned].
@@ -12,7 +16,7 @@ use std::io::prelude::*;
 /// # Examples
 /// ```
 /// use serde_derive::{Deserialize, Serialize};
-/// use linear_hyperbolic::input::{self, InputParams};
+/// use linear_hyperbolic::input::{self, InputParams, ValidationError};
 ///
 /// #[derive(Debug, Serialize, Deserialize, PartialEq)]
 /// pub struct SpecificInputParams {
@@ -22,12 +26,11 @@ use std::io::prelude::*;
 /// }
 ///
 /// impl InputParams for SpecificInputParams {
-///     fn validate_params(&self) -> Result<(), &'static str> {
-///         if self.b <= 0.0 {
-///             return Err("b must be positive");
-///         }
+///     fn validate_params(&self) -> Result<(), ValidationError> {
+///         let mut errors = ValidationError::new();
+///         errors.check(self.b > 0.0, "b", self.b, "must be positive");
 ///
-///         Ok(())
+///         errors.into_result()
 ///     }
 /// }
 ///
@@ -43,7 +46,8 @@ use std::io::prelude::*;
 /// ```
 ///
 /// # Errors
-/// Returns an error if the input is invalid.
+/// Returns an error if the input cannot be read or deserialized,
+/// or a [ValidationError] listing all the invalid parameters.
 pub fn read_input_params<T: InputParams + Serialize + DeserializeOwned>(
     inputstream: &mut impl Read,
 ) -> Result<T, Box<dyn Error>> {
@@ -57,6 +61,21 @@ pub fn read_input_params<T: InputParams + Serialize + DeserializeOwned>(
 
 /// Input parameters.
 pub trait InputParams {
-    /// Validate the input parameters.
-    fn validate_params(&self) -> Result<(), &'static str>;
+    /// Validate the input parameters, collecting all the violations.
+    fn validate_params(&self) -> Result<(), ValidationError>;
+}
+
+/// Return the default number of cycles between outputs, `1`, which outputs every cycle.
+pub fn default_ncycle_out() -> usize {
+    1
+}
+
+/// Return the default weighting factor of the implicit schemes, `0.5`, which is the trapezoidal rule.
+pub fn default_lambda() -> f64 {
+    0.5
+}
+
+/// Return the default growth of `\max |u|` regarded as the divergence, `10`.
+pub fn default_growth_limit() -> f64 {
+    10.0
 }
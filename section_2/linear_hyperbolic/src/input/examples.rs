@@ -0,0 +1,264 @@
+//! Input parameters of the examples.
+//!
+//! Each example reads its input file into one of the structs here, which fills the omitted parameters with the defaults
+//! of [super], so that the minimal input files are tested against the same types as the examples use.
+
+use super::{InputParams, ValidationError};
+use crate::initial_conditions::{InitialCondition, Profile};
+use crate::scheme::{BoundaryConditionKind, SchemeKind};
+use serde_derive::{Deserialize, Serialize};
+
+/// Input parameters of the `analyze_dispersion_of_wave_eq_solvers` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecAnalyzeDispersionInputParams {
+    /// Scheme to be analyzed, with the parameters specific to it.
+    #[serde(flatten)]
+    pub scheme: SchemeKind,
+    /// Number of cells, which determines the resolution of the wavenumbers.
+    pub n_x: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+}
+
+impl InputParams for ExecAnalyzeDispersionInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x >= 3, "n_x", self.n_x, "must be at least 3");
+        errors.check(self.n_cfl != 0.0, "n_cfl", self.n_cfl, "must not be zero");
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `map_stability_of_wave_eq_solvers` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecMapStabilityInputParams {
+    /// Scheme to be mapped, with the parameters specific to it.
+    #[serde(flatten)]
+    pub scheme: SchemeKind,
+    /// Boundary condition.
+    pub boundary_condition: BoundaryConditionKind,
+    /// Number of cells.
+    pub n_x: usize,
+    /// Minimum CFL number of the sweep.
+    pub n_cfl_min: f64,
+    /// Maximum CFL number of the sweep.
+    pub n_cfl_max: f64,
+    /// Number of CFL numbers of the sweep, which are equally spaced.
+    pub n_cfl_count: usize,
+    /// Distance travelled by the wave at the end of the calculation.
+    pub ct_end: f64,
+    /// Factor of `\max |u|` to the initial one above which the solution is regarded as diverged, `10` if omitted.
+    #[serde(default = "super::default_growth_limit")]
+    pub growth_limit: f64,
+}
+
+impl InputParams for ExecMapStabilityInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check_result(
+            self.scheme
+                .validate_boundary_condition(self.boundary_condition),
+            "boundary_condition",
+            self.boundary_condition,
+        );
+        errors.check(
+            self.n_cfl_min > 0.0,
+            "n_cfl_min",
+            self.n_cfl_min,
+            "must be positive",
+        );
+        errors.check(
+            self.n_cfl_max >= self.n_cfl_min,
+            "n_cfl_max",
+            self.n_cfl_max,
+            "must not be less than n_cfl_min",
+        );
+        errors.check(
+            self.n_cfl_count > 0,
+            "n_cfl_count",
+            self.n_cfl_count,
+            "must be positive",
+        );
+        errors.check(self.ct_end > 0.0, "ct_end", self.ct_end, "must be positive");
+        errors.check(
+            self.growth_limit > 1.0,
+            "growth_limit",
+            self.growth_limit,
+            "must be greater than 1",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_wave_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecWaveEqInputParams {
+    /// Scheme, with the parameters specific to it.
+    #[serde(flatten)]
+    pub scheme: SchemeKind,
+    /// Number of cells.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number, whose sign is the direction of the advection velocity.
+    /// Only the upwind, implicit upwind, Crank-Nicolson and Beam-Warming methods accept the negative one.
+    pub n_cfl: f64,
+    /// Boundary condition.
+    pub boundary_condition: BoundaryConditionKind,
+    /// Initial condition, the step function at `x = 0` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecWaveEqInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check_result(
+            self.scheme
+                .validate_boundary_condition(self.boundary_condition),
+            "boundary_condition",
+            self.boundary_condition,
+        );
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.n_cfl != 0.0, "n_cfl", self.n_cfl, "must not be zero");
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_wave_eq_by_beamwarming_method_at_large_cfl` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecBeamwarmingAtLargeCflInputParams {
+    /// Number of cells.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Weighting factor in differencing scheme, `0.5` if omitted.
+    #[serde(default = "super::default_lambda")]
+    pub lambda: f64,
+    /// Initial condition, the step function at `x = 0` if omitted.
+    #[serde(default)]
+    pub initial_condition: Profile,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecBeamwarmingAtLargeCflInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.n_cfl != 0.0, "n_cfl", self.n_cfl, "must not be zero");
+        errors.check(
+            self.lambda >= 0.0 && self.lambda <= 1.0,
+            "lambda",
+            self.lambda,
+            "must be between 0 and 1",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `study_convergence_order_of_wave_eq_solvers` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecStudyConvergenceOrderInputParams {
+    /// Scheme to be studied, with the parameters specific to it.
+    #[serde(flatten)]
+    pub scheme: SchemeKind,
+    /// Number of cells of the coarsest grid.
+    pub n_x_min: usize,
+    /// Number of grids, where the number of cells is doubled for each grid.
+    pub n_grid: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Distance travelled by the wave at the end of the calculation.
+    pub ct_end: f64,
+}
+
+impl InputParams for ExecStudyConvergenceOrderInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(
+            self.n_x_min > 0,
+            "n_x_min",
+            self.n_x_min,
+            "must be positive",
+        );
+        errors.check(
+            self.n_grid >= 2,
+            "n_grid",
+            self.n_grid,
+            "must be at least 2",
+        );
+        errors.check(self.n_cfl > 0.0, "n_cfl", self.n_cfl, "must be positive");
+        errors.check(self.ct_end > 0.0, "ct_end", self.ct_end, "must be positive");
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_input_params;
+
+    #[test]
+    fn fn_minimal_input_params_are_completed_by_defaults() {
+        let input_params: ExecWaveEqInputParams = read_input_params(
+            &mut "scheme: beamwarming\nn_x: 20\nstep_max: 10\nn_cfl: 0.5\nboundary_condition: periodic"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(input_params.scheme, SchemeKind::Beamwarming { lambda: 0.5 });
+        assert_eq!(input_params.n_x, 20);
+        assert_eq!(
+            input_params.initial_condition,
+            InitialCondition::Profile(Profile::Step { x_0: 0.0 })
+        );
+        assert_eq!(input_params.ncycle_out, 1);
+    }
+}
@@ -5,30 +5,115 @@
 //! All of the schemes mentioned in the book are implemented in this crate.
 //!
 //! Using this crate, you can actually compute and see how the dissipative and dispersive errors arise for each scheme.
-
+//!
+//! # Features
+//! - `parallel`: Evaluate the explicit stencils in parallel by rayon for the grids larger than
+//!   [PARALLEL_THRESHOLD](solver::PARALLEL_THRESHOLD) points. The results are bitwise identical to the serial ones.
+
+pub mod diagnostics;
+pub mod dispersion;
+pub mod exact;
+pub mod history;
+pub mod initial_conditions;
 pub mod input;
 pub mod math;
 pub mod output;
+pub mod scheme;
 pub mod solver;
 
+use history::History;
 use ndarray::prelude::*;
+use num_traits::Float;
 use solver::Solver;
 use std::error::Error;
 use std::io::Write;
 
 /// Run the solver and output the results.
-pub fn run(
+pub fn run<F: Float>(
+    x: &Array1<f64>,
+    solver: &mut impl Solver<F>,
+    outputstream: &mut impl Write,
+    ncycle_out: usize,
+) -> Result<(), Box<dyn Error>> {
+    // calculate and output
+    output::output(outputstream, 0, x, solver.borrow_u())?;
+    while !solver.is_completed() {
+        solver.integrate()?;
+
+        if solver.get_step().is_multiple_of(ncycle_out) {
+            output::output(outputstream, solver.get_step(), x, solver.borrow_u())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the solver and output the results every `dt_out` in time.
+///
+/// Since the output times `k \Delta t_{out}` are generally not on the time steps,
+/// the results are output at the step nearest to each output time, that is,
+/// the first step with `t \ge k \Delta t_{out} - \Delta t / 2`, without interpolation.
+/// If `dt_out` is less than `\Delta t`, the output times passed within a step are output only once.
+///
+/// The results are labeled with the actual time of the step. See [output::output_at_t] for the format.
+///
+/// # Errors
+/// Returns an error if `dt_out` is not positive or the calculation fails.
+pub fn run_by_time_interval<F: Float>(
+    x: &Array1<f64>,
+    solver: &mut impl Solver<F>,
+    outputstream: &mut impl Write,
+    dt_out: f64,
+) -> Result<(), Box<dyn Error>> {
+    if dt_out <= 0.0 {
+        return Err(Box::<dyn Error>::from("dt_out must be positive"));
+    }
+
+    // calculate and output
+    output::output_at_t(outputstream, solver.get_t(), x, solver.borrow_u())?;
+    let mut t_out = dt_out;
+    while !solver.is_completed() {
+        let t_prev = solver.get_t();
+        solver.integrate()?;
+        let t = solver.get_t();
+        let half_dt = 0.5 * (t - t_prev);
+
+        if t >= t_out - half_dt {
+            output::output_at_t(outputstream, t, x, solver.borrow_u())?;
+            while t_out < t + half_dt {
+                t_out += dt_out;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the solver and output the results, and the diagnostics of every step to `diagnosticsstream`.
+///
+/// See [diagnostics] for the diagnostics and [diagnostics::output_header] for the format.
+/// The grid spacing for the integral of `u` is given by `x`.
+///
+/// # Errors
+/// Returns an error if `x` has less than 2 points or not as many points as `u`, or the calculation fails.
+pub fn run_with_diagnostics<F: Float>(
     x: &Array1<f64>,
-    solver: &mut impl Solver,
+    solver: &mut impl Solver<F>,
     outputstream: &mut impl Write,
+    diagnosticsstream: &mut impl Write,
     ncycle_out: usize,
 ) -> Result<(), Box<dyn Error>> {
+    let dx = grid_spacing(x, solver.borrow_u().len())?;
+
     // calculate and output
     output::output(outputstream, 0, x, solver.borrow_u())?;
+    diagnostics::output_header(diagnosticsstream)?;
+    diagnostics::output(diagnosticsstream, 0, solver.borrow_u(), dx)?;
     while !solver.is_completed() {
         solver.integrate()?;
 
-        if solver.get_step() % ncycle_out == 0 {
+        diagnostics::output(diagnosticsstream, solver.get_step(), solver.borrow_u(), dx)?;
+        if solver.get_step().is_multiple_of(ncycle_out) {
             output::output(outputstream, solver.get_step(), x, solver.borrow_u())?;
         }
     }
@@ -36,16 +121,120 @@ pub fn run(
     Ok(())
 }
 
+/// Run the solver and output the numerical and exact solutions and the errors.
+///
+/// `u_exact` returns the exact solution for the distance `ct` travelled by the wave (see [exact]),
+/// and `ct_per_step` is the distance travelled in each step, `\nu \Delta x`.
+///
+/// # Output Format
+/// The numerical and exact solutions are output to the first and second streams, respectively.
+/// See [output::output] for the format.
+///
+/// The errors against the exact solution are output to the third stream in the following format:
+/// ```text
+/// step ct error_l1 error_l2 error_linf
+/// ```
+/// where `error_*` are the discrete norms of the error. See [exact::ErrorNorms].
+///
+/// # Errors
+/// Returns an error if `x` has less than 2 points or not as many points as `u`, or the calculation fails.
+pub fn run_with_errors<F: Float>(
+    x: &Array1<f64>,
+    solver: &mut impl Solver<F>,
+    u_exact: impl Fn(f64) -> Array1<f64>,
+    ct_per_step: f64,
+    outputstreams: [&mut impl Write; 3],
+    ncycle_out: usize,
+) -> Result<(), Box<dyn Error>> {
+    let [solution_stream, exact_stream, errors_stream] = outputstreams;
+    let dx = grid_spacing(x, solver.borrow_u().len())?;
+
+    writeln!(errors_stream, "step ct error_l1 error_l2 error_linf")?;
+    let mut output_all = |solver: &dyn Solver<F>| -> Result<(), Box<dyn Error>> {
+        let step = solver.get_step();
+        let ct = step as f64 * ct_per_step;
+        let u_exact = u_exact(ct);
+        let error_norms = exact::ErrorNorms::new(solver.borrow_u(), &u_exact, dx);
+
+        output::output(solution_stream, step, x, solver.borrow_u())?;
+        output::output(exact_stream, step, x, &u_exact)?;
+        writeln!(
+            errors_stream,
+            "{} {:.10} {:.10} {:.10} {:.10}",
+            step, ct, error_norms.l1, error_norms.l2, error_norms.linf,
+        )?;
+
+        Ok(())
+    };
+
+    // calculate and output
+    output_all(solver)?;
+    while !solver.is_completed() {
+        solver.integrate()?;
+
+        if solver.get_step().is_multiple_of(ncycle_out) {
+            output_all(solver)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the solver and store the snapshots of `u` in `history` instead of outputting them.
+///
+/// See [History] for the cadence of the snapshots and the accessors.
+pub fn run_with_history<F: Float>(
+    solver: &mut impl Solver<F>,
+    history: &mut History,
+) -> Result<(), Box<dyn Error>> {
+    // calculate and store
+    history.record(solver);
+    while !solver.is_completed() {
+        solver.integrate()?;
+        history.record(solver);
+    }
+
+    Ok(())
+}
+
+/// Return the grid spacing `\Delta x` of the uniform grid `x` of the `n_u` points of `u`.
+///
+/// # Errors
+/// Returns an error if `x` has less than 2 points or not as many points as `u`.
+fn grid_spacing(x: &Array1<f64>, n_u: usize) -> Result<f64, Box<dyn Error>> {
+    if x.len() < 2 {
+        return Err(Box::<dyn Error>::from("x must have at least 2 points"));
+    }
+    if x.len() != n_u {
+        return Err(Box::<dyn Error>::from("x must have as many points as u"));
+    }
+
+    Ok(x[1] - x[0])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use initial_conditions::Profile;
+    use scheme::{SchemeKind, SchemeParams, SpatialOperatorKind, TimeIntegratorKind};
     use solver::beamwarming_solver::{BeamwarmingSolver, BeamwarmingSolverNewParams};
+    use solver::boundary::{BoundaryCondition, Dirichlet, Periodic};
+    use solver::compact_solver::{CompactSolver, CompactSolverNewParams};
+    use solver::cranknicolson_solver::{CranknicolsonSolver, CranknicolsonSolverNewParams};
     use solver::ftcs_solver::{FtcsSolver, FtcsSolverNewParams};
+    use solver::implicit_upwind_solver::{ImplicitUpwindSolver, ImplicitUpwindSolverNewParams};
     use solver::lax_solver::{LaxSolver, LaxSolverNewParams};
+    use solver::laxfriedrichs_solver::{LaxfriedrichsSolver, LaxfriedrichsSolverNewParams};
     use solver::laxwendroff_solver::{LaxwendroffSolver, LaxwendroffSolverNewParams};
     use solver::leapfrog_solver::{LeapfrogSolver, LeapfrogSolverNewParams};
     use solver::maccormack_solver::{MaccormackSolver, MaccormackSolverNewParams};
+    use solver::mol_solver::{MolSolver, MolSolverNewParams};
+    use solver::muscl_solver::{FluxLimiter, MusclSolver, MusclSolverNewParams};
+    use solver::spatial_operator::CentralDifference;
+    use solver::spectral_solver::{SpectralSolver, SpectralSolverNewParams};
+    use solver::time_integrator::Rk2;
     use solver::upwind_solver::{UpwindSolver, UpwindSolverNewParams};
+    use solver::weno_solver::{WenoSolver, WenoSolverNewParams};
 
     #[test]
     fn fn_run_works_with_ftcs_solver() {
@@ -57,9 +246,11 @@ mod tests {
 
         // initialize the solver
         let new_params = FtcsSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = FtcsSolver::new(new_params).unwrap();
 
@@ -128,9 +319,11 @@ mod tests {
 
         // initialize the solver
         let new_params = LaxSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = LaxSolver::new(new_params).unwrap();
 
@@ -185,6 +378,80 @@ mod tests {
 6 1.0000000000 0.0000000000
 
 
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_laxfriedrichs_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = LaxfriedrichsSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            epsilon: 0.75,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = LaxfriedrichsSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.0000000000
+6 -0.8000000000 1.0000000000
+6 -0.7000000000 1.0000000000
+6 -0.6000000000 0.9999961853
+6 -0.5000000000 0.9999504089
+6 -0.4000000000 0.9996070862
+6 -0.3000000000 0.9978523254
+6 -0.2000000000 0.9909286499
+6 -0.1000000000 0.9695968628
+6 0.0000000000 0.9163284302
+6 0.1000000000 0.8096694946
+6 0.2000000000 0.6365776062
+6 0.3000000000 0.4172325134
+6 0.4000000000 0.2026557922
+6 0.5000000000 0.0596046448
+6 0.6000000000 0.0000000000
+6 0.7000000000 0.0000000000
+6 0.8000000000 0.0000000000
+6 0.9000000000 0.0000000000
+6 1.0000000000 0.0000000000
+
+
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
     }
@@ -199,9 +466,11 @@ mod tests {
 
         // initialize the solver
         let new_params = LeapfrogSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 1.0,
+            dt: 0.1,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = LeapfrogSolver::new(new_params).unwrap();
 
@@ -270,9 +539,11 @@ mod tests {
 
         // initialize the solver
         let new_params = LaxwendroffSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = LaxwendroffSolver::new(new_params).unwrap();
 
@@ -341,9 +612,11 @@ mod tests {
 
         // initialize the solver
         let new_params = MaccormackSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = MaccormackSolver::new(new_params).unwrap();
 
@@ -412,9 +685,11 @@ mod tests {
 
         // initialize the solver
         let new_params = UpwindSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = UpwindSolver::new(new_params).unwrap();
 
@@ -483,10 +758,12 @@ mod tests {
 
         // initialize the solver
         let new_params = BeamwarmingSolverNewParams {
-            u: x.map(|x| if *x < 0.0 { 1.0 } else { 0.0 }),
+            u: initial_conditions::step(&x, 0.0),
             step_max: 3,
             n_cfl: 1.0,
+            dt: 0.1,
             lambda: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut solver = BeamwarmingSolver::new(new_params).unwrap();
 
@@ -544,4 +821,854 @@ mod tests {
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
     }
+
+    #[test]
+    fn fn_run_works_with_weno_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = WenoSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = WenoSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.0000000001
+6 -0.8000000000 0.9999999990
+6 -0.7000000000 1.0000000058
+6 -0.6000000000 0.9999999704
+6 -0.5000000000 1.0000001247
+6 -0.4000000000 0.9999995394
+6 -0.3000000000 1.0000018725
+6 -0.2000000000 0.9999947963
+6 -0.1000000000 1.0000013997
+6 0.0000000000 0.9961102433
+6 0.1000000000 0.9524816168
+6 0.2000000000 0.6954940716
+6 0.3000000000 0.2898759988
+6 0.4000000000 0.0552571237
+6 0.5000000000 0.0093305903
+6 0.6000000000 0.0013446865
+6 0.7000000000 0.0001409884
+6 0.8000000000 -0.0000321177
+6 0.9000000000 -0.0000060244
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_muscl_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = MusclSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            limiter: FluxLimiter::Superbee,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = MusclSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.0000000000
+6 -0.8000000000 1.0000000000
+6 -0.7000000000 1.0000000000
+6 -0.6000000000 1.0000000000
+6 -0.5000000000 1.0000000000
+6 -0.4000000000 1.0000000000
+6 -0.3000000000 1.0000000000
+6 -0.2000000000 1.0000000000
+6 -0.1000000000 1.0000000000
+6 0.0000000000 0.9992675781
+6 0.1000000000 0.9798583984
+6 0.2000000000 0.7529907227
+6 0.3000000000 0.2470092773
+6 0.4000000000 0.0201416016
+6 0.5000000000 0.0007324219
+6 0.6000000000 0.0000000000
+6 0.7000000000 0.0000000000
+6 0.8000000000 0.0000000000
+6 0.9000000000 0.0000000000
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_compact_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = CompactSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = CompactSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.3269359411
+6 -0.8000000000 0.9460930526
+6 -0.7000000000 1.1161991804
+6 -0.6000000000 0.8872349777
+6 -0.5000000000 1.0787107416
+6 -0.4000000000 0.9860838488
+6 -0.3000000000 0.9326454019
+6 -0.2000000000 1.1252132096
+6 -0.1000000000 0.8944586419
+6 0.0000000000 0.9825443746
+6 0.1000000000 1.1865011580
+6 0.2000000000 0.7431474262
+6 0.3000000000 0.1593722671
+6 0.4000000000 -0.0300173428
+6 0.5000000000 -0.0099969533
+6 0.6000000000 0.0031891689
+6 0.7000000000 0.0002120884
+6 0.8000000000 -0.0003038868
+6 0.9000000000 0.0000752457
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_spectral_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = SpectralSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.25,
+            dt: 0.025,
+        };
+        let mut solver = SpectralSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 1.0000000000
+
+
+6 -1.0000000000 -0.1392453221
+6 -0.9000000000 0.5000000000
+6 -0.8000000000 1.1392453221
+6 -0.7000000000 0.9189763957
+6 -0.6000000000 1.0603977519
+6 -0.5000000000 0.9481651282
+6 -0.4000000000 1.0494116407
+6 -0.3000000000 0.9481651282
+6 -0.2000000000 1.0603977519
+6 -0.1000000000 0.9189763957
+6 0.0000000000 1.1392453221
+6 0.1000000000 0.5000000000
+6 0.2000000000 -0.1392453221
+6 0.3000000000 0.0810236043
+6 0.4000000000 -0.0603977519
+6 0.5000000000 0.0518348718
+6 0.6000000000 -0.0494116407
+6 0.7000000000 0.0518348718
+6 0.8000000000 -0.0603977519
+6 0.9000000000 0.0810236043
+6 1.0000000000 -0.1392453221
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_mol_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = MolSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            spatial_operator: Box::new(CentralDifference::new(Box::new(Dirichlet::new(1.0, 0.0)))),
+            time_integrator: Box::new(Rk2::new()),
+        };
+        let mut solver = MolSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.0000152234
+6 -0.8000000000 0.9998398814
+6 -0.7000000000 1.0012485320
+6 -0.6000000000 0.9926784234
+6 -0.5000000000 1.0321817743
+6 -0.4000000000 0.8967319038
+6 -0.3000000000 1.2258806434
+6 -0.2000000000 0.7226424646
+6 -0.1000000000 1.0276726615
+6 0.0000000000 1.3225417007
+6 0.1000000000 1.0175115038
+6 0.2000000000 0.5142733250
+6 0.3000000000 0.1851245854
+6 0.4000000000 0.0496747149
+6 0.5000000000 0.0101713641
+6 0.6000000000 0.0016012555
+6 0.7000000000 0.0001926040
+6 0.8000000000 0.0000173049
+6 0.9000000000 0.0000011083
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_implicit_upwind_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = ImplicitUpwindSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = ImplicitUpwindSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 1.0000000000
+6 -0.8000000000 1.0000000000
+6 -0.7000000000 1.0000000000
+6 -0.6000000000 1.0000000000
+6 -0.5000000000 1.0000000000
+6 -0.4000000000 1.0000000000
+6 -0.3000000000 1.0000000000
+6 -0.2000000000 1.0000000000
+6 -0.1000000000 1.0000000000
+6 0.0000000000 0.9122085048
+6 0.1000000000 0.7366255144
+6 0.2000000000 0.5317786923
+6 0.3000000000 0.3496926282
+6 0.4000000000 0.2131280801
+6 0.5000000000 0.1220850480
+6 0.6000000000 0.0664476395
+6 0.7000000000 0.0346548347
+6 0.8000000000 0.0174337321
+6 0.9000000000 0.0085042714
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_works_with_cranknicolson_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+
+        // initialize the solver
+        let new_params = CranknicolsonSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = CranknicolsonSolver::new(new_params).unwrap();
+
+        // execute run()
+        run(&x, &mut solver, &mut outputstream, 6).unwrap();
+
+        // check if the output is correct
+        let output_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.9000000000 1.0000000000
+0 -0.8000000000 1.0000000000
+0 -0.7000000000 1.0000000000
+0 -0.6000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 -0.4000000000 1.0000000000
+0 -0.3000000000 1.0000000000
+0 -0.2000000000 1.0000000000
+0 -0.1000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.1000000000 0.0000000000
+0 0.2000000000 0.0000000000
+0 0.3000000000 0.0000000000
+0 0.4000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 0.6000000000 0.0000000000
+0 0.7000000000 0.0000000000
+0 0.8000000000 0.0000000000
+0 0.9000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+6 -1.0000000000 1.0000000000
+6 -0.9000000000 0.8799599432
+6 -0.8000000000 0.9094011139
+6 -0.7000000000 0.9557630917
+6 -0.6000000000 0.9711841135
+6 -0.5000000000 1.0276851469
+6 -0.4000000000 0.9034152265
+6 -0.3000000000 1.2037776335
+6 -0.2000000000 0.7219917149
+6 -0.1000000000 1.0805717123
+6 0.0000000000 1.3293269561
+6 0.1000000000 0.9708314280
+6 0.2000000000 0.4887065895
+6 0.3000000000 0.1896444644
+6 0.4000000000 0.0609766495
+6 0.5000000000 0.0170443690
+6 0.6000000000 0.0042807151
+6 0.7000000000 0.0009887095
+6 0.8000000000 0.0002134611
+6 0.9000000000 0.0000442013
+6 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_by_time_interval_works() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+
+        // initialize the solver
+        let new_params = UpwindSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 6,
+            n_cfl: 0.1,
+            dt: 0.05,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = UpwindSolver::new(new_params).unwrap();
+
+        // execute run_by_time_interval()
+        run_by_time_interval(&x, &mut solver, &mut outputstream, 0.12).unwrap();
+
+        // check if the steps nearest to t = 0.12 and 0.24 are output
+        let output_expected = "\
+0.0000000000 -1.0000000000 1.0000000000
+0.0000000000 -0.5000000000 1.0000000000
+0.0000000000 0.0000000000 0.0000000000
+0.0000000000 0.5000000000 0.0000000000
+0.0000000000 1.0000000000 0.0000000000
+
+
+0.1000000000 -1.0000000000 1.0000000000
+0.1000000000 -0.5000000000 1.0000000000
+0.1000000000 0.0000000000 0.1900000000
+0.1000000000 0.5000000000 0.0100000000
+0.1000000000 1.0000000000 0.0000000000
+
+
+0.2500000000 -1.0000000000 1.0000000000
+0.2500000000 -0.5000000000 1.0000000000
+0.2500000000 0.0000000000 0.4095100000
+0.2500000000 0.5000000000 0.0814600000
+0.2500000000 1.0000000000 0.0000000000
+
+
+";
+        assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+    }
+
+    #[test]
+    fn fn_run_with_diagnostics_works() {
+        // setup output streams
+        let mut outputstream: Vec<u8> = Vec::new();
+        let mut diagnosticsstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+
+        // initialize the solver
+        let new_params = LaxwendroffSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 2,
+            n_cfl: 0.5,
+            dt: 0.25,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = LaxwendroffSolver::new(new_params).unwrap();
+
+        // execute run_with_diagnostics()
+        run_with_diagnostics(
+            &x,
+            &mut solver,
+            &mut outputstream,
+            &mut diagnosticsstream,
+            2,
+        )
+        .unwrap();
+
+        // check if the diagnostics of every step are output, the overshoot is detected
+        // and the integral grows by the inflow c dt u_{-} = 0.25 per step
+        let diagnostics_expected = "\
+step total_variation u_max u_min integral
+0 1.0000000000 1.0000000000 0.0000000000 0.7500000000
+1 1.2500000000 1.1250000000 0.0000000000 1.0000000000
+2 1.3125000000 1.1562500000 0.0000000000 1.2500000000
+";
+        assert_eq!(
+            String::from_utf8(diagnosticsstream).unwrap(),
+            diagnostics_expected
+        );
+        assert!(String::from_utf8(outputstream)
+            .unwrap()
+            .contains("2 -0.5000000000 1.1562500000"));
+    }
+
+    #[test]
+    fn fn_run_with_diagnostics_rejects_invalid_grid() {
+        // initialize the solver on 5 points
+        let new_params = LaxwendroffSolverNewParams {
+            u: initial_conditions::step(&Array1::linspace(-1.0, 1.0, 4 + 1), 0.0),
+            step_max: 2,
+            n_cfl: 0.5,
+            dt: 0.25,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = LaxwendroffSolver::new(new_params).unwrap();
+
+        // check if the grids of 1 point and of the different number of points are rejected
+        for (x, message) in [
+            (array![0.0], "x must have at least 2 points"),
+            (
+                Array1::linspace(-1.0, 1.0, 2 + 1),
+                "x must have as many points as u",
+            ),
+        ] {
+            let result = run_with_diagnostics(&x, &mut solver, &mut Vec::new(), &mut Vec::new(), 2);
+            assert_eq!(result.unwrap_err().to_string(), message);
+        }
+    }
+
+    #[test]
+    fn fn_run_with_errors_works() {
+        // setup output streams
+        let mut solution_stream: Vec<u8> = Vec::new();
+        let mut exact_stream: Vec<u8> = Vec::new();
+        let mut errors_stream: Vec<u8> = Vec::new();
+
+        // setup coordinates and the exact solution
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+        let profile = Profile::Step { x_0: 0.0 };
+
+        // initialize the solver
+        let new_params = UpwindSolverNewParams {
+            u: profile.create(&x),
+            step_max: 2,
+            n_cfl: 0.5,
+            dt: 0.25,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = UpwindSolver::new(new_params).unwrap();
+
+        // execute run_with_errors()
+        run_with_errors(
+            &x,
+            &mut solver,
+            |ct| exact::solution(&profile, &x, ct),
+            0.5 * 0.5,
+            [&mut solution_stream, &mut exact_stream, &mut errors_stream],
+            2,
+        )
+        .unwrap();
+
+        // check if the output is correct
+        let solution_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+2 -1.0000000000 1.0000000000
+2 -0.5000000000 1.0000000000
+2 0.0000000000 0.7500000000
+2 0.5000000000 0.2500000000
+2 1.0000000000 0.0000000000
+
+
+";
+        let exact_expected = "\
+0 -1.0000000000 1.0000000000
+0 -0.5000000000 1.0000000000
+0 0.0000000000 0.0000000000
+0 0.5000000000 0.0000000000
+0 1.0000000000 0.0000000000
+
+
+2 -1.0000000000 1.0000000000
+2 -0.5000000000 1.0000000000
+2 0.0000000000 1.0000000000
+2 0.5000000000 0.0000000000
+2 1.0000000000 0.0000000000
+
+
+";
+        let errors_expected = "\
+step ct error_l1 error_l2 error_linf
+0 0.0000000000 0.0000000000 0.0000000000 0.0000000000
+2 0.5000000000 0.2500000000 0.2500000000 0.2500000000
+";
+        assert_eq!(
+            String::from_utf8(solution_stream).unwrap(),
+            solution_expected
+        );
+        assert_eq!(String::from_utf8(exact_stream).unwrap(), exact_expected);
+        assert_eq!(String::from_utf8(errors_stream).unwrap(), errors_expected);
+    }
+
+    #[test]
+    fn fn_run_with_errors_rejects_invalid_grid() {
+        // initialize the solver on 5 points
+        let profile = Profile::Step { x_0: 0.0 };
+        let new_params = UpwindSolverNewParams {
+            u: profile.create(&Array1::linspace(-1.0, 1.0, 4 + 1)),
+            step_max: 2,
+            n_cfl: 0.5,
+            dt: 0.25,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = UpwindSolver::new(new_params).unwrap();
+
+        // check if the grids of 1 point and of the different number of points are rejected
+        for (x, message) in [
+            (array![0.0], "x must have at least 2 points"),
+            (
+                Array1::linspace(-1.0, 1.0, 2 + 1),
+                "x must have as many points as u",
+            ),
+        ] {
+            let result = run_with_errors(
+                &x,
+                &mut solver,
+                |ct| exact::solution(&profile, &x, ct),
+                0.5 * 0.5,
+                [&mut Vec::new(), &mut Vec::new(), &mut Vec::new()],
+                2,
+            );
+            assert_eq!(result.unwrap_err().to_string(), message);
+        }
+    }
+
+    #[test]
+    fn fn_run_with_errors_works_in_f32_with_all_schemes() {
+        // run the scheme in the floating-point type F and return the error norms of the last step
+        fn run_in<F: Float + rustfft::FftNum>(
+            scheme: SchemeKind,
+            x: &Array1<f64>,
+            profile: &Profile,
+        ) -> Vec<f64> {
+            let u: Array1<F> = profile.create(x).mapv(|u| F::from(u).unwrap());
+            let boundary_condition: Box<dyn BoundaryCondition<F>> = match scheme {
+                SchemeKind::ImplicitUpwind | SchemeKind::Compact => Box::new(Dirichlet::fixed(&u)),
+                _ => Box::new(Periodic),
+            };
+            let mut solver = scheme
+                .create_solver(SchemeParams {
+                    u,
+                    step_max: 8,
+                    n_cfl: 0.5,
+                    dt: 0.05,
+                    boundary_condition,
+                })
+                .unwrap();
+
+            let [mut solution_stream, mut exact_stream, mut errors_stream] =
+                [Vec::<u8>::new(), Vec::new(), Vec::new()];
+            run_with_errors(
+                x,
+                &mut solver,
+                |ct| exact::solution_periodic(profile, x, ct),
+                0.5 * 0.1,
+                [&mut solution_stream, &mut exact_stream, &mut errors_stream],
+                8,
+            )
+            .unwrap();
+
+            String::from_utf8(errors_stream)
+                .unwrap()
+                .lines()
+                .last()
+                .unwrap()
+                .split_whitespace()
+                .skip(2)
+                .map(|error| error.parse().unwrap())
+                .collect()
+        }
+
+        // setup coordinates and the exact solution
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+        let profile = Profile::Sine { wavelength: 2.0 };
+
+        // check if every scheme runs in f32 with the errors agreeing with those in f64 to the round-off
+        let schemes = [
+            SchemeKind::Ftcs,
+            SchemeKind::Upwind,
+            SchemeKind::ImplicitUpwind,
+            SchemeKind::Lax,
+            SchemeKind::Laxfriedrichs { epsilon: 0.5 },
+            SchemeKind::Laxwendroff,
+            SchemeKind::Leapfrog,
+            SchemeKind::Maccormack,
+            SchemeKind::Cranknicolson,
+            SchemeKind::Beamwarming { lambda: 0.5 },
+            SchemeKind::Compact,
+            SchemeKind::Muscl {
+                limiter: FluxLimiter::VanLeer,
+            },
+            SchemeKind::Weno,
+            SchemeKind::Spectral,
+            SchemeKind::Mol {
+                spatial_operator: SpatialOperatorKind::Upwind,
+                time_integrator: TimeIntegratorKind::AdamsBashforth2,
+            },
+        ];
+        for scheme in schemes {
+            let errors_f32 = run_in::<f32>(scheme, &x, &profile);
+            let errors_f64 = run_in::<f64>(scheme, &x, &profile);
+            let is_error_close = errors_f32
+                .iter()
+                .zip(&errors_f64)
+                .all(|(e_f32, e_f64)| (e_f32 - e_f64).abs() < 1e-5);
+            assert!(is_error_close, "{}", scheme.name());
+        }
+    }
+
+    #[test]
+    fn fn_run_with_history_works() {
+        // initialize the solver
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+        let new_params = UpwindSolverNewParams {
+            u: initial_conditions::step(&x, 0.0),
+            step_max: 5,
+            n_cfl: 0.5,
+            dt: 0.25,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut solver = UpwindSolver::new(new_params).unwrap();
+
+        // run
+        let mut history = History::new(2).unwrap();
+        run_with_history(&mut solver, &mut history).unwrap();
+
+        // check if the snapshots of every 2 steps are stored
+        let steps: Vec<usize> = history.snapshots().iter().map(|s| s.step).collect();
+        assert_eq!(steps, vec![0, 2, 4]);
+        assert_eq!(history.at_time(1.0).unwrap().step, 4);
+        assert_eq!(history.at_step(4).unwrap().u, history.to_array2().row(2));
+    }
 }
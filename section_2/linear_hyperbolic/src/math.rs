@@ -1,3 +1,3 @@
 //! Math module.
 
-pub mod trinomial_eq;
+pub mod limiters;
@@ -0,0 +1,154 @@
+//! Module for the flux limiters of the TVD schemes.
+//!
+//! A flux limiter `\phi(r)` is a function of the ratio of the consecutive gradients,
+//! ```math
+//! r_j = \frac{u_j - u_{j-1}}{u_{j+1} - u_j},
+//! ```
+//! which limits the anti-diffusive flux of the second-order schemes near discontinuities.
+//!
+//! All the limiters in this module lie in the second-order TVD region of Sweby, that is,
+//! ```math
+//! \phi(r) = 0 (r \le 0), r \le \phi(r) \le \min(2 r, 1) (0 < r \le 1), 1 \le \phi(r) \le \min(r, 2) (1 < r),
+//! ```
+//! and are symmetric in the sense that
+//! ```math
+//! \frac{\phi(r)}{r} = \phi(\frac{1}{r}),
+//! ```
+//! so that the forward and backward gradients are treated in the same manner.
+
+/// Flux limiter of the TVD schemes.
+pub trait Limiter {
+    /// Return the limiter function `\phi(r)`.
+    fn phi(&self, r: f64) -> f64;
+}
+
+/// Minmod limiter,
+/// ```math
+/// \phi(r) = \max(0, \min(1, r)),
+/// ```
+/// which is the most dissipative one in the second-order TVD region.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Minmod;
+
+impl Limiter for Minmod {
+    fn phi(&self, r: f64) -> f64 {
+        minmod(r)
+    }
+}
+
+/// Superbee limiter,
+/// ```math
+/// \phi(r) = \max(0, \min(2 r, 1), \min(r, 2)),
+/// ```
+/// which is the least dissipative one in the second-order TVD region.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Superbee;
+
+impl Limiter for Superbee {
+    fn phi(&self, r: f64) -> f64 {
+        superbee(r)
+    }
+}
+
+/// Van Leer limiter,
+/// ```math
+/// \phi(r) = \frac{r + |r|}{1 + |r|},
+/// ```
+/// which is smooth for `r > 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VanLeer;
+
+impl Limiter for VanLeer {
+    fn phi(&self, r: f64) -> f64 {
+        van_leer(r)
+    }
+}
+
+/// Monotonized central (MC) limiter,
+/// ```math
+/// \phi(r) = \max(0, \min(2 r, \frac{1}{2} (1 + r), 2)),
+/// ```
+/// which uses the central gradient unless it violates the TVD condition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mc;
+
+impl Limiter for Mc {
+    fn phi(&self, r: f64) -> f64 {
+        mc(r)
+    }
+}
+
+/// Minmod limiter function. See [Minmod].
+///
+/// # Examples
+/// ```
+/// use linear_hyperbolic::math::limiters;
+///
+/// assert_eq!(limiters::minmod(-1.0), 0.0);
+/// assert_eq!(limiters::minmod(0.5), 0.5);
+/// assert_eq!(limiters::minmod(3.0), 1.0);
+/// ```
+pub fn minmod(r: f64) -> f64 {
+    r.clamp(0.0, 1.0)
+}
+
+/// Superbee limiter function. See [Superbee].
+pub fn superbee(r: f64) -> f64 {
+    0.0_f64.max((2.0 * r).min(1.0)).max(r.min(2.0))
+}
+
+/// Van Leer limiter function. See [VanLeer].
+pub fn van_leer(r: f64) -> f64 {
+    (r + r.abs()) / (1.0 + r.abs())
+}
+
+/// Monotonized central limiter function. See [Mc].
+pub fn mc(r: f64) -> f64 {
+    (2.0 * r).min(0.5 * (1.0 + r)).clamp(0.0, 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITERS: [&dyn Limiter; 4] = [&Minmod, &Superbee, &VanLeer, &Mc];
+
+    fn sample_r() -> impl Iterator<Item = f64> {
+        (1..=200).map(|i| 0.05 * i as f64)
+    }
+
+    #[test]
+    fn fn_phi_is_symmetric() {
+        // check if phi(r) / r = phi(1 / r) for each limiter
+        for limiter in LIMITERS {
+            let is_symmetric =
+                sample_r().all(|r| (limiter.phi(r) / r - limiter.phi(1.0 / r)).abs() < 1e-10);
+            assert!(is_symmetric);
+        }
+    }
+
+    #[test]
+    fn fn_phi_is_in_tvd_region() {
+        for limiter in LIMITERS {
+            // check if phi vanishes for the non-positive r
+            assert!([-10.0, -1.0, -0.5, 0.0]
+                .iter()
+                .all(|r| limiter.phi(*r) == 0.0));
+
+            // check if phi is in the second-order TVD region for the positive r
+            let is_in_tvd_region = sample_r().all(|r| {
+                let phi = limiter.phi(r);
+                let (phi_min, phi_max) = if r <= 1.0 {
+                    (r, (2.0 * r).min(1.0))
+                } else {
+                    (1.0, r.min(2.0))
+                };
+                phi >= phi_min - 1e-10 && phi <= phi_max + 1e-10
+            });
+            assert!(is_in_tvd_region);
+
+            // check if phi(1) = 1 for the second-order accuracy
+            assert!((limiter.phi(1.0) - 1.0).abs() < 1e-10);
+        }
+    }
+}
@@ -1,10 +1,12 @@
 //! Module to output the results.
 
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::io::{Error, Write};
 
 /// Output the results.
 ///
+/// `u` of any floating-point type is output as `f64`.
 /// # Output Format
 /// The output is formatted as follows:
 /// ```text
@@ -53,14 +55,68 @@ use std::io::{Error, Write};
 ///
 /// # Errors
 /// Returns an error if the output fails.
-pub fn output(
+pub fn output<F: Float>(
     outputstream: &mut impl Write,
     step: usize,
     x: &Array1<f64>,
-    u: &Array1<f64>,
+    u: &Array1<F>,
 ) -> Result<(), Error> {
     for (x, u) in x.iter().zip(u.iter()) {
-        writeln!(outputstream, "{} {:.10} {:.10}", step, x, u)?;
+        writeln!(
+            outputstream,
+            "{} {:.10} {:.10}",
+            step,
+            x,
+            u.to_f64().unwrap()
+        )?;
+    }
+    writeln!(outputstream)?;
+    writeln!(outputstream)?;
+
+    Ok(())
+}
+
+/// Output the results labeled with the time `t` instead of the step.
+///
+/// The output is formatted in the same way as [output] except that `step` is replaced with `t`.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use linear_hyperbolic::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let t = 0.25;
+/// let x = array![-1.0, 0.0, 1.0];
+/// let u = array![0.0, 1.0, 2.0];
+/// output::output_at_t(&mut outputstream, t, &x, &u).unwrap();
+///
+/// let output_expected = "\
+/// 0.2500000000 -1.0000000000 0.0000000000
+/// 0.2500000000 0.0000000000 1.0000000000
+/// 0.2500000000 1.0000000000 2.0000000000
+///
+///
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_at_t<F: Float>(
+    outputstream: &mut impl Write,
+    t: f64,
+    x: &Array1<f64>,
+    u: &Array1<F>,
+) -> Result<(), Error> {
+    for (x, u) in x.iter().zip(u.iter()) {
+        writeln!(
+            outputstream,
+            "{:.10} {:.10} {:.10}",
+            t,
+            x,
+            u.to_f64().unwrap()
+        )?;
     }
     writeln!(outputstream)?;
     writeln!(outputstream)?;
@@ -0,0 +1,512 @@
+//! Module to create the solvers by the names of the schemes.
+//!
+//! [SchemeKind] can be named in the input files by the `scheme` field,
+//! together with the parameters specific to the scheme, e.g.
+//! ```yaml
+//! scheme: beamwarming
+//! lambda: 0.5
+//! ```
+//! and creates the solver as `Box<dyn Solver>` from the parameters common to all schemes,
+//! so that the downstream code doesn't need to match on the concrete types of the solvers.
+//!
+//! # Examples
+//! ```
+//! use linear_hyperbolic::scheme::{SchemeKind, SchemeParams};
+//! use linear_hyperbolic::solver::boundary::Dirichlet;
+//! use ndarray::prelude::*;
+//!
+//! let scheme: SchemeKind = serde_yaml::from_str("scheme: laxwendroff").unwrap();
+//! let mut solver = scheme
+//!     .create_solver(SchemeParams {
+//!         u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+//!         step_max: 6,
+//!         n_cfl: 0.5,
+//!         dt: 0.5,
+//!         boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+//!     })
+//!     .unwrap();
+//! solver.integrate().unwrap();
+//!
+//! assert_eq!(solver.get_step(), 1);
+//! ```
+
+use crate::solver::beamwarming_solver::{BeamwarmingSolver, BeamwarmingSolverNewParams};
+use crate::solver::boundary::{BoundaryCondition, Dirichlet, Neumann, Outflow, Periodic};
+use crate::solver::compact_solver::{CompactSolver, CompactSolverNewParams};
+use crate::solver::cranknicolson_solver::{CranknicolsonSolver, CranknicolsonSolverNewParams};
+use crate::solver::ftcs_solver::{FtcsSolver, FtcsSolverNewParams};
+use crate::solver::implicit_upwind_solver::{ImplicitUpwindSolver, ImplicitUpwindSolverNewParams};
+use crate::solver::lax_solver::{LaxSolver, LaxSolverNewParams};
+use crate::solver::laxfriedrichs_solver::{LaxfriedrichsSolver, LaxfriedrichsSolverNewParams};
+use crate::solver::laxwendroff_solver::{LaxwendroffSolver, LaxwendroffSolverNewParams};
+use crate::solver::leapfrog_solver::{LeapfrogSolver, LeapfrogSolverNewParams};
+use crate::solver::maccormack_solver::{MaccormackSolver, MaccormackSolverNewParams};
+use crate::solver::mol_solver::{MolSolver, MolSolverNewParams};
+use crate::solver::muscl_solver::{FluxLimiter, MusclSolver, MusclSolverNewParams};
+use crate::solver::spatial_operator::{
+    CentralDifference, SpatialOperator, UpwindDifference, Weno5,
+};
+use crate::solver::spectral_solver::{SpectralSolver, SpectralSolverNewParams};
+use crate::solver::time_integrator::{AdamsBashforth2, ForwardEuler, Rk2, SspRk3, TimeIntegrator};
+use crate::solver::upwind_solver::{UpwindSolver, UpwindSolverNewParams};
+use crate::solver::weno_solver::{WenoSolver, WenoSolverNewParams};
+use crate::solver::Solver;
+use ndarray::prelude::*;
+use num_traits::Float;
+use rustfft::FftNum;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Scheme for the transport equation, with the parameters specific to it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+pub enum SchemeKind {
+    /// See [crate::solver::ftcs_solver].
+    Ftcs,
+    /// See [crate::solver::upwind_solver].
+    Upwind,
+    /// See [crate::solver::implicit_upwind_solver].
+    ImplicitUpwind,
+    /// See [crate::solver::lax_solver].
+    Lax,
+    /// See [crate::solver::laxfriedrichs_solver].
+    Laxfriedrichs {
+        /// Numerical viscosity coefficient.
+        epsilon: f64,
+    },
+    /// See [crate::solver::laxwendroff_solver].
+    Laxwendroff,
+    /// See [crate::solver::leapfrog_solver].
+    Leapfrog,
+    /// See [crate::solver::maccormack_solver].
+    Maccormack,
+    /// See [crate::solver::cranknicolson_solver].
+    Cranknicolson,
+    /// See [crate::solver::beamwarming_solver].
+    Beamwarming {
+        /// Weighting factor in differencing scheme, `0.5` if omitted.
+        #[serde(default = "crate::input::default_lambda")]
+        lambda: f64,
+    },
+    /// See [crate::solver::compact_solver].
+    Compact,
+    /// See [crate::solver::muscl_solver].
+    Muscl {
+        /// Flux limiter.
+        limiter: FluxLimiter,
+    },
+    /// See [crate::solver::weno_solver].
+    Weno,
+    /// See [crate::solver::spectral_solver].
+    Spectral,
+    /// See [crate::solver::mol_solver].
+    Mol {
+        /// Spatial operator.
+        spatial_operator: SpatialOperatorKind,
+        /// Time integrator.
+        time_integrator: TimeIntegratorKind,
+    },
+}
+
+impl SchemeKind {
+    /// Return the name of the scheme, which is the value of the `scheme` field in the input files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SchemeKind::Ftcs => "ftcs",
+            SchemeKind::Upwind => "upwind",
+            SchemeKind::ImplicitUpwind => "implicit_upwind",
+            SchemeKind::Lax => "lax",
+            SchemeKind::Laxfriedrichs { .. } => "laxfriedrichs",
+            SchemeKind::Laxwendroff => "laxwendroff",
+            SchemeKind::Leapfrog => "leapfrog",
+            SchemeKind::Maccormack => "maccormack",
+            SchemeKind::Cranknicolson => "cranknicolson",
+            SchemeKind::Beamwarming { .. } => "beamwarming",
+            SchemeKind::Compact => "compact",
+            SchemeKind::Muscl { .. } => "muscl",
+            SchemeKind::Weno => "weno",
+            SchemeKind::Spectral => "spectral",
+            SchemeKind::Mol { .. } => "mol",
+        }
+    }
+
+    /// Validate that the kind of the boundary condition is supported by the scheme,
+    /// so that the incompatible combination in the input files is rejected before creating the solver.
+    pub fn validate_boundary_condition(
+        &self,
+        boundary_condition: BoundaryConditionKind,
+    ) -> Result<(), &'static str> {
+        match (self, boundary_condition) {
+            (SchemeKind::Compact, BoundaryConditionKind::Periodic) => {
+                Err("boundary_condition must not be periodic for the compact scheme")
+            }
+            (SchemeKind::Spectral, boundary_condition)
+                if boundary_condition != BoundaryConditionKind::Periodic =>
+            {
+                Err("boundary_condition must be periodic for the spectral method")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Create the solver of the scheme.
+    ///
+    /// # Errors
+    /// Returns an error if the parameters are invalid for the solver,
+    /// e.g. the boundary condition is not supported by the scheme.
+    pub fn create_solver<F: Float + FftNum>(
+        &self,
+        params: SchemeParams<F>,
+    ) -> Result<Box<dyn Solver<F>>, &'static str> {
+        let SchemeParams {
+            u,
+            step_max,
+            n_cfl,
+            dt,
+            boundary_condition,
+        } = params;
+
+        let solver: Box<dyn Solver<F>> = match *self {
+            SchemeKind::Ftcs => Box::new(FtcsSolver::new(FtcsSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Upwind => Box::new(UpwindSolver::new(UpwindSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::ImplicitUpwind => {
+                Box::new(ImplicitUpwindSolver::new(ImplicitUpwindSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                    boundary_condition,
+                })?)
+            }
+            SchemeKind::Lax => Box::new(LaxSolver::new(LaxSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Laxfriedrichs { epsilon } => {
+                Box::new(LaxfriedrichsSolver::new(LaxfriedrichsSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                    epsilon,
+                    boundary_condition,
+                })?)
+            }
+            SchemeKind::Laxwendroff => {
+                Box::new(LaxwendroffSolver::new(LaxwendroffSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                    boundary_condition,
+                })?)
+            }
+            SchemeKind::Leapfrog => Box::new(LeapfrogSolver::new(LeapfrogSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Maccormack => Box::new(MaccormackSolver::new(MaccormackSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Cranknicolson => {
+                Box::new(CranknicolsonSolver::new(CranknicolsonSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                    boundary_condition,
+                })?)
+            }
+            SchemeKind::Beamwarming { lambda } => {
+                Box::new(BeamwarmingSolver::new(BeamwarmingSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                    lambda,
+                    boundary_condition,
+                })?)
+            }
+            SchemeKind::Compact => Box::new(CompactSolver::new(CompactSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Muscl { limiter } => Box::new(MusclSolver::new(MusclSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                limiter,
+                boundary_condition,
+            })?),
+            SchemeKind::Weno => Box::new(WenoSolver::new(WenoSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                boundary_condition,
+            })?),
+            SchemeKind::Spectral => {
+                if !boundary_condition.is_periodic() {
+                    return Err(
+                        "only periodic boundary condition is supported by the spectral method",
+                    );
+                }
+
+                Box::new(SpectralSolver::new(SpectralSolverNewParams {
+                    u,
+                    step_max,
+                    n_cfl,
+                    dt,
+                })?)
+            }
+            SchemeKind::Mol {
+                spatial_operator,
+                time_integrator,
+            } => Box::new(MolSolver::new(MolSolverNewParams {
+                u,
+                step_max,
+                n_cfl,
+                dt,
+                spatial_operator: spatial_operator.create(boundary_condition, n_cfl),
+                time_integrator: time_integrator.create(),
+            })?),
+        };
+
+        Ok(solver)
+    }
+}
+
+/// Parameters common to all schemes for creating a new solver, for `u` of the floating-point type `F`.
+pub struct SchemeParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+/// Kinds of the boundary condition.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryConditionKind {
+    /// See [Dirichlet::fixed].
+    Dirichlet,
+    /// See [Neumann].
+    Neumann,
+    /// See [Periodic].
+    Periodic,
+    /// See [Outflow].
+    Outflow,
+}
+
+impl BoundaryConditionKind {
+    /// Create the boundary condition for the initial value `u`.
+    ///
+    /// # Panics
+    /// Panics if `u` is empty for [BoundaryConditionKind::Dirichlet].
+    pub fn create<F: Float + Debug + 'static>(
+        &self,
+        u: &Array1<F>,
+    ) -> Box<dyn BoundaryCondition<F>> {
+        match self {
+            BoundaryConditionKind::Dirichlet => Box::new(Dirichlet::fixed(u)),
+            BoundaryConditionKind::Neumann => Box::new(Neumann),
+            BoundaryConditionKind::Periodic => Box::new(Periodic),
+            BoundaryConditionKind::Outflow => Box::new(Outflow),
+        }
+    }
+}
+
+/// Kinds of the spatial operator.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpatialOperatorKind {
+    /// See [CentralDifference].
+    Central,
+    /// See [UpwindDifference].
+    Upwind,
+    /// See [Weno5].
+    Weno5,
+}
+
+impl SpatialOperatorKind {
+    /// Create the spatial operator with the boundary condition and the CFL number `n_cfl`,
+    /// whose sign gives the upwind direction.
+    pub fn create<F: Float + Debug + 'static>(
+        &self,
+        boundary_condition: Box<dyn BoundaryCondition<F>>,
+        n_cfl: f64,
+    ) -> Box<dyn SpatialOperator<F>> {
+        match self {
+            SpatialOperatorKind::Central => Box::new(CentralDifference::new(boundary_condition)),
+            SpatialOperatorKind::Upwind => {
+                Box::new(UpwindDifference::new(boundary_condition, n_cfl))
+            }
+            SpatialOperatorKind::Weno5 => Box::new(Weno5::new(boundary_condition)),
+        }
+    }
+}
+
+/// Kinds of the time integrator.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeIntegratorKind {
+    /// See [ForwardEuler].
+    ForwardEuler,
+    /// See [Rk2].
+    Rk2,
+    /// See [SspRk3].
+    SspRk3,
+    /// See [AdamsBashforth2].
+    AdamsBashforth2,
+}
+
+impl TimeIntegratorKind {
+    /// Create the time integrator.
+    pub fn create<F: Float + Debug + 'static>(&self) -> Box<dyn TimeIntegrator<F>> {
+        match self {
+            TimeIntegratorKind::ForwardEuler => Box::new(ForwardEuler),
+            TimeIntegratorKind::Rk2 => Box::new(Rk2::new()),
+            TimeIntegratorKind::SspRk3 => Box::new(SspRk3::new()),
+            TimeIntegratorKind::AdamsBashforth2 => Box::new(AdamsBashforth2::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_scheme_kind_can_be_named_in_input() {
+        // read schemes from the YAML input
+        let cases = [
+            ("scheme: laxwendroff", SchemeKind::Laxwendroff),
+            (
+                "scheme: muscl\nlimiter: van_leer",
+                SchemeKind::Muscl {
+                    limiter: FluxLimiter::VanLeer,
+                },
+            ),
+            (
+                "scheme: beamwarming",
+                SchemeKind::Beamwarming {
+                    lambda: crate::input::default_lambda(),
+                },
+            ),
+            (
+                "scheme: mol\nspatial_operator: weno5\ntime_integrator: ssp_rk3",
+                SchemeKind::Mol {
+                    spatial_operator: SpatialOperatorKind::Weno5,
+                    time_integrator: TimeIntegratorKind::SspRk3,
+                },
+            ),
+        ];
+
+        // check if the schemes are correctly read
+        for (input, scheme) in cases {
+            assert_eq!(serde_yaml::from_str::<SchemeKind>(input).unwrap(), scheme);
+            assert_eq!(
+                format!("scheme: {}", scheme.name()),
+                input.lines().next().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn fn_create_solver_works() {
+        // create the upwind solver and run integrate()
+        let mut solver = SchemeKind::Upwind
+            .create_solver(SchemeParams {
+                u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+                step_max: 6,
+                n_cfl: 0.5,
+                dt: 0.5,
+                boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+            })
+            .unwrap();
+        solver.integrate().unwrap();
+
+        // check if u is updated as with the concrete solver
+        let u_exact = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        let is_u_correctly_updated = (solver.borrow_u() - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+
+        // check if the unsupported boundary conditions are rejected
+        let create_solver = |scheme: SchemeKind, boundary_condition| {
+            scheme
+                .create_solver(SchemeParams {
+                    u: array![1.0, 1.0, 0.0, 0.0, 1.0],
+                    step_max: 6,
+                    n_cfl: 0.5,
+                    dt: 0.5,
+                    boundary_condition,
+                })
+                .map(|_| ())
+        };
+        assert!(create_solver(SchemeKind::Compact, Box::new(Periodic)).is_err());
+        assert!(create_solver(SchemeKind::Spectral, Box::new(Dirichlet::new(1.0, 1.0))).is_err());
+        assert!(create_solver(SchemeKind::Spectral, Box::new(Periodic)).is_ok());
+    }
+
+    #[test]
+    fn fn_validate_boundary_condition_works() {
+        // check if the incompatible combinations are rejected without creating the solver
+        let cases = [
+            (SchemeKind::Compact, BoundaryConditionKind::Periodic, false),
+            (SchemeKind::Compact, BoundaryConditionKind::Outflow, true),
+            (
+                SchemeKind::Spectral,
+                BoundaryConditionKind::Dirichlet,
+                false,
+            ),
+            (SchemeKind::Spectral, BoundaryConditionKind::Periodic, true),
+            (
+                SchemeKind::Laxwendroff,
+                BoundaryConditionKind::Periodic,
+                true,
+            ),
+        ];
+        for (scheme, boundary_condition, is_valid) in cases {
+            assert_eq!(
+                scheme
+                    .validate_boundary_condition(boundary_condition)
+                    .is_ok(),
+                is_valid
+            );
+        }
+    }
+}
@@ -1,26 +1,133 @@
 //! Solvers for the transport equation.
 
 pub mod beamwarming_solver;
+pub mod boundary;
+pub mod compact_solver;
+pub mod cranknicolson_solver;
 pub mod ftcs_solver;
+pub mod implicit_upwind_solver;
 pub mod lax_solver;
+pub mod laxfriedrichs_solver;
 pub mod laxwendroff_solver;
 pub mod leapfrog_solver;
 pub mod maccormack_solver;
+pub mod mol_solver;
+pub mod muscl_solver;
+pub mod spatial_operator;
+pub mod spectral_solver;
+pub mod time_integrator;
 pub mod upwind_solver;
+pub mod weno_solver;
 
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt;
+
+/// Number of points above which the explicit stencils are evaluated in parallel with the `parallel` feature.
+///
+/// The stencils of the smaller grids are evaluated serially, since the overhead of the threads exceeds the gain.
+/// The results are bitwise identical either way, because each point is evaluated independently.
+pub const PARALLEL_THRESHOLD: usize = 100_000;
+
+/// Zip the arrays of a stencil as [ndarray::azip],
+/// or as [ndarray::par_azip] for more than [PARALLEL_THRESHOLD] points with the `parallel` feature.
+macro_rules! stencil_zip {
+    ($len:expr, ($($producers:tt)*) $body:block) => {{
+        #[cfg(feature = "parallel")]
+        if $len > $crate::solver::PARALLEL_THRESHOLD {
+            ndarray::par_azip!(($($producers)*) $body);
+        } else {
+            ndarray::azip!(($($producers)*) $body);
+        }
+        #[cfg(not(feature = "parallel"))]
+        ndarray::azip!(($($producers)*) $body);
+    }};
+}
+pub(crate) use stencil_zip;
+
+/// Cast the coefficient matrix of the implicit schemes, which is calculated in `f64`,
+/// to the floating-point type `F` of `u`.
+pub(crate) fn cast_mat_coef<F: Float>(mat_coef: Array1<(f64, f64, f64)>) -> Array1<(F, F, F)> {
+    let cast = |coef: f64| F::from(coef).unwrap();
+
+    mat_coef.mapv(|(lower, diag, upper)| (cast(lower), cast(diag), cast(upper)))
+}
 
 /// Solver for the transport equation.
-pub trait Solver {
+///
+/// `F` is the floating-point type of `u`.
+/// All the solvers accept `f32` as well as `f64`,
+/// so that the round-off sensitivity of the schemes can be studied in single precision.
+/// The functions to run the solvers, e.g. [run](crate::run), accept either and output `u` as `f64`.
+pub trait Solver<F = f64> {
     /// Return a reference to the current `u`.
-    fn borrow_u(&self) -> &Array1<f64>;
+    fn borrow_u(&self) -> &Array1<F>;
     /// Return the current `step`.
     fn get_step(&self) -> usize;
+    /// Return the current `t`.
+    fn get_t(&self) -> f64;
     /// Return `true` if the calculation has been completed.
     fn is_completed(&self) -> bool;
+    /// Return the stability expected from the known stability bound of the scheme.
+    fn get_stability(&self) -> Stability;
     /// Integrate the transport equation by one step.
     fn integrate(&mut self) -> Result<(), Box<dyn Error>>;
+    /// Restart the calculation from `u_init` at `step = 0`, keeping the parameters of the solver.
+    ///
+    /// The boundary condition is prepared on `u_init` as in the construction,
+    /// while the values fixed at the construction, e.g. the Dirichlet boundary values, are kept.
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>>;
+    /// Integrate the transport equation until `t` reaches `t_target` or the calculation is completed,
+    /// and return the reached `t`.
+    ///
+    /// The integration stops at the first step with `t \ge t_target`, so the reached `t` may exceed `t_target`
+    /// by less than one time step, or fall short of it if the calculation is completed before.
+    /// The rounding errors of `t` are absorbed by the relative tolerance of `1e-10`.
+    fn step_until(&mut self, t_target: f64) -> Result<f64, Box<dyn Error>> {
+        let t_stop = t_target - 1e-10 * t_target.abs().max(1.0);
+        while !self.is_completed() && self.get_t() < t_stop {
+            self.integrate()?;
+        }
+
+        Ok(self.get_t())
+    }
+}
+
+/// The boxed solvers, e.g. those created by [SchemeKind::create_solver](crate::scheme::SchemeKind::create_solver),
+/// can be used as the solvers as they are.
+impl<F, S: Solver<F> + ?Sized> Solver<F> for Box<S> {
+    fn borrow_u(&self) -> &Array1<F> {
+        (**self).borrow_u()
+    }
+
+    fn get_step(&self) -> usize {
+        (**self).get_step()
+    }
+
+    fn get_t(&self) -> f64 {
+        (**self).get_t()
+    }
+
+    fn is_completed(&self) -> bool {
+        (**self).is_completed()
+    }
+
+    fn get_stability(&self) -> Stability {
+        (**self).get_stability()
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        (**self).integrate()
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        (**self).reset(u_init)
+    }
+
+    fn step_until(&mut self, t_target: f64) -> Result<f64, Box<dyn Error>> {
+        (**self).step_until(t_target)
+    }
 }
 
 /// Parameters for creating a new solver.
@@ -28,3 +135,25 @@ pub trait NewParams {
     /// Validate the parameters for creating a new solver.
     fn validate_new_params(&self) -> Result<(), &'static str>;
 }
+
+/// Stability of the scheme expected from its known stability bound, which is evaluated at the construction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stability {
+    /// The scheme is expected to be stable.
+    Stable,
+    /// The scheme is expected to be unstable, with the explanation.
+    Unstable(&'static str),
+    /// No stability bound is known for the given parameters.
+    Unknown,
+}
+
+impl fmt::Display for Stability {
+    /// Format the stability as `stable`, `unstable: explanation` or `unknown`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stability::Stable => write!(f, "stable"),
+            Stability::Unstable(reason) => write!(f, "unstable: {}", reason),
+            Stability::Unknown => write!(f, "unknown"),
+        }
+    }
+}
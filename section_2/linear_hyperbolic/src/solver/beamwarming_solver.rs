@@ -6,88 +6,138 @@
 //! -\frac{\nu}{2} \lambda u_{j-1}^{n+1} + u_j^{n+1} + \frac{\nu}{2} \lambda u_{j+1}^{n+1} =
 //! \frac{\nu}{2} (1 - \lambda) u_{j-1}^n + u_j^n - \frac{\nu}{2} (1 - \lambda) u_{j+1}^n,
 //! ```
-//! where `\nu = c \frac{\Delta t}{\Delta x}` and `\lambda \in [0, 1]` is the weighting factor.
+//! where `\nu = c \frac{\Delta t}{\Delta x}` is the signed CFL number and `\lambda \in [0, 1]` is the weighting factor.
+//! Since the differences are central, either sign of `c` is allowed.
 //!
 //! The Beam-Warming method is equivalent to the Crank-Nicolson method when `\lambda = 0.5`,
 //! explicit euler method when `\lambda = 0` and implicit euler method when `\lambda = 1`.
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
-//! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
-//! ```
+//! See [BoundaryCondition].
+//!
+//! For the non-periodic boundary conditions, the trinomial equation is solved by [TrinomialEq]
+//! and the end points are overwritten by the boundary condition after that.
+//!
+//! For [Periodic](super::boundary::Periodic), the coefficient matrix for `u_0, \cdots, u_{N-1}` on the ring
+//! is cyclic tridiagonal, which is solved by [CyclicTrinomialEq].
 
-use super::{NewParams, Solver};
-use crate::math::trinomial_eq::TrinomialEq;
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{cast_mat_coef, NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
+use numerics::cyclic_trinomial_eq::CyclicTrinomialEq;
+use numerics::trinomial_eq::TrinomialEq;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the Beam-Warming method.
 #[derive(Debug)]
-pub struct BeamwarmingSolver {
-    u: Array1<f64>,
+pub struct BeamwarmingSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
     step_max: usize,
     n_cfl: f64,
     lambda: f64,
-    trinomial_eq: TrinomialEq,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    linear_eq: LinearEq<F>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl BeamwarmingSolver {
+impl<F: Float + Debug + Send + Sync + 'static> BeamwarmingSolver<F> {
     /// Create a new `BeamwarmingSolver` instance.
-    pub fn new(new_params: BeamwarmingSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: BeamwarmingSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
 
-        let u_len = new_params.u.len();
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let linear_eq = if new_params.boundary_condition.is_periodic() {
+            LinearEq::Cyclic(CyclicTrinomialEq::new(cast_mat_coef(
+                Self::create_mat_coef(u.len() - 1, new_params.n_cfl, new_params.lambda),
+            ))?)
+        } else {
+            LinearEq::Trinomial(TrinomialEq::new(cast_mat_coef(Self::create_mat_coef(
+                u.len(),
+                new_params.n_cfl,
+                new_params.lambda,
+            )))?)
+        };
+
+        let u_next = Array1::zeros(u.len());
 
         Ok(Self {
-            u: new_params.u,
+            u,
+            u_next,
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
             lambda: new_params.lambda,
-            trinomial_eq: TrinomialEq::new(Self::create_mat_coef(
-                u_len,
-                new_params.n_cfl,
-                new_params.lambda,
-            )),
+            boundary_condition: new_params.boundary_condition,
+            linear_eq,
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
-        let coef_lower_rhs = 0.5 * self.n_cfl * (1.0 - self.lambda);
-        let coef_diag_rhs = 1.0;
-        let coef_upper_rhs = -coef_lower_rhs;
+    /// Create a new `BeamwarmingSolverBuilder` instance to build a `BeamwarmingSolver` instance.
+    pub fn builder() -> BeamwarmingSolverBuilder<F> {
+        BeamwarmingSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &BeamwarmingSolverNewParams<F>) -> Stability {
+        // the explicit part dominates the implicit part for lambda < 0.5
+        if new_params.lambda < 0.5 {
+            return Stability::Unstable(
+                "the Beam-Warming method is unconditionally unstable for lambda < 0.5",
+            );
+        }
 
-        let mut u_next: Array1<f64> = (0..self.u.len())
-            .map(|i| {
-                if i == 0 {
-                    return coef_diag_rhs * self.u[i] + coef_upper_rhs * self.u[i + 1];
-                }
-                if i == self.u.len() - 1 {
-                    return coef_lower_rhs * self.u[i - 1] + coef_diag_rhs * self.u[i];
-                }
+        Stability::Stable
+    }
 
-                coef_lower_rhs * self.u[i - 1]
+    fn calculate_u_next(&mut self) -> Result<(), Box<dyn Error>> {
+        let coef_lower_rhs = F::from(0.5 * self.n_cfl * (1.0 - self.lambda)).unwrap();
+        let coef_diag_rhs = F::one();
+        let coef_upper_rhs = -coef_lower_rhs;
+
+        let len = self.u.len();
+        let rhs = |i: usize| {
+            if self.boundary_condition.is_periodic() {
+                let i_m = self.boundary_condition.shift(i, -1, len);
+                let i_p = self.boundary_condition.shift(i, 1, len);
+                return coef_lower_rhs * self.u[i_m]
                     + coef_diag_rhs * self.u[i]
-                    + coef_upper_rhs * self.u[i + 1]
-            })
-            .collect();
+                    + coef_upper_rhs * self.u[i_p];
+            }
+            if i == 0 {
+                return coef_diag_rhs * self.u[i] + coef_upper_rhs * self.u[i + 1];
+            }
+            if i == len - 1 {
+                return coef_lower_rhs * self.u[i - 1] + coef_diag_rhs * self.u[i];
+            }
 
-        self.trinomial_eq.solve(&mut u_next)?;
+            coef_lower_rhs * self.u[i - 1]
+                + coef_diag_rhs * self.u[i]
+                + coef_upper_rhs * self.u[i + 1]
+        };
+        for (i, u_next) in self.u_next.indexed_iter_mut() {
+            *u_next = rhs(i);
+        }
 
-        Ok(u_next
-            .indexed_iter()
-            .map(|(i, v)| {
-                if i == 0 || i == u_next.len() - 1 {
-                    return self.u[i];
-                }
+        match &self.linear_eq {
+            LinearEq::Trinomial(trinomial_eq) => trinomial_eq.solve(&mut self.u_next)?,
+            LinearEq::Cyclic(cyclic_trinomial_eq) => {
+                cyclic_trinomial_eq.solve(&mut self.u_next.slice_mut(s![..len - 1]))?
+            }
+        }
+        self.boundary_condition.apply(&mut self.u_next);
 
-                *v
-            })
-            .collect())
+        Ok(())
     }
 
     fn create_mat_coef(n_dim: usize, n_cfl: f64, lambda: f64) -> Array1<(f64, f64, f64)> {
@@ -99,8 +149,8 @@ impl BeamwarmingSolver {
     }
 }
 
-impl Solver for BeamwarmingSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for BeamwarmingSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -108,10 +158,33 @@ impl Solver for BeamwarmingSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -119,7 +192,8 @@ impl Solver for BeamwarmingSolver {
             ));
         }
 
-        self.u = self.calculate_u_next()?;
+        self.calculate_u_next()?;
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -131,18 +205,22 @@ impl Solver for BeamwarmingSolver {
 }
 
 /// Parameters for creating a new `BeamwarmingSolver` instance.
-pub struct BeamwarmingSolverNewParams {
+pub struct BeamwarmingSolverNewParams<F = f64> {
     /// Initial values of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
-    /// CFL number.
+    /// CFL number, whose sign is the direction of the advection velocity.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
     /// Weighting factor in differencing scheme.
     pub lambda: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for BeamwarmingSolverNewParams {
+impl<F: Float> NewParams for BeamwarmingSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -150,20 +228,122 @@ impl NewParams for BeamwarmingSolverNewParams {
         if self.step_max == 0 {
             return Err("step_max must be positive");
         }
-        if self.n_cfl <= 0.0 {
-            return Err("n_cfl must be positive");
+        if self.n_cfl == 0.0 {
+            return Err("n_cfl must not be zero");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
         }
         if self.lambda < 0.0 || self.lambda > 1.0 {
             return Err("lambda must be between 0 and 1");
         }
 
+        self.boundary_condition.validate(self.u.len())?;
+
         Ok(())
     }
 }
 
+/// Linear equation to be solved in each step.
+#[derive(Debug)]
+enum LinearEq<F> {
+    Trinomial(TrinomialEq<F>),
+    Cyclic(CyclicTrinomialEq<F>),
+}
+
+/// Builder of `BeamwarmingSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `lambda`: `0.5`, that is, the Crank-Nicolson method,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct BeamwarmingSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    lambda: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for BeamwarmingSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            lambda: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> BeamwarmingSolverBuilder<F> {
+    /// Set the initial values of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number, whose sign is the direction of the advection velocity.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the weighting factor in differencing scheme.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        self.lambda = Some(lambda);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `BeamwarmingSolver` instance, whose parameters are validated as in [BeamwarmingSolver::new].
+    pub fn build(self) -> Result<BeamwarmingSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        BeamwarmingSolver::new(BeamwarmingSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            lambda: self.lambda.unwrap_or(0.5),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
 
     #[test]
     fn fn_beamwarming_integrate_works() {
@@ -173,17 +353,68 @@ mod tests {
             u: u_init,
             step_max: 3,
             n_cfl: 1.0,
+            dt: 1.0,
             lambda: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
         beamwarming_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 1.22910216718, 0.52631578947, 0.12383900929, 0.0];
+        assert_eq!(beamwarming_solver.get_t(), 1.0);
         let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
             .iter()
             .all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(beamwarming_solver.step, 1);
     }
+
+    #[test]
+    fn fn_beamwarming_new_checks_stability() {
+        // setup beamwarming solvers with the weighting factors above and below 0.5
+        let create_solver = |lambda| {
+            BeamwarmingSolver::new(BeamwarmingSolverNewParams {
+                u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+                step_max: 3,
+                n_cfl: 10.0,
+                dt: 10.0,
+                lambda,
+                boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+            })
+            .unwrap()
+        };
+
+        // check if the stability is evaluated independently of n_cfl
+        assert_eq!(create_solver(0.5).get_stability(), Stability::Stable);
+        assert_eq!(
+            create_solver(0.25).get_stability(),
+            Stability::Unstable(
+                "the Beam-Warming method is unconditionally unstable for lambda < 0.5"
+            )
+        );
+    }
+
+    #[test]
+    fn fn_beamwarming_integrate_works_with_periodic_boundary() {
+        // setup beamwarming solver equivalent to the Crank-Nicolson method on the ring and run integrate()
+        let u_init = array![1.0, 0.0, 0.0, 0.0, 1.0];
+        let new_params = BeamwarmingSolverNewParams {
+            u: u_init,
+            step_max: 3,
+            n_cfl: 2.0,
+            dt: 2.0,
+            lambda: 0.5,
+            boundary_condition: Box::new(Periodic),
+        };
+        let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
+        beamwarming_solver.integrate().unwrap();
+
+        // check if u is advected across the boundary
+        let u_exact = array![0.5, 0.5, 0.5, -0.5, 0.5];
+        let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
 }
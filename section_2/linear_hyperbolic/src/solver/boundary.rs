@@ -0,0 +1,200 @@
+//! Boundary conditions for the solvers.
+//!
+//! Each solver updates the interior points by its own scheme and then applies the injected [BoundaryCondition]
+//! to the end points `u_0` and `u_N`.
+//! Only [Periodic] lets the scheme update the end points as well, with the neighbors wrapped around the domain.
+
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::fmt::Debug;
+
+/// Boundary condition at both ends of the domain, for `u` of the floating-point type `F`.
+pub trait BoundaryCondition<F = f64>: Debug {
+    /// Apply the boundary condition to the end points of `u`, whose interior points have already been updated.
+    fn apply(&self, u: &mut Array1<F>);
+
+    /// Return `true` if the domain is periodic.
+    fn is_periodic(&self) -> bool {
+        false
+    }
+
+    /// Make the initial values of `u` consistent with the boundary condition.
+    fn prepare(&self, _u: &mut Array1<F>) {}
+
+    /// Validate the boundary condition for `len` points.
+    fn validate(&self, len: usize) -> Result<(), &'static str> {
+        if len < 2 {
+            return Err("u must have at least 2 points for the boundary condition");
+        }
+
+        Ok(())
+    }
+
+    /// Return `true` if the value at the `i`-th point of `len` points is given by the boundary condition
+    /// rather than by the scheme.
+    fn is_edge(&self, i: usize, len: usize) -> bool {
+        !self.is_periodic() && (i == 0 || i == len - 1)
+    }
+
+    /// Return the index of the point `offset` points away from the `i`-th point of `len` points.
+    ///
+    /// For the non-periodic boundary conditions, the resulting point must be inside the domain.
+    fn shift(&self, i: usize, offset: isize, len: usize) -> usize {
+        if self.is_periodic() {
+            let period = len as isize - 1;
+            return (i as isize + offset).rem_euclid(period) as usize;
+        }
+
+        (i as isize + offset) as usize
+    }
+}
+
+/// Dirichlet boundary condition,
+/// ```math
+/// u(x_{-}, t) = u_{-}, u(x_{+}, t) = u_{+}.
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dirichlet<F = f64> {
+    u_minus: F,
+    u_plus: F,
+}
+
+impl<F: Float> Dirichlet<F> {
+    /// Create a new `Dirichlet` instance with the values `u_minus` at `x_{-}` and `u_plus` at `x_{+}`.
+    pub fn new(u_minus: F, u_plus: F) -> Self {
+        Self { u_minus, u_plus }
+    }
+
+    /// Create a new `Dirichlet` instance that fixes the end points at the initial values `u`,
+    /// ```math
+    /// u(x_{\pm}, t) = u(x_{\pm}, 0).
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `u` is empty.
+    pub fn fixed(u: &Array1<F>) -> Self {
+        Self::new(u[0], u[u.len() - 1])
+    }
+}
+
+impl<F: Float + Debug> BoundaryCondition<F> for Dirichlet<F> {
+    fn apply(&self, u: &mut Array1<F>) {
+        let last = u.len() - 1;
+        u[0] = self.u_minus;
+        u[last] = self.u_plus;
+    }
+}
+
+/// Neumann boundary condition with zero gradient,
+/// ```math
+/// \frac{\partial u}{\partial x}(x_{\pm}, t) = 0,
+/// ```
+/// which is discretized as `u_0 = u_1` and `u_N = u_{N-1}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Neumann;
+
+impl<F: Float> BoundaryCondition<F> for Neumann {
+    fn apply(&self, u: &mut Array1<F>) {
+        let last = u.len() - 1;
+        u[0] = u[1];
+        u[last] = u[last - 1];
+    }
+}
+
+/// Periodic boundary condition,
+/// ```math
+/// u(x_{-}, t) = u(x_{+}, t).
+/// ```
+///
+/// The first and last points are identified, so the initial value of the last point is replaced with that of the first point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Periodic;
+
+impl<F: Float> BoundaryCondition<F> for Periodic {
+    fn apply(&self, u: &mut Array1<F>) {
+        let last = u.len() - 1;
+        u[last] = u[0];
+    }
+
+    fn is_periodic(&self) -> bool {
+        true
+    }
+
+    fn prepare(&self, u: &mut Array1<F>) {
+        self.apply(u);
+    }
+
+    fn validate(&self, len: usize) -> Result<(), &'static str> {
+        if len < 3 {
+            return Err("u must have at least 3 points for the periodic boundary condition");
+        }
+
+        Ok(())
+    }
+}
+
+/// Outflow boundary condition, in which the end points are linearly extrapolated from the interior as
+/// ```math
+/// u_0 = 2 u_1 - u_2, u_N = 2 u_{N-1} - u_{N-2}.
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outflow;
+
+impl<F: Float> BoundaryCondition<F> for Outflow {
+    fn apply(&self, u: &mut Array1<F>) {
+        let last = u.len() - 1;
+        let two = F::from(2.0).unwrap();
+        u[0] = two * u[1] - u[2];
+        u[last] = two * u[last - 1] - u[last - 2];
+    }
+
+    fn validate(&self, len: usize) -> Result<(), &'static str> {
+        if len < 3 {
+            return Err("u must have at least 3 points for the outflow boundary condition");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_apply_works() {
+        // setup u whose end points are to be overwritten
+        let u_init = array![9.0, 1.0, 2.0, 4.0, 9.0];
+
+        // check if the end points are correctly set by each boundary condition
+        let cases: [(Box<dyn BoundaryCondition>, Array1<f64>); 4] = [
+            (
+                Box::new(Dirichlet::new(-1.0, 1.0)),
+                array![-1.0, 1.0, 2.0, 4.0, 1.0],
+            ),
+            (Box::new(Neumann), array![1.0, 1.0, 2.0, 4.0, 4.0]),
+            (Box::new(Periodic), array![9.0, 1.0, 2.0, 4.0, 9.0]),
+            (Box::new(Outflow), array![0.0, 1.0, 2.0, 4.0, 6.0]),
+        ];
+        for (boundary_condition, u_exact) in cases {
+            let mut u = u_init.clone();
+            boundary_condition.apply(&mut u);
+            assert_eq!(u, u_exact);
+        }
+    }
+
+    #[test]
+    fn fn_shift_works() {
+        // check if the index is shifted without wrapping for the non-periodic boundary conditions
+        let (neumann, periodic): (&dyn BoundaryCondition, &dyn BoundaryCondition) =
+            (&Neumann, &Periodic);
+        assert_eq!(neumann.shift(1, -1, 5), 0);
+        assert_eq!(neumann.shift(3, 1, 5), 4);
+
+        // check if the index is wrapped around the identified end points for the periodic boundary condition
+        assert_eq!(periodic.shift(0, -1, 5), 3);
+        assert_eq!(periodic.shift(3, 1, 5), 0);
+        assert_eq!(periodic.shift(4, 1, 5), 1);
+        assert_eq!(periodic.shift(0, -2, 5), 2);
+    }
+}
@@ -0,0 +1,389 @@
+//! Solver for the transport equation using the fourth-order compact (Padé) scheme.
+//!
+//! # Scheme
+//! The spatial derivative `u_x` is obtained implicitly by the fourth-order compact scheme,
+//! ```math
+//! \frac{1}{4} (u_x)_{j-1} + (u_x)_j + \frac{1}{4} (u_x)_{j+1} = \frac{3}{4 \Delta x} (u_{j+1} - u_{j-1}),
+//! ```
+//! which is a trinomial equation for `u_x` and is solved by [TrinomialEq].
+//!
+//! At the end points, the third-order one-sided closures
+//! ```math
+//! (u_x)_0 + 2 (u_x)_1 = \frac{1}{\Delta x} (- \frac{5}{2} u_0 + 2 u_1 + \frac{1}{2} u_2),
+//! 2 (u_x)_{N-1} + (u_x)_N = \frac{1}{\Delta x} (\frac{5}{2} u_N - 2 u_{N-1} - \frac{1}{2} u_{N-2})
+//! ```
+//! are used.
+//!
+//! Compared to the explicit central difference of the same order, the compact scheme resolves much shorter waves,
+//! so the dispersion error is close to that of the spectral methods.
+//!
+//! The time integration is carried out by the third-order SSP (strong stability preserving) Runge-Kutta method,
+//! ```math
+//! u^{(1)} = u^n + \Delta t L(u^n),
+//! u^{(2)} = \frac{3}{4} u^n + \frac{1}{4} (u^{(1)} + \Delta t L(u^{(1)})),
+//! u^{n+1} = \frac{1}{3} u^n + \frac{2}{3} (u^{(2)} + \Delta t L(u^{(2)})),
+//! ```
+//! where `\Delta t L(u)_j = - \nu \Delta x (u_x)_j` and `\nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+//! The boundary condition is applied after each stage of the Runge-Kutta method.
+//!
+//! [Periodic](super::boundary::Periodic) is not supported.
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{cast_mat_coef, NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the fourth-order compact (Padé) scheme.
+#[derive(Debug)]
+pub struct CompactSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    u_stage: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    trinomial_eq: TrinomialEq<F>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> CompactSolver<F> {
+    /// Create a new `CompactSolver` instance.
+    pub fn new(new_params: CompactSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let u_len = new_params.u.len();
+
+        Ok(Self {
+            u: new_params.u,
+            u_next: Array1::zeros(u_len),
+            u_stage: Array1::zeros(u_len),
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            boundary_condition: new_params.boundary_condition,
+            trinomial_eq: TrinomialEq::new(cast_mat_coef(Self::create_mat_coef(u_len)))?,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `CompactSolverBuilder` instance to build a `CompactSolver` instance.
+    pub fn builder() -> CompactSolverBuilder<F> {
+        CompactSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &CompactSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.abs() > 1.0 {
+            return Stability::Unstable(
+                "the compact scheme with the SSP-RK3 method is unstable for |n_cfl| > 1",
+            );
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) -> Result<(), Box<dyn Error>> {
+        // the buffers are taken out of the instance while the stages borrow it
+        let mut u_stage = std::mem::replace(&mut self.u_stage, Array1::zeros(0));
+        let mut u_next = std::mem::replace(&mut self.u_next, Array1::zeros(0));
+        let result = self.calculate_stages(&mut u_stage, &mut u_next);
+        self.u_stage = u_stage;
+        self.u_next = u_next;
+
+        result
+    }
+
+    /// Write `u^{n+1}` into `u_next`, using `u_stage` for `u^{(1)}` and `\Delta t L(u^{(2)})`.
+    fn calculate_stages(
+        &self,
+        u_stage: &mut Array1<F>,
+        u_next: &mut Array1<F>,
+    ) -> Result<(), Box<dyn Error>> {
+        let c = |x: f64| F::from(x).unwrap();
+        let u = &self.u;
+
+        self.calculate_du_into(u, u_stage)?;
+        azip!((u_1 in &mut *u_stage, &u in u) *u_1 = *u_1 + u);
+        self.boundary_condition.apply(u_stage);
+
+        self.calculate_du_into(u_stage, u_next)?;
+        azip!((u_2 in &mut *u_next, &u in u, &u_1 in &*u_stage) *u_2 = c(0.75) * u + c(0.25) * (u_1 + *u_2));
+        self.boundary_condition.apply(u_next);
+
+        self.calculate_du_into(u_next, u_stage)?;
+        azip!((u_next in &mut *u_next, &u in u, &du in &*u_stage) {
+            *u_next = u / c(3.0) + c(2.0 / 3.0) * (*u_next + du)
+        });
+        self.boundary_condition.apply(u_next);
+
+        Ok(())
+    }
+
+    /// Write `\Delta t L(u)` into `du`.
+    fn calculate_du_into(&self, u: &Array1<F>, du: &mut Array1<F>) -> Result<(), Box<dyn Error>> {
+        let c = |x: f64| F::from(x).unwrap();
+        let n_cfl = c(self.n_cfl);
+
+        let len = u.len();
+
+        // solve the trinomial equation for `\Delta x u_x`
+        for (i, dudx) in du.indexed_iter_mut() {
+            *dudx = if i == 0 {
+                c(-2.5) * u[0] + c(2.0) * u[1] + c(0.5) * u[2]
+            } else if i == len - 1 {
+                c(2.5) * u[i] - c(2.0) * u[i - 1] - c(0.5) * u[i - 2]
+            } else {
+                c(0.75) * (u[i + 1] - u[i - 1])
+            };
+        }
+        self.trinomial_eq.solve(du)?;
+
+        for (i, du) in du.indexed_iter_mut() {
+            *du = if self.boundary_condition.is_edge(i, len) {
+                F::zero()
+            } else {
+                -n_cfl * *du
+            };
+        }
+
+        Ok(())
+    }
+
+    fn create_mat_coef(n_dim: usize) -> Array1<(f64, f64, f64)> {
+        let mut mat_coef = Array::from_elem(n_dim, (0.25, 1.0, 0.25));
+        mat_coef[0] = (0.0, 1.0, 2.0);
+        mat_coef[n_dim - 1] = (2.0, 1.0, 0.0);
+
+        mat_coef
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for CompactSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next()?;
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `CompactSolver` instance.
+pub struct CompactSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for CompactSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.len() < 3 {
+            return Err("u must have at least 3 points");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+        if self.boundary_condition.is_periodic() {
+            return Err("periodic boundary condition is not supported by the compact scheme");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `CompactSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct CompactSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for CompactSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> CompactSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `CompactSolver` instance, whose parameters are validated as in [CompactSolver::new].
+    pub fn build(self) -> Result<CompactSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        CompactSolver::new(CompactSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+
+    #[test]
+    fn fn_compact_calculate_du_works() {
+        // setup compact solver with u = x^3 (\Delta x = 1), whose derivative is exactly given by the scheme
+        let u_init = Array1::from_iter((0..7).map(|i| (i as f64).powi(3)));
+        let new_params = CompactSolverNewParams {
+            u: u_init.clone(),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::fixed(&u_init)),
+        };
+        let compact_solver = CompactSolver::new(new_params).unwrap();
+        let mut du = Array1::zeros(u_init.len());
+        compact_solver.calculate_du_into(&u_init, &mut du).unwrap();
+
+        // check if du = - \nu \Delta x u_x at the interior points
+        let du_exact = array![0.0, -1.5, -6.0, -13.5, -24.0, -37.5, 0.0];
+        let is_du_correctly_calculated = (du - du_exact).iter().all(|du| du.abs() < 1e-10);
+        assert!(is_du_correctly_calculated);
+    }
+
+    #[test]
+    fn fn_compact_integrate_works() {
+        // setup compact solver and run integrate()
+        let u_init = array![1.0, 1.0, 1.0, 1.0, 1.0];
+        let new_params = CompactSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 1.0)),
+        };
+        let mut compact_solver = CompactSolver::new(new_params).unwrap();
+        compact_solver.integrate().unwrap();
+
+        // check if the uniform u is unchanged and step is correctly updated
+        assert_eq!(compact_solver.get_t(), 0.5);
+        let is_u_correctly_updated = compact_solver.u.iter().all(|u| (u - 1.0).abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(compact_solver.step, 1);
+    }
+}
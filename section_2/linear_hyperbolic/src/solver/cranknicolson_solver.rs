@@ -0,0 +1,397 @@
+//! Solver for the transport equation using the Crank-Nicolson method.
+//!
+//! # Scheme
+//! The Crank-Nicolson method is given by
+//! ```math
+//! -\frac{\nu}{4} u_{j-1}^{n+1} + u_j^{n+1} + \frac{\nu}{4} u_{j+1}^{n+1} =
+//! \frac{\nu}{4} u_{j-1}^n + u_j^n - \frac{\nu}{4} u_{j+1}^n,
+//! ```
+//! where `\nu = c \frac{\Delta t}{\Delta x}` is the signed CFL number.
+//! Since the differences are central, either sign of `c` is allowed.
+//!
+//! The method is second-order accurate in both time and space, and unconditionally stable without any dissipation.
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+//!
+//! For the non-periodic boundary conditions, the trinomial equation is solved by [TrinomialEq]
+//! and the end points are overwritten by the boundary condition after that.
+//!
+//! For [Periodic](super::boundary::Periodic), the coefficient matrix for `u_0, \cdots, u_{N-1}` is cyclic tridiagonal,
+//! that is, it has the additional corner elements coupling `u_0` and `u_{N-1}`.
+//! The cyclic system is solved by [CyclicTrinomialEq].
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{cast_mat_coef, NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use numerics::cyclic_trinomial_eq::CyclicTrinomialEq;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the Crank-Nicolson method.
+#[derive(Debug)]
+pub struct CranknicolsonSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    linear_eq: LinearEq<F>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> CranknicolsonSolver<F> {
+    /// Create a new `CranknicolsonSolver` instance.
+    pub fn new(new_params: CranknicolsonSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let linear_eq = if new_params.boundary_condition.is_periodic() {
+            LinearEq::Cyclic(CyclicTrinomialEq::new(cast_mat_coef(
+                Self::create_mat_coef(u.len() - 1, new_params.n_cfl),
+            ))?)
+        } else {
+            LinearEq::Trinomial(TrinomialEq::new(cast_mat_coef(Self::create_mat_coef(
+                u.len(),
+                new_params.n_cfl,
+            )))?)
+        };
+
+        let u_next = Array1::zeros(u.len());
+
+        Ok(Self {
+            u,
+            u_next,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            boundary_condition: new_params.boundary_condition,
+            linear_eq,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `CranknicolsonSolverBuilder` instance to build a `CranknicolsonSolver` instance.
+    pub fn builder() -> CranknicolsonSolverBuilder<F> {
+        CranknicolsonSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &CranknicolsonSolverNewParams<F>) -> Stability {
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) -> Result<(), Box<dyn Error>> {
+        let coef_lower_rhs = F::from(0.25 * self.n_cfl).unwrap();
+        let coef_diag_rhs = F::one();
+        let coef_upper_rhs = -coef_lower_rhs;
+
+        let len = self.u.len();
+        let rhs = |i: usize| {
+            if self.boundary_condition.is_periodic() {
+                let i_m = self.boundary_condition.shift(i, -1, len);
+                let i_p = self.boundary_condition.shift(i, 1, len);
+                return coef_lower_rhs * self.u[i_m]
+                    + coef_diag_rhs * self.u[i]
+                    + coef_upper_rhs * self.u[i_p];
+            }
+            if i == 0 {
+                return coef_diag_rhs * self.u[i] + coef_upper_rhs * self.u[i + 1];
+            }
+            if i == len - 1 {
+                return coef_lower_rhs * self.u[i - 1] + coef_diag_rhs * self.u[i];
+            }
+
+            coef_lower_rhs * self.u[i - 1]
+                + coef_diag_rhs * self.u[i]
+                + coef_upper_rhs * self.u[i + 1]
+        };
+        for (i, u_next) in self.u_next.indexed_iter_mut() {
+            *u_next = rhs(i);
+        }
+
+        match &self.linear_eq {
+            LinearEq::Trinomial(trinomial_eq) => trinomial_eq.solve(&mut self.u_next)?,
+            LinearEq::Cyclic(cyclic_trinomial_eq) => {
+                cyclic_trinomial_eq.solve(&mut self.u_next.slice_mut(s![..len - 1]))?
+            }
+        }
+        self.boundary_condition.apply(&mut self.u_next);
+
+        Ok(())
+    }
+
+    fn create_mat_coef(n_dim: usize, n_cfl: f64) -> Array1<(f64, f64, f64)> {
+        let coef_lower = -0.25 * n_cfl;
+        let coef_diag = 1.0;
+        let coef_upper = -coef_lower;
+
+        Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper))
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for CranknicolsonSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next()?;
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `CranknicolsonSolver` instance.
+pub struct CranknicolsonSolverNewParams<F = f64> {
+    /// Initial values of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number, whose sign is the direction of the advection velocity.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for CranknicolsonSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl == 0.0 {
+            return Err("n_cfl must not be zero");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Linear equation to be solved in each step.
+#[derive(Debug)]
+enum LinearEq<F> {
+    Trinomial(TrinomialEq<F>),
+    Cyclic(CyclicTrinomialEq<F>),
+}
+
+/// Builder of `CranknicolsonSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct CranknicolsonSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for CranknicolsonSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> CranknicolsonSolverBuilder<F> {
+    /// Set the initial values of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number, whose sign is the direction of the advection velocity.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `CranknicolsonSolver` instance, whose parameters are validated as in [CranknicolsonSolver::new].
+    pub fn build(self) -> Result<CranknicolsonSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        CranknicolsonSolver::new(CranknicolsonSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
+
+    #[test]
+    fn fn_cranknicolson_integrate_works() {
+        // setup cranknicolson solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = CranknicolsonSolverNewParams {
+            u: u_init,
+            step_max: 3,
+            n_cfl: 1.0,
+            dt: 1.0,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut cranknicolson_solver = CranknicolsonSolver::new(new_params).unwrap();
+        cranknicolson_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.22910216718, 0.52631578947, 0.12383900929, 0.0];
+        assert_eq!(cranknicolson_solver.get_t(), 1.0);
+        let is_u_correctly_updated = (cranknicolson_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(cranknicolson_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_cranknicolson_reset_works() {
+        // setup cranknicolson solver and run integrate()
+        let new_params = CranknicolsonSolverNewParams {
+            u: array![0.0, 0.0, 0.0, 0.0, 0.0],
+            step_max: 3,
+            n_cfl: 1.0,
+            dt: 1.0,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut cranknicolson_solver = CranknicolsonSolver::new(new_params).unwrap();
+        cranknicolson_solver.integrate().unwrap();
+
+        // reset the solver with another u and run integrate() with the factored matrix reused
+        cranknicolson_solver
+            .reset(array![1.0, 1.0, 0.0, 0.0, 0.0])
+            .unwrap();
+        cranknicolson_solver.integrate().unwrap();
+
+        // check if u, t and step are updated as those of a new solver
+        let u_exact = array![1.0, 1.22910216718, 0.52631578947, 0.12383900929, 0.0];
+        assert_eq!(cranknicolson_solver.get_t(), 1.0);
+        let is_u_correctly_updated = (cranknicolson_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(cranknicolson_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_cranknicolson_integrate_works_with_periodic_boundary() {
+        // setup cranknicolson solver with the periodic boundary condition and run integrate()
+        let u_init = array![1.0, 0.0, 0.0, 0.0, 1.0];
+        let new_params = CranknicolsonSolverNewParams {
+            u: u_init,
+            step_max: 3,
+            n_cfl: 2.0,
+            dt: 2.0,
+            boundary_condition: Box::new(Periodic),
+        };
+        let mut cranknicolson_solver = CranknicolsonSolver::new(new_params).unwrap();
+        cranknicolson_solver.integrate().unwrap();
+
+        // check if u is advected across the boundary
+        let u_exact = array![0.5, 0.5, 0.5, -0.5, 0.5];
+        let is_u_correctly_updated = (cranknicolson_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+}
@@ -7,56 +7,80 @@
 //! ```
 //! where `\nu = c \frac{\Delta t}{\Delta x}`.
 //!
+//! The method is implemented in the method of lines as [CentralDifference] integrated by [ForwardEuler].
+//! See [mol_solver](super::mol_solver).
+//!
 //! # Boundary Condition
-//! The boundary condition is fixed as
-//! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
-//! ```
+//! See [BoundaryCondition].
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::spatial_operator::{CentralDifference, SpatialOperator};
+use super::time_integrator::{ForwardEuler, TimeIntegrator};
+use super::{NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the FTCS method.
 #[derive(Debug)]
-pub struct FtcsSolver {
-    u: Array1<f64>,
+pub struct FtcsSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
     step_max: usize,
     n_cfl: f64,
+    spatial_operator: CentralDifference<F>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl FtcsSolver {
+impl<F: Float + Debug + Send + Sync + 'static> FtcsSolver<F> {
     /// Create a new `FtcsSolver` instance.
-    pub fn new(new_params: FtcsSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: FtcsSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
 
         Ok(Self {
-            u: new_params.u,
+            u,
+            u_next,
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
+            spatial_operator: CentralDifference::new(new_params.boundary_condition),
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        self.u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+    /// Create a new `FtcsSolverBuilder` instance to build a `FtcsSolver` instance.
+    pub fn builder() -> FtcsSolverBuilder<F> {
+        FtcsSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &FtcsSolverNewParams<F>) -> Stability {
+        Stability::Unstable("the FTCS method is unconditionally unstable")
+    }
 
-                self.u[i] - 0.5 * self.n_cfl * (self.u[i + 1] - self.u[i - 1])
-            })
-            .collect()
+    fn calculate_u_next(&mut self) {
+        ForwardEuler.advance_into(
+            &self.spatial_operator,
+            &self.u,
+            self.n_cfl,
+            &mut self.u_next,
+        );
     }
 }
 
-impl Solver for FtcsSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for FtcsSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -64,10 +88,35 @@ impl Solver for FtcsSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.spatial_operator
+            .boundary_condition()
+            .prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -75,7 +124,8 @@ impl Solver for FtcsSolver {
             ));
         }
 
-        self.u = self.calculate_u_next();
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -87,16 +137,20 @@ impl Solver for FtcsSolver {
 }
 
 /// Parameters for creating a new `FtcsSolver` instance.
-pub struct FtcsSolverNewParams {
+pub struct FtcsSolverNewParams<F = f64> {
     /// Initial value of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
     /// CFL number.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for FtcsSolverNewParams {
+impl<F: Float> NewParams for FtcsSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -107,14 +161,99 @@ impl NewParams for FtcsSolverNewParams {
         if self.n_cfl <= 0.0 {
             return Err("n_cfl must be positive");
         }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
 
         Ok(())
     }
 }
 
+/// Builder of `FtcsSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct FtcsSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for FtcsSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> FtcsSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `FtcsSolver` instance, whose parameters are validated as in [FtcsSolver::new].
+    pub fn build(self) -> Result<FtcsSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        FtcsSolver::new(FtcsSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::Dirichlet;
 
     #[test]
     fn fn_ftcs_integrate_works() {
@@ -124,12 +263,15 @@ mod tests {
             u: u_init,
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
         ftcs_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 1.25, 0.25, 0.0, 0.0];
+        assert_eq!(ftcs_solver.get_t(), 0.5);
         let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(ftcs_solver.step, 1);
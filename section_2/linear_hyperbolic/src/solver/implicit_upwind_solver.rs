@@ -0,0 +1,336 @@
+//! Solver for the transport equation using the implicit upwind method.
+//!
+//! # Scheme
+//! The implicit upwind method (backward Euler in time and first-order upwind difference in space) is given by
+//! ```math
+//! -\nu u_{j-1}^{n+1} + (1 + \nu) u_j^{n+1} = u_j^n
+//! ```
+//! for `c > 0` and
+//! ```math
+//! (1 - \nu) u_j^{n+1} + \nu u_{j+1}^{n+1} = u_j^n
+//! ```
+//! for `c < 0`, where `\nu = c \frac{\Delta t}{\Delta x}` is the signed CFL number.
+//!
+//! The coefficient matrix is lower bidiagonal for `c > 0` and upper bidiagonal for `c < 0`,
+//! which is solved as a trinomial equation by [TrinomialEq].
+//!
+//! The amplification factor is
+//! ```math
+//! g = \frac{1}{1 + \nu (1 - e^{-i k \Delta x})}
+//! ```
+//! for `c > 0` (and its mirror image for `c < 0`),
+//! whose magnitude never exceeds 1, so the method is unconditionally stable,
+//! that is, the CFL number can be much larger than 1 unlike the explicit methods.
+//! Instead, the method is highly dissipative at large CFL numbers.
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+//! The end points are overwritten by the boundary condition after the trinomial equation is solved.
+//!
+//! [Periodic](super::boundary::Periodic) is not supported.
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{cast_mat_coef, NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the implicit upwind method.
+#[derive(Debug)]
+pub struct ImplicitUpwindSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    trinomial_eq: TrinomialEq<F>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> ImplicitUpwindSolver<F> {
+    /// Create a new `ImplicitUpwindSolver` instance.
+    pub fn new(new_params: ImplicitUpwindSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let u_len = new_params.u.len();
+
+        Ok(Self {
+            u: new_params.u,
+            u_next: Array1::zeros(u_len),
+            step_max: new_params.step_max,
+            boundary_condition: new_params.boundary_condition,
+            trinomial_eq: TrinomialEq::new(cast_mat_coef(Self::create_mat_coef(
+                u_len,
+                new_params.n_cfl,
+            )))?,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `ImplicitUpwindSolverBuilder` instance to build a `ImplicitUpwindSolver` instance.
+    pub fn builder() -> ImplicitUpwindSolverBuilder<F> {
+        ImplicitUpwindSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &ImplicitUpwindSolverNewParams<F>) -> Stability {
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) -> Result<(), Box<dyn Error>> {
+        self.u_next.assign(&self.u);
+
+        self.trinomial_eq.solve(&mut self.u_next)?;
+        self.boundary_condition.apply(&mut self.u_next);
+
+        Ok(())
+    }
+
+    fn create_mat_coef(n_dim: usize, n_cfl: f64) -> Array1<(f64, f64, f64)> {
+        let coef_lower = -n_cfl.max(0.0);
+        let coef_diag = 1.0 + n_cfl.abs();
+        let coef_upper = n_cfl.min(0.0);
+
+        // the row of the inflow boundary is left as identity
+        let mut mat_coef = Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper));
+        if n_cfl > 0.0 {
+            mat_coef[0] = (0.0, 1.0, 0.0);
+        } else {
+            mat_coef[n_dim - 1] = (0.0, 1.0, 0.0);
+        }
+
+        mat_coef
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for ImplicitUpwindSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next()?;
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `ImplicitUpwindSolver` instance.
+pub struct ImplicitUpwindSolverNewParams<F = f64> {
+    /// Initial values of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number, whose sign is the direction of the advection velocity.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for ImplicitUpwindSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl == 0.0 {
+            return Err("n_cfl must not be zero");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+        if self.boundary_condition.is_periodic() {
+            return Err(
+                "periodic boundary condition is not supported by the implicit upwind method",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder of `ImplicitUpwindSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct ImplicitUpwindSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for ImplicitUpwindSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> ImplicitUpwindSolverBuilder<F> {
+    /// Set the initial values of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number, whose sign is the direction of the advection velocity.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `ImplicitUpwindSolver` instance, whose parameters are validated as in [ImplicitUpwindSolver::new].
+    pub fn build(self) -> Result<ImplicitUpwindSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        ImplicitUpwindSolver::new(ImplicitUpwindSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+
+    #[test]
+    fn fn_implicit_upwind_integrate_works() {
+        // setup implicit upwind solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = ImplicitUpwindSolverNewParams {
+            u: u_init,
+            step_max: 3,
+            n_cfl: 1.0,
+            dt: 1.0,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut implicit_upwind_solver = ImplicitUpwindSolver::new(new_params).unwrap();
+        implicit_upwind_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.0, 0.5, 0.25, 0.0];
+        assert_eq!(implicit_upwind_solver.get_t(), 1.0);
+        let is_u_correctly_updated = (implicit_upwind_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(implicit_upwind_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_implicit_upwind_integrate_works_with_negative_velocity() {
+        // setup implicit upwind solver with the negative CFL number and run integrate()
+        let u_init = array![0.0, 0.0, 0.0, 1.0, 1.0];
+        let new_params = ImplicitUpwindSolverNewParams {
+            u: u_init,
+            step_max: 3,
+            n_cfl: -1.0,
+            dt: 1.0,
+            boundary_condition: Box::new(Dirichlet::new(0.0, 1.0)),
+        };
+        let mut implicit_upwind_solver = ImplicitUpwindSolver::new(new_params).unwrap();
+        implicit_upwind_solver.integrate().unwrap();
+
+        // check if u is advected to the left
+        let u_exact = array![0.0, 0.25, 0.5, 1.0, 1.0];
+        let is_u_correctly_updated = (implicit_upwind_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+}
@@ -8,56 +8,91 @@
 //! where `\nu = c \frac{\Delta t}{\Delta x}`.
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
-//! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
-//! ```
+//! See [BoundaryCondition].
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{stencil_zip, NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the Lax method.
 #[derive(Debug)]
-pub struct LaxSolver {
-    u: Array1<f64>,
+pub struct LaxSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
     step_max: usize,
     n_cfl: f64,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl LaxSolver {
+impl<F: Float + Send + Sync> LaxSolver<F> {
     /// Create a new `LaxSolver` instance.
-    pub fn new(new_params: LaxSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: LaxSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
 
         Ok(Self {
-            u: new_params.u,
+            u,
+            u_next,
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
+            boundary_condition: new_params.boundary_condition,
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        self.u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+    /// Create a new `LaxSolverBuilder` instance to build a `LaxSolver` instance.
+    pub fn builder() -> LaxSolverBuilder<F> {
+        LaxSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &LaxSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.abs() > 1.0 {
+            return Stability::Unstable("the Lax method is unstable for |n_cfl| > 1");
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        let n_cfl = F::from(self.n_cfl).unwrap();
+        let half = F::from(0.5).unwrap();
+        let stencil = |u_m: F, u_p: F| half * (u_m + u_p) - half * n_cfl * (u_p - u_m);
 
-                0.5 * (self.u[i - 1] + self.u[i + 1])
-                    - 0.5 * self.n_cfl * (self.u[i + 1] - self.u[i - 1])
-            })
-            .collect()
+        let u = &self.u;
+        let len = u.len();
+        stencil_zip!(len, (
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_m in u.slice(s![..len - 2]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_next = stencil(u_m, u_p);
+        });
+        if self.boundary_condition.is_periodic() {
+            let i_m = self.boundary_condition.shift(0, -1, len);
+            self.u_next[0] = stencil(u[i_m], u[1]);
+        }
+
+        self.boundary_condition.apply(&mut self.u_next);
     }
 }
 
-impl Solver for LaxSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Send + Sync> Solver<F> for LaxSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -65,10 +100,33 @@ impl Solver for LaxSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -76,7 +134,8 @@ impl Solver for LaxSolver {
             ));
         }
 
-        self.u = self.calculate_u_next();
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -88,16 +147,20 @@ impl Solver for LaxSolver {
 }
 
 /// Parameters for creating a new `LaxSolver` instance.
-pub struct LaxSolverNewParams {
+pub struct LaxSolverNewParams<F = f64> {
     /// Initial value of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
     /// CFL number.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for LaxSolverNewParams {
+impl<F: Float> NewParams for LaxSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -108,14 +171,99 @@ impl NewParams for LaxSolverNewParams {
         if self.n_cfl <= 0.0 {
             return Err("n_cfl must be positive");
         }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
 
         Ok(())
     }
 }
 
+/// Builder of `LaxSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct LaxSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for LaxSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> LaxSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `LaxSolver` instance, whose parameters are validated as in [LaxSolver::new].
+    pub fn build(self) -> Result<LaxSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        LaxSolver::new(LaxSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
 
     #[test]
     fn fn_lax_integrate_works() {
@@ -125,14 +273,41 @@ mod tests {
             u: u_init,
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut lax_solver = LaxSolver::new(new_params).unwrap();
         lax_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 0.75, 0.75, 0.0, 0.0];
+        assert_eq!(lax_solver.get_t(), 0.5);
         let is_u_correctly_updated = (lax_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(lax_solver.step, 1);
     }
+
+    #[test]
+    fn fn_lax_integrate_works_on_large_grid() {
+        // setup lax solver on the grid large enough to be evaluated in parallel with the parallel feature
+        let len = crate::solver::PARALLEL_THRESHOLD + 3;
+        let u_init = Array1::from_shape_fn(len, |i| ((i * i) % 7) as f64);
+        let mut lax_solver = LaxSolver::builder()
+            .u(u_init.clone())
+            .step_max(1)
+            .n_cfl(0.5)
+            .boundary_condition(Box::new(Periodic))
+            .build()
+            .unwrap();
+        lax_solver.integrate().unwrap();
+
+        // check if u is bitwise identical to that evaluated point by point
+        let mut u_exact = Array1::from_shape_fn(len, |i| {
+            let i_m = if i == 0 { len - 2 } else { i - 1 };
+            let i_p = if i == len - 1 { 1 } else { i + 1 };
+            0.5 * (u_init[i_m] + u_init[i_p]) - 0.5 * 0.5 * (u_init[i_p] - u_init[i_m])
+        });
+        u_exact[len - 1] = u_exact[0];
+        assert_eq!(lax_solver.u, u_exact);
+    }
 }
@@ -0,0 +1,319 @@
+//! Solver for the transport equation using the Lax-Friedrichs method with tunable numerical viscosity.
+//!
+//! # Scheme
+//! The Lax-Friedrichs method with tunable numerical viscosity is given by
+//! ```math
+//! u_j^{n+1} = u_j^n - \frac{1}{2} \nu (u_{j+1}^n - u_{j-1}^n) + \frac{1}{2} \epsilon (u_{j+1}^n - 2 u_j^n + u_{j-1}^n),
+//! ```
+//! where `\nu = c \frac{\Delta t}{\Delta x}` and `\epsilon \in [0, 1]` is the numerical viscosity coefficient.
+//!
+//! The method is equivalent to the FTCS method when `\epsilon = 0` and the Lax method when `\epsilon = 1`,
+//! so the intermediate values of `\epsilon` show the continuum between the two.
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{stencil_zip, NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the Lax-Friedrichs method.
+#[derive(Debug)]
+pub struct LaxfriedrichsSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    epsilon: f64,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Send + Sync> LaxfriedrichsSolver<F> {
+    /// Create a new `LaxfriedrichsSolver` instance.
+    pub fn new(new_params: LaxfriedrichsSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
+
+        Ok(Self {
+            u,
+            u_next,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            epsilon: new_params.epsilon,
+            boundary_condition: new_params.boundary_condition,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `LaxfriedrichsSolverBuilder` instance to build a `LaxfriedrichsSolver` instance.
+    pub fn builder() -> LaxfriedrichsSolverBuilder<F> {
+        LaxfriedrichsSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &LaxfriedrichsSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.powi(2) > new_params.epsilon {
+            return Stability::Unstable(
+                "the Lax-Friedrichs method is unstable for n_cfl^2 > epsilon",
+            );
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        let n_cfl = F::from(self.n_cfl).unwrap();
+        let epsilon = F::from(self.epsilon).unwrap();
+        let half = F::from(0.5).unwrap();
+        let two = F::from(2.0).unwrap();
+        let stencil = |u_m: F, u_c: F, u_p: F| {
+            u_c - half * n_cfl * (u_p - u_m) + half * epsilon * (u_p - two * u_c + u_m)
+        };
+
+        let u = &self.u;
+        let len = u.len();
+        stencil_zip!(len, (
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_m in u.slice(s![..len - 2]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_next = stencil(u_m, u_c, u_p);
+        });
+        if self.boundary_condition.is_periodic() {
+            let i_m = self.boundary_condition.shift(0, -1, len);
+            self.u_next[0] = stencil(u[i_m], u[0], u[1]);
+        }
+
+        self.boundary_condition.apply(&mut self.u_next);
+    }
+}
+
+impl<F: Float + Send + Sync> Solver<F> for LaxfriedrichsSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `LaxfriedrichsSolver` instance.
+pub struct LaxfriedrichsSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Numerical viscosity coefficient.
+    pub epsilon: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for LaxfriedrichsSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+        if self.epsilon < 0.0 || self.epsilon > 1.0 {
+            return Err("epsilon must be between 0 and 1");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `LaxfriedrichsSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `epsilon`: `1`, that is, the Lax method,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct LaxfriedrichsSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    epsilon: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for LaxfriedrichsSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            epsilon: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> LaxfriedrichsSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the numerical viscosity coefficient.
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Some(epsilon);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `LaxfriedrichsSolver` instance, whose parameters are validated as in [LaxfriedrichsSolver::new].
+    pub fn build(self) -> Result<LaxfriedrichsSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        LaxfriedrichsSolver::new(LaxfriedrichsSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            epsilon: self.epsilon.unwrap_or(1.0),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+
+    #[test]
+    fn fn_laxfriedrichs_integrate_works() {
+        // setup laxfriedrichs solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = LaxfriedrichsSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            epsilon: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut laxfriedrichs_solver = LaxfriedrichsSolver::new(new_params).unwrap();
+        laxfriedrichs_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        assert_eq!(laxfriedrichs_solver.get_t(), 0.5);
+        let is_u_correctly_updated = (laxfriedrichs_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(laxfriedrichs_solver.step, 1);
+    }
+}
@@ -25,67 +25,112 @@
 //! **The latter is implemented in this module.**
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
-//! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
-//! ```
+//! See [BoundaryCondition].
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{stencil_zip, NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the Lax-Wendroff method.
 #[derive(Debug)]
-pub struct LaxwendroffSolver {
-    u: Array1<f64>,
+pub struct LaxwendroffSolver<F = f64> {
+    u: Array1<F>,
+    u_halfstep: Array1<F>,
+    u_next: Array1<F>,
     step_max: usize,
     n_cfl: f64,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl LaxwendroffSolver {
+impl<F: Float + Send + Sync> LaxwendroffSolver<F> {
     /// Create a new `LaxwendroffSolver` instance.
-    pub fn new(new_params: LaxwendroffSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: LaxwendroffSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_halfstep = Array1::zeros(u.len());
+        let u_next = Array1::zeros(u.len());
 
         Ok(Self {
-            u: new_params.u,
+            u,
+            u_halfstep,
+            u_next,
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
+            boundary_condition: new_params.boundary_condition,
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        let u_halfstep: Array1<f64> = self
-            .u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+    /// Create a new `LaxwendroffSolverBuilder` instance to build a `LaxwendroffSolver` instance.
+    pub fn builder() -> LaxwendroffSolverBuilder<F> {
+        LaxwendroffSolverBuilder::default()
+    }
 
-                0.5 * (self.u[i + 1] + self.u[i]) - 0.5 * self.n_cfl * (self.u[i + 1] - self.u[i])
-            })
-            .collect();
+    fn check_stability(new_params: &LaxwendroffSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.abs() > 1.0 {
+            return Stability::Unstable("the Lax-Wendroff method is unstable for |n_cfl| > 1");
+        }
 
-        self.u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+        Stability::Stable
+    }
 
-                self.u[i] - self.n_cfl * (u_halfstep[i] - u_halfstep[i - 1])
-            })
-            .collect()
+    fn calculate_u_next(&mut self) {
+        let n_cfl = F::from(self.n_cfl).unwrap();
+        let half = F::from(0.5).unwrap();
+        let stencil_halfstep = |u_c: F, u_p: F| half * (u_p + u_c) - half * n_cfl * (u_p - u_c);
+        let stencil =
+            |u_c: F, u_halfstep_m: F, u_halfstep_c: F| u_c - n_cfl * (u_halfstep_c - u_halfstep_m);
+        let is_periodic = self.boundary_condition.is_periodic();
+
+        let u = &self.u;
+        let len = u.len();
+        stencil_zip!(len, (
+            u_halfstep in self.u_halfstep.slice_mut(s![1..len - 1]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_halfstep = stencil_halfstep(u_c, u_p);
+        });
+        self.u_halfstep[0] = if is_periodic {
+            stencil_halfstep(u[0], u[1])
+        } else {
+            u[0]
+        };
+
+        let u_halfstep = &self.u_halfstep;
+        stencil_zip!(len, (
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_halfstep_m in u_halfstep.slice(s![..len - 2]),
+            &u_halfstep_c in u_halfstep.slice(s![1..len - 1])
+        ) {
+            *u_next = stencil(u_c, u_halfstep_m, u_halfstep_c);
+        });
+        if is_periodic {
+            let i_m = self.boundary_condition.shift(0, -1, len);
+            self.u_next[0] = stencil(u[0], u_halfstep[i_m], u_halfstep[0]);
+        }
+
+        self.boundary_condition.apply(&mut self.u_next);
     }
 }
 
-impl Solver for LaxwendroffSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Send + Sync> Solver<F> for LaxwendroffSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -93,10 +138,33 @@ impl Solver for LaxwendroffSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -104,7 +172,8 @@ impl Solver for LaxwendroffSolver {
             ));
         }
 
-        self.u = self.calculate_u_next();
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -116,16 +185,20 @@ impl Solver for LaxwendroffSolver {
 }
 
 /// Parameters for creating a new `LaxwendroffSolver` instance.
-pub struct LaxwendroffSolverNewParams {
+pub struct LaxwendroffSolverNewParams<F = f64> {
     /// Initial value of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
     /// CFL number.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for LaxwendroffSolverNewParams {
+impl<F: Float> NewParams for LaxwendroffSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -136,14 +209,99 @@ impl NewParams for LaxwendroffSolverNewParams {
         if self.n_cfl <= 0.0 {
             return Err("n_cfl must be positive");
         }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
 
         Ok(())
     }
 }
 
+/// Builder of `LaxwendroffSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct LaxwendroffSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for LaxwendroffSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> LaxwendroffSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `LaxwendroffSolver` instance, whose parameters are validated as in [LaxwendroffSolver::new].
+    pub fn build(self) -> Result<LaxwendroffSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        LaxwendroffSolver::new(LaxwendroffSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::Dirichlet;
 
     #[test]
     fn fn_laxwendroff_integrate_works() {
@@ -153,12 +311,15 @@ mod tests {
             u: u_init,
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut laxwendroff_solver = LaxwendroffSolver::new(new_params).unwrap();
         laxwendroff_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 1.125, 0.375, 0.0, 0.0];
+        assert_eq!(laxwendroff_solver.get_t(), 0.5);
         let is_u_correctly_updated = (laxwendroff_solver.u - u_exact)
             .iter()
             .all(|u| u.abs() < 1e-10);
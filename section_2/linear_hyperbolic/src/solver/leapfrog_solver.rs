@@ -8,57 +8,92 @@
 //! where `\nu = c \frac{\Delta t}{\Delta x}`.
 //!
 //! # Boundary Condition
-//! The boundary condition is fixed as
-//! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
-//! ```
+//! See [BoundaryCondition].
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{stencil_zip, NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the Leap-Frog method.
 #[derive(Debug)]
-pub struct LeapfrogSolver {
-    u: Array1<f64>,
+pub struct LeapfrogSolver<F = f64> {
+    u: Array1<F>,
     step_max: usize,
     n_cfl: f64,
-    u_prev: Array1<f64>,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    u_prev: Array1<F>,
+    u_next: Array1<F>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl LeapfrogSolver {
+impl<F: Float + Send + Sync> LeapfrogSolver<F> {
     /// Create a new `LeapfrogSolver` instance.
-    pub fn new(new_params: LeapfrogSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: LeapfrogSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
 
         Ok(Self {
-            u: new_params.u.clone(),
+            u: u.clone(),
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
-            u_prev: new_params.u,
+            boundary_condition: new_params.boundary_condition,
+            u_next: Array1::zeros(u.len()),
+            u_prev: u,
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        self.u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+    /// Create a new `LeapfrogSolverBuilder` instance to build a `LeapfrogSolver` instance.
+    pub fn builder() -> LeapfrogSolverBuilder<F> {
+        LeapfrogSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &LeapfrogSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.abs() > 1.0 {
+            return Stability::Unstable("the Leap-Frog method is unstable for |n_cfl| > 1");
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        let n_cfl = F::from(self.n_cfl).unwrap();
+        let half = F::from(0.5).unwrap();
+        let stencil = |u_prev: F, u_m: F, u_p: F| u_prev - half * n_cfl * (u_p - u_m);
+
+        let (u, u_prev) = (&self.u, &self.u_prev);
+        let len = u.len();
+        stencil_zip!(len, (
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_prev in u_prev.slice(s![1..len - 1]),
+            &u_m in u.slice(s![..len - 2]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_next = stencil(u_prev, u_m, u_p);
+        });
+        if self.boundary_condition.is_periodic() {
+            let i_m = self.boundary_condition.shift(0, -1, len);
+            self.u_next[0] = stencil(u_prev[0], u[i_m], u[1]);
+        }
 
-                self.u_prev[i] - 0.5 * self.n_cfl * (self.u[i + 1] - self.u[i - 1])
-            })
-            .collect()
+        self.boundary_condition.apply(&mut self.u_next);
     }
 }
 
-impl Solver for LeapfrogSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Send + Sync> Solver<F> for LeapfrogSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -66,10 +101,34 @@ impl Solver for LeapfrogSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.u_prev.assign(&self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -77,9 +136,9 @@ impl Solver for LeapfrogSolver {
             ));
         }
 
-        let next_u = self.calculate_u_next();
-        self.u_prev = self.u.clone();
-        self.u = next_u;
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u_prev, &mut self.u);
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -91,16 +150,20 @@ impl Solver for LeapfrogSolver {
 }
 
 /// Parameters for creating a new `LeapfrogSolver` instance.
-pub struct LeapfrogSolverNewParams {
+pub struct LeapfrogSolverNewParams<F = f64> {
     /// Initial value of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
     /// CFL number.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for LeapfrogSolverNewParams {
+impl<F: Float> NewParams for LeapfrogSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -111,14 +174,99 @@ impl NewParams for LeapfrogSolverNewParams {
         if self.n_cfl <= 0.0 {
             return Err("n_cfl must be positive");
         }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
 
         Ok(())
     }
 }
 
+/// Builder of `LeapfrogSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct LeapfrogSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for LeapfrogSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> LeapfrogSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `LeapfrogSolver` instance, whose parameters are validated as in [LeapfrogSolver::new].
+    pub fn build(self) -> Result<LeapfrogSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        LeapfrogSolver::new(LeapfrogSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
 
     #[test]
     fn fn_leapfrog_integrate_works() {
@@ -128,16 +276,50 @@ mod tests {
             u: u_init,
             step_max: 6,
             n_cfl: 1.0,
+            dt: 1.0,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut leapfrog_solver = LeapfrogSolver::new(new_params).unwrap();
         leapfrog_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 1.5, 0.5, 0.0, 0.0];
+        assert_eq!(leapfrog_solver.get_t(), 1.0);
         let is_u_correctly_updated = (leapfrog_solver.u - u_exact)
             .iter()
             .all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(leapfrog_solver.step, 1);
     }
+
+    #[test]
+    fn fn_leapfrog_works_in_single_precision() {
+        // setup leapfrog solvers in single and double precision and run them on the same sine wave
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 21);
+        let u_init = x.mapv(|x| (std::f64::consts::PI * x).sin());
+        let mut solver_f32 = LeapfrogSolver::builder()
+            .u(u_init.mapv(|u| u as f32))
+            .step_max(40)
+            .n_cfl(0.5)
+            .boundary_condition(Box::new(Periodic))
+            .build()
+            .unwrap();
+        let mut solver_f64 = LeapfrogSolver::builder()
+            .u(u_init)
+            .step_max(40)
+            .n_cfl(0.5)
+            .boundary_condition(Box::new(Periodic))
+            .build()
+            .unwrap();
+        while !solver_f64.is_completed() {
+            solver_f32.integrate().unwrap();
+            solver_f64.integrate().unwrap();
+        }
+
+        // check if the solutions agree up to the round-off errors of single precision
+        let is_u_close = (solver_f32.borrow_u().mapv(f64::from) - solver_f64.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-5);
+        assert!(is_u_close);
+    }
 }
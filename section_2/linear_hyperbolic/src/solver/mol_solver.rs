@@ -0,0 +1,303 @@
+//! Solver for the transport equation using the method of lines.
+//!
+//! # Scheme
+//! The transport equation is discretized in space by a [SpatialOperator] into the system of ODEs,
+//! ```math
+//! \frac{d u_j}{d \tau} = L(u)_j,
+//! ```
+//! where `\tau = c t / \Delta x`, and the system is integrated in time by a [TimeIntegrator]
+//! with the normalized time step `\Delta \tau = \nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! Any combination of the spatial operators and the time integrators can be used.
+//! For example, [CentralDifference](super::spatial_operator::CentralDifference) with
+//! [ForwardEuler](super::time_integrator::ForwardEuler) is the FTCS method and
+//! [UpwindDifference](super::spatial_operator::UpwindDifference) with
+//! [ForwardEuler](super::time_integrator::ForwardEuler) is the upwind method.
+//!
+//! # Boundary Condition
+//! The boundary condition of the spatial operator is used. See [BoundaryCondition](super::boundary::BoundaryCondition).
+
+use super::spatial_operator::SpatialOperator;
+use super::time_integrator::{SspRk3, TimeIntegrator};
+use super::{NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the method of lines.
+#[derive(Debug)]
+pub struct MolSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    spatial_operator: Box<dyn SpatialOperator<F>>,
+    time_integrator: Box<dyn TimeIntegrator<F>>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> MolSolver<F> {
+    /// Create a new `MolSolver` instance.
+    pub fn new(new_params: MolSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params
+            .spatial_operator
+            .boundary_condition()
+            .prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
+
+        Ok(Self {
+            u,
+            u_next,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            spatial_operator: new_params.spatial_operator,
+            time_integrator: new_params.time_integrator,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `MolSolverBuilder` instance to build a `MolSolver` instance.
+    pub fn builder() -> MolSolverBuilder<F> {
+        MolSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &MolSolverNewParams<F>) -> Stability {
+        // the bound depends on the combination of the spatial operator and the time integrator
+        Stability::Unknown
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for MolSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.spatial_operator
+            .boundary_condition()
+            .prepare(&mut self.u);
+        self.time_integrator.reset();
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.time_integrator.advance_into(
+            self.spatial_operator.as_ref(),
+            &self.u,
+            self.n_cfl,
+            &mut self.u_next,
+        );
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `MolSolver` instance.
+pub struct MolSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Spatial operator.
+    pub spatial_operator: Box<dyn SpatialOperator<F>>,
+    /// Time integrator.
+    pub time_integrator: Box<dyn TimeIntegrator<F>>,
+}
+
+impl<F: Float> NewParams for MolSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.spatial_operator
+            .boundary_condition()
+            .validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `MolSolver` instances.
+///
+/// `u`, `step_max`, `n_cfl` and `spatial_operator` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `time_integrator`: [SspRk3].
+#[derive(Debug)]
+pub struct MolSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    spatial_operator: Option<Box<dyn SpatialOperator<F>>>,
+    time_integrator: Option<Box<dyn TimeIntegrator<F>>>,
+}
+
+impl<F> Default for MolSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            spatial_operator: None,
+            time_integrator: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> MolSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the spatial operator.
+    pub fn spatial_operator(mut self, spatial_operator: Box<dyn SpatialOperator<F>>) -> Self {
+        self.spatial_operator = Some(spatial_operator);
+        self
+    }
+
+    /// Set the time integrator.
+    pub fn time_integrator(mut self, time_integrator: Box<dyn TimeIntegrator<F>>) -> Self {
+        self.time_integrator = Some(time_integrator);
+        self
+    }
+
+    /// Build a new `MolSolver` instance, whose parameters are validated as in [MolSolver::new].
+    pub fn build(self) -> Result<MolSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        let spatial_operator = self
+            .spatial_operator
+            .ok_or("spatial_operator must be set")?;
+
+        MolSolver::new(MolSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            spatial_operator,
+            time_integrator: self
+                .time_integrator
+                .unwrap_or_else(|| Box::new(SspRk3::new())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+    use crate::solver::spatial_operator::UpwindDifference;
+    use crate::solver::time_integrator::ForwardEuler;
+
+    #[test]
+    fn fn_mol_integrate_works() {
+        // setup mol solver equivalent to the upwind method and run integrate()
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = MolSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            spatial_operator: Box::new(UpwindDifference::new(
+                Box::new(Dirichlet::new(1.0, 0.0)),
+                0.5,
+            )),
+            time_integrator: Box::new(ForwardEuler),
+        };
+        let mut mol_solver = MolSolver::new(new_params).unwrap();
+        mol_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        assert_eq!(mol_solver.get_t(), 0.5);
+        let is_u_correctly_updated = (mol_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(mol_solver.step, 1);
+    }
+}
@@ -0,0 +1,394 @@
+//! Solver for the transport equation using the TVD MUSCL method.
+//!
+//! # Scheme
+//! The MUSCL (monotonic upstream-centered scheme for conservation laws) method is given by
+//! ```math
+//! u_j^{n+1} = u_j^n - \nu (u_{j+1/2}^n - u_{j-1/2}^n),
+//! ```
+//! where `\nu = c \frac{\Delta t}{\Delta x}` and the value at the cell interface is reconstructed from the upwind side
+//! (`c > 0`) with the limited slope,
+//! ```math
+//! u_{j+1/2}^n = u_j^n + \frac{1}{2} (1 - \nu) \phi(r_j) (u_{j+1}^n - u_j^n), r_j = \frac{u_j^n - u_{j-1}^n}{u_{j+1}^n - u_j^n}.
+//! ```
+//!
+//! The flux limiter `\phi` is selected by [FluxLimiter].
+//! The method is reduced to the upwind method when `\phi = 0` and the Lax-Wendroff method when `\phi = 1`.
+//! With the limiters in [FluxLimiter], the method is second-order accurate in smooth regions
+//! and TVD (total variation diminishing) for `0 < \nu \le 1`, so no spurious oscillation arises near discontinuities.
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+//!
+//! Except for the periodic boundary condition, the stencils near the boundaries are closed
+//! by extending the end points as constants.
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::{stencil_zip, NewParams, Solver, Stability};
+use crate::math::limiters::{self, Limiter};
+use ndarray::prelude::*;
+use num_traits::Float;
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Flux limiters for the MUSCL method.
+///
+/// Each variant corresponds to the limiter of the same name in [limiters].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FluxLimiter {
+    /// Minmod limiter, `\phi(r) = \max(0, \min(1, r))`.
+    Minmod,
+    /// Superbee limiter, `\phi(r) = \max(0, \min(2 r, 1), \min(r, 2))`.
+    Superbee,
+    /// Van Leer limiter, `\phi(r) = \frac{r + |r|}{1 + |r|}`.
+    VanLeer,
+    /// Monotonized central limiter, `\phi(r) = \max(0, \min(2 r, \frac{1}{2} (1 + r), 2))`.
+    Mc,
+}
+
+impl Limiter for FluxLimiter {
+    fn phi(&self, r: f64) -> f64 {
+        match self {
+            FluxLimiter::Minmod => limiters::minmod(r),
+            FluxLimiter::Superbee => limiters::superbee(r),
+            FluxLimiter::VanLeer => limiters::van_leer(r),
+            FluxLimiter::Mc => limiters::mc(r),
+        }
+    }
+}
+
+/// Solver for the transport equation using the TVD MUSCL method.
+#[derive(Debug)]
+pub struct MusclSolver<F = f64> {
+    u: Array1<F>,
+    u_interface: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    limiter: FluxLimiter,
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Send + Sync> MusclSolver<F> {
+    /// Create a new `MusclSolver` instance.
+    pub fn new(new_params: MusclSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_interface = Array1::zeros(u.len());
+        let u_next = Array1::zeros(u.len());
+
+        Ok(Self {
+            u,
+            u_interface,
+            u_next,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            limiter: new_params.limiter,
+            boundary_condition: new_params.boundary_condition,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `MusclSolverBuilder` instance to build a `MusclSolver` instance.
+    pub fn builder() -> MusclSolverBuilder<F> {
+        MusclSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &MusclSolverNewParams<F>) -> Stability {
+        // n_cfl is limited to 1 by the validation
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        let len = self.u.len();
+        let n_cfl = F::from(self.n_cfl).unwrap();
+        let half = F::from(0.5).unwrap();
+        let limiter = &self.limiter;
+        let interface = |u_m: F, u_c: F, u_p: F| {
+            let du_m = u_c - u_m;
+            let du_p = u_p - u_c;
+            if du_p.is_zero() {
+                return u_c;
+            }
+
+            // the limiter function is evaluated in f64
+            let phi = F::from(limiter.phi((du_m / du_p).to_f64().unwrap())).unwrap();
+
+            u_c + half * (F::one() - n_cfl) * phi * du_p
+        };
+        let stencil = |u_c: F, u_interface_m: F, u_interface_c: F| {
+            u_c - n_cfl * (u_interface_c - u_interface_m)
+        };
+        // the gradient is taken as zero outside the domain for the non-periodic boundary conditions
+        let i_m = if self.boundary_condition.is_periodic() {
+            self.boundary_condition.shift(0, -1, len)
+        } else {
+            0
+        };
+
+        let u = &self.u;
+        stencil_zip!(len, (
+            u_interface in self.u_interface.slice_mut(s![1..len - 1]),
+            &u_m in u.slice(s![..len - 2]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_interface = interface(u_m, u_c, u_p);
+        });
+        self.u_interface[0] = interface(u[i_m], u[0], u[1]);
+
+        let u_interface = &self.u_interface;
+        stencil_zip!(len, (
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_interface_m in u_interface.slice(s![..len - 2]),
+            &u_interface_c in u_interface.slice(s![1..len - 1])
+        ) {
+            *u_next = stencil(u_c, u_interface_m, u_interface_c);
+        });
+        if self.boundary_condition.is_periodic() {
+            self.u_next[0] = stencil(u[0], u_interface[i_m], u_interface[0]);
+        }
+
+        self.boundary_condition.apply(&mut self.u_next);
+    }
+}
+
+impl<F: Float + Send + Sync> Solver<F> for MusclSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.boundary_condition.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `MusclSolver` instance.
+pub struct MusclSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Flux limiter.
+    pub limiter: FluxLimiter,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for MusclSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+        if self.n_cfl > 1.0 {
+            return Err("n_cfl must not be greater than 1 for the MUSCL method to be TVD");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `MusclSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `limiter`: [FluxLimiter::Minmod],
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct MusclSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    limiter: Option<FluxLimiter>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for MusclSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            limiter: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> MusclSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the flux limiter.
+    pub fn limiter(mut self, limiter: FluxLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `MusclSolver` instance, whose parameters are validated as in [MusclSolver::new].
+    pub fn build(self) -> Result<MusclSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        MusclSolver::new(MusclSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            limiter: self.limiter.unwrap_or(FluxLimiter::Minmod),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+
+    #[test]
+    fn fn_muscl_integrate_works() {
+        // setup muscl solver and run integrate()
+        let u_init = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        let new_params = MusclSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            limiter: FluxLimiter::Minmod,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut muscl_solver = MusclSolver::new(new_params).unwrap();
+        muscl_solver.integrate().unwrap();
+
+        // check if u, t and step are correctly updated
+        let u_exact = array![1.0, 1.0, 0.8125, 0.1875, 0.0];
+        assert_eq!(muscl_solver.get_t(), 0.5);
+        let is_u_correctly_updated = (muscl_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(muscl_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_muscl_new_rejects_large_cfl() {
+        // check if the CFL number larger than 1 is rejected
+        let new_params = MusclSolverNewParams {
+            u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+            step_max: 6,
+            n_cfl: 1.5,
+            dt: 1.5,
+            limiter: FluxLimiter::Minmod,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        assert!(MusclSolver::new(new_params).is_err());
+    }
+}
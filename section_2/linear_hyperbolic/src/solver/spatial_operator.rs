@@ -0,0 +1,223 @@
+//! Spatial operators for the method of lines.
+//!
+//! In the method of lines, the transport equation is first discretized in space into the system of ODEs,
+//! ```math
+//! \frac{d u_j}{d \tau} = L(u)_j,
+//! ```
+//! where `\tau = c t / \Delta x` is the time normalized by `\Delta x / c`,
+//! so that the normalized time step is equal to the CFL number `\nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! A [SpatialOperator] gives `L(u)`, which is then integrated in time by a
+//! [TimeIntegrator](super::time_integrator::TimeIntegrator).
+
+use super::boundary::BoundaryCondition;
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::fmt::Debug;
+
+/// Spatial discretization of the transport equation, for `u` of the floating-point type `F`.
+pub trait SpatialOperator<F = f64>: Debug {
+    /// Write `L(u) = \frac{d u}{d \tau}` into `dudt` of the same length as `u`.
+    ///
+    /// The values at the end points given by the boundary condition are set to zero.
+    fn calculate_dudt_into(&self, u: &Array1<F>, dudt: &mut Array1<F>);
+
+    /// Return `L(u)` in a new array. See [SpatialOperator::calculate_dudt_into].
+    fn calculate_dudt(&self, u: &Array1<F>) -> Array1<F>
+    where
+        F: Float,
+    {
+        let mut dudt = Array1::zeros(u.len());
+        self.calculate_dudt_into(u, &mut dudt);
+
+        dudt
+    }
+
+    /// Return a reference to the boundary condition.
+    fn boundary_condition(&self) -> &dyn BoundaryCondition<F>;
+}
+
+/// Second-order central difference,
+/// ```math
+/// L(u)_j = - \frac{1}{2} (u_{j+1} - u_{j-1}).
+/// ```
+#[derive(Debug)]
+pub struct CentralDifference<F = f64> {
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F> CentralDifference<F> {
+    /// Create a new `CentralDifference` instance.
+    pub fn new(boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        Self { boundary_condition }
+    }
+}
+
+impl<F: Float + Debug> SpatialOperator<F> for CentralDifference<F> {
+    fn calculate_dudt_into(&self, u: &Array1<F>, dudt: &mut Array1<F>) {
+        let half = F::from(0.5).unwrap();
+
+        let len = u.len();
+        for (i, dudt) in dudt.indexed_iter_mut() {
+            if self.boundary_condition.is_edge(i, len) {
+                *dudt = F::zero();
+                continue;
+            }
+            let i_m = self.boundary_condition.shift(i, -1, len);
+            let i_p = self.boundary_condition.shift(i, 1, len);
+
+            *dudt = -half * (u[i_p] - u[i_m]);
+        }
+    }
+
+    fn boundary_condition(&self) -> &dyn BoundaryCondition<F> {
+        self.boundary_condition.as_ref()
+    }
+}
+
+/// First-order upwind difference,
+/// ```math
+/// L(u)_j = - (u_j - u_{j-1}) \quad (c > 0), \qquad L(u)_j = - (u_{j+1} - u_j) \quad (c < 0).
+/// ```
+#[derive(Debug)]
+pub struct UpwindDifference<F = f64> {
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+    offset_upwind: isize,
+}
+
+impl<F> UpwindDifference<F> {
+    /// Create a new `UpwindDifference` instance.
+    ///
+    /// The upwind side is chosen by the sign of `n_cfl`, that is, the sign of the advection velocity.
+    pub fn new(boundary_condition: Box<dyn BoundaryCondition<F>>, n_cfl: f64) -> Self {
+        Self {
+            boundary_condition,
+            offset_upwind: if n_cfl < 0.0 { 1 } else { -1 },
+        }
+    }
+}
+
+impl<F: Float + Debug> SpatialOperator<F> for UpwindDifference<F> {
+    fn calculate_dudt_into(&self, u: &Array1<F>, dudt: &mut Array1<F>) {
+        let sign = F::from(self.offset_upwind).unwrap();
+
+        let len = u.len();
+        for (i, dudt) in dudt.indexed_iter_mut() {
+            if self.boundary_condition.is_edge(i, len) {
+                *dudt = F::zero();
+                continue;
+            }
+            let i_upwind = self.boundary_condition.shift(i, self.offset_upwind, len);
+
+            *dudt = sign * (u[i] - u[i_upwind]);
+        }
+    }
+
+    fn boundary_condition(&self) -> &dyn BoundaryCondition<F> {
+        self.boundary_condition.as_ref()
+    }
+}
+
+/// Fifth-order WENO (weighted essentially non-oscillatory) reconstruction (`c > 0`),
+/// ```math
+/// L(u)_j = - (u_{j+1/2} - u_{j-1/2}).
+/// ```
+///
+/// For the reconstruction of `u_{j+1/2}`, see [weno_solver](super::weno_solver).
+/// Except for the periodic boundary condition, the stencils near the boundaries are closed
+/// by extending the end points as constants.
+#[derive(Debug)]
+pub struct Weno5<F = f64> {
+    boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> Weno5<F> {
+    /// Create a new `Weno5` instance.
+    pub fn new(boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        Self { boundary_condition }
+    }
+
+    /// Reconstruct `u_{j+1/2}` from `v = [u_{j-2}, u_{j-1}, u_j, u_{j+1}, u_{j+2}]`.
+    fn reconstruct(v: [F; 5]) -> F {
+        let c = |x: f64| F::from(x).unwrap();
+        let epsilon = c(1.0e-6);
+        let d = [c(0.1), c(0.6), c(0.3)];
+
+        let q = [
+            (c(2.0) * v[0] - c(7.0) * v[1] + c(11.0) * v[2]) / c(6.0),
+            (-v[1] + c(5.0) * v[2] + c(2.0) * v[3]) / c(6.0),
+            (c(2.0) * v[2] + c(5.0) * v[3] - v[4]) / c(6.0),
+        ];
+        let beta = [
+            c(13.0 / 12.0) * (v[0] - c(2.0) * v[1] + v[2]).powi(2)
+                + c(0.25) * (v[0] - c(4.0) * v[1] + c(3.0) * v[2]).powi(2),
+            c(13.0 / 12.0) * (v[1] - c(2.0) * v[2] + v[3]).powi(2)
+                + c(0.25) * (v[1] - v[3]).powi(2),
+            c(13.0 / 12.0) * (v[2] - c(2.0) * v[3] + v[4]).powi(2)
+                + c(0.25) * (c(3.0) * v[2] - c(4.0) * v[3] + v[4]).powi(2),
+        ];
+        let alpha = [0, 1, 2].map(|k| d[k] / (epsilon + beta[k]).powi(2));
+        let alpha_sum = alpha[0] + alpha[1] + alpha[2];
+
+        (0..3).fold(F::zero(), |acc, k| acc + alpha[k] / alpha_sum * q[k])
+    }
+}
+
+impl<F: Float + Debug> SpatialOperator<F> for Weno5<F> {
+    fn calculate_dudt_into(&self, u: &Array1<F>, dudt: &mut Array1<F>) {
+        let len = u.len();
+        let index = |i: usize, offset: isize| {
+            if self.boundary_condition.is_periodic() {
+                self.boundary_condition.shift(i, offset, len)
+            } else {
+                (i as isize + offset).clamp(0, len as isize - 1) as usize
+            }
+        };
+        let flux = |i: usize| {
+            Self::reconstruct([
+                u[index(i, -2)],
+                u[index(i, -1)],
+                u[i],
+                u[index(i, 1)],
+                u[index(i, 2)],
+            ])
+        };
+
+        for (i, dudt) in dudt.indexed_iter_mut() {
+            if self.boundary_condition.is_edge(i, len) {
+                *dudt = F::zero();
+                continue;
+            }
+
+            *dudt = -(flux(i) - flux(index(i, -1)));
+        }
+    }
+
+    fn boundary_condition(&self) -> &dyn BoundaryCondition<F> {
+        self.boundary_condition.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
+
+    #[test]
+    fn fn_calculate_dudt_works() {
+        // setup u and the spatial operators
+        let u = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let central_difference = CentralDifference::new(Box::new(Dirichlet::new(1.0, 0.0)));
+        let upwind_difference = UpwindDifference::new(Box::new(Periodic), 0.5);
+        let upwind_difference_reversed = UpwindDifference::new(Box::new(Periodic), -0.5);
+
+        // check if L(u) is correctly calculated
+        let dudt_exact = array![0.0, 0.5, 0.5, 0.0, 0.0];
+        assert_eq!(central_difference.calculate_dudt(&u), dudt_exact);
+        let u = array![1.0, 1.0, 0.0, 0.0, 1.0];
+        let dudt_exact = array![-1.0, 0.0, 1.0, 0.0, -1.0];
+        assert_eq!(upwind_difference.calculate_dudt(&u), dudt_exact);
+        let dudt_exact = array![0.0, 1.0, 0.0, -1.0, 0.0];
+        assert_eq!(upwind_difference_reversed.calculate_dudt(&u), dudt_exact);
+    }
+}
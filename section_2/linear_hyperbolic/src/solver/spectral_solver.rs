@@ -0,0 +1,378 @@
+//! Solver for the transport equation using the Fourier spectral method.
+//!
+//! # Scheme
+//! On the periodic domain, `u` is expanded into the Fourier series,
+//! ```math
+//! u_j = \frac{1}{N} \sum_{m} \hat{u}_m e^{i k_m x_j}, k_m = \frac{2 \pi m}{N \Delta x},
+//! ```
+//! where `N` is the number of cells and `\hat{u}_m` is given by the FFT of `u_0, \cdots, u_{N-1}`.
+//!
+//! The spatial derivative is obtained by multiplying each Fourier coefficient by `i k_m`,
+//! ```math
+//! (u_x)_j = \frac{1}{N} \sum_{m} i k_m \hat{u}_m e^{i k_m x_j},
+//! ```
+//! and the time integration is carried out exactly for each Fourier mode,
+//! ```math
+//! \hat{u}_m^{n+1} = e^{-i k_m c \Delta t} \hat{u}_m^n = e^{-2 \pi i m \nu / N} \hat{u}_m^n,
+//! ```
+//! where `\nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! Therefore, the method has neither dissipative nor dispersive errors for the modes resolved on the grid,
+//! and can be used as a quasi-exact reference for the other schemes.
+//!
+//! The wavenumbers are taken as `-N/2 < m \le N/2` and the imaginary part of the inverse FFT is discarded.
+//!
+//! # Boundary Condition
+//! The domain is always periodic, that is, the end points are identified as `u_N = u_0`.
+//! The initial value of the last point is replaced with that of the first point.
+
+use super::boundary::{BoundaryCondition, Periodic};
+use super::{NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftNum, FftPlanner};
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+/// Solver for the transport equation using the Fourier spectral method.
+#[derive(Debug)]
+pub struct SpectralSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    fourier_modes: FourierModes<F>,
+    step_max: usize,
+    n_cfl: f64,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + FftNum> SpectralSolver<F> {
+    /// Create a new `SpectralSolver` instance.
+    pub fn new(new_params: SpectralSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        Periodic.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
+        let fourier_modes = FourierModes::new(u.len() - 1);
+
+        Ok(Self {
+            u,
+            u_next,
+            fourier_modes,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `SpectralSolverBuilder` instance to build a `SpectralSolver` instance.
+    pub fn builder() -> SpectralSolverBuilder<F> {
+        SpectralSolverBuilder::default()
+    }
+
+    fn check_stability(_new_params: &SpectralSolverNewParams<F>) -> Stability {
+        Stability::Stable
+    }
+
+    /// Return `\Delta x u_x` calculated by the Fourier spectral method.
+    pub fn calculate_dudx(&self) -> Array1<F> {
+        let n = self.u.len() - 1;
+
+        let mut dudx = Array1::zeros(self.u.len());
+        FourierModes::new(n).transform(
+            &self.u,
+            |m| Complex::new(0.0, 2.0 * PI * m / n as f64),
+            &mut dudx,
+        );
+
+        dudx
+    }
+
+    fn calculate_u_next(&mut self) {
+        let n = self.u.len() - 1;
+
+        self.fourier_modes.transform(
+            &self.u,
+            |m| Complex::from_polar(1.0, -2.0 * PI * m * self.n_cfl / n as f64),
+            &mut self.u_next,
+        );
+    }
+}
+
+/// FFT plans of `u_0, \cdots, u_{N-1}` with the buffers of the Fourier coefficients,
+/// which are created once and reused at every step.
+struct FourierModes<F> {
+    fft_forward: Arc<dyn Fft<F>>,
+    fft_inverse: Arc<dyn Fft<F>>,
+    u_hat: Vec<Complex<F>>,
+    scratch: Vec<Complex<F>>,
+}
+
+impl<F: Float + FftNum> FourierModes<F> {
+    /// Create a new `FourierModes` instance for `n` cells.
+    fn new(n: usize) -> Self {
+        let mut planner = FftPlanner::new();
+        let fft_forward = planner.plan_fft_forward(n);
+        let fft_inverse = planner.plan_fft_inverse(n);
+        let scratch_len = fft_forward
+            .get_inplace_scratch_len()
+            .max(fft_inverse.get_inplace_scratch_len());
+
+        Self {
+            fft_forward,
+            fft_inverse,
+            u_hat: vec![Complex::new(F::zero(), F::zero()); n],
+            scratch: vec![Complex::new(F::zero(), F::zero()); scratch_len],
+        }
+    }
+
+    /// Multiply the Fourier coefficient of the wavenumber `m` of `u` by `factor(m)` calculated in `f64`
+    /// and write the result in the real space into `u_out`.
+    fn transform(
+        &mut self,
+        u: &Array1<F>,
+        factor: impl Fn(f64) -> Complex<f64>,
+        u_out: &mut Array1<F>,
+    ) {
+        let n = self.u_hat.len();
+        let cast = |x: f64| F::from(x).unwrap();
+
+        for (u_hat, u) in self.u_hat.iter_mut().zip(u) {
+            *u_hat = Complex::new(*u, F::zero());
+        }
+        self.fft_forward
+            .process_with_scratch(&mut self.u_hat, &mut self.scratch);
+
+        for (i, u_hat) in self.u_hat.iter_mut().enumerate() {
+            let m = if 2 * i <= n {
+                i as f64
+            } else {
+                i as f64 - n as f64
+            };
+            let factor = factor(m);
+            *u_hat = *u_hat * Complex::new(cast(factor.re), cast(factor.im));
+        }
+        self.fft_inverse
+            .process_with_scratch(&mut self.u_hat, &mut self.scratch);
+
+        for (i, u_out) in u_out.indexed_iter_mut() {
+            *u_out = self.u_hat[i % n].re / cast(n as f64);
+        }
+    }
+}
+
+impl<F> fmt::Debug for FourierModes<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FourierModes")
+    }
+}
+
+impl<F: Float + FftNum> Solver<F> for SpectralSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        Periodic.prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `SpectralSolver` instance.
+pub struct SpectralSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+}
+
+impl<F: Float> NewParams for SpectralSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        BoundaryCondition::<f64>::validate(&Periodic, self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `SpectralSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`.
+#[derive(Debug)]
+pub struct SpectralSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+}
+
+impl<F> Default for SpectralSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+        }
+    }
+}
+
+impl<F: Float + FftNum> SpectralSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Build a new `SpectralSolver` instance, whose parameters are validated as in [SpectralSolver::new].
+    pub fn build(self) -> Result<SpectralSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+
+        SpectralSolver::new(SpectralSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_spectral_integrate_works() {
+        // setup spectral solver with u = sin(pi x) and run integrate()
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 40 + 1);
+        let new_params = SpectralSolverNewParams {
+            u: x.map(|x| (PI * x).sin()),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+        };
+        let mut spectral_solver = SpectralSolver::new(new_params).unwrap();
+        spectral_solver.integrate().unwrap();
+
+        // check if u is exactly shifted by c dt = 0.5 dx and step is correctly updated
+        let u_exact = x.map(|x| (PI * (x - 0.025)).sin());
+        assert_eq!(spectral_solver.get_t(), 0.5);
+        let is_u_correctly_updated = (spectral_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(spectral_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_spectral_calculate_dudx_works() {
+        // setup spectral solver with u = sin(pi x)
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 40 + 1);
+        let new_params = SpectralSolverNewParams {
+            u: x.map(|x| (PI * x).sin()),
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+        };
+        let spectral_solver = SpectralSolver::new(new_params).unwrap();
+
+        // check if dx u_x = dx pi cos(pi x)
+        let dudx_exact = x.map(|x| 0.05 * PI * (PI * x).cos());
+        let is_dudx_correctly_calculated = (spectral_solver.calculate_dudx() - dudx_exact)
+            .iter()
+            .all(|dudx| dudx.abs() < 1e-10);
+        assert!(is_dudx_correctly_calculated);
+    }
+}
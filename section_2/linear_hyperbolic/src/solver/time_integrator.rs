@@ -0,0 +1,288 @@
+//! Time integrators for the method of lines.
+//!
+//! A [TimeIntegrator] advances the system of ODEs given by a [SpatialOperator],
+//! ```math
+//! \frac{d u}{d \tau} = L(u),
+//! ```
+//! by the normalized time step `\Delta \tau = \nu`, where `\nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! The boundary condition of the spatial operator is applied after each stage.
+
+use super::spatial_operator::SpatialOperator;
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::fmt::Debug;
+
+/// Time integration of the semi-discrete transport equation, for `u` of the floating-point type `F`.
+pub trait TimeIntegrator<F = f64>: Debug {
+    /// Write `u` advanced by the normalized time step `n_cfl` into `u_next` of the same length as `u`.
+    ///
+    /// The intermediate stages, if any, are kept in the buffers of the instance,
+    /// so that no array is allocated per step once the buffers are sized to `u`.
+    fn advance_into(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+        u_next: &mut Array1<F>,
+    );
+    /// Return `u` advanced by the normalized time step `n_cfl` in a new array.
+    /// See [TimeIntegrator::advance_into].
+    fn advance(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+    ) -> Array1<F>
+    where
+        F: Float,
+    {
+        let mut u_next = Array1::zeros(u.len());
+        self.advance_into(spatial_operator, u, n_cfl, &mut u_next);
+
+        u_next
+    }
+    /// Discard the state kept from the previous steps, if any, to start a new sequence of `u`.
+    fn reset(&mut self) {}
+}
+
+/// Resize `buffer` to `len` if it has another length, e.g. before the first step.
+fn fit_buffer<F: Float>(buffer: &mut Array1<F>, len: usize) {
+    if buffer.len() != len {
+        *buffer = Array1::zeros(len);
+    }
+}
+
+/// Forward Euler method,
+/// ```math
+/// u^{n+1} = u^n + \nu L(u^n).
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForwardEuler;
+
+impl<F: Float> TimeIntegrator<F> for ForwardEuler {
+    fn advance_into(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+        u_next: &mut Array1<F>,
+    ) {
+        let n_cfl = F::from(n_cfl).unwrap();
+
+        // L(u^n) is written into u_next and then overwritten by u^{n+1}
+        spatial_operator.calculate_dudt_into(u, u_next);
+        azip!((u_next in &mut *u_next, &u in u) *u_next = u + n_cfl * *u_next);
+        spatial_operator.boundary_condition().apply(u_next);
+    }
+}
+
+/// Second-order Runge-Kutta method of Heun,
+/// ```math
+/// u^{(1)} = u^n + \nu L(u^n),
+/// u^{n+1} = \frac{1}{2} u^n + \frac{1}{2} (u^{(1)} + \nu L(u^{(1)})),
+/// ```
+/// which is a convex combination of the forward Euler steps.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Rk2<F = f64> {
+    u_stage: Array1<F>,
+}
+
+impl<F: Float> Rk2<F> {
+    /// Create a new `Rk2` instance.
+    pub fn new() -> Self {
+        Self {
+            u_stage: Array1::zeros(0),
+        }
+    }
+}
+
+impl<F: Float + Debug> TimeIntegrator<F> for Rk2<F> {
+    fn advance_into(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+        u_next: &mut Array1<F>,
+    ) {
+        let half = F::from(0.5).unwrap();
+
+        fit_buffer(&mut self.u_stage, u.len());
+        ForwardEuler.advance_into(spatial_operator, u, n_cfl, &mut self.u_stage);
+
+        ForwardEuler.advance_into(spatial_operator, &self.u_stage, n_cfl, u_next);
+        azip!((u_next in &mut *u_next, &u in u) *u_next = half * u + half * *u_next);
+        spatial_operator.boundary_condition().apply(u_next);
+    }
+}
+
+/// Third-order SSP (strong stability preserving) Runge-Kutta method of Shu and Osher,
+/// ```math
+/// u^{(1)} = u^n + \nu L(u^n),
+/// u^{(2)} = \frac{3}{4} u^n + \frac{1}{4} (u^{(1)} + \nu L(u^{(1)})),
+/// u^{n+1} = \frac{1}{3} u^n + \frac{2}{3} (u^{(2)} + \nu L(u^{(2)})),
+/// ```
+/// which is a convex combination of the forward Euler steps.
+///
+/// The stability region contains a part of the imaginary axis, `|\nu \lambda| \le \sqrt{3}`,
+/// so the method is stable with [CentralDifference](super::spatial_operator::CentralDifference) for `\nu \le \sqrt{3}`,
+/// while the forward Euler method is unconditionally unstable with it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SspRk3<F = f64> {
+    u_stage: Array1<F>,
+}
+
+impl<F: Float> SspRk3<F> {
+    /// Create a new `SspRk3` instance.
+    pub fn new() -> Self {
+        Self {
+            u_stage: Array1::zeros(0),
+        }
+    }
+}
+
+impl<F: Float + Debug> TimeIntegrator<F> for SspRk3<F> {
+    fn advance_into(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+        u_next: &mut Array1<F>,
+    ) {
+        let c = |x: f64| F::from(x).unwrap();
+
+        // u^{(1)} and then u^{(2)} + \nu L(u^{(2)}) are kept in u_stage, while u^{(2)} is kept in u_next
+        fit_buffer(&mut self.u_stage, u.len());
+        ForwardEuler.advance_into(spatial_operator, u, n_cfl, &mut self.u_stage);
+
+        ForwardEuler.advance_into(spatial_operator, &self.u_stage, n_cfl, u_next);
+        azip!((u_2 in &mut *u_next, &u in u) *u_2 = c(0.75) * u + c(0.25) * *u_2);
+        spatial_operator.boundary_condition().apply(u_next);
+
+        ForwardEuler.advance_into(spatial_operator, u_next, n_cfl, &mut self.u_stage);
+        azip!((u_next in &mut *u_next, &u in u, &u_stage in &self.u_stage) {
+            *u_next = u / c(3.0) + c(2.0 / 3.0) * u_stage
+        });
+        spatial_operator.boundary_condition().apply(u_next);
+    }
+}
+
+/// Second-order Adams-Bashforth method,
+/// ```math
+/// u^{n+1} = u^n + \nu (\frac{3}{2} L(u^n) - \frac{1}{2} L(u^{n-1})).
+/// ```
+///
+/// As a multistep method, `L(u^{n-1})` of the previous step is kept in the instance,
+/// so an instance must be used for only one sequence of `u` unless it is reset by [TimeIntegrator::reset].
+/// The first step, for which `L(u^{n-1})` is not available, is bootstrapped by [Rk2] of the same order.
+///
+/// The stability region contains no part of the imaginary axis except for the origin,
+/// so the method is weakly unstable with [CentralDifference](super::spatial_operator::CentralDifference),
+/// although much less than the forward Euler method.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AdamsBashforth2<F = f64> {
+    dudt: Array1<F>,
+    dudt_prev: Option<Array1<F>>,
+    bootstrap: Rk2<F>,
+}
+
+impl<F: Float> AdamsBashforth2<F> {
+    /// Create a new `AdamsBashforth2` instance.
+    pub fn new() -> Self {
+        Self {
+            dudt: Array1::zeros(0),
+            dudt_prev: None,
+            bootstrap: Rk2::new(),
+        }
+    }
+}
+
+impl<F: Float + Debug> TimeIntegrator<F> for AdamsBashforth2<F> {
+    fn advance_into(
+        &mut self,
+        spatial_operator: &dyn SpatialOperator<F>,
+        u: &Array1<F>,
+        n_cfl: f64,
+        u_next: &mut Array1<F>,
+    ) {
+        let c = |x: f64| F::from(x).unwrap();
+        let n_cfl_f = c(n_cfl);
+
+        fit_buffer(&mut self.dudt, u.len());
+        spatial_operator.calculate_dudt_into(u, &mut self.dudt);
+
+        match &mut self.dudt_prev {
+            None => {
+                self.bootstrap
+                    .advance_into(spatial_operator, u, n_cfl, u_next);
+                // the buffer of L(u^{n-1}) is allocated only once, at the first step
+                self.dudt_prev = Some(Array1::zeros(u.len()));
+            }
+            Some(dudt_prev) => {
+                azip!((u_next in &mut *u_next, &u in u, &dudt in &self.dudt, &dudt_prev in &*dudt_prev) {
+                    *u_next = u + n_cfl_f * (c(1.5) * dudt - c(0.5) * dudt_prev)
+                });
+                spatial_operator.boundary_condition().apply(u_next);
+            }
+        }
+        if let Some(dudt_prev) = &mut self.dudt_prev {
+            std::mem::swap(dudt_prev, &mut self.dudt);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.dudt_prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Dirichlet;
+    use crate::solver::spatial_operator::CentralDifference;
+
+    #[test]
+    fn fn_advance_works() {
+        // setup u and the spatial operator
+        let u = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let central_difference = CentralDifference::new(Box::new(Dirichlet::new(1.0, 0.0)));
+
+        // check if u is correctly advanced by each time integrator
+        let cases: [(Box<dyn TimeIntegrator>, Array1<f64>); 3] = [
+            (Box::new(ForwardEuler), array![1.0, 1.25, 0.25, 0.0, 0.0]),
+            (
+                Box::new(Rk2::new()),
+                array![1.0, 1.21875, 0.28125, 0.03125, 0.0],
+            ),
+            (
+                Box::new(SspRk3::new()),
+                array![1.0, 467.0 / 384.0, 53.0 / 192.0, 13.0 / 384.0, 0.0],
+            ),
+        ];
+        for (mut time_integrator, u_exact) in cases {
+            let u_next = time_integrator.advance(&central_difference, &u, 0.5);
+            let is_u_correctly_advanced = (u_next - u_exact).iter().all(|u| u.abs() < 1e-10);
+            assert!(is_u_correctly_advanced);
+        }
+    }
+
+    #[test]
+    fn fn_adams_bashforth2_advance_works() {
+        // setup u and the spatial operator
+        let u = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let central_difference = CentralDifference::new(Box::new(Dirichlet::new(1.0, 0.0)));
+
+        // check if the first step is bootstrapped by Rk2
+        let mut adams_bashforth2 = AdamsBashforth2::new();
+        let u_1 = adams_bashforth2.advance(&central_difference, &u, 0.5);
+        let u_exact = array![1.0, 39.0 / 32.0, 9.0 / 32.0, 1.0 / 32.0, 0.0];
+        let is_u_correctly_advanced = (&u_1 - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_advanced);
+
+        // check if the second step uses L(u) of the first step
+        let u_2 = adams_bashforth2.advance(&central_difference, &u_1, 0.5);
+        let u_exact = array![1.0, 349.0 / 256.0, 77.0 / 128.0, 35.0 / 256.0, 0.0];
+        let is_u_correctly_advanced = (u_2 - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_advanced);
+    }
+}
@@ -3,60 +3,96 @@
 //! # Scheme
 //! The upwind method is given by
 //! ```math
-//! u_j^{n+1} = u_j^n - \nu (u_j^n - u_{j-1}^n),
+//! u_j^{n+1} = u_j^n - \nu (u_j^n - u_{j-1}^n)
 //! ```
-//! where `\nu = c \frac{\Delta t}{\Delta x}`.
-//!
-//! # Boundary Condition
-//! The boundary condition is fixed as
+//! for `c > 0` and
 //! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! u_j^{n+1} = u_j^n - \nu (u_{j+1}^n - u_j^n)
 //! ```
+//! for `c < 0`, where `\nu = c \frac{\Delta t}{\Delta x}` is the signed CFL number.
+//! That is, the difference is always taken on the upwind side.
+//!
+//! The method is implemented in the method of lines as [UpwindDifference] integrated by [ForwardEuler].
+//! See [mol_solver](super::mol_solver).
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::spatial_operator::{SpatialOperator, UpwindDifference};
+use super::time_integrator::{ForwardEuler, TimeIntegrator};
+use super::{NewParams, Solver, Stability};
 use ndarray::prelude::*;
+use num_traits::Float;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Solver for the transport equation using the upwind method.
 #[derive(Debug)]
-pub struct UpwindSolver {
-    u: Array1<f64>,
+pub struct UpwindSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
     step_max: usize,
     n_cfl: f64,
+    spatial_operator: UpwindDifference<F>,
     step: usize,
     completed: bool,
+    stability: Stability,
+    dt: f64,
 }
 
-impl UpwindSolver {
+impl<F: Float + Debug + Send + Sync + 'static> UpwindSolver<F> {
     /// Create a new `UpwindSolver` instance.
-    pub fn new(new_params: UpwindSolverNewParams) -> Result<Self, &'static str> {
+    pub fn new(new_params: UpwindSolverNewParams<F>) -> Result<Self, &'static str> {
         new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
 
         Ok(Self {
-            u: new_params.u,
+            u,
+            u_next,
             step_max: new_params.step_max,
             n_cfl: new_params.n_cfl,
+            spatial_operator: UpwindDifference::new(
+                new_params.boundary_condition,
+                new_params.n_cfl,
+            ),
             step: 0,
             completed: false,
+            stability,
+            dt: new_params.dt,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        self.u
-            .indexed_iter()
-            .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
-                }
+    /// Create a new `UpwindSolverBuilder` instance to build a `UpwindSolver` instance.
+    pub fn builder() -> UpwindSolverBuilder<F> {
+        UpwindSolverBuilder::default()
+    }
 
-                self.u[i] - self.n_cfl * (self.u[i] - self.u[i - 1])
-            })
-            .collect()
+    fn check_stability(new_params: &UpwindSolverNewParams<F>) -> Stability {
+        if new_params.n_cfl.abs() > 1.0 {
+            return Stability::Unstable("the upwind method is unstable for |n_cfl| > 1");
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        ForwardEuler.advance_into(
+            &self.spatial_operator,
+            &self.u,
+            self.n_cfl,
+            &mut self.u_next,
+        );
     }
 }
 
-impl Solver for UpwindSolver {
-    fn borrow_u(&self) -> &Array1<f64> {
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for UpwindSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
         &self.u
     }
 
@@ -64,10 +100,35 @@ impl Solver for UpwindSolver {
         self.step
     }
 
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
     fn is_completed(&self) -> bool {
         self.completed
     }
 
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.spatial_operator
+            .boundary_condition()
+            .prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -75,7 +136,8 @@ impl Solver for UpwindSolver {
             ));
         }
 
-        self.u = self.calculate_u_next();
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -87,16 +149,20 @@ impl Solver for UpwindSolver {
 }
 
 /// Parameters for creating a new `UpwindSolver` instance.
-pub struct UpwindSolverNewParams {
+pub struct UpwindSolverNewParams<F = f64> {
     /// Initial value of `u`.
-    pub u: Array1<f64>,
+    pub u: Array1<F>,
     /// Maximum number of time steps.
     pub step_max: usize,
-    /// CFL number.
+    /// CFL number, whose sign is the direction of the advection velocity.
     pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
 }
 
-impl NewParams for UpwindSolverNewParams {
+impl<F: Float> NewParams for UpwindSolverNewParams<F> {
     fn validate_new_params(&self) -> Result<(), &'static str> {
         if self.u.is_empty() {
             return Err("u must not be empty");
@@ -104,17 +170,102 @@ impl NewParams for UpwindSolverNewParams {
         if self.step_max == 0 {
             return Err("step_max must be positive");
         }
-        if self.n_cfl <= 0.0 {
-            return Err("n_cfl must be positive");
+        if self.n_cfl == 0.0 {
+            return Err("n_cfl must not be zero");
         }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
 
         Ok(())
     }
 }
 
+/// Builder of `UpwindSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct UpwindSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for UpwindSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> UpwindSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number, whose sign is the direction of the advection velocity.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `UpwindSolver` instance, whose parameters are validated as in [UpwindSolver::new].
+    pub fn build(self) -> Result<UpwindSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        UpwindSolver::new(UpwindSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::boundary::{Dirichlet, Periodic};
 
     #[test]
     fn fn_upwind_integrate_works() {
@@ -124,14 +275,185 @@ mod tests {
             u: u_init,
             step_max: 6,
             n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
         };
         let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
         upwind_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        assert_eq!(upwind_solver.get_t(), 0.5);
         let is_u_correctly_updated = (upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(upwind_solver.step, 1);
     }
+
+    #[test]
+    fn fn_upwind_integrate_works_with_periodic_boundary() {
+        // setup upwind solver with the periodic boundary condition and run integrate()
+        let u_init = array![1.0, 0.0, 0.0, 0.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Periodic),
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u is advected across the boundary
+        let u_exact = array![0.5, 0.5, 0.0, 0.0, 0.5];
+        let is_u_correctly_updated = (upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_upwind_integrate_works_with_negative_velocity() {
+        // setup upwind solver with the negative CFL number and run integrate()
+        let u_init = array![0.0, 0.0, 0.0, 1.0, 1.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init,
+            step_max: 6,
+            n_cfl: -0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(0.0, 1.0)),
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u is advected to the left
+        let u_exact = array![0.0, 0.0, 0.5, 1.0, 1.0];
+        let is_u_correctly_updated = (upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_upwind_reset_works() {
+        // setup upwind solver and run integrate() until completed
+        let u_init = array![1.0, 1.0, 0.0, 0.0, 0.0];
+        let new_params = UpwindSolverNewParams {
+            u: u_init.clone(),
+            step_max: 2,
+            n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+        while !upwind_solver.is_completed() {
+            upwind_solver.integrate().unwrap();
+        }
+        let u_first_run = upwind_solver.u.clone();
+
+        // reset the solver and run it again
+        upwind_solver.reset(u_init).unwrap();
+        assert_eq!(upwind_solver.step, 0);
+        assert!(!upwind_solver.is_completed());
+        while !upwind_solver.is_completed() {
+            upwind_solver.integrate().unwrap();
+        }
+
+        // check if the second run reproduces the first one
+        assert_eq!(upwind_solver.u, u_first_run);
+        assert_eq!(upwind_solver.step, 2);
+
+        // check if u_init of a different length is rejected
+        assert!(upwind_solver.reset(array![1.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn fn_upwind_step_until_works() {
+        // setup upwind solver
+        let new_params = UpwindSolverNewParams {
+            u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+            step_max: 4,
+            n_cfl: 0.5,
+            dt: 0.1,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+        let mut upwind_solver = UpwindSolver::new(new_params).unwrap();
+
+        // check if the integration stops at the first step reaching t_target
+        assert!((upwind_solver.step_until(0.3).unwrap() - 0.3).abs() < 1e-10);
+        assert_eq!(upwind_solver.step, 3);
+        assert!((upwind_solver.step_until(0.25).unwrap() - 0.3).abs() < 1e-10);
+        assert_eq!(upwind_solver.step, 3);
+
+        // check if the integration stops when the calculation is completed
+        assert!((upwind_solver.step_until(1.0).unwrap() - 0.4).abs() < 1e-10);
+        assert!(upwind_solver.is_completed());
+    }
+
+    #[test]
+    fn fn_upwind_builder_works() {
+        // build upwind solver with the default dt and boundary condition and run integrate()
+        let mut upwind_solver = UpwindSolver::builder()
+            .u(array![1.0, 1.0, 0.0, 0.0, 0.0])
+            .step_max(6)
+            .n_cfl(0.5)
+            .build()
+            .unwrap();
+        upwind_solver.integrate().unwrap();
+
+        // check if u and t are updated as with the explicit parameters
+        let u_exact = array![1.0, 1.0, 0.5, 0.0, 0.0];
+        assert_eq!(upwind_solver.get_t(), 0.5);
+        let is_u_correctly_updated = (upwind_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+
+        // check if the missing and invalid parameters are rejected
+        let result = UpwindSolver::builder()
+            .u(array![1.0, 0.0])
+            .step_max(6)
+            .build();
+        assert_eq!(result.unwrap_err(), "n_cfl must be set");
+        let result = UpwindSolver::<f64>::builder()
+            .u(array![])
+            .step_max(6)
+            .n_cfl(0.5)
+            .build();
+        assert_eq!(result.unwrap_err(), "u must not be empty");
+    }
+
+    #[test]
+    fn fn_upwind_new_rejects_zero_velocity() {
+        // setup upwind solver with the zero CFL number
+        let new_params = UpwindSolverNewParams {
+            u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+            step_max: 6,
+            n_cfl: 0.0,
+            dt: 0.0,
+            boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+        };
+
+        // check if the zero velocity is rejected
+        assert_eq!(
+            UpwindSolver::new(new_params).unwrap_err(),
+            "n_cfl must not be zero"
+        );
+    }
+
+    #[test]
+    fn fn_upwind_new_checks_stability() {
+        // setup upwind solvers with the CFL numbers inside and outside the stability bound
+        let create_solver = |n_cfl| {
+            UpwindSolver::new(UpwindSolverNewParams {
+                u: array![1.0, 1.0, 0.0, 0.0, 0.0],
+                step_max: 6,
+                n_cfl,
+                dt: 0.1,
+                boundary_condition: Box::new(Dirichlet::new(1.0, 0.0)),
+            })
+            .unwrap()
+        };
+
+        // check if the stability is correctly evaluated regardless of the sign of n_cfl
+        assert_eq!(create_solver(1.0).get_stability(), Stability::Stable);
+        assert_eq!(create_solver(-1.0).get_stability(), Stability::Stable);
+        assert_eq!(
+            create_solver(-1.5).get_stability(),
+            Stability::Unstable("the upwind method is unstable for |n_cfl| > 1")
+        );
+    }
 }
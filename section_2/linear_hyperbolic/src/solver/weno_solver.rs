@@ -0,0 +1,325 @@
+//! Solver for the transport equation using the fifth-order WENO method.
+//!
+//! # Scheme
+//! The transport equation is discretized in space by the conservative difference
+//! ```math
+//! \frac{d u_j}{d t} = L(u)_j = - \frac{c}{\Delta x} (u_{j+1/2} - u_{j-1/2}),
+//! ```
+//! where `u_{j+1/2}` is reconstructed from the upwind values `u_{j-2}, \cdots, u_{j+2}` (`c > 0`)
+//! by the fifth-order WENO (weighted essentially non-oscillatory) reconstruction,
+//! ```math
+//! u_{j+1/2} = \omega_0 q_0 + \omega_1 q_1 + \omega_2 q_2.
+//! ```
+//!
+//! Here, `q_k` are the third-order reconstructions on the three candidate stencils,
+//! ```math
+//! q_0 = \frac{1}{6} (2 u_{j-2} - 7 u_{j-1} + 11 u_j),
+//! q_1 = \frac{1}{6} (- u_{j-1} + 5 u_j + 2 u_{j+1}),
+//! q_2 = \frac{1}{6} (2 u_j + 5 u_{j+1} - u_{j+2}),
+//! ```
+//! and `\omega_k` are the nonlinear weights,
+//! ```math
+//! \omega_k = \frac{\alpha_k}{\alpha_0 + \alpha_1 + \alpha_2}, \alpha_k = \frac{d_k}{(\varepsilon + \beta_k)^2},
+//! ```
+//! with the linear weights `(d_0, d_1, d_2) = (1/10, 6/10, 3/10)`, `\varepsilon = 10^{-6}` and the smoothness indicators
+//! ```math
+//! \beta_0 = \frac{13}{12} (u_{j-2} - 2 u_{j-1} + u_j)^2 + \frac{1}{4} (u_{j-2} - 4 u_{j-1} + 3 u_j)^2,
+//! \beta_1 = \frac{13}{12} (u_{j-1} - 2 u_j + u_{j+1})^2 + \frac{1}{4} (u_{j-1} - u_{j+1})^2,
+//! \beta_2 = \frac{13}{12} (u_j - 2 u_{j+1} + u_{j+2})^2 + \frac{1}{4} (3 u_j - 4 u_{j+1} + u_{j+2})^2.
+//! ```
+//!
+//! The weights are close to the linear weights in smooth regions, where the reconstruction is fifth-order accurate,
+//! while the stencils containing a discontinuity are given almost zero weights.
+//!
+//! The time integration is carried out by the third-order SSP (strong stability preserving) Runge-Kutta method,
+//! ```math
+//! u^{(1)} = u^n + \Delta t L(u^n),
+//! u^{(2)} = \frac{3}{4} u^n + \frac{1}{4} (u^{(1)} + \Delta t L(u^{(1)})),
+//! u^{n+1} = \frac{1}{3} u^n + \frac{2}{3} (u^{(2)} + \Delta t L(u^{(2)})),
+//! ```
+//! where `\Delta t L(u)_j = - \nu (u_{j+1/2} - u_{j-1/2})` and `\nu = c \frac{\Delta t}{\Delta x}`.
+//!
+//! The method is implemented in the method of lines as [Weno5] integrated by [SspRk3].
+//! See [mol_solver](super::mol_solver).
+//!
+//! # Boundary Condition
+//! See [BoundaryCondition].
+//!
+//! The boundary condition is applied after each stage of the Runge-Kutta method.
+//! Except for the periodic boundary condition, the stencils near the boundaries are closed
+//! by extending the end points as constants.
+
+use super::boundary::{BoundaryCondition, Dirichlet};
+use super::spatial_operator::{SpatialOperator, Weno5};
+use super::time_integrator::{SspRk3, TimeIntegrator};
+use super::{NewParams, Solver, Stability};
+use ndarray::prelude::*;
+use num_traits::Float;
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Solver for the transport equation using the fifth-order WENO method.
+#[derive(Debug)]
+pub struct WenoSolver<F = f64> {
+    u: Array1<F>,
+    u_next: Array1<F>,
+    step_max: usize,
+    n_cfl: f64,
+    spatial_operator: Weno5<F>,
+    time_integrator: SspRk3<F>,
+    step: usize,
+    completed: bool,
+    stability: Stability,
+    dt: f64,
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> WenoSolver<F> {
+    /// Create a new `WenoSolver` instance.
+    pub fn new(new_params: WenoSolverNewParams<F>) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+        let stability = Self::check_stability(&new_params);
+
+        let mut u = new_params.u;
+        new_params.boundary_condition.prepare(&mut u);
+
+        let u_next = Array1::zeros(u.len());
+
+        Ok(Self {
+            u,
+            u_next,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            spatial_operator: Weno5::new(new_params.boundary_condition),
+            time_integrator: SspRk3::new(),
+            step: 0,
+            completed: false,
+            stability,
+            dt: new_params.dt,
+        })
+    }
+
+    /// Create a new `WenoSolverBuilder` instance to build a `WenoSolver` instance.
+    pub fn builder() -> WenoSolverBuilder<F> {
+        WenoSolverBuilder::default()
+    }
+
+    fn check_stability(new_params: &WenoSolverNewParams<F>) -> Stability {
+        // the bound is not known exactly because of the nonlinear weights
+        if new_params.n_cfl > 1.0 {
+            return Stability::Unknown;
+        }
+
+        Stability::Stable
+    }
+
+    fn calculate_u_next(&mut self) {
+        self.time_integrator.advance_into(
+            &self.spatial_operator,
+            &self.u,
+            self.n_cfl,
+            &mut self.u_next,
+        );
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> Solver<F> for WenoSolver<F> {
+    fn borrow_u(&self) -> &Array1<F> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn get_t(&self) -> f64 {
+        self.step as f64 * self.dt
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_stability(&self) -> Stability {
+        self.stability
+    }
+
+    fn reset(&mut self, u_init: Array1<F>) -> Result<(), Box<dyn Error>> {
+        if u_init.len() != self.u.len() {
+            return Err(Box::<dyn Error>::from(
+                "u_init must have the same length as u",
+            ));
+        }
+
+        self.u = u_init;
+        self.spatial_operator
+            .boundary_condition()
+            .prepare(&mut self.u);
+        self.step = 0;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.calculate_u_next();
+        std::mem::swap(&mut self.u, &mut self.u_next);
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `WenoSolver` instance.
+pub struct WenoSolverNewParams<F = f64> {
+    /// Initial value of `u`.
+    pub u: Array1<F>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number.
+    pub n_cfl: f64,
+    /// Time step.
+    pub dt: f64,
+    /// Boundary condition.
+    pub boundary_condition: Box<dyn BoundaryCondition<F>>,
+}
+
+impl<F: Float> NewParams for WenoSolverNewParams<F> {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.n_cfl <= 0.0 {
+            return Err("n_cfl must be positive");
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be positive");
+        }
+
+        self.boundary_condition.validate(self.u.len())?;
+
+        Ok(())
+    }
+}
+
+/// Builder of `WenoSolver` instances.
+///
+/// `u`, `step_max` and `n_cfl` must be set, while the others default to:
+/// - `dt`: `|n_cfl|`, that is, `t` is measured in units of `\Delta x / |c|`,
+/// - `boundary_condition`: [Dirichlet::fixed] for the initial value of `u`.
+#[derive(Debug)]
+pub struct WenoSolverBuilder<F = f64> {
+    u: Option<Array1<F>>,
+    step_max: Option<usize>,
+    n_cfl: Option<f64>,
+    dt: Option<f64>,
+    boundary_condition: Option<Box<dyn BoundaryCondition<F>>>,
+}
+
+impl<F> Default for WenoSolverBuilder<F> {
+    fn default() -> Self {
+        Self {
+            u: None,
+            step_max: None,
+            n_cfl: None,
+            dt: None,
+            boundary_condition: None,
+        }
+    }
+}
+
+impl<F: Float + Debug + Send + Sync + 'static> WenoSolverBuilder<F> {
+    /// Set the initial value of `u`.
+    pub fn u(mut self, u: Array1<F>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set the CFL number.
+    pub fn n_cfl(mut self, n_cfl: f64) -> Self {
+        self.n_cfl = Some(n_cfl);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the boundary condition.
+    pub fn boundary_condition(mut self, boundary_condition: Box<dyn BoundaryCondition<F>>) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Build a new `WenoSolver` instance, whose parameters are validated as in [WenoSolver::new].
+    pub fn build(self) -> Result<WenoSolver<F>, &'static str> {
+        let u = self.u.ok_or("u must be set")?;
+        let step_max = self.step_max.ok_or("step_max must be set")?;
+        let n_cfl = self.n_cfl.ok_or("n_cfl must be set")?;
+        if u.is_empty() {
+            return Err("u must not be empty");
+        }
+        let boundary_condition = self
+            .boundary_condition
+            .unwrap_or_else(|| Box::new(Dirichlet::fixed(&u)));
+
+        WenoSolver::new(WenoSolverNewParams {
+            u,
+            step_max,
+            n_cfl,
+            dt: self.dt.unwrap_or(n_cfl.abs()),
+            boundary_condition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::boundary::Periodic;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn fn_weno_integrate_works() {
+        // setup weno solver with a smooth periodic u and run integrate() for one period
+        let n_x = 40;
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let u_init = x.map(|x| (PI * x).sin());
+        let new_params = WenoSolverNewParams {
+            u: u_init.clone(),
+            step_max: 2 * n_x,
+            n_cfl: 0.5,
+            dt: 0.5,
+            boundary_condition: Box::new(Periodic),
+        };
+        let mut weno_solver = WenoSolver::new(new_params).unwrap();
+        while !weno_solver.is_completed() {
+            weno_solver.integrate().unwrap();
+        }
+
+        // check if u returns to the initial value and step is correctly updated
+        let is_u_correctly_updated = (weno_solver.u - u_init).iter().all(|u| u.abs() < 1e-3);
+        assert!(is_u_correctly_updated);
+        assert_eq!(weno_solver.step, 2 * n_x);
+    }
+}
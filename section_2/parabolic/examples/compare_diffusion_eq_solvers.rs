@@ -0,0 +1,239 @@
+//! Compare the accuracy and the stability of the FTCS method and the Beam-Warming method
+//! for several values of `mu` and `lambda`.
+//!
+//! # Formulation
+//! The same problem as [parabolic::exact::triangle] is solved, that is, the diffusion equation
+//! ```math
+//! \frac{\partial u}{\partial t} = \alpha \frac{\partial^2 u}{\partial x^2} (x \in [-1, 1]),
+//! ```
+//! with the initial condition
+//! ```math
+//! u(x, 0) = 1 - |x|,
+//! ```
+//! and the boundary condition `u(\pm 1, t) = 0`.
+//!
+//! Every run starts from the same initial values on the same grid
+//! and is stopped when `\max |u|` grows beyond `growth_limit` times the initial one.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 20
+//! step_max: 500
+//! mu: [0.25, 0.5, 0.6]
+//! lambda: [0.5, 1.0]
+//! growth_limit: 10.0
+//! ncycle_out: 100
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecCompareInputParams].
+//!
+//! # Output Format
+//! The solutions are output to `ftcs_mu_<mu>.dat` and `beamwarming_lambda_<lambda>_mu_<mu>.dat`
+//! at the same steps. See [parabolic::output::output_with_errors] for the format.
+//!
+//! The summary is output to `summary.dat` as follows:
+//! ```text
+//! scheme lambda mu step l2_error linf_error blowup_step
+//! ftcs 0 0.25 500 1.0e-4 2.0e-4 -
+//! ftcs 0 0.6 23 3.0e0 5.0e0 23
+//! beamwarming 0.5 0.25 500 1.0e-4 2.0e-4 -
+//! ...
+//! ```
+//! where `step` is the last step, at which the errors are evaluated,
+//! and `blowup_step` is the step at which the run is stopped by the blow-up, or `-` if not stopped.
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::exact::{self, ErrorNorms};
+use parabolic::input;
+use parabolic::input::examples::ExecCompareInputParams;
+use parabolic::output;
+use parabolic::solver::beamwarming_solver::BeamwarmingSolver;
+use parabolic::solver::ftcs_solver::FtcsSolver;
+use parabolic::solver::Solver;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::process;
+
+/// Solve the diffusion equation by each scheme and output the results and the summary to files.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/compare_diffusion_eq_solvers");
+
+    // read input parameters
+    let input_params: ExecCompareInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked,
+    // where the FTCS method is the Beam-Warming method of lambda = 0
+    exec_args.exit_if_check(|| {
+        let unstable_cases: Vec<String> = [0.0]
+            .iter()
+            .chain(&input_params.lambda)
+            .flat_map(|&lambda| input_params.mu.iter().map(move |&mu| (lambda, mu)))
+            .filter(|&(lambda, mu)| mu > BeamwarmingSolver::mu_limit(lambda))
+            .map(|(lambda, mu)| format!("(lambda, mu) = ({}, {})", lambda, mu))
+            .collect();
+        Report::new()
+            .quantity("dx", format!("{:.10e}", 2.0 / input_params.n_x as f64))
+            .quantity("mu", format!("{:?}", input_params.mu))
+            .quantity("lambda", format!("{:?}", input_params.lambda))
+            .stability(if unstable_cases.is_empty() {
+                "stable for all the cases".to_string()
+            } else {
+                format!("unstable for {}", unstable_cases.join(", "))
+            })
+    });
+
+    // setup output directory
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    compare(&input_params, output_dir).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Solve the problem by each scheme and output the results and the summary.
+fn compare(input_params: &ExecCompareInputParams, output_dir: &Path) -> Result<(), Box<dyn Error>> {
+    // setup coordinates and initial values
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let u_init = x.mapv(|x| 1.0 - x.abs());
+
+    let mut summaryfile = File::create(output_dir.join("summary.dat"))?;
+    writeln!(
+        summaryfile,
+        "scheme lambda mu step l2_error linf_error blowup_step"
+    )?;
+
+    for &mu in &input_params.mu {
+        let mut solver = FtcsSolver::builder()
+            .u(u_init.clone())
+            .step_max(input_params.step_max)
+            .mu(mu)
+            .build()?;
+        let mut outputfile = File::create(output_dir.join(format!("ftcs_mu_{}.dat", mu)))?;
+        let result = run_case(input_params, &x, mu, &mut solver, &mut outputfile)?;
+        write_summary(&mut summaryfile, "ftcs", 0.0, mu, &result)?;
+    }
+
+    for &lambda in &input_params.lambda {
+        for &mu in &input_params.mu {
+            let mut solver = BeamwarmingSolver::builder()
+                .u(u_init.clone())
+                .step_max(input_params.step_max)
+                .mu(mu)
+                .lambda(lambda)
+                .build()?;
+            let mut outputfile = File::create(
+                output_dir.join(format!("beamwarming_lambda_{}_mu_{}.dat", lambda, mu)),
+            )?;
+            let result = run_case(input_params, &x, mu, &mut solver, &mut outputfile)?;
+            write_summary(&mut summaryfile, "beamwarming", lambda, mu, &result)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Result of a run.
+struct CaseResult {
+    /// Last step.
+    step: usize,
+    /// Norms of the error at the last step.
+    error_norms: ErrorNorms,
+    /// Step at which the run is stopped by the blow-up.
+    blowup_step: Option<usize>,
+}
+
+/// Run the solver until the completion or the blow-up, outputting the results with the errors every `ncycle_out` steps.
+fn run_case(
+    input_params: &ExecCompareInputParams,
+    x: &Array1<f64>,
+    mu: f64,
+    solver: &mut impl Solver<Field = Array1<f64>>,
+    outputstream: &mut impl Write,
+) -> Result<CaseResult, Box<dyn Error>> {
+    let dx = 2.0 / input_params.n_x as f64;
+    let u_exact = |step: usize| exact::triangle(x, step as f64 * mu * dx * dx, 1000);
+    let max_abs = |u: &Array1<f64>| u.fold(0.0, |acc: f64, u| acc.max(u.abs()));
+    let u_limit = input_params.growth_limit * max_abs(solver.borrow_u());
+
+    output::output_with_errors(outputstream, 0, x, solver.borrow_u(), &u_exact(0))?;
+    let mut blowup_step = None;
+    while !solver.is_completed() {
+        solver.integrate()?;
+        let step = solver.get_step();
+
+        let is_blown_up = solver
+            .borrow_u()
+            .iter()
+            .any(|u| !u.is_finite() || u.abs() > u_limit);
+        if is_blown_up || step.is_multiple_of(input_params.ncycle_out) {
+            output::output_with_errors(outputstream, step, x, solver.borrow_u(), &u_exact(step))?;
+        }
+        if is_blown_up {
+            blowup_step = Some(step);
+            break;
+        }
+    }
+
+    let step = solver.get_step();
+    Ok(CaseResult {
+        step,
+        error_norms: ErrorNorms::new(solver.borrow_u(), &u_exact(step), dx),
+        blowup_step,
+    })
+}
+
+/// Write the line of the summary for the run.
+fn write_summary(
+    outputstream: &mut impl Write,
+    scheme: &str,
+    lambda: f64,
+    mu: f64,
+    result: &CaseResult,
+) -> Result<(), Box<dyn Error>> {
+    let blowup_step = match result.blowup_step {
+        Some(step) => step.to_string(),
+        None => String::from("-"),
+    };
+    writeln!(
+        outputstream,
+        "{} {} {} {} {:.10e} {:.10e} {}",
+        scheme,
+        lambda,
+        mu,
+        result.step,
+        result.error_norms.l2,
+        result.error_norms.linf,
+        blowup_step
+    )?;
+    println!(
+        "{} (lambda = {}, mu = {}): max error {:.3e} at step {}{}",
+        scheme,
+        lambda,
+        mu,
+        result.error_norms.linf,
+        result.step,
+        if result.blowup_step.is_some() {
+            ", blown up"
+        } else {
+            ""
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! Solve the advection-diffusion equation by the [parabolic::solver::advectiondiffusion_solver].
+//!
+//! # Formulation
+//! The advection-diffusion equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = \alpha \frac{\partial^2 u}{\partial x^2}
+//! (x \in [-1, 1]),
+//! ```
+//! where `u` is the transported quantity, `c` is the advection velocity and `\alpha` is the diffusion coefficient.
+//!
+//! The initial condition is selected in the input file, see [InitialCondition](parabolic::initial_conditions::InitialCondition), and defaults to
+//! ```math
+//! u(x, 0) = -x + 1 (x \ge 0), u(x, 0) = x + 1 (x < 0).
+//! ```
+//!
+//! For the boundary condition, see [parabolic::solver::advectiondiffusion_solver].
+//!
+//! # Scheme
+//! The difference operator for the advection term and the time integration of the diffusion term
+//! are selected in the input file. See [parabolic::solver::advectiondiffusion_solver].
+//! Try `convection: central` with `mu` smaller than `n_cfl / 2` to see the validation of the cell Péclet number.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! convection: upwind
+//! diffusion: explicit
+//! n_x: 100
+//! step_max: 100
+//! n_cfl: 0.2
+//! mu: 0.2
+//! ncycle_out: 25
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecAdvectiondiffusionInputParams].
+//!
+//! # Output Format
+//! See [parabolic::output::output].
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::input;
+use parabolic::input::examples::ExecAdvectiondiffusionInputParams;
+use parabolic::solver::advectiondiffusion_solver::{
+    AdvectiondiffusionSolver, AdvectiondiffusionSolverNewParams, Convection,
+};
+use parabolic::solver::Diffusion;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the advection-diffusion equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_advection_diffusion_eq");
+
+    // read input parameters
+    let input_params: ExecAdvectiondiffusionInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+
+    // setup the initial condition
+    let u_init = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
+
+    // initialize the solver
+    let new_params = AdvectiondiffusionSolverNewParams {
+        u: u_init,
+        step_max: input_params.step_max,
+        n_cfl: input_params.n_cfl,
+        mu: input_params.mu,
+        convection: input_params.convection,
+        diffusion: input_params.diffusion,
+    };
+    let mut solver = AdvectiondiffusionSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+    println!("cell Peclet number: {}", solver.peclet_number());
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let (nu, mu) = (input_params.n_cfl, input_params.mu);
+        let report = Report::new()
+            .quantity("dx", format!("{:.10e}", x[1] - x[0]))
+            .quantity("nu", format!("{:.10e}", nu))
+            .quantity("mu", format!("{:.10e}", mu));
+        report.stability(match (input_params.convection, input_params.diffusion) {
+            (Convection::Upwind, Diffusion::Explicit) if nu.abs() + 2.0 * mu <= 1.0 => {
+                "stable since |nu| + 2 mu <= 1"
+            }
+            (Convection::Upwind, Diffusion::Explicit) => "unstable since |nu| + 2 mu > 1",
+            (Convection::Upwind, Diffusion::Implicit) if nu.abs() <= 1.0 => {
+                "stable since |nu| <= 1"
+            }
+            (Convection::Upwind, Diffusion::Implicit) => "unstable since |nu| > 1",
+            (Convection::Central, Diffusion::Explicit) if nu * nu <= 2.0 * mu && mu <= 0.5 => {
+                "stable since nu^2 <= 2 mu <= 1"
+            }
+            (Convection::Central, Diffusion::Explicit) => "unstable unless nu^2 <= 2 mu <= 1",
+            (Convection::Central, Diffusion::Implicit) if nu * nu <= 2.0 * mu => {
+                "stable since nu^2 <= 2 mu"
+            }
+            (Convection::Central, Diffusion::Implicit) => "unstable since nu^2 > 2 mu",
+        })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out).unwrap_or_else(
+        |err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        },
+    );
+}
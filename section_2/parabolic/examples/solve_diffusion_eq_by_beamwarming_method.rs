@@ -7,12 +7,14 @@
 //! ```
 //! where `u` is the diffusion quantity and `\alpha` is the diffusion coefficient.
 //!
-//! The initial condition is given by
+//! The initial condition is selected in the input file, see [InitialCondition](parabolic::initial_conditions::InitialCondition), and defaults to
 //! ```math
 //! u(x, 0) = -x + 1 (x \ge 0), u(x, 0) = x + 1 (x < 0).
 //! ```
 //!
-//! For the boundary condition, see [parabolic::solver::beamwarming_solver].
+//! The boundary condition at each end is selected in the input file, see [BoundaryCondition](parabolic::solver::boundary::BoundaryCondition),
+//! and defaults to the Dirichlet boundary condition `u(\pm 1, t) = u(\pm 1, 0)`.
+//! See also [parabolic::solver::beamwarming_solver] for the boundary condition.
 //!
 //! # Scheme
 //! See [parabolic::solver::beamwarming_solver].
@@ -25,104 +27,114 @@
 //! mu: 0.5
 //! lambda: 0.5
 //! ncycle_out: 1000
+//! output_errors: false
 //! ```
 //!
+//! Instead of `n_x` and `mu`, the physical quantities `alpha`, `dx` and `dt` can be given,
+//! in which case the time is output as well. See [Resolution](parabolic::input::Resolution).
+//!
 //! For the meaning of each parameter, see [ExecBeamwarmingInputParams].
 //!
 //! # Output Format
-//! See [parabolic::output::output].
+//! See [parabolic::output::output], or [parabolic::output::output_with_errors] if `output_errors` is `true`.
 
+use cli::check::Report;
 use ndarray::prelude::*;
+use parabolic::exact;
 use parabolic::input;
-use parabolic::input::InputParams;
+use parabolic::input::examples::ExecBeamwarmingInputParams;
 use parabolic::solver::beamwarming_solver::{BeamwarmingSolver, BeamwarmingSolverNewParams};
-use serde_derive::{Deserialize, Serialize};
 use std::fs::{self, File};
 use std::process;
 
 /// Solve the diffusion equation with the given input parameters and output the results to a file.
 fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_diffusion_eq_by_beamwarming_method");
+
     // read input parameters
-    let mut inputfile =
-        File::open("inputs/section_2/parabolic/solve_diffusion_eq_by_beamwarming_method/input.yml")
-            .unwrap_or_else(|err| {
-                eprintln!("Problem opening input file: {}", err);
-                process::exit(1);
-            });
-    let input_params: ExecBeamwarmingInputParams = input::read_input_params(&mut inputfile)
+    let input_params: ExecBeamwarmingInputParams = exec_args
+        .read_input_params(input::read_input_params)
         .unwrap_or_else(|err| {
             eprintln!("Problem reading input parameters: {}", err);
             process::exit(1);
         });
 
-    // setup output files
-    let dir_str = "outputs/section_2/parabolic/solve_diffusion_eq_by_beamwarming_method";
-    fs::create_dir_all(dir_str).unwrap_or_else(|err| {
-        eprintln!("Problem creating output directory: {}", err);
-        process::exit(1);
-    });
-    let mut outputfile = File::create(format!("{}/solution.dat", dir_str)).unwrap_or_else(|err| {
-        eprintln!("Problem creating output files: {}", err);
-        process::exit(1);
-    });
-
     // setup coordinates
-    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let n_x = input_params.resolution.n_x(2.0);
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+
+    // setup the initial condition
+    let u_init = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
 
     // initialize the solver
     let new_params = BeamwarmingSolverNewParams {
-        u: x.map(|x| if *x < 0.0 { *x + 1.0 } else { -(*x) + 1.0 }),
+        u: u_init,
         step_max: input_params.step_max,
-        mu: input_params.mu,
+        mu: input_params.resolution.mu(),
         lambda: input_params.lambda,
+        source: None,
+        boundary_condition: input_params.boundary_condition,
+        dx: Some(2.0 / n_x as f64),
+        dt: input_params.resolution.dt(),
     };
     let mut solver = BeamwarmingSolver::new(new_params).unwrap_or_else(|err| {
         eprintln!("Problem creating solver: {}", err);
         process::exit(1);
     });
 
-    // run
-    parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out).unwrap_or_else(
-        |err| {
-            eprintln!("Application error: {}", err);
-            process::exit(1);
-        },
-    );
-}
-
-/// Input parameters.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ExecBeamwarmingInputParams {
-    /// Number of cells.
-    pub n_x: usize,
-    /// Maximum number of time steps.
-    pub step_max: usize,
-    /// diffusion coefficient * dt / dx^2.
-    pub mu: f64,
-    /// Weighting factor in differencing scheme.
-    pub lambda: f64,
-    /// Number of cycles between outputs.
-    pub ncycle_out: usize,
-}
-
-impl InputParams for ExecBeamwarmingInputParams {
-    fn validate_params(&self) -> Result<(), &'static str> {
-        if self.n_x == 0 {
-            return Err("n_x must be positive");
-        }
-        if self.step_max == 0 {
-            return Err("step_max must be positive");
-        }
-        if self.mu <= 0.0 {
-            return Err("mu must be positive");
-        }
-        if self.lambda < 0.0 || self.lambda > 1.0 {
-            return Err("lambda must be between 0 and 1");
-        }
-        if self.ncycle_out == 0 {
-            return Err("ncycle_out must be positive");
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let mu = input_params.resolution.mu();
+        let mu_limit = BeamwarmingSolver::mu_limit(input_params.lambda);
+        let mut report = Report::new()
+            .quantity("dx", format!("{:.10e}", 2.0 / n_x as f64))
+            .quantity("mu", format!("{:.10e}", mu));
+        if let Some(dt) = input_params.resolution.dt() {
+            report = report.quantity("dt", format!("{:.10e}", dt));
         }
+        report.stability(if mu_limit.is_infinite() {
+            "stable for any mu since lambda >= 1/2".to_string()
+        } else if mu <= mu_limit {
+            format!("stable since mu <= {:.10e}", mu_limit)
+        } else {
+            format!("unstable since mu > {:.10e}", mu_limit)
+        })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
 
-        Ok(())
-    }
+    // run
+    let result = if input_params.output_errors {
+        let dx = 2.0 / n_x as f64;
+        let alpha_dt = input_params.resolution.mu() * dx * dx;
+        parabolic::run_with_exact(
+            &x,
+            &mut solver,
+            &mut outputfile,
+            input_params.ncycle_out,
+            |step| exact::triangle(&x, step as f64 * alpha_dt, 1000),
+        )
+    } else {
+        parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out)
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
 }
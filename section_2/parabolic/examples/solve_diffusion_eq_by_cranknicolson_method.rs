@@ -0,0 +1,114 @@
+//! Solve the diffusion equation by the [parabolic::solver::cranknicolson_solver].
+//!
+//! # Formulation
+//! The diffusion equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \alpha \frac{\partial^2 u}{\partial x^2} (x \in [-1, 1]),
+//! ```
+//! where `u` is the diffusion quantity and `\alpha` is the diffusion coefficient.
+//!
+//! The initial condition is selected in the input file, see [InitialCondition](parabolic::initial_conditions::InitialCondition), and defaults to
+//! ```math
+//! u(x, 0) = -x + 1 (x \ge 0), u(x, 0) = x + 1 (x < 0).
+//! ```
+//!
+//! For the boundary condition, see [parabolic::solver::cranknicolson_solver].
+//!
+//! # Scheme
+//! See [parabolic::solver::cranknicolson_solver].
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 100
+//! step_max: 10000
+//! mu: 0.5
+//! ncycle_out: 1000
+//! ```
+//!
+//! Instead of `n_x` and `mu`, the physical quantities `alpha`, `dx` and `dt` can be given,
+//! in which case the time is output as well. See [Resolution](parabolic::input::Resolution).
+//!
+//! For the meaning of each parameter, see [ExecCranknicolsonInputParams].
+//!
+//! # Output Format
+//! See [parabolic::output::output].
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::input;
+use parabolic::input::examples::ExecCranknicolsonInputParams;
+use parabolic::solver::cranknicolson_solver::{CranknicolsonSolver, CranknicolsonSolverNewParams};
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the diffusion equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_diffusion_eq_by_cranknicolson_method");
+
+    // read input parameters
+    let input_params: ExecCranknicolsonInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let n_x = input_params.resolution.n_x(2.0);
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+
+    // setup the initial condition
+    let u_init = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
+
+    // initialize the solver
+    let new_params = CranknicolsonSolverNewParams {
+        u: u_init,
+        step_max: input_params.step_max,
+        mu: input_params.resolution.mu(),
+        source: None,
+        dt: input_params.resolution.dt(),
+    };
+    let mut solver = CranknicolsonSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        let mu = input_params.resolution.mu();
+        let mut report = Report::new()
+            .quantity("dx", format!("{:.10e}", 2.0 / n_x as f64))
+            .quantity("mu", format!("{:.10e}", mu));
+        if let Some(dt) = input_params.resolution.dt() {
+            report = report.quantity("dt", format!("{:.10e}", dt));
+        }
+        report.stability("stable for any mu")
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out).unwrap_or_else(
+        |err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        },
+    );
+}
@@ -0,0 +1,109 @@
+//! Solve the two-dimensional diffusion equation by the [parabolic::solver::ftcs_2d_solver].
+//!
+//! # Formulation
+//! The two-dimensional diffusion equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \alpha (\frac{\partial^2 u}{\partial x^2} + \frac{\partial^2 u}{\partial y^2})
+//! (x \in [-1, 1], y \in [-1, 1]),
+//! ```
+//! where `u` is the diffusion quantity and `\alpha` is the diffusion coefficient.
+//!
+//! The initial condition is given by the pyramid
+//! ```math
+//! u(x, y, 0) = (1 - |x|) (1 - |y|).
+//! ```
+//!
+//! For the boundary condition, see [parabolic::solver::ftcs_2d_solver].
+//!
+//! # Scheme
+//! See [parabolic::solver::ftcs_2d_solver].
+//! The solution is stable for `\mu \le 1/4`, so try `mu` slightly above it to see the instability.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 40
+//! step_max: 400
+//! mu: 0.25
+//! ncycle_out: 100
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecFtcs2dInputParams].
+//!
+//! # Output Format
+//! See [parabolic::output::output_2d].
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::input;
+use parabolic::input::examples::ExecFtcs2dInputParams;
+use parabolic::solver::ftcs_2d_solver::{Ftcs2dSolver, Ftcs2dSolverNewParams};
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the two-dimensional diffusion equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_diffusion_eq_by_ftcs_2d_method");
+
+    // read input parameters
+    let input_params: ExecFtcs2dInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+    let y = x.clone();
+
+    // initialize the solver
+    let new_params = Ftcs2dSolverNewParams {
+        u: Array2::from_shape_fn((x.len(), y.len()), |(i, j)| {
+            (1.0 - x[i].abs()) * (1.0 - y[j].abs())
+        }),
+        step_max: input_params.step_max,
+        mu: input_params.mu,
+    };
+    let mut solver = Ftcs2dSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    exec_args.exit_if_check(|| {
+        Report::new()
+            .quantity("dx", format!("{:.10e}", x[1] - x[0]))
+            .quantity("mu", format!("{:.10e}", input_params.mu))
+            .stability(if input_params.mu <= 0.25 {
+                "stable since mu <= 1/4"
+            } else {
+                "unstable since mu > 1/4"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    parabolic::run_2d(
+        &x,
+        &y,
+        &mut solver,
+        &mut outputfile,
+        input_params.ncycle_out,
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
@@ -0,0 +1,129 @@
+//! Solve the diffusion equation with the variable diffusion coefficient by the
+//! [parabolic::solver::ftcs_adaptive_solver].
+//!
+//! # Formulation
+//! The diffusion equation with the variable diffusion coefficient is given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \frac{\partial}{\partial x} (\alpha(x, t) \frac{\partial u}{\partial x})
+//! (x \in [-1, 1]),
+//! ```
+//! where `u` is the diffusion quantity and the diffusion coefficient grows in space and time as
+//! ```math
+//! \alpha(x, t) = \alpha_0 (1 + x^2) (1 + r t).
+//! ```
+//!
+//! The initial condition is selected in the input file, see [InitialCondition](parabolic::initial_conditions::InitialCondition), and defaults to
+//! ```math
+//! u(x, 0) = -x + 1 (x \ge 0), u(x, 0) = x + 1 (x < 0).
+//! ```
+//!
+//! For the boundary condition, see [parabolic::solver::ftcs_adaptive_solver].
+//!
+//! # Scheme
+//! See [parabolic::solver::ftcs_adaptive_solver].
+//! The time step shrinks as `\alpha` grows, keeping the calculation stable.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! n_x: 100
+//! t_max: 0.1
+//! step_max: 100000
+//! alpha: 1.0
+//! alpha_rate: 10.0
+//! safety_factor: 0.9
+//! ncycle_out: 100
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecFtcsAdaptiveInputParams].
+//!
+//! # Output Format
+//! See [parabolic::output::output_with_time].
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::input;
+use parabolic::input::examples::ExecFtcsAdaptiveInputParams;
+use parabolic::solver::ftcs_adaptive_solver::{FtcsAdaptiveSolver, FtcsAdaptiveSolverNewParams};
+use parabolic::solver::DiffusionCoefficient;
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the diffusion equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_diffusion_eq_by_ftcs_adaptive_method");
+
+    // read input parameters
+    let input_params: ExecFtcsAdaptiveInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+
+    // setup the initial condition
+    let u_init = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
+
+    // initialize the solver
+    let x_alpha = x.clone();
+    let alpha = input_params.alpha;
+    let alpha_rate = input_params.alpha_rate;
+    let new_params = FtcsAdaptiveSolverNewParams {
+        u: u_init,
+        t_max: input_params.t_max,
+        step_max: input_params.step_max,
+        dx: 2.0 / input_params.n_x as f64,
+        alpha: DiffusionCoefficient::new(move |t| {
+            x_alpha.mapv(|x| alpha * (1.0 + x * x) * (1.0 + alpha_rate * t))
+        }),
+        safety_factor: input_params.safety_factor,
+    };
+    let mut solver = FtcsAdaptiveSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    // the initial time step is limited by the maximum alpha(x, 0) = 2 alpha at x = \pm 1
+    exec_args.exit_if_check(|| {
+        let dx = 2.0 / input_params.n_x as f64;
+        let dt_init = input_params.safety_factor * dx * dx / (2.0 * 2.0 * input_params.alpha);
+        Report::new()
+            .quantity("dx", format!("{:.10e}", dx))
+            .quantity("dt", format!("{:.10e} initially", dt_init))
+            .stability(if input_params.safety_factor <= 1.0 {
+                "stable since the time step is adapted with the safety factor <= 1"
+            } else {
+                "unstable since the safety factor > 1"
+            })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out).unwrap_or_else(
+        |err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        },
+    );
+}
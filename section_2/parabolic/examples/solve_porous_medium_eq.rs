@@ -0,0 +1,117 @@
+//! Solve the porous medium equation by the [parabolic::solver::porousmedium_solver].
+//!
+//! # Formulation
+//! The porous medium equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \frac{\partial}{\partial x} (u^m \frac{\partial u}{\partial x}) (x \in [-1, 1]).
+//! ```
+//!
+//! The initial condition is selected in the input file, see [InitialCondition](parabolic::initial_conditions::InitialCondition), and defaults to
+//! ```math
+//! u(x, 0) = 1 (|x| < 0.2), u(x, 0) = 0 (|x| \ge 0.2).
+//! ```
+//!
+//! For the boundary condition, see [parabolic::solver::porousmedium_solver].
+//!
+//! # Scheme
+//! The time integration is selected in the input file. See [parabolic::solver::porousmedium_solver].
+//! Compare `m: 2.0` with `m: 0.0`, the linear diffusion, to see the sharp fronts spreading at the finite speed.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! diffusion: explicit
+//! n_x: 100
+//! step_max: 2000
+//! mu: 0.2
+//! m: 2.0
+//! ncycle_out: 500
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecPorousmediumInputParams].
+//!
+//! # Output Format
+//! See [parabolic::output::output].
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::input;
+use parabolic::input::examples::ExecPorousmediumInputParams;
+use parabolic::solver::porousmedium_solver::{PorousmediumSolver, PorousmediumSolverNewParams};
+use parabolic::solver::{Diffusion, Solver};
+use std::fs::{self, File};
+use std::process;
+
+/// Solve the porous medium equation with the given input parameters and output the results to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args = cli::parse("section_2/parabolic/solve_porous_medium_eq");
+
+    // read input parameters
+    let input_params: ExecPorousmediumInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // setup coordinates
+    let x: Array1<f64> = Array1::linspace(-1.0, 1.0, input_params.n_x + 1);
+
+    // setup the initial condition
+    let u_init = input_params
+        .initial_condition
+        .create(&x)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem creating initial condition: {}", err);
+            process::exit(1);
+        });
+
+    // initialize the solver
+    let new_params = PorousmediumSolverNewParams {
+        u: u_init,
+        step_max: input_params.step_max,
+        mu: input_params.mu,
+        m: input_params.m,
+        diffusion: input_params.diffusion,
+    };
+    let mut solver = PorousmediumSolver::new(new_params).unwrap_or_else(|err| {
+        eprintln!("Problem creating solver: {}", err);
+        process::exit(1);
+    });
+
+    // report the derived quantities and exit if only the input parameters are checked
+    // the maximum of u, and thus of the diffusion coefficient u^m, never grows by the maximum principle
+    exec_args.exit_if_check(|| {
+        let u_max = solver.borrow_u().fold(0.0f64, |acc, u| acc.max(*u));
+        let mu_d_max = input_params.mu * u_max.powf(input_params.m);
+        let report = Report::new()
+            .quantity("dx", format!("{:.10e}", x[1] - x[0]))
+            .quantity("mu", format!("{:.10e}", input_params.mu))
+            .quantity("mu max D", format!("{:.10e}", mu_d_max));
+        report.stability(match input_params.diffusion {
+            Diffusion::Implicit => "stable for any mu",
+            Diffusion::Explicit if mu_d_max <= 0.5 => "stable since mu max D <= 1/2",
+            Diffusion::Explicit => "unstable since mu max D > 1/2",
+        })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("solution.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    parabolic::run(&x, &mut solver, &mut outputfile, input_params.ncycle_out).unwrap_or_else(
+        |err| {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        },
+    );
+}
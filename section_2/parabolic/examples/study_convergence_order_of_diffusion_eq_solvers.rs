@@ -0,0 +1,248 @@
+//! Study the order of accuracy of a scheme for the diffusion equation by refining the grid.
+//!
+//! The chosen scheme is run on a sequence of grids, where the number of cells is doubled for each grid
+//! with either `mu` or the time step kept constant, and the errors against the exact solution are calculated at the end.
+//! The observed orders of accuracy in space and time are given by
+//! ```math
+//! p_x = \frac{\log (e_{i - 1} / e_i)}{\log (\Delta x_{i - 1} / \Delta x_i)},
+//! p_t = \frac{\log (e_{i - 1} / e_i)}{\log (\Delta t_{i - 1} / \Delta t_i)}
+//! ```
+//! for each pair of the successive grids.
+//!
+//! When `mu` is kept constant, `\Delta t` is proportional to `\Delta x^2`, so that `p_t = p_x / 2`
+//! and the error of the scheme `O(\Delta t^q, \Delta x^2)` is observed as `p_x = \min(2 q, 2)`.
+//! When the time step is kept constant, `p_t` is not defined and `p_x` approaches 0
+//! once the temporal error dominates the spatial one.
+//!
+//! # Formulation
+//! The same problem as [parabolic::exact::triangle] is solved, that is, the diffusion equation
+//! ```math
+//! \frac{\partial u}{\partial t} = \alpha \frac{\partial^2 u}{\partial x^2} (x \in [-1, 1]),
+//! ```
+//! with the initial condition
+//! ```math
+//! u(x, 0) = 1 - |x|,
+//! ```
+//! and the boundary condition `u(\pm 1, t) = 0`.
+//!
+//! # Scheme
+//! One of [Scheme] is chosen.
+//!
+//! # Input Format
+//! Input should be a YAML file in the following format:
+//! ```yaml
+//! scheme: beamwarming
+//! lambda: 0.5
+//! n_x_min: 10
+//! n_grid: 5
+//! hold: mu
+//! mu: 0.5
+//! alpha_t_end: 0.1
+//! ```
+//!
+//! For the meaning of each parameter, see [ExecStudyConvergenceOrderInputParams].
+//!
+//! # Output Format
+//! The convergence table is output to `convergence.dat` in the following format:
+//! ```text
+//! n_x dx dt error_l2 order_x_l2 order_t_l2 error_linf order_x_linf order_t_linf
+//! 10 0.2000000000 2.0000000000e-2 1.2345678900e-3 - - 1.2345678900e-3 - -
+//! 20 0.1000000000 5.0000000000e-3 3.0864197250e-4 2.0000000000 1.0000000000 3.0864197250e-4 2.0000000000 1.0000000000
+//! ```
+//! where `dt` is the diffusion coefficient * time step, and `order_*` is the observed order of accuracy against the previous grid,
+//! or `-` if not defined.
+
+use cli::check::Report;
+use ndarray::prelude::*;
+use parabolic::exact::{self, ErrorNorms};
+use parabolic::input;
+use parabolic::input::examples::{ExecStudyConvergenceOrderInputParams, Hold, Scheme};
+use parabolic::solver::beamwarming_solver::BeamwarmingSolver;
+use parabolic::solver::ftcs_solver::FtcsSolver;
+use parabolic::solver::Solver;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::Write;
+use std::process;
+
+/// Run the scheme on each grid and output the convergence table to a file.
+fn main() {
+    // parse command line arguments
+    let exec_args =
+        cli::parse("section_2/parabolic/study_convergence_order_of_diffusion_eq_solvers");
+
+    // read input parameters
+    let input_params: ExecStudyConvergenceOrderInputParams = exec_args
+        .read_input_params(input::read_input_params)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem reading input parameters: {}", err);
+            process::exit(1);
+        });
+
+    // report the derived quantities and exit if only the input parameters are checked,
+    // where mu is the largest on the finest grid if dt is held
+    exec_args.exit_if_check(|| {
+        let n_x_max = input_params.n_x_min << (input_params.n_grid - 1);
+        let [dx_max, dx_min] = [input_params.n_x_min, n_x_max].map(|n_x| 2.0 / n_x as f64);
+        let mu_limit = match input_params.scheme {
+            Scheme::Ftcs => BeamwarmingSolver::mu_limit(0.0),
+            Scheme::Beamwarming { lambda } => BeamwarmingSolver::mu_limit(lambda),
+        };
+        let report = Report::new().quantity("dx", format!("{:.10e} to {:.10e}", dx_max, dx_min));
+        let report = match input_params.hold {
+            Hold::Mu => report.quantity("mu", format!("{:.10e}", input_params.mu)),
+            Hold::Dt => report
+                .quantity(
+                    "mu",
+                    format!(
+                        "{:.10e} to {:.10e}",
+                        input_params.mu * (dx_min / dx_max).powi(2),
+                        input_params.mu
+                    ),
+                )
+                .quantity("dt", format!("{:.10e}", input_params.mu * dx_min * dx_min)),
+        };
+        report.stability(if mu_limit.is_infinite() {
+            "stable for any mu since lambda >= 1/2".to_string()
+        } else if input_params.mu <= mu_limit {
+            format!("stable since mu <= {:.10e}", mu_limit)
+        } else {
+            format!("unstable since mu > {:.10e}", mu_limit)
+        })
+    });
+
+    // setup output files
+    let output_dir = &exec_args.output_dir;
+    fs::create_dir_all(output_dir).unwrap_or_else(|err| {
+        eprintln!("Problem creating output directory: {}", err);
+        process::exit(1);
+    });
+    let mut outputfile = File::create(output_dir.join("convergence.dat")).unwrap_or_else(|err| {
+        eprintln!("Problem creating output files: {}", err);
+        process::exit(1);
+    });
+
+    // run
+    study(&input_params, &mut outputfile).unwrap_or_else(|err| {
+        eprintln!("Application error: {}", err);
+        process::exit(1);
+    });
+}
+
+/// Run the scheme on each grid and output the errors and the observed orders of accuracy.
+fn study(
+    input_params: &ExecStudyConvergenceOrderInputParams,
+    outputstream: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    let n_x_max = input_params.n_x_min << (input_params.n_grid - 1);
+    let dx_min = 2.0 / n_x_max as f64;
+
+    // calculate the errors on each grid
+    let mut dxs = Vec::with_capacity(input_params.n_grid);
+    let mut dts = Vec::with_capacity(input_params.n_grid);
+    let mut errors = Vec::with_capacity(input_params.n_grid);
+    for i_grid in 0..input_params.n_grid {
+        let n_x = input_params.n_x_min << i_grid;
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let dx = x[1] - x[0];
+        let mu = match input_params.hold {
+            Hold::Mu => input_params.mu,
+            Hold::Dt => input_params.mu * (dx_min / dx).powi(2),
+        };
+        let dt = mu * dx * dx;
+        let step_max = ((input_params.alpha_t_end / dt).round() as usize).max(1);
+
+        let mut solver = create_solver(
+            &input_params.scheme,
+            x.mapv(|x| 1.0 - x.abs()),
+            step_max,
+            mu,
+        )?;
+        while !solver.is_completed() {
+            solver.integrate()?;
+        }
+
+        let u_exact = exact::triangle(&x, step_max as f64 * dt, 1000);
+        dxs.push(dx);
+        dts.push(dt);
+        errors.push(ErrorNorms::new(solver.borrow_u(), &u_exact, dx));
+    }
+
+    // output the convergence table
+    writeln!(
+        outputstream,
+        "n_x dx dt error_l2 order_x_l2 order_t_l2 error_linf order_x_linf order_t_linf"
+    )?;
+    for i_grid in 0..input_params.n_grid {
+        write!(
+            outputstream,
+            "{} {:.10} {:.10e}",
+            input_params.n_x_min << i_grid,
+            dxs[i_grid],
+            dts[i_grid]
+        )?;
+        for norm in [|e: &ErrorNorms| e.l2, |e: &ErrorNorms| e.linf] {
+            write!(outputstream, " {:.10e}", norm(&errors[i_grid]))?;
+            if i_grid == 0 {
+                write!(outputstream, " - -")?;
+                continue;
+            }
+
+            let error_ratio = (norm(&errors[i_grid - 1]) / norm(&errors[i_grid])).ln();
+            write!(
+                outputstream,
+                " {:.10}",
+                error_ratio / (dxs[i_grid - 1] / dxs[i_grid]).ln()
+            )?;
+            match input_params.hold {
+                Hold::Mu => write!(
+                    outputstream,
+                    " {:.10}",
+                    error_ratio / (dts[i_grid - 1] / dts[i_grid]).ln()
+                )?,
+                Hold::Dt => write!(outputstream, " -")?,
+            }
+        }
+        writeln!(outputstream)?;
+    }
+
+    // output the orders of the finest pair
+    let i_grid = input_params.n_grid - 1;
+    let order_x =
+        (errors[i_grid - 1].linf / errors[i_grid].linf).ln() / (dxs[i_grid - 1] / dxs[i_grid]).ln();
+    println!(
+        "{:?}: observed order {:.3} in space (Linf) between n_x = {} and {}.",
+        input_params.scheme,
+        order_x,
+        input_params.n_x_min << (i_grid - 1),
+        n_x_max
+    );
+
+    Ok(())
+}
+
+/// Create the solver of the `scheme` with the Dirichlet boundary condition.
+fn create_solver(
+    scheme: &Scheme,
+    u: Array1<f64>,
+    step_max: usize,
+    mu: f64,
+) -> Result<Box<dyn Solver<Field = Array1<f64>>>, &'static str> {
+    Ok(match *scheme {
+        Scheme::Ftcs => Box::new(
+            FtcsSolver::builder()
+                .u(u)
+                .step_max(step_max)
+                .mu(mu)
+                .build()?,
+        ),
+        Scheme::Beamwarming { lambda } => Box::new(
+            BeamwarmingSolver::builder()
+                .u(u)
+                .step_max(step_max)
+                .mu(mu)
+                .lambda(lambda)
+                .build()?,
+        ),
+    })
+}
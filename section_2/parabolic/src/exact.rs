@@ -0,0 +1,80 @@
+//! Module for the exact solutions of the diffusion equation and the errors of the numerical solutions.
+
+use ndarray::prelude::*;
+use numerics::norms;
+use std::f64::consts::PI;
+
+/// Return the exact solution at `x` for the triangle initial condition of the book,
+/// ```math
+/// u(x, 0) = 1 - |x| (x \in [-1, 1]), u(\pm 1, t) = 0.
+/// ```
+///
+/// The solution is given by the Fourier series
+/// ```math
+/// u(x, t) = \sum_{k = 1, 3, 5, \cdots} \frac{8}{k^2 \pi^2} \cos \frac{k \pi x}{2} \exp(-\frac{k^2 \pi^2}{4} \alpha t),
+/// ```
+/// which is truncated after the first `n_terms` odd `k`.
+/// The truncation error is about `0.4 / n_terms` at `t = 0` and decays rapidly with `t`.
+///
+/// # Arguments
+/// * `x` - points to evaluate the solution at.
+/// * `alpha_t` - diffusion coefficient * time, which is `step * mu * dx^2` for the solvers of this crate.
+/// * `n_terms` - number of terms of the series.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use parabolic::exact;
+///
+/// let x = array![-1.0, 0.0, 0.5, 1.0];
+/// let u = exact::triangle(&x, 0.0, 100000);
+///
+/// let is_u_triangle = (&u - array![0.0, 1.0, 0.5, 0.0]).iter().all(|u| u.abs() < 1e-5);
+/// assert!(is_u_triangle);
+/// ```
+pub fn triangle(x: &Array1<f64>, alpha_t: f64, n_terms: usize) -> Array1<f64> {
+    x.mapv(|x| {
+        (0..n_terms)
+            .map(|n| {
+                let k = (2 * n + 1) as f64;
+                8.0 / (k * k * PI * PI)
+                    * (0.5 * k * PI * x).cos()
+                    * (-0.25 * k * k * PI * PI * alpha_t).exp()
+            })
+            .sum()
+    })
+}
+
+/// Norms of the error of the numerical solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorNorms {
+    /// Discrete L2 norm, `\sqrt{\sum_j (u_j - u_{exact,j})^2 \Delta x}`.
+    pub l2: f64,
+    /// Maximum norm, `\max_j |u_j - u_{exact,j}|`.
+    pub linf: f64,
+}
+
+impl ErrorNorms {
+    /// Calculate the norms of `u - u_exact` on the grid with the spacing `dx`.
+    ///
+    /// # Examples
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use parabolic::exact::ErrorNorms;
+    ///
+    /// let u = array![0.0, 0.5, 1.0];
+    /// let u_exact = array![0.0, 0.2, 0.6];
+    /// let error_norms = ErrorNorms::new(&u, &u_exact, 0.5);
+    ///
+    /// assert!((error_norms.l2 - 0.125f64.sqrt()).abs() < 1e-10);
+    /// assert!((error_norms.linf - 0.4).abs() < 1e-10);
+    /// ```
+    pub fn new(u: &Array1<f64>, u_exact: &Array1<f64>, dx: f64) -> Self {
+        let error = u - u_exact;
+
+        Self {
+            l2: norms::l2(&error, dx),
+            linf: norms::linf(&error),
+        }
+    }
+}
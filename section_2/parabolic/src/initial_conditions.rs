@@ -0,0 +1,188 @@
+//! Module to create the initial profiles of `u`.
+//!
+//! The profiles are given by functions of `x` as [Profile], which can be named in the input files,
+//! and together with the profile read from a file as [InitialCondition].
+//!
+//! # Examples
+//! ```
+//! use parabolic::initial_conditions::Profile;
+//! use ndarray::prelude::*;
+//!
+//! let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 4 + 1);
+//!
+//! assert_eq!(Profile::default().create(&x), array![0.0, 0.5, 1.0, 0.5, 0.0]);
+//! assert_eq!(
+//!     Profile::TopHat { x_0: 0.0, half_width: 0.75 }.create(&x),
+//!     array![0.0, 1.0, 1.0, 1.0, 0.0]
+//! );
+//! ```
+
+use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+pub use numerics::initial_condition::{read, AnalyticProfile, FileProfile};
+
+/// Initial profile of `u`, which is the triangle of [crate::exact::triangle] by default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "profile", rename_all = "snake_case")]
+pub enum Profile {
+    /// Step function,
+    /// ```math
+    /// u(x) = 1 (x < x_0), u(x) = 0 (x \ge x_0).
+    /// ```
+    Step {
+        /// Position of the step.
+        x_0: f64,
+    },
+    /// Sine wave,
+    /// ```math
+    /// u(x) = \sin \frac{2 \pi x}{\lambda}.
+    /// ```
+    Sine {
+        /// Wavelength `\lambda`.
+        wavelength: f64,
+    },
+    /// Gaussian pulse,
+    /// ```math
+    /// u(x) = \exp (- \frac{(x - x_0)^2}{w^2}).
+    /// ```
+    Gaussian {
+        /// Center of the pulse.
+        x_0: f64,
+        /// Width `w` of the pulse.
+        width: f64,
+    },
+    /// Triangle pulse,
+    /// ```math
+    /// u(x) = \max (1 - \frac{|x - x_0|}{w}, 0).
+    /// ```
+    Triangle {
+        /// Center of the pulse.
+        x_0: f64,
+        /// Half width `w` of the pulse.
+        half_width: f64,
+    },
+    /// Top hat pulse,
+    /// ```math
+    /// u(x) = 1 (|x - x_0| < w), u(x) = 0 (|x - x_0| \ge w).
+    /// ```
+    TopHat {
+        /// Center of the pulse.
+        x_0: f64,
+        /// Half width `w` of the pulse.
+        half_width: f64,
+    },
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile::Triangle {
+            x_0: 0.0,
+            half_width: 1.0,
+        }
+    }
+}
+
+impl Profile {
+    /// Return the value of the profile at `x`.
+    pub fn value(&self, x: f64) -> f64 {
+        match *self {
+            Profile::Step { x_0 } => {
+                if x < x_0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Profile::Sine { wavelength } => (2.0 * PI * x / wavelength).sin(),
+            Profile::Gaussian { x_0, width } => (-((x - x_0) / width).powi(2)).exp(),
+            Profile::Triangle { x_0, half_width } => (1.0 - (x - x_0).abs() / half_width).max(0.0),
+            Profile::TopHat { x_0, half_width } => {
+                if (x - x_0).abs() < half_width {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// Return the profile evaluated on the coordinate array `x`.
+    pub fn create(&self, x: &Array1<f64>) -> Array1<f64> {
+        x.map(|x| self.value(*x))
+    }
+
+    /// Validate the parameters of the profile.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match *self {
+            Profile::Sine { wavelength } if wavelength <= 0.0 => Err("wavelength must be positive"),
+            Profile::Gaussian { width, .. } if width <= 0.0 => Err("width must be positive"),
+            Profile::Triangle { half_width, .. } | Profile::TopHat { half_width, .. }
+                if half_width <= 0.0 =>
+            {
+                Err("half_width must be positive")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Initial condition of `u`, given as one of [Profile] or read from a file, e.g.
+/// ```yaml
+/// profile: from_file
+/// path: path/to/u_init.dat
+/// ```
+/// which is the default [Profile] if omitted. See [numerics::initial_condition] for the file format.
+pub type InitialCondition = numerics::initial_condition::InitialCondition<Profile>;
+
+impl AnalyticProfile for Profile {
+    fn create(&self, x: &Array1<f64>) -> Array1<f64> {
+        Profile::create(self, x)
+    }
+
+    fn validate(&self) -> Result<(), &'static str> {
+        Profile::validate(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn fn_initial_condition_can_be_named_in_input() {
+        // read the initial conditions from the YAML input
+        let cases = [
+            (
+                "profile: top_hat\nx_0: 0.0\nhalf_width: 0.2",
+                InitialCondition::Profile(Profile::TopHat {
+                    x_0: 0.0,
+                    half_width: 0.2,
+                }),
+            ),
+            (
+                "profile: from_file\npath: u_init.dat",
+                InitialCondition::File(FileProfile::FromFile {
+                    path: PathBuf::from("u_init.dat"),
+                }),
+            ),
+        ];
+
+        // check if the initial conditions are correctly read and validated
+        for (input, initial_condition) in cases {
+            let initial_condition_read: InitialCondition = serde_yaml::from_str(input).unwrap();
+            assert_eq!(initial_condition_read, initial_condition);
+            assert!(initial_condition_read.validate().is_ok());
+        }
+        assert_eq!(
+            Profile::TopHat {
+                x_0: 0.0,
+                half_width: 0.0
+            }
+            .validate(),
+            Err("half_width must be positive")
+        );
+    }
+}
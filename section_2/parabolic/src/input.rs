@@ -1,10 +1,14 @@
 //! Module to read the input parameters.
 
+pub mod examples;
+
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde_derive::{Deserialize, Serialize};
 use std::error::Error;
 use std::io::prelude::*;
 
+pub use cli::validation::{ValidationError, Violation};
+
 /// Read the input parameters from the input.
 ///
 /// The format of the input should be defined by a struct that implements [InputParams], [Serialize] and [DeserializeOwned].
@@ -12,7 +16,7 @@ use std::io::prelude::*;
 /// # Examples
 /// ```
 /// use serde_derive::{Deserialize, Serialize};
-/// use parabolic::input::{self, InputParams};
+/// use parabolic::input::{self, InputParams, ValidationError};
 ///
 /// #[derive(Debug, Serialize, Deserialize, PartialEq)]
 /// pub struct SpecificInputParams {
@@ -22,12 +26,11 @@ use std::io::prelude::*;
 /// }
 ///
 /// impl InputParams for SpecificInputParams {
-///     fn validate_params(&self) -> Result<(), &'static str> {
-///         if self.b <= 0.0 {
-///             return Err("b must be positive");
-///         }
+///     fn validate_params(&self) -> Result<(), ValidationError> {
+///         let mut errors = ValidationError::new();
+///         errors.check(self.b > 0.0, "b", self.b, "must be positive");
 ///
-///         Ok(())
+///         errors.into_result()
 ///     }
 /// }
 ///
@@ -43,8 +46,9 @@ use std::io::prelude::*;
 /// ```
 ///
 /// # Errors
-/// Returns an error if the input is invalid.
-pub fn read_input_params<T: InputParams + Serialize + DeserializeOwned>(
+/// Returns an error if the input cannot be read or deserialized,
+/// or a [ValidationError] listing all the invalid parameters.
+pub fn read_input_params<T: InputParams + serde::Serialize + DeserializeOwned>(
     inputstream: &mut impl Read,
 ) -> Result<T, Box<dyn Error>> {
     let mut contents = String::new();
@@ -57,6 +61,129 @@ pub fn read_input_params<T: InputParams + Serialize + DeserializeOwned>(
 
 /// Input parameters.
 pub trait InputParams {
-    /// Validate the input parameters.
-    fn validate_params(&self) -> Result<(), &'static str>;
+    /// Validate the input parameters, collecting all the violations.
+    fn validate_params(&self) -> Result<(), ValidationError>;
+}
+
+/// Return the default number of cycles between outputs, `1`, which outputs every cycle.
+pub fn default_ncycle_out() -> usize {
+    1
+}
+
+/// Return the default weighting factor of the implicit schemes, `0.5`, which is the trapezoidal rule.
+pub fn default_lambda() -> f64 {
+    0.5
+}
+
+/// Return the default growth of `\max |u|` regarded as the divergence, `10`.
+pub fn default_growth_limit() -> f64 {
+    10.0
+}
+
+/// Return the default list of the weighting factors, which consists of [default_lambda].
+pub fn default_lambda_list() -> Vec<f64> {
+    vec![default_lambda()]
+}
+
+/// Return the default ratio of the time step to the stability limit, `0.9`.
+pub fn default_safety_factor() -> f64 {
+    0.9
+}
+
+/// Resolution in space and time, given either by the nondimensional parameters or by the physical quantities.
+///
+/// In the input file, either `n_x` and `mu` or `alpha`, `dx` and `dt` are given at the top level, e.g.
+/// ```yaml
+/// alpha: 1.0
+/// dx: 0.02
+/// dt: 0.0002
+/// ```
+///
+/// # Examples
+/// ```
+/// use parabolic::input::Resolution;
+///
+/// let resolution: Resolution = serde_yaml::from_str("alpha: 2.0\ndx: 0.5\ndt: 0.05").unwrap();
+/// resolution.validate(2.0).unwrap();
+///
+/// assert_eq!(resolution.n_x(2.0), 4);
+/// assert!((resolution.mu() - 0.4).abs() < 1e-10);
+/// assert_eq!(resolution.dt(), Some(0.05));
+///
+/// let resolution: Resolution = serde_yaml::from_str("alpha: -1.0\ndx: 0.3\ndt: 0.05").unwrap();
+/// let fields: Vec<_> = resolution.validate(2.0).unwrap_err().violations().iter().map(|v| v.field.clone()).collect();
+/// assert_eq!(fields, ["alpha", "dx"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Resolution {
+    /// Nondimensional parameters, where the time is counted by the steps.
+    Nondimensional {
+        /// Number of cells.
+        n_x: usize,
+        /// diffusion coefficient * dt / dx^2.
+        mu: f64,
+    },
+    /// Physical quantities, from which the number of cells and `mu` are computed.
+    Physical {
+        /// Diffusion coefficient.
+        alpha: f64,
+        /// Grid spacing.
+        dx: f64,
+        /// Time step.
+        dt: f64,
+    },
+}
+
+impl Resolution {
+    /// Validate the resolution for the domain of the length `length`, collecting all the violations.
+    pub fn validate(&self, length: f64) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        match *self {
+            Resolution::Nondimensional { n_x, mu } => {
+                errors.check(n_x > 0, "n_x", n_x, "must be positive");
+                errors.check(mu > 0.0, "mu", mu, "must be positive");
+            }
+            Resolution::Physical { alpha, dx, dt } => {
+                errors.check(alpha > 0.0, "alpha", alpha, "must be positive");
+                errors.check(dx > 0.0, "dx", dx, "must be positive");
+                errors.check(dt > 0.0, "dt", dt, "must be positive");
+                if dx > 0.0 {
+                    let n_x = length / dx;
+                    errors.check(
+                        n_x >= 0.5 && (n_x - n_x.round()).abs() <= 1e-9 * n_x,
+                        "dx",
+                        dx,
+                        "must divide the length of the domain",
+                    );
+                }
+            }
+        }
+
+        errors.into_result()
+    }
+
+    /// Return the number of cells in the domain of the length `length`.
+    pub fn n_x(&self, length: f64) -> usize {
+        match *self {
+            Resolution::Nondimensional { n_x, .. } => n_x,
+            Resolution::Physical { dx, .. } => (length / dx).round() as usize,
+        }
+    }
+
+    /// Return `mu`, that is, diffusion coefficient * dt / dx^2.
+    pub fn mu(&self) -> f64 {
+        match *self {
+            Resolution::Nondimensional { mu, .. } => mu,
+            Resolution::Physical { alpha, dx, dt } => alpha * dt / (dx * dx),
+        }
+    }
+
+    /// Return the time step, or `None` for the nondimensional parameters.
+    pub fn dt(&self) -> Option<f64> {
+        match *self {
+            Resolution::Nondimensional { .. } => None,
+            Resolution::Physical { dt, .. } => Some(dt),
+        }
+    }
 }
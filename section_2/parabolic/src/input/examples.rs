@@ -0,0 +1,588 @@
+//! Input parameters of the examples.
+//!
+//! Each example reads its input file into one of the structs here, which fills the omitted parameters with the defaults
+//! of [super], so that the minimal input files are tested against the same types as the examples use.
+
+use super::{InputParams, Resolution, ValidationError};
+use crate::initial_conditions::{InitialCondition, Profile};
+use crate::solver::advectiondiffusion_solver::Convection;
+use crate::solver::boundary::BoundaryCondition;
+use crate::solver::Diffusion;
+use serde_derive::{Deserialize, Serialize};
+
+/// Input parameters of the `compare_diffusion_eq_solvers` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecCompareInputParams {
+    /// Number of cells.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// List of diffusion coefficient * dt / dx^2.
+    pub mu: Vec<f64>,
+    /// List of weighting factors of the Beam-Warming method, `[0.5]` if omitted.
+    #[serde(default = "super::default_lambda_list")]
+    pub lambda: Vec<f64>,
+    /// Growth of max |u| regarded as blow-up, `10` if omitted.
+    #[serde(default = "super::default_growth_limit")]
+    pub growth_limit: f64,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecCompareInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(
+            !self.mu.is_empty() && self.mu.iter().all(|mu| *mu > 0.0),
+            "mu",
+            &self.mu,
+            "must be a non-empty list of positive values",
+        );
+        errors.check(
+            self.lambda
+                .iter()
+                .all(|lambda| (0.0..=1.0).contains(lambda)),
+            "lambda",
+            &self.lambda,
+            "must be between 0 and 1",
+        );
+        errors.check(
+            self.growth_limit > 1.0,
+            "growth_limit",
+            self.growth_limit,
+            "must be greater than 1",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_advection_diffusion_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecAdvectiondiffusionInputParams {
+    /// Difference operator for the advection term.
+    pub convection: Convection,
+    /// Time integration of the diffusion term.
+    pub diffusion: Diffusion,
+    /// Number of cells.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// advection velocity * dt / dx.
+    pub n_cfl: f64,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+    /// Initial condition, the triangle `u(x, 0) = 1 - |x|` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecAdvectiondiffusionInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.mu > 0.0, "mu", self.mu, "must be positive");
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_beamwarming_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecBeamwarmingInputParams {
+    /// Number of cells and `mu`, or the physical quantities.
+    #[serde(flatten)]
+    pub resolution: Resolution,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// Weighting factor in differencing scheme, `0.5` if omitted.
+    #[serde(default = "super::default_lambda")]
+    pub lambda: f64,
+    /// Initial condition, the triangle `u(x, 0) = 1 - |x|` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Boundary conditions at `x_-` and `x_+`, Dirichlet at both ends if omitted.
+    #[serde(default)]
+    pub boundary_condition: (BoundaryCondition, BoundaryCondition),
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+    /// Output the norms of the error against [crate::exact::triangle], `false` if omitted,
+    /// which requires the default initial and boundary conditions.
+    #[serde(default)]
+    pub output_errors: bool,
+}
+
+impl InputParams for ExecBeamwarmingInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.merge(self.resolution.validate(2.0));
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(
+            self.lambda >= 0.0 && self.lambda <= 1.0,
+            "lambda",
+            self.lambda,
+            "must be between 0 and 1",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+        errors.check_result(
+            BoundaryCondition::validate_pair(
+                &self.boundary_condition,
+                self.resolution.n_x(2.0) + 1,
+            ),
+            "boundary_condition",
+            self.boundary_condition,
+        );
+        errors.check(
+            !self.output_errors
+                || (self.initial_condition == InitialCondition::default()
+                    && self.boundary_condition == Default::default()),
+            "output_errors",
+            self.output_errors,
+            "must be false unless the initial and boundary conditions are those of the exact solution",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_cranknicolson_2d_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecCranknicolson2dInputParams {
+    /// Number of cells in each direction.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecCranknicolson2dInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.mu > 0.0, "mu", self.mu, "must be positive");
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_cranknicolson_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecCranknicolsonInputParams {
+    /// Number of cells and `mu`, or the physical quantities.
+    #[serde(flatten)]
+    pub resolution: Resolution,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// Initial condition, the triangle `u(x, 0) = 1 - |x|` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecCranknicolsonInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.merge(self.resolution.validate(2.0));
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_ftcs_2d_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecFtcs2dInputParams {
+    /// Number of cells in each direction.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecFtcs2dInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.mu > 0.0, "mu", self.mu, "must be positive");
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_ftcs_adaptive_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecFtcsAdaptiveInputParams {
+    /// Number of cells.
+    pub n_x: usize,
+    /// Time to end the calculation.
+    pub t_max: f64,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// Diffusion coefficient at `x = 0` and `t = 0`.
+    pub alpha: f64,
+    /// Growth rate of the diffusion coefficient in time.
+    pub alpha_rate: f64,
+    /// Ratio of dt to the stability limit, `0.9` if omitted.
+    #[serde(default = "super::default_safety_factor")]
+    pub safety_factor: f64,
+    /// Initial condition, the triangle `u(x, 0) = 1 - |x|` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+impl InputParams for ExecFtcsAdaptiveInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(self.t_max > 0.0, "t_max", self.t_max, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.alpha > 0.0, "alpha", self.alpha, "must be positive");
+        errors.check(
+            self.alpha_rate >= 0.0,
+            "alpha_rate",
+            self.alpha_rate,
+            "must not be negative",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_diffusion_eq_by_ftcs_method` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecFtcsInputParams {
+    /// Number of cells and `mu`, or the physical quantities.
+    #[serde(flatten)]
+    pub resolution: Resolution,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// Initial condition, the triangle `u(x, 0) = 1 - |x|` if omitted.
+    #[serde(default)]
+    pub initial_condition: InitialCondition,
+    /// Boundary conditions at `x_-` and `x_+`, Dirichlet at both ends if omitted.
+    #[serde(default)]
+    pub boundary_condition: (BoundaryCondition, BoundaryCondition),
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+    /// Output the norms of the error against [crate::exact::triangle], `false` if omitted,
+    /// which requires the default initial and boundary conditions.
+    #[serde(default)]
+    pub output_errors: bool,
+}
+
+impl InputParams for ExecFtcsInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.merge(self.resolution.validate(2.0));
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+        errors.check_result(
+            BoundaryCondition::validate_pair(
+                &self.boundary_condition,
+                self.resolution.n_x(2.0) + 1,
+            ),
+            "boundary_condition",
+            self.boundary_condition,
+        );
+        errors.check(
+            !self.output_errors
+                || (self.initial_condition == InitialCondition::default()
+                    && self.boundary_condition == Default::default()),
+            "output_errors",
+            self.output_errors,
+            "must be false unless the initial and boundary conditions are those of the exact solution",
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Input parameters of the `solve_porous_medium_eq` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecPorousmediumInputParams {
+    /// Time integration of the diffusion term.
+    pub diffusion: Diffusion,
+    /// Number of cells.
+    pub n_x: usize,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// dt / dx^2.
+    pub mu: f64,
+    /// Exponent of `u` in the diffusion coefficient.
+    pub m: f64,
+    /// Initial condition, the top hat `u(x, 0) = 1 (|x| < 0.2)` if omitted.
+    #[serde(default = "default_initial_condition")]
+    pub initial_condition: InitialCondition,
+    /// Number of cycles between outputs, `1` if omitted.
+    #[serde(default = "super::default_ncycle_out")]
+    pub ncycle_out: usize,
+}
+
+fn default_initial_condition() -> InitialCondition {
+    InitialCondition::Profile(Profile::TopHat {
+        x_0: 0.0,
+        half_width: 0.2,
+    })
+}
+
+impl InputParams for ExecPorousmediumInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(self.n_x > 0, "n_x", self.n_x, "must be positive");
+        errors.check(
+            self.step_max > 0,
+            "step_max",
+            self.step_max,
+            "must be positive",
+        );
+        errors.check(self.mu > 0.0, "mu", self.mu, "must be positive");
+        errors.check(self.m >= 0.0, "m", self.m, "must not be negative");
+        errors.check(
+            self.ncycle_out > 0,
+            "ncycle_out",
+            self.ncycle_out,
+            "must be positive",
+        );
+        errors.check_result(
+            self.initial_condition.validate(),
+            "initial_condition",
+            &self.initial_condition,
+        );
+
+        errors.into_result()
+    }
+}
+
+/// Scheme for the diffusion equation, with the parameters specific to it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+pub enum Scheme {
+    /// See [crate::solver::ftcs_solver].
+    Ftcs,
+    /// See [crate::solver::beamwarming_solver].
+    Beamwarming {
+        /// Weighting factor of the implicit term.
+        lambda: f64,
+    },
+}
+
+/// Quantity held fixed while refining the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hold {
+    /// `mu`, so that the time step is quartered for each grid.
+    Mu,
+    /// Time step, so that `mu` is quadrupled for each grid.
+    Dt,
+}
+
+/// Input parameters of the `study_convergence_order_of_diffusion_eq_solvers` example.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecStudyConvergenceOrderInputParams {
+    /// Scheme to be studied, with the parameters specific to it.
+    #[serde(flatten)]
+    pub scheme: Scheme,
+    /// Number of cells of the coarsest grid.
+    pub n_x_min: usize,
+    /// Number of grids, where the number of cells is doubled for each grid.
+    pub n_grid: usize,
+    /// Quantity held fixed.
+    pub hold: Hold,
+    /// Diffusion coefficient * dt / dx^2 on the finest grid.
+    pub mu: f64,
+    /// Diffusion coefficient * time at the end of the calculation.
+    pub alpha_t_end: f64,
+}
+
+impl InputParams for ExecStudyConvergenceOrderInputParams {
+    fn validate_params(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.check(
+            self.n_x_min > 0,
+            "n_x_min",
+            self.n_x_min,
+            "must be positive",
+        );
+        errors.check(
+            self.n_grid >= 2,
+            "n_grid",
+            self.n_grid,
+            "must be at least 2",
+        );
+        errors.check(self.mu > 0.0, "mu", self.mu, "must be positive");
+        errors.check(
+            self.alpha_t_end > 0.0,
+            "alpha_t_end",
+            self.alpha_t_end,
+            "must be positive",
+        );
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_input_params;
+
+    #[test]
+    fn fn_minimal_input_params_are_completed_by_defaults() {
+        let input_params: ExecBeamwarmingInputParams =
+            read_input_params(&mut "n_x: 20\nmu: 0.5\nstep_max: 10".as_bytes()).unwrap();
+
+        assert_eq!(
+            input_params.resolution,
+            Resolution::Nondimensional { n_x: 20, mu: 0.5 }
+        );
+        assert_eq!(
+            input_params.initial_condition,
+            InitialCondition::Profile(Profile::Triangle {
+                x_0: 0.0,
+                half_width: 1.0
+            })
+        );
+        assert_eq!(
+            input_params.boundary_condition,
+            (BoundaryCondition::Dirichlet, BoundaryCondition::Dirichlet)
+        );
+        assert_eq!(input_params.lambda, 0.5);
+        assert_eq!(input_params.ncycle_out, 1);
+        assert!(!input_params.output_errors);
+    }
+}
@@ -6,9 +6,15 @@
 //! All of the schemes mentioned in the book are implemented in this crate.
 //!
 //! Using this crate, you can actually compute and check the stability of each scheme.
+//!
+//! # Features
+//! - `parallel`: Solve the trinomial equations along the rows or the columns of [solver::cranknicolson_2d_solver]
+//!   in parallel by rayon.
 
+pub mod exact;
+pub mod initial_conditions;
 pub mod input;
-pub mod math;
+pub mod mms;
 pub mod output;
 pub mod solver;
 
@@ -18,19 +24,86 @@ use std::error::Error;
 use std::io::Write;
 
 /// Run the solver and output the results.
+///
+/// If the solver tracks the time, that is, [Solver::get_t] and [Solver::get_dt] return `Some`,
+/// the time is output as well. See [output::output_with_time] for the output format.
 pub fn run(
     x: &Array1<f64>,
-    solver: &mut impl Solver,
+    solver: &mut impl Solver<Field = Array1<f64>>,
     outputstream: &mut impl Write,
     ncycle_out: usize,
 ) -> Result<(), Box<dyn Error>> {
     // calculate and output
-    output::output(outputstream, 0, x, solver.borrow_u())?;
+    output_step(outputstream, x, solver)?;
     while !solver.is_completed() {
         solver.integrate()?;
 
-        if solver.get_step() % ncycle_out == 0 {
-            output::output(outputstream, solver.get_step(), x, solver.borrow_u())?;
+        if solver.get_step().is_multiple_of(ncycle_out) {
+            output_step(outputstream, x, solver)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Output the current step of the solver, with the time if the solver tracks it.
+fn output_step(
+    outputstream: &mut impl Write,
+    x: &Array1<f64>,
+    solver: &impl Solver<Field = Array1<f64>>,
+) -> Result<(), Box<dyn Error>> {
+    let step = solver.get_step();
+    match (solver.get_t(), solver.get_dt()) {
+        (Some(t), Some(dt)) => {
+            output::output_with_time(outputstream, step, t, dt, x, solver.borrow_u())?
+        }
+        _ => output::output(outputstream, step, x, solver.borrow_u())?,
+    }
+
+    Ok(())
+}
+
+/// Run the solver and output the results together with the norms of the error against the exact solution.
+///
+/// `u_exact` returns the exact solution at the given step. See [output::output_with_errors] for the output format.
+pub fn run_with_exact(
+    x: &Array1<f64>,
+    solver: &mut impl Solver<Field = Array1<f64>>,
+    outputstream: &mut impl Write,
+    ncycle_out: usize,
+    u_exact: impl Fn(usize) -> Array1<f64>,
+) -> Result<(), Box<dyn Error>> {
+    // calculate and output
+    output::output_with_errors(outputstream, 0, x, solver.borrow_u(), &u_exact(0))?;
+    while !solver.is_completed() {
+        solver.integrate()?;
+
+        if solver.get_step().is_multiple_of(ncycle_out) {
+            let step = solver.get_step();
+            output::output_with_errors(outputstream, step, x, solver.borrow_u(), &u_exact(step))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the solver in 2D and output the results.
+///
+/// See [output::output_2d] for the output format.
+pub fn run_2d(
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    solver: &mut impl Solver<Field = Array2<f64>>,
+    outputstream: &mut impl Write,
+    ncycle_out: usize,
+) -> Result<(), Box<dyn Error>> {
+    // calculate and output
+    output::output_2d(outputstream, 0, x, y, solver.borrow_u())?;
+    while !solver.is_completed() {
+        solver.integrate()?;
+
+        if solver.get_step().is_multiple_of(ncycle_out) {
+            output::output_2d(outputstream, solver.get_step(), x, y, solver.borrow_u())?;
         }
     }
 
@@ -56,6 +129,10 @@ mod tests {
             u: x.map(|x| if *x < 0.0 { *x + 1.0 } else { -(*x) + 1.0 }),
             step_max: 500,
             mu: 0.5,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
         };
         let mut solver = FtcsSolver::new(new_params).unwrap();
 
@@ -128,6 +205,10 @@ mod tests {
             step_max: 500,
             mu: 0.5,
             lambda: 0.5,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
         };
         let mut solver = BeamwarmingSolver::new(new_params).unwrap();
 
@@ -185,4 +266,48 @@ mod tests {
 ";
         assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
     }
+
+    #[test]
+    fn fn_run_with_exact_works_with_ftcs_solver() {
+        // setup output stream
+        let mut outputstream: Vec<u8> = Vec::new();
+
+        // setup coordinates
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 20 + 1);
+        let dx = 0.1;
+
+        // initialize the solver
+        let mu = 0.4;
+        let new_params = FtcsSolverNewParams {
+            u: x.map(|x| 1.0 - x.abs()),
+            step_max: 100,
+            mu,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
+        };
+        let mut solver = FtcsSolver::new(new_params).unwrap();
+
+        // execute run_with_exact()
+        run_with_exact(&x, &mut solver, &mut outputstream, 50, |step| {
+            exact::triangle(&x, step as f64 * mu * dx * dx, 1000)
+        })
+        .unwrap();
+
+        // check if the error norms are output for every block and small
+        let output = String::from_utf8(outputstream).unwrap();
+        let error_norms: Vec<(f64, f64)> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("# l2 "))
+            .map(|line| {
+                let (l2, linf) = line.split_once(" linf ").unwrap();
+                (l2.parse().unwrap(), linf.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(error_norms.len(), 3);
+        assert!(error_norms
+            .iter()
+            .all(|(l2, linf)| *l2 < 1e-3 && *linf < 1e-3));
+    }
 }
@@ -1,3 +0,0 @@
-//! Math module.
-
-pub mod trinomial_eq;
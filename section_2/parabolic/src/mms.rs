@@ -0,0 +1,176 @@
+//! Module for the verification of the solvers by the method of manufactured solutions.
+//!
+//! An arbitrary function `u(x, t)` is made the exact solution of the diffusion equation
+//! by adding the source term
+//! ```math
+//! S(x, t) = \frac{\partial u}{\partial t} - \alpha \frac{\partial^2 u}{\partial x^2},
+//! ```
+//! so that the error of a solver can be measured for any smooth solution.
+//! The derivatives are evaluated by the central differences with the steps small enough
+//! for the errors of the derivatives to be about `10^{-8}` of `u`, which is negligible against the errors of the schemes.
+
+use crate::exact::ErrorNorms;
+use crate::solver::{Solver, Source};
+use ndarray::prelude::*;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+/// Manufactured solution `u(x, t)` of the diffusion equation with the diffusion coefficient `\alpha`.
+///
+/// The solvers of this crate keep the end points fixed at the initial values for the Dirichlet boundary condition,
+/// so `u` should not vary in time at the end points when they are used with the default boundary condition.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use parabolic::mms::ManufacturedSolution;
+/// use parabolic::solver::ftcs_solver::FtcsSolver;
+///
+/// // u = (1 - x^2)(1 + t) is reproduced exactly by the FTCS method with the source 1 - x^2 + 2 alpha (1 + t)
+/// let alpha = 0.5;
+/// let solution = ManufacturedSolution::new(|x, t| (1.0 - x * x) * (1.0 + t), alpha);
+///
+/// let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 21);
+/// let (mu, dx) = (0.25, 0.1);
+/// let dt = mu * dx * dx / alpha;
+/// let mut solver = FtcsSolver::builder()
+///     .u(solution.evaluate(&x, 0.0))
+///     .step_max(100)
+///     .mu(mu)
+///     .source(solution.create_source(&x, dt))
+///     .build()
+///     .unwrap();
+/// let error_norms = solution.run(&x, dt, &mut solver).unwrap();
+///
+/// assert!(error_norms.linf < 1e-6);
+/// ```
+#[derive(Clone)]
+pub struct ManufacturedSolution {
+    u: Rc<dyn Fn(f64, f64) -> f64>,
+    alpha: f64,
+}
+
+impl ManufacturedSolution {
+    /// Create a new `ManufacturedSolution` instance from the closure returning `u(x, t)`.
+    pub fn new(u: impl Fn(f64, f64) -> f64 + 'static, alpha: f64) -> Self {
+        Self {
+            u: Rc::new(u),
+            alpha,
+        }
+    }
+
+    /// Return `u(x_j, t)` at all points `x_j`.
+    pub fn evaluate(&self, x: &Array1<f64>, t: f64) -> Array1<f64> {
+        x.mapv(|x| (self.u)(x, t))
+    }
+
+    /// Return the source term `S(x_j, t)` at all points `x_j`.
+    pub fn source_term(&self, x: &Array1<f64>, t: f64) -> Array1<f64> {
+        x.mapv(|x| Self::source_term_at(&*self.u, self.alpha, x, t))
+    }
+
+    /// Create the [Source] returning `\Delta t S(x_j, n \Delta t)` for the time step `n`.
+    pub fn create_source(&self, x: &Array1<f64>, dt: f64) -> Source {
+        let solution = self.clone();
+        let x = x.clone();
+
+        Source::new(move |n| dt * solution.source_term(&x, n as f64 * dt))
+    }
+
+    /// Run the solver until the completion and return the norms of the error against `u` at the end.
+    ///
+    /// # Arguments
+    /// * `x` - coordinates of the points.
+    /// * `dt` - time step of the solver.
+    /// * `solver` - solver created with the initial values `u(x_j, 0)` and the source by [ManufacturedSolution::create_source].
+    ///
+    /// # Errors
+    /// Returns an error if the integration fails.
+    pub fn run(
+        &self,
+        x: &Array1<f64>,
+        dt: f64,
+        solver: &mut impl Solver<Field = Array1<f64>>,
+    ) -> Result<ErrorNorms, Box<dyn Error>> {
+        while !solver.is_completed() {
+            solver.integrate()?;
+        }
+
+        let u_exact = self.evaluate(x, solver.get_step() as f64 * dt);
+        let dx = if x.len() > 1 { x[1] - x[0] } else { 0.0 };
+
+        Ok(ErrorNorms::new(solver.borrow_u(), &u_exact, dx))
+    }
+
+    fn source_term_at(u: &dyn Fn(f64, f64) -> f64, alpha: f64, x: f64, t: f64) -> f64 {
+        // steps balancing the truncation and rounding errors of the central differences
+        let h_t = f64::EPSILON.cbrt() * t.abs().max(1.0);
+        let h_x = f64::EPSILON.sqrt().sqrt() * x.abs().max(1.0);
+
+        let u_t = (u(x, t + h_t) - u(x, t - h_t)) / (2.0 * h_t);
+        let u_xx = (u(x + h_x, t) - 2.0 * u(x, t) + u(x - h_x, t)) / (h_x * h_x);
+
+        u_t - alpha * u_xx
+    }
+}
+
+impl fmt::Debug for ManufacturedSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManufacturedSolution")
+            .field("alpha", &self.alpha)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::cranknicolson_solver::{CranknicolsonSolver, CranknicolsonSolverNewParams};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn fn_source_term_works() {
+        // setup u = sin(pi x) exp(-t), whose source term is (alpha pi^2 - 1) sin(pi x) exp(-t)
+        let alpha = 0.5;
+        let solution = ManufacturedSolution::new(|x, t| (PI * x).sin() * (-t).exp(), alpha);
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, 11);
+
+        // check if the source term is derived correctly
+        let t: f64 = 0.3;
+        let source_exact = x.mapv(|x| (alpha * PI * PI - 1.0) * (PI * x).sin() * (-t).exp());
+        let is_source_correct = (solution.source_term(&x, t) - source_exact)
+            .iter()
+            .all(|s| s.abs() < 1e-6);
+        assert!(is_source_correct);
+    }
+
+    #[test]
+    fn fn_run_converges_in_second_order() {
+        // setup u = sin(pi x) exp(-t) and run the Crank-Nicolson method with the fixed mu on two grids
+        let alpha = 0.5;
+        let solution = ManufacturedSolution::new(|x, t| (PI * x).sin() * (-t).exp(), alpha);
+        let errors: Vec<f64> = [20, 40]
+            .iter()
+            .map(|&n_x| {
+                let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+                let dx = x[1] - x[0];
+                let mu = 0.5;
+                let dt = mu * dx * dx / alpha;
+                let mut solver = CranknicolsonSolver::new(CranknicolsonSolverNewParams {
+                    u: solution.evaluate(&x, 0.0),
+                    step_max: (0.5 / dt).round() as usize,
+                    mu,
+                    source: Some(solution.create_source(&x, dt)),
+                    dt: Some(dt),
+                })
+                .unwrap();
+                solution.run(&x, dt, &mut solver).unwrap().linf
+            })
+            .collect();
+
+        // check if the error is reduced to about a quarter
+        let order = (errors[0] / errors[1]).log2();
+        assert!((order - 2.0).abs() < 0.2, "order = {}", order);
+    }
+}
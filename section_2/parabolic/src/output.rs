@@ -1,5 +1,6 @@
 //! Module to output the results.
 
+use crate::exact::ErrorNorms;
 use ndarray::prelude::*;
 use std::io::{Error, Write};
 
@@ -67,3 +68,192 @@ pub fn output(
 
     Ok(())
 }
+
+/// Output the results together with the time `t` and the time step `dt` of the last step.
+///
+/// # Output Format
+/// The output is formatted as in [output], except that each block ends with the comment line of the time,
+/// ```text
+/// step_0 x_0 u_0
+/// ...
+/// step_0 x_n u_n
+/// # t t_0 dt dt_0
+///
+///
+/// ...
+/// ```
+/// which is ignored when plotting by gnuplot.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use parabolic::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let step = 3;
+/// let x = array![-1.0, 0.0, 1.0];
+/// let u = array![0.0, 1.0, 2.0];
+/// output::output_with_time(&mut outputstream, step, 0.75, 0.25, &x, &u).unwrap();
+///
+/// let output_expected = "\
+/// 3 -1.0000000000 0.0000000000
+/// 3 0.0000000000 1.0000000000
+/// 3 1.0000000000 2.0000000000
+/// ## t 7.5000000000e-1 dt 2.5000000000e-1
+///
+///
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_with_time(
+    outputstream: &mut impl Write,
+    step: usize,
+    t: f64,
+    dt: f64,
+    x: &Array1<f64>,
+    u: &Array1<f64>,
+) -> Result<(), Error> {
+    for (x, u) in x.iter().zip(u.iter()) {
+        writeln!(outputstream, "{} {:.10} {:.10}", step, x, u)?;
+    }
+    writeln!(outputstream, "# t {:.10e} dt {:.10e}", t, dt)?;
+    writeln!(outputstream)?;
+    writeln!(outputstream)?;
+
+    Ok(())
+}
+
+/// Output the results together with the norms of the error against the exact solution `u_exact`.
+///
+/// # Output Format
+/// The output is formatted as in [output], except that each block ends with the comment line of the error norms,
+/// ```text
+/// step_0 x_0 u_0
+/// ...
+/// step_0 x_n u_n
+/// # l2 l2_error linf linf_error
+///
+///
+/// ...
+/// ```
+/// which is ignored when plotting by gnuplot. See [ErrorNorms] for the norms.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use parabolic::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let step = 3;
+/// let x = array![-1.0, 0.0, 1.0];
+/// let u = array![0.0, 1.0, 2.0];
+/// let u_exact = array![0.0, 1.0, 1.5];
+/// output::output_with_errors(&mut outputstream, step, &x, &u, &u_exact).unwrap();
+///
+/// let output_expected = "\
+/// 3 -1.0000000000 0.0000000000
+/// 3 0.0000000000 1.0000000000
+/// 3 1.0000000000 2.0000000000
+/// ## l2 5.0000000000e-1 linf 5.0000000000e-1
+///
+///
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_with_errors(
+    outputstream: &mut impl Write,
+    step: usize,
+    x: &Array1<f64>,
+    u: &Array1<f64>,
+    u_exact: &Array1<f64>,
+) -> Result<(), Error> {
+    let dx = if x.len() > 1 { x[1] - x[0] } else { 0.0 };
+    let error_norms = ErrorNorms::new(u, u_exact, dx);
+
+    for (x, u) in x.iter().zip(u.iter()) {
+        writeln!(outputstream, "{} {:.10} {:.10}", step, x, u)?;
+    }
+    writeln!(
+        outputstream,
+        "# l2 {:.10e} linf {:.10e}",
+        error_norms.l2, error_norms.linf
+    )?;
+    writeln!(outputstream)?;
+    writeln!(outputstream)?;
+
+    Ok(())
+}
+
+/// Output the results in 2D.
+///
+/// # Output Format
+/// The output is formatted as follows, where `u_{i,j}` is at `(x_i, y_j)`:
+/// ```text
+/// step_0 x_0 y_0 u_{0,0}
+/// step_0 x_0 y_1 u_{0,1}
+/// ...
+/// step_0 x_0 y_m u_{0,m}
+///
+/// step_0 x_1 y_0 u_{1,0}
+/// ...
+/// step_0 x_n y_m u_{n,m}
+///
+///
+/// step_1 x_0 y_0 u_{0,0}
+/// ...
+/// ```
+/// That is, the rows of the same `x` are separated by a blank line and the steps are separated by two blank lines,
+/// which can be plotted by `splot` of gnuplot.
+///
+/// # Examples
+/// ```
+/// use ndarray::prelude::*;
+/// use parabolic::output;
+///
+/// let mut outputstream: Vec<u8> = Vec::new();
+/// let step = 3;
+/// let x = array![-1.0, 1.0];
+/// let y = array![0.0, 1.0];
+/// let u = array![[0.0, 1.0], [2.0, 3.0]];
+/// output::output_2d(&mut outputstream, step, &x, &y, &u).unwrap();
+///
+/// let output_expected = "\
+/// 3 -1.0000000000 0.0000000000 0.0000000000
+/// 3 -1.0000000000 1.0000000000 1.0000000000
+///
+/// 3 1.0000000000 0.0000000000 2.0000000000
+/// 3 1.0000000000 1.0000000000 3.0000000000
+///
+///
+/// ";
+/// assert_eq!(String::from_utf8(outputstream).unwrap(), output_expected);
+/// ```
+///
+/// # Errors
+/// Returns an error if the output fails.
+pub fn output_2d(
+    outputstream: &mut impl Write,
+    step: usize,
+    x: &Array1<f64>,
+    y: &Array1<f64>,
+    u: &Array2<f64>,
+) -> Result<(), Error> {
+    for (i, x) in x.iter().enumerate() {
+        if i > 0 {
+            writeln!(outputstream)?;
+        }
+        for (y, u) in y.iter().zip(u.row(i).iter()) {
+            writeln!(outputstream, "{} {:.10} {:.10} {:.10}", step, x, y, u)?;
+        }
+    }
+    writeln!(outputstream)?;
+    writeln!(outputstream)?;
+
+    Ok(())
+}
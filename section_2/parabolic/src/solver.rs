@@ -1,25 +1,126 @@
 //! Solvers for the diffusion equation.
 
+pub mod advectiondiffusion_solver;
 pub mod beamwarming_solver;
+pub mod boundary;
+pub mod cranknicolson_2d_solver;
+pub mod cranknicolson_solver;
+pub mod ftcs_2d_solver;
+pub mod ftcs_adaptive_solver;
 pub mod ftcs_solver;
+pub mod porousmedium_solver;
+pub mod spectral_solver;
 
 use ndarray::prelude::*;
+use serde_derive::{Deserialize, Serialize};
 use std::error::Error;
+use std::fmt;
 
 /// Solver for the diffusion equation.
 pub trait Solver {
+    /// Type of `u`, e.g. `Array1<f64>` in 1D and `Array2<f64>` in 2D.
+    type Field;
+
     /// Return a reference to the current `u`.
-    fn borrow_u(&self) -> &Array1<f64>;
+    fn borrow_u(&self) -> &Self::Field;
     /// Return the current `step`.
     fn get_step(&self) -> usize;
     /// Return `true` if the calculation has been completed.
     fn is_completed(&self) -> bool;
+    /// Return the current time `t`, or `None` if the solver only counts the steps.
+    fn get_t(&self) -> Option<f64> {
+        None
+    }
+    /// Return the time step `dt` of the last step, or `None` if the solver only counts the steps.
+    fn get_dt(&self) -> Option<f64> {
+        None
+    }
     /// Integrate the transport equation by one step.
     fn integrate(&mut self) -> Result<(), Box<dyn Error>>;
 }
 
+/// Time integration of the diffusion term.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Diffusion {
+    /// Forward Euler as in the FTCS method.
+    Explicit,
+    /// Backward Euler, solving the trinomial equation every step.
+    /// For the nonlinear diffusion, the coefficients are lagged, that is, evaluated with `u^n`.
+    Implicit,
+}
+
 /// Parameters for creating a new solver.
 pub trait NewParams {
     /// Validate the parameters for creating a new solver.
     fn validate_new_params(&self) -> Result<(), &'static str>;
 }
+
+/// Source term `S(x, t)` of the inhomogeneous diffusion equation
+/// ```math
+/// \frac{\partial u}{\partial t} = \alpha \frac{\partial^2 u}{\partial x^2} + S(x, t).
+/// ```
+///
+/// The closure receives the time step `n` and must return `\Delta t S(x_j, t_n)` at all points `x_j`,
+/// that is, the source already multiplied by the time step.
+pub struct Source(Box<dyn Fn(usize) -> Array1<f64>>);
+
+impl Source {
+    /// Create a new `Source` instance from the closure returning `\Delta t S(x_j, t_n)` for the time step `n`.
+    pub fn new(source: impl Fn(usize) -> Array1<f64> + 'static) -> Self {
+        Self(Box::new(source))
+    }
+
+    /// Return `\Delta t S(x_j, t_n)` at the time step `n`, checking that it has `len` points.
+    pub fn evaluate(&self, n: usize, len: usize) -> Result<Array1<f64>, Box<dyn Error>> {
+        let source = (self.0)(n);
+        if source.len() != len {
+            return Err(Box::<dyn Error>::from(
+                "source must have the same length as u",
+            ));
+        }
+
+        Ok(source)
+    }
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Source")
+    }
+}
+
+/// Diffusion coefficient `\alpha(x, t)` varying in space and time.
+///
+/// The closure receives the time `t` and must return `\alpha(x_j, t)` at all points `x_j`.
+pub struct DiffusionCoefficient(Box<dyn Fn(f64) -> Array1<f64>>);
+
+impl DiffusionCoefficient {
+    /// Create a new `DiffusionCoefficient` instance from the closure returning `\alpha(x_j, t)` for the time `t`.
+    pub fn new(alpha: impl Fn(f64) -> Array1<f64> + 'static) -> Self {
+        Self(Box::new(alpha))
+    }
+
+    /// Return `\alpha(x_j, t)` at the time `t`, checking that it has `len` positive values.
+    pub fn evaluate(&self, t: f64, len: usize) -> Result<Array1<f64>, Box<dyn Error>> {
+        let alpha = (self.0)(t);
+        if alpha.len() != len {
+            return Err(Box::<dyn Error>::from(
+                "diffusion coefficient must have the same length as u",
+            ));
+        }
+        if alpha.iter().any(|alpha| alpha.is_nan() || *alpha <= 0.0) {
+            return Err(Box::<dyn Error>::from(
+                "diffusion coefficient must be positive",
+            ));
+        }
+
+        Ok(alpha)
+    }
+}
+
+impl fmt::Debug for DiffusionCoefficient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DiffusionCoefficient")
+    }
+}
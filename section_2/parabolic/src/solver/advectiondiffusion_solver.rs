@@ -0,0 +1,288 @@
+//! Solver for the advection-diffusion equation.
+//!
+//! # Formulation
+//! The advection-diffusion equation is given by
+//! ```math
+//! \frac{\partial u}{\partial t} + c \frac{\partial u}{\partial x} = \alpha \frac{\partial^2 u}{\partial x^2},
+//! ```
+//! which is the transport equation of the linear_hyperbolic crate with the diffusion term of this crate.
+//!
+//! # Scheme
+//! The advection term is always explicit and the diffusion term is either explicit or implicit,
+//! ```math
+//! u_j^{n+1} - \lambda \mu (u_{j+1}^{n+1} - 2 u_j^{n+1} + u_{j-1}^{n+1}) =
+//! u_j^n - \nu D u_j^n + (1 - \lambda) \mu (u_{j+1}^n - 2 u_j^n + u_{j-1}^n),
+//! ```
+//! where `\nu = c \frac{\Delta t}{\Delta x}`, `\mu = \frac{\alpha \Delta t}{\Delta x^2}`,
+//! `\lambda = 0` for [Diffusion::Explicit] and `\lambda = 1` for [Diffusion::Implicit].
+//! `D` is the difference operator selected by [Convection].
+//!
+//! The explicit upwind scheme is stable for `|\nu| + 2 \mu \le 1`.
+//!
+//! # Cell Péclet Number
+//! The ratio of the advection to the diffusion on the grid is measured by the cell Péclet number,
+//! ```math
+//! P = \frac{|c| \Delta x}{\alpha} = \frac{|\nu|}{\mu}.
+//! ```
+//! With the central difference, the steady solution oscillates for `P > 2`,
+//! so such parameters are rejected in the validation.
+//! The upwind difference doesn't oscillate for any `P`, at the cost of the numerical diffusion `|\nu| \Delta x / 2`.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed as
+//! ```math
+//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! ```
+
+use super::{Diffusion, NewParams, Solver};
+use ndarray::prelude::*;
+use numerics::trinomial_eq::TrinomialEq;
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+
+/// Difference operator for the advection term.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Convection {
+    /// Upwind difference, `D u_j = u_j - u_{j-1}` for `c > 0` and `D u_j = u_{j+1} - u_j` for `c < 0`.
+    Upwind,
+    /// Central difference, `D u_j = (u_{j+1} - u_{j-1}) / 2`.
+    Central,
+}
+
+/// Solver for the advection-diffusion equation.
+#[derive(Debug)]
+pub struct AdvectiondiffusionSolver {
+    u: Array1<f64>,
+    step_max: usize,
+    n_cfl: f64,
+    mu: f64,
+    convection: Convection,
+    trinomial_eq: Option<TrinomialEq>,
+    step: usize,
+    completed: bool,
+}
+
+impl AdvectiondiffusionSolver {
+    /// Create a new `AdvectiondiffusionSolver` instance.
+    pub fn new(new_params: AdvectiondiffusionSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let trinomial_eq = match new_params.diffusion {
+            Diffusion::Explicit => None,
+            Diffusion::Implicit => Some(TrinomialEq::new(Self::create_mat_coef(
+                new_params.u.len(),
+                new_params.mu,
+            ))?),
+        };
+
+        Ok(Self {
+            u: new_params.u,
+            step_max: new_params.step_max,
+            n_cfl: new_params.n_cfl,
+            mu: new_params.mu,
+            convection: new_params.convection,
+            trinomial_eq,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    /// Return the cell Péclet number `|\nu| / \mu`.
+    pub fn peclet_number(&self) -> f64 {
+        self.n_cfl.abs() / self.mu
+    }
+
+    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
+        // the diffusion term is included in the explicit part only for the explicit diffusion
+        let mu_explicit = match self.trinomial_eq {
+            Some(_) => 0.0,
+            None => self.mu,
+        };
+
+        let mut u_next: Array1<f64> = (0..self.u.len())
+            .map(|i| {
+                if i == 0 || i == self.u.len() - 1 {
+                    return self.u[i];
+                }
+
+                let difference = match self.convection {
+                    Convection::Upwind if self.n_cfl >= 0.0 => self.u[i] - self.u[i - 1],
+                    Convection::Upwind => self.u[i + 1] - self.u[i],
+                    Convection::Central => 0.5 * (self.u[i + 1] - self.u[i - 1]),
+                };
+
+                self.u[i] - self.n_cfl * difference
+                    + mu_explicit * (self.u[i + 1] - 2.0 * self.u[i] + self.u[i - 1])
+            })
+            .collect();
+
+        if let Some(trinomial_eq) = &self.trinomial_eq {
+            trinomial_eq.solve(&mut u_next)?;
+        }
+
+        Ok(u_next)
+    }
+
+    fn create_mat_coef(n_dim: usize, mu: f64) -> Array1<(f64, f64, f64)> {
+        let mut mat_coef = Array::from_elem(n_dim, (-mu, 1.0 + 2.0 * mu, -mu));
+        mat_coef[0] = (0.0, 1.0, 0.0);
+        mat_coef[n_dim - 1] = (0.0, 1.0, 0.0);
+
+        mat_coef
+    }
+}
+
+impl Solver for AdvectiondiffusionSolver {
+    type Field = Array1<f64>;
+
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next()?;
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `AdvectiondiffusionSolver` instance.
+pub struct AdvectiondiffusionSolverNewParams {
+    /// Initial values of `u`.
+    pub u: Array1<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// CFL number, advection velocity * dt / dx.
+    pub n_cfl: f64,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+    /// Difference operator for the advection term.
+    pub convection: Convection,
+    /// Time integration of the diffusion term.
+    pub diffusion: Diffusion,
+}
+
+impl NewParams for AdvectiondiffusionSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if !self.n_cfl.is_finite() {
+            return Err("n_cfl must be finite");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+        if self.convection == Convection::Central && self.n_cfl.abs() > 2.0 * self.mu {
+            return Err(
+                "cell Peclet number |n_cfl| / mu must not exceed 2 for the central convection",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_advectiondiffusion_integrate_works() {
+        // setup explicit upwind advectiondiffusion solver and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = AdvectiondiffusionSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            n_cfl: 0.5,
+            mu: 0.25,
+            convection: Convection::Upwind,
+            diffusion: Diffusion::Explicit,
+        };
+        let mut advectiondiffusion_solver = AdvectiondiffusionSolver::new(new_params).unwrap();
+        advectiondiffusion_solver.integrate().unwrap();
+
+        // check if u and step are correctly updated
+        let u_exact = array![0.0, 0.25, 0.5, 0.75, 0.0];
+        let is_u_correctly_updated = (advectiondiffusion_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(advectiondiffusion_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_advectiondiffusion_converges_to_discrete_steady_state() {
+        // setup implicit central advectiondiffusion solver with the cell Peclet number 1
+        // and run until the steady state
+        let new_params = AdvectiondiffusionSolverNewParams {
+            u: array![0.0, 0.0, 0.0, 0.0, 1.0],
+            step_max: 200,
+            n_cfl: 1.0,
+            mu: 1.0,
+            convection: Convection::Central,
+            diffusion: Diffusion::Implicit,
+        };
+        let mut advectiondiffusion_solver = AdvectiondiffusionSolver::new(new_params).unwrap();
+        while !advectiondiffusion_solver.is_completed() {
+            advectiondiffusion_solver.integrate().unwrap();
+        }
+
+        // check if u is the steady solution of the central difference, u_j = (r^j - 1) / (r^4 - 1)
+        // with r = (1 + P / 2) / (1 - P / 2) = 3
+        let u_exact = Array1::from_shape_fn(5, |j| (3f64.powi(j as i32) - 1.0) / 80.0);
+        let is_u_correctly_updated = (advectiondiffusion_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_advectiondiffusion_rejects_large_peclet_number_for_central() {
+        // setup central advectiondiffusion solver with the cell Peclet number 4
+        let new_params = AdvectiondiffusionSolverNewParams {
+            u: array![0.0, 0.5, 1.0, 0.5, 0.0],
+            step_max: 10,
+            n_cfl: 0.4,
+            mu: 0.1,
+            convection: Convection::Central,
+            diffusion: Diffusion::Explicit,
+        };
+
+        // check if the validation fails only for the central difference
+        assert!(AdvectiondiffusionSolver::new(new_params).is_err());
+        let new_params = AdvectiondiffusionSolverNewParams {
+            u: array![0.0, 0.5, 1.0, 0.5, 0.0],
+            step_max: 10,
+            n_cfl: 0.4,
+            mu: 0.1,
+            convection: Convection::Upwind,
+            diffusion: Diffusion::Explicit,
+        };
+        let advectiondiffusion_solver = AdvectiondiffusionSolver::new(new_params).unwrap();
+        assert!((advectiondiffusion_solver.peclet_number() - 4.0).abs() < 1e-10);
+    }
+}
@@ -8,15 +8,23 @@
 //! ```
 //! where `\mu = \frac{\alpha \Delta t}{\Delta x^2}` and `\lambda \in [0, 1]` is the weighting factor.
 //!
+//! If the source term `S(x, t)` is given, the same weighting is applied to it,
+//! that is, `\Delta t ((1 - \lambda) S(x_j, t_n) + \lambda S(x_j, t_{n+1}))` is added to the right-hand side.
+//!
 //! # Boundary Condition
-//! The boundary condition is fixed as
+//! The boundary condition at each end is given by [BoundaryCondition](super::boundary::BoundaryCondition).
+//! Unless the value is fixed, the ghost point is eliminated from the first and last rows of the trinomial equation,
+//! e.g.
 //! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! (1 + 2 \lambda \mu) u_0^{n+1} - 2 \lambda \mu u_1^{n+1} =
+//! (1 - 2 (1 - \lambda) \mu) u_0^n + 2 (1 - \lambda) \mu u_1^n - 2 \mu \Delta x g_{-}
 //! ```
+//! for the Neumann boundary condition.
 
-use super::{NewParams, Solver};
-use crate::math::trinomial_eq::TrinomialEq;
+use super::boundary::{BoundaryCondition, Edge};
+use super::{NewParams, Solver, Source};
 use ndarray::prelude::*;
+use numerics::trinomial_eq::TrinomialEq;
 use std::error::Error;
 
 /// Solver for the diffusion equation using the Beam-Warming method.
@@ -26,7 +34,12 @@ pub struct BeamwarmingSolver {
     step_max: usize,
     mu: f64,
     lambda: f64,
+    source: Option<Source>,
+    boundary_condition: (BoundaryCondition, BoundaryCondition),
     trinomial_eq: TrinomialEq,
+    u_next: Array1<f64>,
+    dx: f64,
+    dt: Option<f64>,
     step: usize,
     completed: bool,
 }
@@ -37,66 +50,155 @@ impl BeamwarmingSolver {
         new_params.validate_new_params()?;
 
         let u_len = new_params.u.len();
+        // dx is used only by the flux boundary conditions, for which it is given
+        let dx = new_params.dx.unwrap_or(1.0);
 
         Ok(Self {
             u: new_params.u,
             step_max: new_params.step_max,
             mu: new_params.mu,
             lambda: new_params.lambda,
+            source: new_params.source,
+            boundary_condition: new_params.boundary_condition,
             trinomial_eq: TrinomialEq::new(Self::create_mat_coef(
                 u_len,
                 new_params.mu,
                 new_params.lambda,
-            )),
+                new_params.boundary_condition,
+                dx,
+            ))?,
+            u_next: Array1::zeros(u_len),
+            dx,
+            dt: new_params.dt,
             step: 0,
             completed: false,
         })
     }
 
-    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
+    /// Return the upper limit of `mu` for the stability with the weighting factor `lambda`,
+    /// which is `1 / (2 (1 - 2 \lambda))` for `\lambda < 1/2` and infinite for `\lambda \ge 1/2`.
+    ///
+    /// The FTCS method is the case of `\lambda = 0`, whose limit is `1/2`.
+    ///
+    /// # Examples
+    /// ```
+    /// use parabolic::solver::beamwarming_solver::BeamwarmingSolver;
+    ///
+    /// assert_eq!(BeamwarmingSolver::mu_limit(0.0), 0.5);
+    /// assert_eq!(BeamwarmingSolver::mu_limit(0.25), 1.0);
+    /// assert_eq!(BeamwarmingSolver::mu_limit(0.5), f64::INFINITY);
+    /// ```
+    pub fn mu_limit(lambda: f64) -> f64 {
+        if lambda >= 0.5 {
+            f64::INFINITY
+        } else {
+            0.5 / (1.0 - 2.0 * lambda)
+        }
+    }
+
+    /// Create a new `BeamwarmingSolverBuilder` instance to build a `BeamwarmingSolver` instance.
+    pub fn builder() -> BeamwarmingSolverBuilder {
+        BeamwarmingSolverBuilder::default()
+    }
+
+    fn calculate_u_next(&mut self) -> Result<(), Box<dyn Error>> {
         let coef_lower_rhs = (1.0 - self.lambda) * self.mu;
         let coef_diag_rhs = 1.0 - 2.0 * (1.0 - self.lambda) * self.mu;
         let coef_upper_rhs = coef_lower_rhs;
 
-        let mut u_next: Array1<f64> = (0..self.u.len())
-            .map(|i| {
-                if i == 0 {
-                    return coef_diag_rhs * self.u[i] + coef_upper_rhs * self.u[i + 1];
-                }
-                if i == self.u.len() - 1 {
-                    return coef_lower_rhs * self.u[i - 1] + coef_diag_rhs * self.u[i];
+        // assemble the right-hand side
+        let u = &self.u;
+        let len = u.len();
+        azip!((
+            u_next in self.u_next.slice_mut(s![1..len - 1]),
+            &u_m in u.slice(s![..len - 2]),
+            &u_c in u.slice(s![1..len - 1]),
+            &u_p in u.slice(s![2..])
+        ) {
+            *u_next = coef_lower_rhs * u_m + coef_diag_rhs * u_c + coef_upper_rhs * u_p;
+        });
+        self.u_next[0] = match self.boundary_condition.0.edge(u[0], -1.0, self.dx) {
+            Edge::Fixed(_) => coef_diag_rhs * u[0] + coef_upper_rhs * u[1],
+            Edge::Ghost(alpha, beta) => {
+                (coef_diag_rhs + alpha * coef_lower_rhs) * u[0]
+                    + 2.0 * coef_upper_rhs * u[1]
+                    + self.mu * beta
+            }
+        };
+        self.u_next[len - 1] = match self.boundary_condition.1.edge(u[len - 1], 1.0, self.dx) {
+            Edge::Fixed(_) => coef_lower_rhs * u[len - 2] + coef_diag_rhs * u[len - 1],
+            Edge::Ghost(alpha, beta) => {
+                2.0 * coef_lower_rhs * u[len - 2]
+                    + (coef_diag_rhs + alpha * coef_upper_rhs) * u[len - 1]
+                    + self.mu * beta
+            }
+        };
+
+        if let Some(source) = &self.source {
+            let source_now = source.evaluate(self.step, len)?;
+            let source_next = source.evaluate(self.step + 1, len)?;
+            for i in 0..len {
+                if self.fixed_edge(i).is_none() {
+                    self.u_next[i] +=
+                        (1.0 - self.lambda) * source_now[i] + self.lambda * source_next[i];
                 }
+            }
+        }
 
-                coef_lower_rhs * self.u[i - 1]
-                    + coef_diag_rhs * self.u[i]
-                    + coef_upper_rhs * self.u[i + 1]
-            })
-            .collect();
+        // solve in place and write the fixed end points
+        self.trinomial_eq.solve(&mut self.u_next)?;
+        for i in [0, len - 1] {
+            if let Some(u_edge) = self.fixed_edge(i) {
+                self.u_next[i] = u_edge;
+            }
+        }
 
-        self.trinomial_eq.solve(&mut u_next)?;
+        Ok(())
+    }
 
-        Ok(u_next
-            .indexed_iter()
-            .map(|(i, v)| {
-                if i == 0 || i == u_next.len() - 1 {
-                    return self.u[i];
-                }
+    /// Return the fixed value if the `i`-th point is the end point fixed by the boundary condition.
+    fn fixed_edge(&self, i: usize) -> Option<f64> {
+        let edge = if i == 0 {
+            self.boundary_condition.0.edge(self.u[i], -1.0, self.dx)
+        } else if i == self.u.len() - 1 {
+            self.boundary_condition.1.edge(self.u[i], 1.0, self.dx)
+        } else {
+            return None;
+        };
 
-                *v
-            })
-            .collect())
+        match edge {
+            Edge::Fixed(u) => Some(u),
+            Edge::Ghost(..) => None,
+        }
     }
 
-    fn create_mat_coef(n_dim: usize, mu: f64, lambda: f64) -> Array1<(f64, f64, f64)> {
+    fn create_mat_coef(
+        n_dim: usize,
+        mu: f64,
+        lambda: f64,
+        boundary_condition: (BoundaryCondition, BoundaryCondition),
+        dx: f64,
+    ) -> Array1<(f64, f64, f64)> {
         let coef_lower = -lambda * mu;
         let coef_diag = 1.0 + 2.0 * lambda * mu;
         let coef_upper = coef_lower;
 
-        Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper))
+        // the ghost points don't depend on the current values, so `u_edge` is irrelevant here
+        let mut mat_coef = Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper));
+        if let Edge::Ghost(alpha, _) = boundary_condition.0.edge(0.0, -1.0, dx) {
+            mat_coef[0] = (0.0, coef_diag + alpha * coef_lower, 2.0 * coef_upper);
+        }
+        if let Edge::Ghost(alpha, _) = boundary_condition.1.edge(0.0, 1.0, dx) {
+            mat_coef[n_dim - 1] = (2.0 * coef_lower, coef_diag + alpha * coef_upper, 0.0);
+        }
+
+        mat_coef
     }
 }
 
 impl Solver for BeamwarmingSolver {
+    type Field = Array1<f64>;
+
     fn borrow_u(&self) -> &Array1<f64> {
         &self.u
     }
@@ -109,6 +211,14 @@ impl Solver for BeamwarmingSolver {
         self.completed
     }
 
+    fn get_t(&self) -> Option<f64> {
+        self.dt.map(|dt| self.step as f64 * dt)
+    }
+
+    fn get_dt(&self) -> Option<f64> {
+        self.dt
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -116,7 +226,8 @@ impl Solver for BeamwarmingSolver {
             ));
         }
 
-        self.u = self.calculate_u_next()?;
+        self.calculate_u_next()?;
+        std::mem::swap(&mut self.u, &mut self.u_next);
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -137,6 +248,14 @@ pub struct BeamwarmingSolverNewParams {
     pub mu: f64,
     /// Weighting factor in differencing scheme.
     pub lambda: f64,
+    /// Source term, or `None` for the homogeneous equation.
+    pub source: Option<Source>,
+    /// Boundary conditions at `x_-` and `x_+`.
+    pub boundary_condition: (BoundaryCondition, BoundaryCondition),
+    /// Grid spacing, which must be given for the flux boundary conditions, or `None` for the fixed values at both ends.
+    pub dx: Option<f64>,
+    /// Time step, or `None` to count only the steps.
+    pub dt: Option<f64>,
 }
 
 impl NewParams for BeamwarmingSolverNewParams {
@@ -150,14 +269,114 @@ impl NewParams for BeamwarmingSolverNewParams {
         if self.mu <= 0.0 {
             return Err("mu must be positive");
         }
+        if self.dx.is_some_and(|dx| dx <= 0.0) {
+            return Err("dx must be positive");
+        }
+        if self.dt.is_some_and(|dt| dt <= 0.0) {
+            return Err("dt must be positive");
+        }
         if self.lambda < 0.0 || self.lambda > 1.0 {
             return Err("lambda must be between 0 and 1");
         }
 
+        BoundaryCondition::validate_pair(&self.boundary_condition, self.u.len())?;
+        if self.dx.is_none()
+            && (self.boundary_condition.0.is_flux() || self.boundary_condition.1.is_flux())
+        {
+            return Err("dx must be given for the flux boundary condition");
+        }
+
         Ok(())
     }
 }
 
+/// Builder of `BeamwarmingSolver` instances.
+///
+/// `u`, `step_max` and `mu` must be set, while the others default to:
+/// - `lambda`: `0.5`, that is, the Crank-Nicolson method,
+/// - `source`: `None`, that is, the homogeneous equation,
+/// - `boundary_condition`: [BoundaryCondition::Dirichlet] at both ends,
+/// - `dx`: `None`, which must be set for the flux boundary conditions,
+/// - `dt`: `None`, that is, only the steps are counted.
+#[derive(Debug, Default)]
+pub struct BeamwarmingSolverBuilder {
+    u: Option<Array1<f64>>,
+    step_max: Option<usize>,
+    mu: Option<f64>,
+    lambda: Option<f64>,
+    source: Option<Source>,
+    boundary_condition: Option<(BoundaryCondition, BoundaryCondition)>,
+    dx: Option<f64>,
+    dt: Option<f64>,
+}
+
+impl BeamwarmingSolverBuilder {
+    /// Set the initial values of `u`.
+    pub fn u(mut self, u: Array1<f64>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set diffusion coefficient * dt / dx^2.
+    pub fn mu(mut self, mu: f64) -> Self {
+        self.mu = Some(mu);
+        self
+    }
+
+    /// Set the weighting factor of the implicit term.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        self.lambda = Some(lambda);
+        self
+    }
+
+    /// Set the source term.
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Set the boundary conditions at `x_-` and `x_+`.
+    pub fn boundary_condition(
+        mut self,
+        boundary_condition: (BoundaryCondition, BoundaryCondition),
+    ) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Set the grid spacing.
+    pub fn dx(mut self, dx: f64) -> Self {
+        self.dx = Some(dx);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Build a new `BeamwarmingSolver` instance, whose parameters are validated as in [BeamwarmingSolver::new].
+    pub fn build(self) -> Result<BeamwarmingSolver, &'static str> {
+        BeamwarmingSolver::new(BeamwarmingSolverNewParams {
+            u: self.u.ok_or("u must be set")?,
+            step_max: self.step_max.ok_or("step_max must be set")?,
+            mu: self.mu.ok_or("mu must be set")?,
+            lambda: self.lambda.unwrap_or(0.5),
+            source: self.source,
+            boundary_condition: self.boundary_condition.unwrap_or_default(),
+            dx: self.dx,
+            dt: self.dt,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,16 +390,183 @@ mod tests {
             step_max: 10000,
             mu: 0.5,
             lambda: 0.5,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
         };
         let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
         beamwarming_solver.integrate().unwrap();
 
         // check if u, t and step are correctly updated
         let u_exact = array![0.0, 0.46969696970, 0.6565656566, 0.46969696970, 0.0];
-        let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
+        let is_u_correctly_updated = (&beamwarming_solver.u - &u_exact)
             .iter()
             .all(|u| u.abs() < 1e-10);
         assert!(is_u_correctly_updated);
         assert_eq!(beamwarming_solver.step, 1);
     }
+
+    #[test]
+    fn fn_beamwarming_builder_works() {
+        // build beamwarming solver with the default lambda, source, boundary condition and dt and run integrate()
+        let mut beamwarming_solver = BeamwarmingSolver::builder()
+            .u(array![0.0, 0.5, 1.0, 0.5, 0.0])
+            .step_max(10000)
+            .mu(0.5)
+            .build()
+            .unwrap();
+        beamwarming_solver.integrate().unwrap();
+
+        // check if u is updated as with lambda = 0.5
+        let u_exact = array![0.0, 0.46969696970, 0.6565656566, 0.46969696970, 0.0];
+        let is_u_correctly_updated = (&beamwarming_solver.u - &u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+
+        // check if the missing and invalid parameters are rejected
+        let result = BeamwarmingSolver::builder()
+            .u(array![0.0, 1.0, 0.0])
+            .mu(0.5)
+            .build();
+        assert_eq!(result.unwrap_err(), "step_max must be set");
+        let result = BeamwarmingSolver::builder()
+            .u(array![0.0, 1.0, 0.0])
+            .step_max(10)
+            .mu(0.5)
+            .lambda(1.5)
+            .build();
+        assert_eq!(result.unwrap_err(), "lambda must be between 0 and 1");
+    }
+
+    #[test]
+    fn fn_beamwarming_integrate_works_with_source() {
+        // setup fully implicit beamwarming solver with the source dt * S(t_n) = 0.1 n and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = BeamwarmingSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.5,
+            lambda: 1.0,
+            source: Some(Source::new(|n| Array1::from_elem(5, 0.1 * n as f64))),
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
+        };
+        let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
+        beamwarming_solver.integrate().unwrap();
+
+        // check if only the source at the next step is added to the right-hand side
+        let u_exact = array![0.0, 7.0 / 13.0, 213.0 / 260.0, 7.0 / 13.0, 0.0];
+        let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_beamwarming_conserves_total_with_insulated_boundaries() {
+        // setup beamwarming solver with the insulated boundaries and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0, 0.0, 0.0];
+        let new_params = BeamwarmingSolverNewParams {
+            u: u_init,
+            step_max: 200,
+            mu: 2.0,
+            lambda: 0.5,
+            source: None,
+            boundary_condition: (
+                BoundaryCondition::Neumann(0.0),
+                BoundaryCondition::Neumann(0.0),
+            ),
+            dx: Some(1.0 / 6.0),
+            dt: None,
+        };
+        let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
+
+        // check if the total by the trapezoidal rule is conserved every step
+        let total = |u: &Array1<f64>| u.sum() - 0.5 * (u[0] + u[u.len() - 1]);
+        while !beamwarming_solver.is_completed() {
+            beamwarming_solver.integrate().unwrap();
+            assert!((total(&beamwarming_solver.u) - 2.0).abs() < 1e-10);
+        }
+
+        // check if u converges to the uniform mean
+        let is_u_uniform = beamwarming_solver
+            .u
+            .iter()
+            .all(|u| (u - 1.0 / 3.0).abs() < 1e-8);
+        assert!(is_u_uniform);
+    }
+
+    #[test]
+    fn fn_beamwarming_converges_to_steady_state_with_robin() {
+        // setup fully implicit beamwarming solver on [0, 1] with u - 0.1 u_x = 0 at x_- and u_x = 10 / 11 at x_+
+        // and run until the steady state
+        let new_params = BeamwarmingSolverNewParams {
+            u: Array1::zeros(11),
+            step_max: 200,
+            mu: 5.0,
+            lambda: 1.0,
+            source: None,
+            boundary_condition: (
+                BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: -0.1,
+                    g: 0.0,
+                },
+                BoundaryCondition::Neumann(10.0 / 11.0),
+            ),
+            dx: Some(0.1),
+            dt: None,
+        };
+        let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
+        while !beamwarming_solver.is_completed() {
+            beamwarming_solver.integrate().unwrap();
+        }
+
+        // check if u is the linear profile u_j = (1 + j) / 11 satisfying both boundary conditions
+        let u_exact = Array1::from_shape_fn(11, |j| (1.0 + j as f64) / 11.0);
+        let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_beamwarming_steady_slope_with_prescribed_flux_is_independent_of_resolution() {
+        for n_x in [10, 20] {
+            // setup fully implicit beamwarming solver on [0, 1] of n_x cells
+            // with u - 0.5 u_x = 0 at x_- and the flux u_x = 2 at x_+ and run until the steady state
+            let dx = 1.0 / n_x as f64;
+            let new_params = BeamwarmingSolverNewParams {
+                u: Array1::ones(n_x + 1),
+                step_max: 200,
+                mu: 50.0,
+                lambda: 1.0,
+                source: None,
+                boundary_condition: (
+                    BoundaryCondition::Robin {
+                        a: 1.0,
+                        b: -0.5,
+                        g: 0.0,
+                    },
+                    BoundaryCondition::Neumann(2.0),
+                ),
+                dx: Some(dx),
+                dt: None,
+            };
+            let mut beamwarming_solver = BeamwarmingSolver::new(new_params).unwrap();
+            while !beamwarming_solver.is_completed() {
+                beamwarming_solver.integrate().unwrap();
+            }
+
+            // check if u is the linear profile u = 1 + 2 x with the prescribed slope at both resolutions
+            let u_exact = Array1::from_shape_fn(n_x + 1, |j| 1.0 + 2.0 * j as f64 * dx);
+            let is_u_correctly_updated = (beamwarming_solver.u - u_exact)
+                .iter()
+                .all(|u| u.abs() < 1e-8);
+            assert!(is_u_correctly_updated);
+        }
+    }
 }
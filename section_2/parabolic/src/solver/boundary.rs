@@ -0,0 +1,118 @@
+//! Boundary conditions for the solvers.
+//!
+//! A [BoundaryCondition] is given for each end of the domain, as the pair for `x_-` and `x_+`,
+//! which can be given in the input files, e.g.
+//! ```yaml
+//! boundary_condition: [dirichlet, !robin { a: 1.0, b: 1.0, g: 0.0 }]
+//! ```
+
+use serde_derive::{Deserialize, Serialize};
+
+/// Boundary condition at one end of the domain.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryCondition {
+    /// Dirichlet boundary condition, where the value is fixed as
+    /// ```math
+    /// u(x_{\pm}, t) = u(x_{\pm}, 0).
+    /// ```
+    #[default]
+    Dirichlet,
+    /// Neumann boundary condition, where the flux is prescribed as
+    /// ```math
+    /// \frac{\partial u}{\partial x}(x_{\pm}, t) = g_{\pm},
+    /// ```
+    /// with `g_{\pm} = 0` for the insulated boundary.
+    ///
+    /// The end point is updated by the scheme with the ghost point given by the central difference,
+    /// `u_{-1} = u_1 - 2 \Delta x g_{-}` and `u_{N+1} = u_{N-1} + 2 \Delta x g_{+}`.
+    Neumann(f64),
+    /// Robin boundary condition, where the linear combination of the value and the flux is prescribed as
+    /// ```math
+    /// a u(x_{\pm}, t) + b \frac{\partial u}{\partial x}(x_{\pm}, t) = g.
+    /// ```
+    ///
+    /// The ghost point is given by the central difference as for the Neumann boundary condition,
+    /// e.g. `u_{-1} = u_1 + 2 \Delta x (a u_0 - g) / b`.
+    /// For `b = 0`, the value is fixed as `u(x_{\pm}, t) = g / a`.
+    Robin {
+        /// Coefficient of `u`.
+        a: f64,
+        /// Coefficient of `u_x`.
+        b: f64,
+        /// Right-hand side.
+        g: f64,
+    },
+}
+
+/// Treatment of an end point derived from its [BoundaryCondition].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Edge {
+    /// The end point is fixed to the value.
+    Fixed(f64),
+    /// The end point is updated by the scheme with the ghost point `u_{ghost} = u_{inner} + \alpha u_{edge} + \beta`,
+    /// given as `(\alpha, \beta)`.
+    Ghost(f64, f64),
+}
+
+impl BoundaryCondition {
+    /// Validate the pair of boundary conditions at `x_-` and `x_+` for `len` points.
+    pub fn validate_pair(pair: &(Self, Self), len: usize) -> Result<(), &'static str> {
+        for bc in [pair.0, pair.1] {
+            if let Self::Robin { a, b, .. } = bc {
+                if a == 0.0 && b == 0.0 {
+                    return Err("a and b of the Robin boundary condition must not be both zero");
+                }
+            }
+            if bc.is_flux() && len < 2 {
+                return Err("u must have at least 2 points for the flux boundary condition");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return whether the end point is updated with the ghost point, which requires the grid spacing.
+    pub(crate) fn is_flux(&self) -> bool {
+        matches!(self.edge(0.0, 1.0, 1.0), Edge::Ghost(..))
+    }
+
+    /// Return the treatment of the end point whose current value is `u_edge`,
+    /// where `outward` is `-1` at `x_-` and `1` at `x_+` and `dx` is the grid spacing.
+    pub(crate) fn edge(&self, u_edge: f64, outward: f64, dx: f64) -> Edge {
+        let outward = outward * dx;
+        match *self {
+            Self::Dirichlet => Edge::Fixed(u_edge),
+            Self::Neumann(g) => Edge::Ghost(0.0, 2.0 * outward * g),
+            Self::Robin { a, b: 0.0, g } => Edge::Fixed(g / a),
+            Self::Robin { a, b, g } => Edge::Ghost(-2.0 * outward * a / b, 2.0 * outward * g / b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_boundary_condition_can_be_given_in_input() {
+        // read the pair of the boundary conditions from the YAML input
+        let input = "[!neumann 0.5, !robin { a: 1.0, b: 0.0, g: 2.0 }]";
+        let pair: (BoundaryCondition, BoundaryCondition) = serde_yaml::from_str(input).unwrap();
+
+        // check if the boundary conditions are correctly read and validated
+        assert_eq!(
+            pair,
+            (
+                BoundaryCondition::Neumann(0.5),
+                BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: 0.0,
+                    g: 2.0
+                }
+            )
+        );
+        assert!(BoundaryCondition::validate_pair(&pair, 3).is_ok());
+        assert!(BoundaryCondition::validate_pair(&pair, 1).is_err());
+    }
+}
@@ -0,0 +1,250 @@
+//! Solver for the two-dimensional diffusion equation using the Crank-Nicolson method with the ADI factorization.
+//!
+//! # Scheme
+//! The Crank-Nicolson method in 2D,
+//! ```math
+//! (1 - \frac{\mu}{2} \delta_x^2 - \frac{\mu}{2} \delta_y^2) u^{n+1} = (1 + \frac{\mu}{2} \delta_x^2 + \frac{\mu}{2} \delta_y^2) u^n,
+//! ```
+//! where `\delta_x^2 u_{i,j} = u_{i+1,j} - 2 u_{i,j} + u_{i-1,j}` and `\mu = \frac{\alpha \Delta t}{\Delta x^2}`,
+//! requires the solution of the pentadiagonal system.
+//! The ADI (Alternating Direction Implicit) method of Peaceman and Rachford factorizes it into the two half steps,
+//! ```math
+//! (1 - \frac{\mu}{2} \delta_x^2) u^* = (1 + \frac{\mu}{2} \delta_y^2) u^n,
+//! ```
+//! ```math
+//! (1 - \frac{\mu}{2} \delta_y^2) u^{n+1} = (1 + \frac{\mu}{2} \delta_x^2) u^*,
+//! ```
+//! each of which is the set of the trinomial equations along the rows or the columns.
+//! The factorization differs from the original method by `O(\Delta t^3)`,
+//! so the method remains second-order accurate in both time and space, and unconditionally stable.
+//! For the 2D diffusion equation, it is equivalent to the Douglas-Gunn method.
+//!
+//! The grid spacing is the same in both directions, `\Delta x = \Delta y`.
+//! The coefficient matrices of both directions are LU-decomposed once at the construction,
+//! and all the lanes of each half step are solved at once by [TrinomialEq::solve_lanes],
+//! in parallel with the `parallel` feature.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed on all four edges as
+//! ```math
+//! u(x_{\pm}, y, t) = u(x_{\pm}, y, 0), u(x, y_{\pm}, t) = u(x, y_{\pm}, 0),
+//! ```
+//! which is also imposed on the intermediate values `u^*`, as the boundary values don't change in time.
+
+use super::{NewParams, Solver};
+use ndarray::prelude::*;
+use ndarray::Slice;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+
+/// Solver for the two-dimensional diffusion equation using the Crank-Nicolson method with the ADI factorization.
+#[derive(Debug)]
+pub struct Cranknicolson2dSolver {
+    u: Array2<f64>,
+    step_max: usize,
+    mu: f64,
+    trinomial_eq_x: TrinomialEq,
+    trinomial_eq_y: TrinomialEq,
+    step: usize,
+    completed: bool,
+}
+
+impl Cranknicolson2dSolver {
+    /// Create a new `Cranknicolson2dSolver` instance.
+    pub fn new(new_params: Cranknicolson2dSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let (n_i, n_j) = new_params.u.dim();
+
+        Ok(Self {
+            u: new_params.u,
+            step_max: new_params.step_max,
+            mu: new_params.mu,
+            trinomial_eq_x: TrinomialEq::new(Self::create_mat_coef(n_i, new_params.mu))?,
+            trinomial_eq_y: TrinomialEq::new(Self::create_mat_coef(n_j, new_params.mu))?,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    /// Return the result of the half step implicit in the direction of `axis`, from `u` and the explicit part
+    /// in the other direction.
+    fn calculate_half_step(
+        &self,
+        u: &Array2<f64>,
+        axis: Axis,
+        trinomial_eq: &TrinomialEq,
+    ) -> Result<Array2<f64>, Box<dyn Error>> {
+        let other = Axis(1 - axis.index());
+        let mut u_next = u.clone();
+        let n_other = u.len_of(other);
+
+        // the right-hand sides, whose values at the edges of the direction of `axis` are fixed
+        for k in 1..n_other - 1 {
+            let lane = u.index_axis(other, k);
+            let lane_minus = u.index_axis(other, k - 1);
+            let lane_plus = u.index_axis(other, k + 1);
+
+            let mut lane_next = u_next.index_axis_mut(other, k);
+            for l in 1..lane.len() - 1 {
+                lane_next[l] =
+                    lane[l] + 0.5 * self.mu * (lane_plus[l] - 2.0 * lane[l] + lane_minus[l]);
+            }
+        }
+
+        // the lanes at the edges of the other direction are fixed
+        let mut u_interior = u_next.slice_axis_mut(other, Slice::from(1..n_other - 1));
+        trinomial_eq.solve_lanes(&mut u_interior, axis)?;
+
+        Ok(u_next)
+    }
+
+    fn calculate_u_next(&self) -> Result<Array2<f64>, Box<dyn Error>> {
+        let u_half = self.calculate_half_step(&self.u, Axis(0), &self.trinomial_eq_x)?;
+
+        self.calculate_half_step(&u_half, Axis(1), &self.trinomial_eq_y)
+    }
+
+    fn create_mat_coef(n_dim: usize, mu: f64) -> Array1<(f64, f64, f64)> {
+        let coef_lower = -0.5 * mu;
+        let coef_diag = 1.0 + mu;
+        let coef_upper = coef_lower;
+
+        let mut mat_coef = Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper));
+        mat_coef[0] = (0.0, 1.0, 0.0);
+        mat_coef[n_dim - 1] = (0.0, 1.0, 0.0);
+
+        mat_coef
+    }
+}
+
+impl Solver for Cranknicolson2dSolver {
+    type Field = Array2<f64>;
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next()?;
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `Cranknicolson2dSolver` instance.
+pub struct Cranknicolson2dSolverNewParams {
+    /// Initial values of `u`, whose element `[i, j]` is at `(x_i, y_j)`.
+    pub u: Array2<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+}
+
+impl NewParams for Cranknicolson2dSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// Return `\sin \pi x \sin \pi y` on `n_x` cells of `[-1, 1]` in both directions.
+    fn sine_2d(n_x: usize) -> Array2<f64> {
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+
+        Array2::from_shape_fn((n_x + 1, n_x + 1), |(i, j)| {
+            (PI * x[i]).sin() * (PI * x[j]).sin()
+        })
+    }
+
+    #[test]
+    fn fn_cranknicolson_2d_integrate_works() {
+        // setup cranknicolson 2d solver with the eigenmode of the difference operator and run integrate()
+        let n_x = 8;
+        let mu = 2.0;
+        let u_init = sine_2d(n_x);
+        let new_params = Cranknicolson2dSolverNewParams {
+            u: u_init.clone(),
+            step_max: 10000,
+            mu,
+        };
+        let mut cranknicolson_2d_solver = Cranknicolson2dSolver::new(new_params).unwrap();
+        cranknicolson_2d_solver.integrate().unwrap();
+
+        // check if the mode is damped by the amplification factor of each direction
+        // g = (1 - \mu k / 2) / (1 + \mu k / 2) with \delta_x^2 u = -k u, k = 4 \sin^2 (\pi \Delta x / 2)
+        let k = 4.0 * (0.5 * PI * 2.0 / n_x as f64).sin().powi(2);
+        let g = (1.0 - 0.5 * mu * k) / (1.0 + 0.5 * mu * k);
+        let u_exact = g * g * u_init;
+        let is_u_correctly_updated = (cranknicolson_2d_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(cranknicolson_2d_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_cranknicolson_2d_is_second_order() {
+        // compare with the exact solution u(x, y, t) = \exp(-2 \pi^2 t) \sin \pi x \sin \pi y at t = 0.1,
+        // refining dx and dt together with the fixed dt / dx
+        let errors: Vec<f64> = [20, 40, 80]
+            .iter()
+            .map(|&n_x| {
+                let dx = 2.0 / n_x as f64;
+                let dt = 0.1 / n_x as f64;
+                let new_params = Cranknicolson2dSolverNewParams {
+                    u: sine_2d(n_x),
+                    step_max: n_x,
+                    mu: dt / (dx * dx),
+                };
+                let mut solver = Cranknicolson2dSolver::new(new_params).unwrap();
+                while !solver.is_completed() {
+                    solver.integrate().unwrap();
+                }
+
+                let u_exact = (-2.0 * PI * PI * 0.1).exp() * sine_2d(n_x);
+                (solver.u - u_exact).fold(0.0, |acc: f64, e| acc.max(e.abs()))
+            })
+            .collect();
+
+        // check if the error is reduced by 4 times by halving dx and dt
+        for pair in errors.windows(2) {
+            let order = (pair[0] / pair[1]).log2();
+            assert!((order - 2.0).abs() < 0.1, "order: {}", order);
+        }
+    }
+}
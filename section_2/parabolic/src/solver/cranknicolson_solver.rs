@@ -0,0 +1,322 @@
+//! Solver for the diffusion equation using the Crank-Nicolson method.
+//!
+//! # Scheme
+//! The Crank-Nicolson method is given by
+//! ```math
+//! -\frac{1}{2} \mu u_{j-1}^{n+1} + (1 + \mu) u_j^{n+1} - \frac{1}{2} \mu u_{j+1}^{n+1} =
+//! \frac{1}{2} \mu u_{j-1}^n + (1 - \mu) u_j^n + \frac{1}{2} \mu u_{j+1}^n,
+//! ```
+//! where `\mu = \frac{\alpha \Delta t}{\Delta x^2}`.
+//!
+//! If the source term `S(x, t)` is given, `\frac{1}{2} \Delta t (S(x_j, t_n) + S(x_j, t_{n+1}))`
+//! is added to the right-hand side, which keeps the second-order accuracy in time.
+//!
+//! This is the Beam-Warming method with `\lambda = 1/2` (see [beamwarming_solver](super::beamwarming_solver)),
+//! which is second-order accurate in both time and space, and unconditionally stable.
+//! That is, the error is `O(\Delta t^2 + \Delta x^2)` for any `\mu`.
+//!
+//! The coefficient matrix doesn't change during the calculation,
+//! so it is LU-decomposed once at the construction and only the substitutions are performed every step.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed as
+//! ```math
+//! u(x_{\pm}, t) = u(x_{\pm}, 0),
+//! ```
+//! which is imposed by the first and last rows of the trinomial equation,
+//! so that the interior points are coupled with the exact boundary values.
+
+use super::{NewParams, Solver, Source};
+use ndarray::prelude::*;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+
+/// Solver for the diffusion equation using the Crank-Nicolson method.
+#[derive(Debug)]
+pub struct CranknicolsonSolver {
+    u: Array1<f64>,
+    step_max: usize,
+    mu: f64,
+    source: Option<Source>,
+    trinomial_eq: TrinomialEq,
+    dt: Option<f64>,
+    step: usize,
+    completed: bool,
+}
+
+impl CranknicolsonSolver {
+    /// Create a new `CranknicolsonSolver` instance.
+    pub fn new(new_params: CranknicolsonSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let u_len = new_params.u.len();
+
+        Ok(Self {
+            u: new_params.u,
+            step_max: new_params.step_max,
+            mu: new_params.mu,
+            source: new_params.source,
+            trinomial_eq: TrinomialEq::new(Self::create_mat_coef(u_len, new_params.mu))?,
+            dt: new_params.dt,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
+        let coef_lower_rhs = 0.5 * self.mu;
+        let coef_diag_rhs = 1.0 - self.mu;
+        let coef_upper_rhs = coef_lower_rhs;
+
+        let mut u_next: Array1<f64> = (0..self.u.len())
+            .map(|i| {
+                if i == 0 || i == self.u.len() - 1 {
+                    return self.u[i];
+                }
+
+                coef_lower_rhs * self.u[i - 1]
+                    + coef_diag_rhs * self.u[i]
+                    + coef_upper_rhs * self.u[i + 1]
+            })
+            .collect();
+
+        if let Some(source) = &self.source {
+            let source_now = source.evaluate(self.step, self.u.len())?;
+            let source_next = source.evaluate(self.step + 1, self.u.len())?;
+            let len = u_next.len();
+            u_next
+                .slice_mut(s![1..len - 1])
+                .zip_mut_with(&source_now.slice(s![1..len - 1]), |u, s| {
+                    *u += (1.0 - 0.5) * s
+                });
+            u_next
+                .slice_mut(s![1..len - 1])
+                .zip_mut_with(&source_next.slice(s![1..len - 1]), |u, s| *u += 0.5 * s);
+        }
+
+        self.trinomial_eq.solve(&mut u_next)?;
+
+        Ok(u_next)
+    }
+
+    fn create_mat_coef(n_dim: usize, mu: f64) -> Array1<(f64, f64, f64)> {
+        let coef_lower = -0.5 * mu;
+        let coef_diag = 1.0 + mu;
+        let coef_upper = coef_lower;
+
+        let mut mat_coef = Array::from_elem(n_dim, (coef_lower, coef_diag, coef_upper));
+        mat_coef[0] = (0.0, 1.0, 0.0);
+        mat_coef[n_dim - 1] = (0.0, 1.0, 0.0);
+
+        mat_coef
+    }
+}
+
+impl Solver for CranknicolsonSolver {
+    type Field = Array1<f64>;
+
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_t(&self) -> Option<f64> {
+        self.dt.map(|dt| self.step as f64 * dt)
+    }
+
+    fn get_dt(&self) -> Option<f64> {
+        self.dt
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next()?;
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `CranknicolsonSolver` instance.
+pub struct CranknicolsonSolverNewParams {
+    /// Initial values of `u`.
+    pub u: Array1<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+    /// Source term, or `None` for the homogeneous equation.
+    pub source: Option<Source>,
+    /// Time step, or `None` to count only the steps.
+    pub dt: Option<f64>,
+}
+
+impl NewParams for CranknicolsonSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+        if self.dt.is_some_and(|dt| dt <= 0.0) {
+            return Err("dt must be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// Run the Crank-Nicolson method from `u(x, 0) = \sin \pi x` on `n_x` cells of `[-1, 1]` until `t = 0.1`
+    /// by `step_max` steps with `\alpha = 1`, and return `x` and `u`.
+    fn run_sine(n_x: usize, step_max: usize) -> (Array1<f64>, Array1<f64>) {
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let dx = 2.0 / n_x as f64;
+        let dt = 0.1 / step_max as f64;
+        let mut solver = CranknicolsonSolver::new(CranknicolsonSolverNewParams {
+            u: x.mapv(|x| (PI * x).sin()),
+            step_max,
+            mu: dt / (dx * dx),
+            source: None,
+            dt: None,
+        })
+        .unwrap();
+        while !solver.is_completed() {
+            solver.integrate().unwrap();
+        }
+
+        (x, solver.u)
+    }
+
+    /// Return the maximum norm of `u - u_exact`.
+    fn max_error(u: &Array1<f64>, u_exact: &Array1<f64>) -> f64 {
+        (u - u_exact).fold(0.0, |acc, e| acc.max(e.abs()))
+    }
+
+    #[test]
+    fn fn_cranknicolson_integrate_works() {
+        // setup cranknicolson solver and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = CranknicolsonSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.5,
+            source: None,
+            dt: None,
+        };
+        let mut cranknicolson_solver = CranknicolsonSolver::new(new_params).unwrap();
+        cranknicolson_solver.integrate().unwrap();
+
+        // check if u and step are correctly updated
+        let u_exact = array![0.0, 15.0 / 34.0, 11.0 / 17.0, 15.0 / 34.0, 0.0];
+        let is_u_correctly_updated = (cranknicolson_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(cranknicolson_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_cranknicolson_is_second_order_in_time() {
+        // compare with the exact solution of the semi-discrete equation on the same grid,
+        // u(x, t) = \exp(-k_h^2 t) \sin \pi x with k_h = \frac{2}{\Delta x} \sin \frac{\pi \Delta x}{2},
+        // so that only the error of the time integration remains
+        let n_x = 40;
+        let dx = 2.0 / n_x as f64;
+        let k_h = 2.0 / dx * (0.5 * PI * dx).sin();
+        let errors: Vec<f64> = [10, 20, 40]
+            .iter()
+            .map(|&step_max| {
+                let (x, u) = run_sine(n_x, step_max);
+                let u_exact = x.mapv(|x| (-k_h * k_h * 0.1).exp() * (PI * x).sin());
+                max_error(&u, &u_exact)
+            })
+            .collect();
+
+        // check if the error is reduced by 4 times by halving dt
+        for pair in errors.windows(2) {
+            let order = (pair[0] / pair[1]).log2();
+            assert!((order - 2.0).abs() < 0.1, "order in time: {}", order);
+        }
+    }
+
+    #[test]
+    fn fn_cranknicolson_is_second_order_in_space() {
+        // compare with the exact solution u(x, t) = \exp(-\pi^2 t) \sin \pi x,
+        // refining dx and dt together with the fixed dt / dx
+        let errors: Vec<f64> = [20, 40, 80]
+            .iter()
+            .map(|&n_x| {
+                let (x, u) = run_sine(n_x, n_x);
+                let u_exact = x.mapv(|x| (-PI * PI * 0.1).exp() * (PI * x).sin());
+                max_error(&u, &u_exact)
+            })
+            .collect();
+
+        // check if the error is reduced by 4 times by halving dx and dt
+        for pair in errors.windows(2) {
+            let order = (pair[0] / pair[1]).log2();
+            assert!((order - 2.0).abs() < 0.1, "order in space: {}", order);
+        }
+    }
+
+    #[test]
+    fn fn_cranknicolson_integrate_works_with_source() {
+        // u(x, t) = (1 + t) \sin \pi x exactly solves the semi-discrete equation with the source
+        // S(x, t) = (1 + k_h^2 (1 + t)) \sin \pi x, k_h = \frac{2}{\Delta x} \sin \frac{\pi \Delta x}{2},
+        // and is reproduced by the time-averaged source as it is linear in t
+        let n_x = 20;
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let dx = 2.0 / n_x as f64;
+        let dt = 0.01;
+        let k_h = 2.0 / dx * (0.5 * PI * dx).sin();
+
+        // setup cranknicolson solver with the source and run integrate()
+        let x_source = x.clone();
+        let new_params = CranknicolsonSolverNewParams {
+            u: x.mapv(|x| (PI * x).sin()),
+            step_max: 10,
+            mu: dt / (dx * dx),
+            source: Some(Source::new(move |n| {
+                let t = n as f64 * dt;
+                x_source.mapv(|x| dt * (1.0 + k_h * k_h * (1.0 + t)) * (PI * x).sin())
+            })),
+            dt: None,
+        };
+        let mut cranknicolson_solver = CranknicolsonSolver::new(new_params).unwrap();
+        while !cranknicolson_solver.is_completed() {
+            cranknicolson_solver.integrate().unwrap();
+        }
+
+        // check if u is the exact solution at t = 10 dt
+        let u_exact = x.mapv(|x| (1.0 + 10.0 * dt) * (PI * x).sin());
+        let is_u_correctly_updated = (cranknicolson_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+}
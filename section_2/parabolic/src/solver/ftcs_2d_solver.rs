@@ -0,0 +1,154 @@
+//! Solver for the two-dimensional diffusion equation using the FTCS method.
+//!
+//! # Scheme
+//! The FTCS (Forward in Time Central Difference in Space) method in 2D is given by
+//! ```math
+//! u_{i,j}^{n+1} = u_{i,j}^n + \mu (u_{i+1,j}^n + u_{i-1,j}^n + u_{i,j+1}^n + u_{i,j-1}^n - 4 u_{i,j}^n),
+//! ```
+//! where `\mu = \frac{\alpha \Delta t}{\Delta x^2}` and the grid spacing is the same in both directions, `\Delta x = \Delta y`.
+//!
+//! The method is stable for `\mu \le 1/4`, which is half the stability limit in 1D.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed on all four edges as
+//! ```math
+//! u(x_{\pm}, y, t) = u(x_{\pm}, y, 0), u(x, y_{\pm}, t) = u(x, y_{\pm}, 0).
+//! ```
+
+use super::{NewParams, Solver};
+use ndarray::prelude::*;
+use std::error::Error;
+
+/// Solver for the two-dimensional diffusion equation using the FTCS method.
+#[derive(Debug)]
+pub struct Ftcs2dSolver {
+    u: Array2<f64>,
+    step_max: usize,
+    mu: f64,
+    step: usize,
+    completed: bool,
+}
+
+impl Ftcs2dSolver {
+    /// Create a new `Ftcs2dSolver` instance.
+    pub fn new(new_params: Ftcs2dSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        Ok(Self {
+            u: new_params.u,
+            step_max: new_params.step_max,
+            mu: new_params.mu,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    fn calculate_u_next(&self) -> Array2<f64> {
+        let (n_i, n_j) = self.u.dim();
+
+        Array2::from_shape_fn((n_i, n_j), |(i, j)| {
+            if i == 0 || i == n_i - 1 || j == 0 || j == n_j - 1 {
+                return self.u[[i, j]];
+            }
+
+            self.u[[i, j]]
+                + self.mu
+                    * (self.u[[i + 1, j]]
+                        + self.u[[i - 1, j]]
+                        + self.u[[i, j + 1]]
+                        + self.u[[i, j - 1]]
+                        - 4.0 * self.u[[i, j]])
+        })
+    }
+}
+
+impl Solver for Ftcs2dSolver {
+    type Field = Array2<f64>;
+
+    fn borrow_u(&self) -> &Array2<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next();
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `Ftcs2dSolver` instance.
+pub struct Ftcs2dSolverNewParams {
+    /// Initial values of `u`, whose element `[i, j]` is at `(x_i, y_j)`.
+    pub u: Array2<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+}
+
+impl NewParams for Ftcs2dSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_ftcs_2d_integrate_works() {
+        // setup ftcs 2d solver and run integrate()
+        let u_init = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+        let new_params = Ftcs2dSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.25,
+        };
+        let mut ftcs_2d_solver = Ftcs2dSolver::new(new_params).unwrap();
+        ftcs_2d_solver.integrate().unwrap();
+
+        // check if u and step are correctly updated
+        let u_exact = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.25, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ];
+        let is_u_correctly_updated = (ftcs_2d_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(ftcs_2d_solver.step, 1);
+    }
+}
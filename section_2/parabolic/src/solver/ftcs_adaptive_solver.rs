@@ -0,0 +1,251 @@
+//! Solver for the diffusion equation with the variable diffusion coefficient using the FTCS method
+//! with the adaptive time step.
+//!
+//! # Formulation
+//! The diffusion equation with the diffusion coefficient varying in space and time is given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \frac{\partial}{\partial x} (\alpha(x, t) \frac{\partial u}{\partial x}).
+//! ```
+//!
+//! # Scheme
+//! The FTCS method in the conservative form is given by
+//! ```math
+//! u_j^{n+1} = u_j^n + \frac{\Delta t^n}{\Delta x^2}
+//! (\alpha_{j+1/2}^n (u_{j+1}^n - u_j^n) - \alpha_{j-1/2}^n (u_j^n - u_{j-1}^n)),
+//! ```
+//! where `\alpha_{j+1/2}^n = (\alpha(x_j, t^n) + \alpha(x_{j+1}, t^n)) / 2`.
+//!
+//! The method is stable for `\Delta t^n \le \Delta x^2 / (2 \max_j \alpha_j^n)`,
+//! so the time step is recomputed every step as
+//! ```math
+//! \Delta t^n = C \frac{\Delta x^2}{2 \max_j \alpha(x_j, t^n)},
+//! ```
+//! where `C \in (0, 1]` is the safety factor.
+//! The last step is shortened so that the calculation ends exactly at `t_max`.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed as
+//! ```math
+//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! ```
+
+use super::{DiffusionCoefficient, NewParams, Solver};
+use ndarray::prelude::*;
+use std::error::Error;
+
+/// Solver for the diffusion equation with the variable diffusion coefficient using the FTCS method
+/// with the adaptive time step.
+#[derive(Debug)]
+pub struct FtcsAdaptiveSolver {
+    u: Array1<f64>,
+    t_max: f64,
+    step_max: usize,
+    dx: f64,
+    alpha: DiffusionCoefficient,
+    safety_factor: f64,
+    t: f64,
+    dt: f64,
+    step: usize,
+    completed: bool,
+}
+
+impl FtcsAdaptiveSolver {
+    /// Create a new `FtcsAdaptiveSolver` instance.
+    pub fn new(new_params: FtcsAdaptiveSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        Ok(Self {
+            u: new_params.u,
+            t_max: new_params.t_max,
+            step_max: new_params.step_max,
+            dx: new_params.dx,
+            alpha: new_params.alpha,
+            safety_factor: new_params.safety_factor,
+            t: 0.0,
+            dt: 0.0,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    /// Return the next time step, which is `safety_factor` times the stability limit for `alpha`
+    /// and doesn't exceed `t_max`.
+    fn calculate_dt(&self, alpha: &Array1<f64>) -> f64 {
+        let alpha_max = alpha.fold(0.0, |acc: f64, alpha| acc.max(*alpha));
+        let dt = self.safety_factor * self.dx * self.dx / (2.0 * alpha_max);
+
+        dt.min(self.t_max - self.t)
+    }
+
+    fn calculate_u_next(&self, alpha: &Array1<f64>, dt: f64) -> Array1<f64> {
+        let ratio = dt / (self.dx * self.dx);
+
+        (0..self.u.len())
+            .map(|i| {
+                if i == 0 || i == self.u.len() - 1 {
+                    return self.u[i];
+                }
+
+                let alpha_plus = 0.5 * (alpha[i] + alpha[i + 1]);
+                let alpha_minus = 0.5 * (alpha[i - 1] + alpha[i]);
+                self.u[i]
+                    + ratio
+                        * (alpha_plus * (self.u[i + 1] - self.u[i])
+                            - alpha_minus * (self.u[i] - self.u[i - 1]))
+            })
+            .collect()
+    }
+}
+
+impl Solver for FtcsAdaptiveSolver {
+    type Field = Array1<f64>;
+
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn get_t(&self) -> Option<f64> {
+        Some(self.t)
+    }
+
+    fn get_dt(&self) -> Option<f64> {
+        Some(self.dt)
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        let alpha = self.alpha.evaluate(self.t, self.u.len())?;
+        self.dt = self.calculate_dt(&alpha);
+        self.u = self.calculate_u_next(&alpha, self.dt);
+        self.t += self.dt;
+        self.step += 1;
+
+        if self.t >= self.t_max || self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `FtcsAdaptiveSolver` instance.
+pub struct FtcsAdaptiveSolverNewParams {
+    /// Initial values of `u`.
+    pub u: Array1<f64>,
+    /// Time to end the calculation.
+    pub t_max: f64,
+    /// Maximum number of time steps, which ends the calculation before `t_max` if reached.
+    pub step_max: usize,
+    /// Grid spacing.
+    pub dx: f64,
+    /// Diffusion coefficient.
+    pub alpha: DiffusionCoefficient,
+    /// Ratio of the time step to the stability limit.
+    pub safety_factor: f64,
+}
+
+impl NewParams for FtcsAdaptiveSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.is_empty() {
+            return Err("u must not be empty");
+        }
+        if self.t_max <= 0.0 {
+            return Err("t_max must be positive");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.dx <= 0.0 {
+            return Err("dx must be positive");
+        }
+        if self.safety_factor <= 0.0 || self.safety_factor > 1.0 {
+            return Err("safety_factor must be in (0, 1]");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::ftcs_solver::{FtcsSolver, FtcsSolverNewParams};
+
+    #[test]
+    fn fn_ftcs_adaptive_agrees_with_ftcs_for_constant_alpha() {
+        // setup ftcs adaptive solver with alpha = 2 and the safety factor 0.8, that is, mu = 0.4,
+        // and ftcs solver with mu = 0.4
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = FtcsAdaptiveSolverNewParams {
+            u: u_init.clone(),
+            t_max: 1.0,
+            step_max: 10,
+            dx: 0.5,
+            alpha: DiffusionCoefficient::new(|_| Array1::from_elem(5, 2.0)),
+            safety_factor: 0.8,
+        };
+        let mut ftcs_adaptive_solver = FtcsAdaptiveSolver::new(new_params).unwrap();
+        let new_params = FtcsSolverNewParams {
+            u: u_init,
+            step_max: 10,
+            mu: 0.4,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
+        };
+        let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+        while !ftcs_adaptive_solver.is_completed() {
+            ftcs_adaptive_solver.integrate().unwrap();
+            ftcs_solver.integrate().unwrap();
+        }
+
+        // check if u, t and dt are the same as the fixed time step
+        let is_u_same = (&ftcs_adaptive_solver.u - ftcs_solver.borrow_u())
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_same);
+        assert_eq!(ftcs_adaptive_solver.get_dt(), Some(0.05));
+        assert!((ftcs_adaptive_solver.t - 0.5).abs() < 1e-10);
+        assert_eq!(ftcs_adaptive_solver.step, 10);
+    }
+
+    #[test]
+    fn fn_ftcs_adaptive_shrinks_dt_and_ends_at_t_max() {
+        // setup ftcs adaptive solver with alpha = 1 + 100 t
+        let new_params = FtcsAdaptiveSolverNewParams {
+            u: array![0.0, 0.5, 1.0, 0.5, 0.0],
+            t_max: 0.3,
+            step_max: 10000,
+            dx: 0.5,
+            alpha: DiffusionCoefficient::new(|t| Array1::from_elem(5, 1.0 + 100.0 * t)),
+            safety_factor: 0.5,
+        };
+        let mut ftcs_adaptive_solver = FtcsAdaptiveSolver::new(new_params).unwrap();
+
+        // check if dt follows the stability limit of the current alpha until the last step
+        let mut dt_prev = f64::INFINITY;
+        while !ftcs_adaptive_solver.is_completed() {
+            let t = ftcs_adaptive_solver.t;
+            ftcs_adaptive_solver.integrate().unwrap();
+            let dt = ftcs_adaptive_solver.get_dt().unwrap();
+            assert!(dt <= 0.5 * 0.25 / (2.0 * (1.0 + 100.0 * t)) + 1e-15);
+            assert!(dt < dt_prev);
+            dt_prev = dt;
+        }
+        assert!((ftcs_adaptive_solver.get_t().unwrap() - 0.3).abs() < 1e-12);
+    }
+}
@@ -7,13 +7,18 @@
 //! ```
 //! where `\mu = \frac{\alpha \Delta t}{\Delta x^2}`.
 //!
+//! If the source term `S(x, t)` is given, `\Delta t S(x_j, t_n)` is added to the right-hand side.
+//!
 //! # Boundary Condition
-//! The boundary condition is fixed as
+//! The boundary condition at each end is given by [BoundaryCondition](super::boundary::BoundaryCondition).
+//! Unless the value is fixed, the end point is updated by the scheme with the ghost point, e.g.
 //! ```math
-//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! u_0^{n+1} = u_0^n + 2 \mu (u_1^n - u_0^n - \Delta x g_{-})
 //! ```
+//! for the Neumann boundary condition.
 
-use super::{NewParams, Solver};
+use super::boundary::{BoundaryCondition, Edge};
+use super::{NewParams, Solver, Source};
 use ndarray::prelude::*;
 use std::error::Error;
 
@@ -23,6 +28,10 @@ pub struct FtcsSolver {
     u: Array1<f64>,
     step_max: usize,
     mu: f64,
+    source: Option<Source>,
+    boundary_condition: (BoundaryCondition, BoundaryCondition),
+    dx: f64,
+    dt: Option<f64>,
     step: usize,
     completed: bool,
 }
@@ -36,26 +45,68 @@ impl FtcsSolver {
             u: new_params.u,
             step_max: new_params.step_max,
             mu: new_params.mu,
+            source: new_params.source,
+            boundary_condition: new_params.boundary_condition,
+            // dx is used only by the flux boundary conditions, for which it is given
+            dx: new_params.dx.unwrap_or(1.0),
+            dt: new_params.dt,
             step: 0,
             completed: false,
         })
     }
 
-    fn calculate_u_next(&self) -> Array1<f64> {
-        self.u
+    /// Create a new `FtcsSolverBuilder` instance to build a `FtcsSolver` instance.
+    pub fn builder() -> FtcsSolverBuilder {
+        FtcsSolverBuilder::default()
+    }
+
+    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
+        let source = match &self.source {
+            Some(source) => source.evaluate(self.step, self.u.len())?,
+            None => Array1::zeros(self.u.len()),
+        };
+
+        let len = self.u.len();
+
+        Ok(self
+            .u
             .indexed_iter()
             .map(|(i, _)| {
-                if i == 0 || i == self.u.len() - 1 {
-                    return self.u[i];
+                if i == 0 {
+                    return self.calculate_edge_next(i, i + 1, -1.0, source[i]);
+                }
+                if i == len - 1 {
+                    return self.calculate_edge_next(i, i - 1, 1.0, source[i]);
                 }
 
-                self.u[i] + self.mu * (self.u[i - 1] - 2.0 * self.u[i] + self.u[i + 1])
+                self.u[i] + self.mu * (self.u[i - 1] - 2.0 * self.u[i] + self.u[i + 1]) + source[i]
             })
-            .collect()
+            .collect())
+    }
+
+    /// Return the next value at the end point `i` next to the point `inner`,
+    /// where `outward` is `-1` at `x_-` and `1` at `x_+` and `source` is `\Delta t S` at the end point.
+    fn calculate_edge_next(&self, i: usize, inner: usize, outward: f64, source: f64) -> f64 {
+        let bc = if outward < 0.0 {
+            self.boundary_condition.0
+        } else {
+            self.boundary_condition.1
+        };
+
+        match bc.edge(self.u[i], outward, self.dx) {
+            Edge::Fixed(u) => u,
+            Edge::Ghost(alpha, beta) => {
+                self.u[i]
+                    + self.mu * (2.0 * self.u[inner] + (alpha - 2.0) * self.u[i] + beta)
+                    + source
+            }
+        }
     }
 }
 
 impl Solver for FtcsSolver {
+    type Field = Array1<f64>;
+
     fn borrow_u(&self) -> &Array1<f64> {
         &self.u
     }
@@ -68,6 +119,14 @@ impl Solver for FtcsSolver {
         self.completed
     }
 
+    fn get_t(&self) -> Option<f64> {
+        self.dt.map(|dt| self.step as f64 * dt)
+    }
+
+    fn get_dt(&self) -> Option<f64> {
+        self.dt
+    }
+
     fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
         if self.completed {
             return Err(Box::<dyn Error>::from(
@@ -75,7 +134,7 @@ impl Solver for FtcsSolver {
             ));
         }
 
-        self.u = self.calculate_u_next();
+        self.u = self.calculate_u_next()?;
         self.step += 1;
 
         if self.step >= self.step_max {
@@ -94,6 +153,14 @@ pub struct FtcsSolverNewParams {
     pub step_max: usize,
     /// diffusion coefficient * dt / dx^2.
     pub mu: f64,
+    /// Source term, or `None` for the homogeneous equation.
+    pub source: Option<Source>,
+    /// Boundary conditions at `x_-` and `x_+`.
+    pub boundary_condition: (BoundaryCondition, BoundaryCondition),
+    /// Grid spacing, which must be given for the flux boundary conditions, or `None` for the fixed values at both ends.
+    pub dx: Option<f64>,
+    /// Time step, or `None` to count only the steps.
+    pub dt: Option<f64>,
 }
 
 impl NewParams for FtcsSolverNewParams {
@@ -107,11 +174,102 @@ impl NewParams for FtcsSolverNewParams {
         if self.mu <= 0.0 {
             return Err("mu must be positive");
         }
+        if self.dx.is_some_and(|dx| dx <= 0.0) {
+            return Err("dx must be positive");
+        }
+        if self.dt.is_some_and(|dt| dt <= 0.0) {
+            return Err("dt must be positive");
+        }
+
+        BoundaryCondition::validate_pair(&self.boundary_condition, self.u.len())?;
+        if self.dx.is_none()
+            && (self.boundary_condition.0.is_flux() || self.boundary_condition.1.is_flux())
+        {
+            return Err("dx must be given for the flux boundary condition");
+        }
 
         Ok(())
     }
 }
 
+/// Builder of `FtcsSolver` instances.
+///
+/// `u`, `step_max` and `mu` must be set, while the others default to:
+/// - `source`: `None`, that is, the homogeneous equation,
+/// - `boundary_condition`: [BoundaryCondition::Dirichlet] at both ends,
+/// - `dx`: `None`, which must be set for the flux boundary conditions,
+/// - `dt`: `None`, that is, only the steps are counted.
+#[derive(Debug, Default)]
+pub struct FtcsSolverBuilder {
+    u: Option<Array1<f64>>,
+    step_max: Option<usize>,
+    mu: Option<f64>,
+    source: Option<Source>,
+    boundary_condition: Option<(BoundaryCondition, BoundaryCondition)>,
+    dx: Option<f64>,
+    dt: Option<f64>,
+}
+
+impl FtcsSolverBuilder {
+    /// Set the initial values of `u`.
+    pub fn u(mut self, u: Array1<f64>) -> Self {
+        self.u = Some(u);
+        self
+    }
+
+    /// Set the maximum number of time steps.
+    pub fn step_max(mut self, step_max: usize) -> Self {
+        self.step_max = Some(step_max);
+        self
+    }
+
+    /// Set diffusion coefficient * dt / dx^2.
+    pub fn mu(mut self, mu: f64) -> Self {
+        self.mu = Some(mu);
+        self
+    }
+
+    /// Set the source term.
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Set the boundary conditions at `x_-` and `x_+`.
+    pub fn boundary_condition(
+        mut self,
+        boundary_condition: (BoundaryCondition, BoundaryCondition),
+    ) -> Self {
+        self.boundary_condition = Some(boundary_condition);
+        self
+    }
+
+    /// Set the grid spacing.
+    pub fn dx(mut self, dx: f64) -> Self {
+        self.dx = Some(dx);
+        self
+    }
+
+    /// Set the time step.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Build a new `FtcsSolver` instance, whose parameters are validated as in [FtcsSolver::new].
+    pub fn build(self) -> Result<FtcsSolver, &'static str> {
+        FtcsSolver::new(FtcsSolverNewParams {
+            u: self.u.ok_or("u must be set")?,
+            step_max: self.step_max.ok_or("step_max must be set")?,
+            mu: self.mu.ok_or("mu must be set")?,
+            source: self.source,
+            boundary_condition: self.boundary_condition.unwrap_or_default(),
+            dx: self.dx,
+            dt: self.dt,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,6 +282,10 @@ mod tests {
             u: u_init,
             step_max: 10000,
             mu: 0.5,
+            source: None,
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
         };
         let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
         ftcs_solver.integrate().unwrap();
@@ -134,4 +296,208 @@ mod tests {
         assert!(is_u_correctly_updated);
         assert_eq!(ftcs_solver.step, 1);
     }
+
+    #[test]
+    fn fn_ftcs_builder_works() {
+        // build ftcs solver with the default source, boundary condition and dt and run integrate()
+        let mut ftcs_solver = FtcsSolver::builder()
+            .u(array![0.0, 0.5, 1.0, 0.5, 0.0])
+            .step_max(10000)
+            .mu(0.5)
+            .build()
+            .unwrap();
+        ftcs_solver.integrate().unwrap();
+
+        // check if u is updated as with the explicit parameters
+        let u_exact = array![0.0, 0.5, 0.5, 0.5, 0.0];
+        assert_eq!(ftcs_solver.get_t(), None);
+        let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+
+        // check if the missing and invalid parameters are rejected
+        let result = FtcsSolver::builder()
+            .u(array![0.0, 1.0, 0.0])
+            .step_max(10)
+            .build();
+        assert_eq!(result.unwrap_err(), "mu must be set");
+        let result = FtcsSolver::builder()
+            .u(array![0.0, 1.0, 0.0])
+            .step_max(10)
+            .mu(-0.5)
+            .build();
+        assert_eq!(result.unwrap_err(), "mu must be positive");
+        let result = FtcsSolver::builder()
+            .u(array![0.0, 1.0, 0.0])
+            .step_max(10)
+            .mu(0.5)
+            .boundary_condition((
+                BoundaryCondition::Neumann(1.0),
+                BoundaryCondition::Dirichlet,
+            ))
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            "dx must be given for the flux boundary condition"
+        );
+    }
+
+    #[test]
+    fn fn_ftcs_integrate_works_with_source() {
+        // setup ftcs solver with the uniform source dt * S = 0.1 and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = FtcsSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.5,
+            source: Some(Source::new(|_| Array1::from_elem(5, 0.1))),
+            boundary_condition: Default::default(),
+            dx: None,
+            dt: None,
+        };
+        let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+        ftcs_solver.integrate().unwrap();
+
+        // check if the source is added to the interior points
+        let u_exact = array![0.0, 0.6, 0.6, 0.6, 0.0];
+        let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_ftcs_integrate_works_with_neumann() {
+        // setup ftcs solver with the insulated boundaries and run integrate()
+        let u_init = array![0.0, 0.5, 1.0, 0.5, 0.0];
+        let new_params = FtcsSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.5,
+            source: None,
+            boundary_condition: (
+                BoundaryCondition::Neumann(0.0),
+                BoundaryCondition::Neumann(0.0),
+            ),
+            dx: Some(0.5),
+            dt: None,
+        };
+        let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+        ftcs_solver.integrate().unwrap();
+
+        // check if the end points are updated with the ghost points
+        let u_exact = array![0.5, 0.5, 0.5, 0.5, 0.5];
+        let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_ftcs_converges_to_linear_profile_with_prescribed_flux() {
+        // setup ftcs solver on [0, 1] with the flux u_x = 1 at x_- and u = 1 at x_+ and run until the steady state
+        let new_params = FtcsSolverNewParams {
+            u: Array1::ones(11),
+            step_max: 2000,
+            mu: 0.4,
+            source: None,
+            boundary_condition: (
+                BoundaryCondition::Neumann(1.0),
+                BoundaryCondition::Dirichlet,
+            ),
+            dx: Some(0.1),
+            dt: None,
+        };
+        let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+        while !ftcs_solver.is_completed() {
+            ftcs_solver.integrate().unwrap();
+        }
+
+        // check if u is the linear profile with the prescribed slope
+        let u_exact = Array1::from_shape_fn(11, |j| 1.0 + 0.1 * (j as f64 - 10.0));
+        let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_ftcs_steady_slope_with_prescribed_flux_is_independent_of_resolution() {
+        for n_x in [10, 20] {
+            // setup ftcs solver on [0, 1] of n_x cells with the flux u_x = 2 at x_- and u = 1 at x_+
+            // and run until the steady state
+            let dx = 1.0 / n_x as f64;
+            let new_params = FtcsSolverNewParams {
+                u: Array1::ones(n_x + 1),
+                step_max: 10000,
+                mu: 0.4,
+                source: None,
+                boundary_condition: (
+                    BoundaryCondition::Neumann(2.0),
+                    BoundaryCondition::Dirichlet,
+                ),
+                dx: Some(dx),
+                dt: None,
+            };
+            let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+            while !ftcs_solver.is_completed() {
+                ftcs_solver.integrate().unwrap();
+            }
+
+            // check if u is the linear profile u = 1 + 2 (x - 1) with the prescribed slope at both resolutions
+            let u_exact = Array1::from_shape_fn(n_x + 1, |j| 1.0 + 2.0 * (j as f64 * dx - 1.0));
+            let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+            assert!(is_u_correctly_updated);
+        }
+    }
+
+    #[test]
+    fn fn_ftcs_converges_to_steady_state_with_robin() {
+        // setup ftcs solver on [0, 1] with u - 0.1 u_x = 0 at x_- and u = 1 at x_+ and run until the steady state
+        let new_params = FtcsSolverNewParams {
+            u: Array1::zeros(11),
+            step_max: 4000,
+            mu: 0.4,
+            source: None,
+            boundary_condition: (
+                BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: -0.1,
+                    g: 0.0,
+                },
+                BoundaryCondition::Robin {
+                    a: 1.0,
+                    b: 0.0,
+                    g: 1.0,
+                },
+            ),
+            dx: Some(0.1),
+            dt: None,
+        };
+        let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+        while !ftcs_solver.is_completed() {
+            ftcs_solver.integrate().unwrap();
+        }
+
+        // check if u is the linear profile u_j = (1 + j) / 11 satisfying both boundary conditions
+        let u_exact = Array1::from_shape_fn(11, |j| (1.0 + j as f64) / 11.0);
+        let is_u_correctly_updated = (ftcs_solver.u - u_exact).iter().all(|u| u.abs() < 1e-8);
+        assert!(is_u_correctly_updated);
+    }
+
+    #[test]
+    fn fn_ftcs_get_t_works() {
+        // setup ftcs solver with and without dt and run integrate() twice
+        for (dt, t_exact) in [(Some(0.25), Some(0.5)), (None, None)] {
+            let new_params = FtcsSolverNewParams {
+                u: array![0.0, 0.5, 1.0, 0.5, 0.0],
+                step_max: 10000,
+                mu: 0.5,
+                source: None,
+                boundary_condition: Default::default(),
+                dx: None,
+                dt,
+            };
+            let mut ftcs_solver = FtcsSolver::new(new_params).unwrap();
+            ftcs_solver.integrate().unwrap();
+            ftcs_solver.integrate().unwrap();
+
+            // check if t is tracked only with dt
+            assert_eq!(ftcs_solver.get_t(), t_exact);
+            assert_eq!(ftcs_solver.get_dt(), dt);
+        }
+    }
 }
@@ -0,0 +1,238 @@
+//! Solver for the porous medium equation.
+//!
+//! # Formulation
+//! The porous medium equation is the nonlinear diffusion equation given by
+//! ```math
+//! \frac{\partial u}{\partial t} = \frac{\partial}{\partial x} (u^m \frac{\partial u}{\partial x}),
+//! ```
+//! where `u \ge 0` and `m \ge 0`.
+//! For `m > 0`, the diffusion coefficient `u^m` degenerates at `u = 0`,
+//! so the support of `u` spreads at the finite speed with the sharp front.
+//!
+//! # Scheme
+//! The flux is differenced in the conservative form,
+//! ```math
+//! u_j^{n+1} - \lambda \mu (D_{j+1/2} (u_{j+1}^{n+1} - u_j^{n+1}) - D_{j-1/2} (u_j^{n+1} - u_{j-1}^{n+1})) =
+//! u_j^n + (1 - \lambda) \mu (D_{j+1/2} (u_{j+1}^n - u_j^n) - D_{j-1/2} (u_j^n - u_{j-1}^n)),
+//! ```
+//! where `\mu = \frac{\Delta t}{\Delta x^2}`, `D_{j+1/2} = ((u_j^n)^m + (u_{j+1}^n)^m) / 2`,
+//! `\lambda = 0` for [Diffusion::Explicit] and `\lambda = 1` for [Diffusion::Implicit].
+//!
+//! The coefficients `D_{j+1/2}` are evaluated with `u^n` also for the implicit method,
+//! so that the trinomial equation remains linear, while its coefficient matrix is LU-decomposed every step.
+//!
+//! The explicit method is stable for `\mu \max_j D_{j+1/2} \le 1/2`, which changes with `u`.
+//!
+//! # Boundary Condition
+//! The boundary condition is fixed as
+//! ```math
+//! u(x_{\pm}, t) = u(x_{\pm}, 0).
+//! ```
+
+use super::{Diffusion, NewParams, Solver};
+use ndarray::prelude::*;
+use numerics::trinomial_eq::TrinomialEq;
+use std::error::Error;
+
+/// Solver for the porous medium equation.
+#[derive(Debug)]
+pub struct PorousmediumSolver {
+    u: Array1<f64>,
+    step_max: usize,
+    mu: f64,
+    m: f64,
+    diffusion: Diffusion,
+    step: usize,
+    completed: bool,
+}
+
+impl PorousmediumSolver {
+    /// Create a new `PorousmediumSolver` instance.
+    pub fn new(new_params: PorousmediumSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        Ok(Self {
+            u: new_params.u,
+            step_max: new_params.step_max,
+            mu: new_params.mu,
+            m: new_params.m,
+            diffusion: new_params.diffusion,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    /// Return the coefficients `D_{j+1/2}` for `j = 0, \cdots, N - 1`.
+    fn calculate_coef_diffusion(&self) -> Array1<f64> {
+        (0..self.u.len() - 1)
+            .map(|i| 0.5 * (self.u[i].powf(self.m) + self.u[i + 1].powf(self.m)))
+            .collect()
+    }
+
+    fn calculate_u_next(&self) -> Result<Array1<f64>, Box<dyn Error>> {
+        let coef_diffusion = self.calculate_coef_diffusion();
+
+        match self.diffusion {
+            Diffusion::Explicit => Ok((0..self.u.len())
+                .map(|i| {
+                    if i == 0 || i == self.u.len() - 1 {
+                        return self.u[i];
+                    }
+
+                    self.u[i]
+                        + self.mu
+                            * (coef_diffusion[i] * (self.u[i + 1] - self.u[i])
+                                - coef_diffusion[i - 1] * (self.u[i] - self.u[i - 1]))
+                })
+                .collect()),
+            Diffusion::Implicit => {
+                let trinomial_eq = TrinomialEq::new(self.create_mat_coef(&coef_diffusion))?;
+                let mut u_next = self.u.clone();
+                trinomial_eq.solve(&mut u_next)?;
+
+                Ok(u_next)
+            }
+        }
+    }
+
+    fn create_mat_coef(&self, coef_diffusion: &Array1<f64>) -> Array1<(f64, f64, f64)> {
+        (0..self.u.len())
+            .map(|i| {
+                if i == 0 || i == self.u.len() - 1 {
+                    return (0.0, 1.0, 0.0);
+                }
+
+                let coef_lower = -self.mu * coef_diffusion[i - 1];
+                let coef_upper = -self.mu * coef_diffusion[i];
+                (coef_lower, 1.0 - coef_lower - coef_upper, coef_upper)
+            })
+            .collect()
+    }
+}
+
+impl Solver for PorousmediumSolver {
+    type Field = Array1<f64>;
+
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next()?;
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `PorousmediumSolver` instance.
+pub struct PorousmediumSolverNewParams {
+    /// Initial values of `u`.
+    pub u: Array1<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// dt / dx^2.
+    pub mu: f64,
+    /// Exponent of `u` in the diffusion coefficient.
+    pub m: f64,
+    /// Time integration of the diffusion term.
+    pub diffusion: Diffusion,
+}
+
+impl NewParams for PorousmediumSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.len() < 2 {
+            return Err("u must have at least 2 points");
+        }
+        if self.u.iter().any(|u| *u < 0.0) {
+            return Err("u must not be negative");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+        if self.m < 0.0 {
+            return Err("m must not be negative");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_porousmedium_integrate_works() {
+        // setup explicit porousmedium solver with m = 1 and run integrate()
+        let u_init = array![0.0, 0.0, 1.0, 0.0, 0.0];
+        let new_params = PorousmediumSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 0.5,
+            m: 1.0,
+            diffusion: Diffusion::Explicit,
+        };
+        let mut porousmedium_solver = PorousmediumSolver::new(new_params).unwrap();
+        porousmedium_solver.integrate().unwrap();
+
+        // check if u and step are correctly updated
+        let u_exact = array![0.0, 0.25, 0.5, 0.25, 0.0];
+        let is_u_correctly_updated = (porousmedium_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-10);
+        assert!(is_u_correctly_updated);
+        assert_eq!(porousmedium_solver.step, 1);
+    }
+
+    #[test]
+    fn fn_porousmedium_implicit_spreads_at_finite_speed() {
+        // setup lagged implicit porousmedium solver with m = 1 and the support of 3 points
+        let mut u_init = Array1::zeros(21);
+        u_init.slice_mut(s![9..12]).fill(1.0);
+        let new_params = PorousmediumSolverNewParams {
+            u: u_init,
+            step_max: 10000,
+            mu: 10.0,
+            m: 1.0,
+            diffusion: Diffusion::Implicit,
+        };
+        let mut porousmedium_solver = PorousmediumSolver::new(new_params).unwrap();
+
+        for step in 1..=5 {
+            porousmedium_solver.integrate().unwrap();
+
+            // check if the support spreads by at most one point on each side every step,
+            // unlike the linear diffusion, and the total is conserved
+            let u = porousmedium_solver.borrow_u();
+            let is_outside_zero = u
+                .indexed_iter()
+                .filter(|(i, _)| i + step < 9 || *i > 11 + step)
+                .all(|(_, u)| *u == 0.0);
+            assert!(is_outside_zero);
+            assert!(u[9 - step] > 0.0);
+            assert!((u.sum() - 3.0).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Solver for the diffusion equation using the Fourier spectral method.
+//!
+//! # Scheme
+//! On the periodic domain, `u` is expanded into the Fourier series,
+//! ```math
+//! u_j = \frac{1}{N} \sum_{m} \hat{u}_m e^{i k_m x_j}, k_m = \frac{2 \pi m}{N \Delta x},
+//! ```
+//! where `N` is the number of cells and `\hat{u}_m` is given by the FFT of `u_0, \cdots, u_{N-1}`.
+//!
+//! Each Fourier mode of the diffusion equation decays independently,
+//! so the time integration is carried out exactly for each mode,
+//! ```math
+//! \hat{u}_m^{n+1} = e^{-k_m^2 \alpha \Delta t} \hat{u}_m^n = e^{-\mu (2 \pi m / N)^2} \hat{u}_m^n,
+//! ```
+//! where `\mu = \frac{\alpha \Delta t}{\Delta x^2}`.
+//!
+//! Therefore, the method is exact up to the round-off errors for the modes resolved on the grid, whatever `\mu` is,
+//! and can be used as a reference solution to measure the errors of the other schemes on the periodic domain.
+//!
+//! The wavenumbers are taken as `-N/2 < m \le N/2` and the imaginary part of the inverse FFT is discarded.
+//!
+//! # Boundary Condition
+//! The domain is always periodic, that is, the end points are identified as `u_N = u_0`.
+//! The initial value of the last point is replaced with that of the first point.
+
+use super::{NewParams, Solver};
+use ndarray::prelude::*;
+use rustfft::num_complex::Complex;
+use rustfft::FftPlanner;
+use std::error::Error;
+use std::f64::consts::PI;
+
+/// Solver for the diffusion equation using the Fourier spectral method.
+#[derive(Debug)]
+pub struct SpectralSolver {
+    u: Array1<f64>,
+    step_max: usize,
+    mu: f64,
+    step: usize,
+    completed: bool,
+}
+
+impl SpectralSolver {
+    /// Create a new `SpectralSolver` instance.
+    pub fn new(new_params: SpectralSolverNewParams) -> Result<Self, &'static str> {
+        new_params.validate_new_params()?;
+
+        let mut u = new_params.u;
+        let last = u.len() - 1;
+        u[last] = u[0];
+
+        Ok(Self {
+            u,
+            step_max: new_params.step_max,
+            mu: new_params.mu,
+            step: 0,
+            completed: false,
+        })
+    }
+
+    fn calculate_u_next(&self) -> Array1<f64> {
+        let n = self.u.len() - 1;
+        let mut planner = FftPlanner::new();
+
+        let mut u_hat: Vec<Complex<f64>> = self
+            .u
+            .iter()
+            .take(n)
+            .map(|u| Complex::new(*u, 0.0))
+            .collect();
+        planner.plan_fft_forward(n).process(&mut u_hat);
+
+        for (i, u_hat) in u_hat.iter_mut().enumerate() {
+            let m = if 2 * i <= n {
+                i as f64
+            } else {
+                i as f64 - n as f64
+            };
+            *u_hat *= (-self.mu * (2.0 * PI * m / n as f64).powi(2)).exp();
+        }
+        planner.plan_fft_inverse(n).process(&mut u_hat);
+
+        (0..=n).map(|i| u_hat[i % n].re / n as f64).collect()
+    }
+}
+
+impl Solver for SpectralSolver {
+    type Field = Array1<f64>;
+
+    fn borrow_u(&self) -> &Array1<f64> {
+        &self.u
+    }
+
+    fn get_step(&self) -> usize {
+        self.step
+    }
+
+    fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn integrate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.completed {
+            return Err(Box::<dyn Error>::from(
+                "calculation has already been completed",
+            ));
+        }
+
+        self.u = self.calculate_u_next();
+        self.step += 1;
+
+        if self.step >= self.step_max {
+            self.completed = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters for creating a new `SpectralSolver` instance.
+pub struct SpectralSolverNewParams {
+    /// Initial values of `u`, whose last point is identified with the first point.
+    pub u: Array1<f64>,
+    /// Maximum number of time steps.
+    pub step_max: usize,
+    /// diffusion coefficient * dt / dx^2.
+    pub mu: f64,
+}
+
+impl NewParams for SpectralSolverNewParams {
+    fn validate_new_params(&self) -> Result<(), &'static str> {
+        if self.u.len() < 3 {
+            return Err("u must have at least 3 points for the periodic domain");
+        }
+        if self.step_max == 0 {
+            return Err("step_max must be positive");
+        }
+        if self.mu <= 0.0 {
+            return Err("mu must be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_spectral_integrate_works() {
+        // setup spectral solver with a sine wave of the wavenumber 2 and a constant, and run integrate()
+        let n_x = 16;
+        let x: Array1<f64> = Array1::linspace(-1.0, 1.0, n_x + 1);
+        let new_params = SpectralSolverNewParams {
+            u: x.mapv(|x| 1.0 + (2.0 * PI * x).sin()),
+            step_max: 10,
+            mu: 2.0,
+        };
+        let mut spectral_solver = SpectralSolver::new(new_params).unwrap();
+        for _ in 0..3 {
+            spectral_solver.integrate().unwrap();
+        }
+
+        // check if the sine wave decays exactly by e^{-k^2 \alpha t} with k = 2 \pi and t = 3 \mu \Delta x^2,
+        // while the constant is kept
+        let dx = 2.0 / n_x as f64;
+        let decay = (-(2.0 * PI).powi(2) * 3.0 * 2.0 * dx * dx).exp();
+        let u_exact = x.mapv(|x| 1.0 + decay * (2.0 * PI * x).sin());
+        let is_u_correctly_updated = (spectral_solver.u - u_exact)
+            .iter()
+            .all(|u| u.abs() < 1e-13);
+        assert!(is_u_correctly_updated);
+        assert_eq!(spectral_solver.step, 3);
+    }
+}